@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+/// Resource configuring and tracking the scene camera
+/// Zoom is applied through the orthographic projection scale, so Camera::viewport_to_world_2d
+/// (and with it agent picking) stays accurate at every zoom level
+#[derive(Resource, Reflect, Debug)]
+#[reflect(Resource)]
+pub struct CameraController {
+    /// Panning speed in screen pixels per second (scaled by zoom so panning feels the same at every level)
+    pub pan_speed: f32,
+    /// Fractional zoom change per scroll-wheel line
+    pub zoom_speed: f32,
+    /// Current projection scale (1.0 = one world unit per pixel, larger = zoomed out)
+    pub zoom: f32,
+    /// Closest zoom allowed
+    pub min_zoom: f32,
+    /// Furthest zoom allowed
+    pub max_zoom: f32,
+    /// Whether the camera tracks the selected NPC instead of responding to panning
+    pub follow_selected: bool,
+    /// How quickly the camera catches up with a followed NPC (per second, higher = tighter)
+    pub follow_smoothing: f32,
+}
+
+/// Resource holding the NPC picked for debugging by clicking on it
+#[derive(Resource, Reflect, Debug, Default, Clone, Copy, PartialEq)]
+#[reflect(Resource)]
+pub struct SelectedNpc(pub Option<Entity>);
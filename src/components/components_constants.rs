@@ -1,22 +1,72 @@
+use crate::components::components_environment::Zone;
 use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
 /// Resource for timing rumor injection
 #[derive(Resource, Reflect)]
 #[reflect(Resource)]
 pub struct RumorTimer(pub Timer);
 
+/// How starting positions of NPCs are laid out across the room
+/// Clustered starts let social-dynamics studies begin from segregated groups
+#[derive(Reflect, Debug, Clone, PartialEq, Default)]
+pub enum SpawnDistribution {
+    /// Independently and uniformly random over the whole room
+    #[default]
+    Uniform,
+    /// Agents dealt round-robin to the centers, each placed uniformly within `spread` of its center
+    Clustered { centers: Vec<Vec2>, spread: f32 },
+    /// Evenly spaced lattice covering the room, filled row by row
+    Grid,
+}
+
+/// What happens to an NPC that reaches the edge of the room
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BoundaryPolicy {
+    /// Bounces off the edge like an elastic collision
+    #[default]
+    Reflect,
+    /// Leaves through one edge and re-enters through the opposite one, making the space toroidal
+    /// The room is then left without walls, so nothing physically stops agents at the edge
+    Wrap,
+    /// Stops at the edge, keeping only the velocity along it
+    Clamp,
+}
+
 /// Resource for game simulation constants
-#[derive(Resource, Reflect)]
+#[derive(Resource, Reflect, Debug, Clone)]
 #[reflect(Resource)]
 pub struct GameConstants {
     /// Number of NPCs in the simulation
     pub num_npcs: usize,
+    /// Where NPCs are placed when the simulation starts
+    pub spawn_distribution: SpawnDistribution,
     /// Radius of each NPC
     pub npc_radius: f32,
+    /// Radius of the sensor collider around each environmental resource
+    pub resource_radius: f32,
+    /// Half width and half height of the walled room the simulation takes place in
+    /// The world's size is fixed here and never follows the window; the camera zooms to fit it instead
+    pub room_half_extents: Vec2,
+    /// How NPCs are kept within room_half_extents (see boundary_collision_system)
+    pub boundary_policy: BoundaryPolicy,
+    /// Distance from a wall at which wandering agents start steering back toward the interior
+    pub wander_boundary_margin: f32,
     /// Movement speed of NPCs
     pub npc_speed: f32,
+    /// Linear damping of NPC bodies, slowing them when no steering force is applied
+    pub npc_linear_damping: f32,
+    /// Body size of spawned NPCs relative to npc_radius; speed and damping follow it (see BodySize)
+    pub npc_size_scale: f32,
+    /// How fast an NPC turns to face its velocity, in radians per second
+    pub npc_turn_rate: f32,
+    /// Speed below which an NPC keeps its current facing instead of turning
+    pub facing_min_speed: f32,
     /// Social interaction distance
     pub social_distance: f32,
+    /// How long two agents interact after making contact before the social need is satisfied, in seconds
+    pub social_interaction_duration: f32,
     /// Decay rate for hunger
     pub hunger_decay: f32,
     /// Decay rate for thirst
@@ -49,15 +99,577 @@ pub struct GameConstants {
     /// See: "Attention and Performance" (Posner & Petersen, 1990)
     pub default_action_timeout: f32,
 
-    /// Distance threshold for considering a target unreachable
-    /// Based on spatial cognition: agents perceive targets >50 units as "far" in 2D space
-    /// Calibrated for 200-unit default vision range (25% of vision range)
+    /// Minimum headway over one StuckEscape window below which a pursuing agent counts as stuck
+    /// An agent at full speed covers several times this distance in a window, so only a wedged agent falls short
     pub stuck_distance_threshold: f32,
 
     /// Timeout multiplication factor for retry attempts
     /// Based on adaptive patience research: 20% increase per failure shows optimal persistence
     /// See: "Adaptive Control of Thought" (Anderson & Lebiere, 1998)
     pub timeout_retry_multiplier: f32,
+
+    /// How strongly contradicting evidence is discounted when updating beliefs (0.0-1.0)
+    /// Based on Confirmation Bias research (Nickerson, 1998): 0.0 = unbiased, 1.0 = contradictions ignored
+    pub confirmation_bias_strength: f32,
+
+    /// Willpower spent each time an agent chooses a desire other than its dominant one
+    /// Based on Ego Depletion Theory (Baumeister et al., 1998)
+    pub willpower_depletion_rate: f32,
+
+    /// Willpower recovered per second while the agent is resting
+    pub willpower_recovery_rate: f32,
+
+    /// Multiplier on willpower_recovery_rate while a fully satisfied agent relaxes in place
+    pub relax_recovery_multiplier: f32,
+
+    /// Fraction of normal decay the need being refilled keeps while an agent refills (0.0 = paused, 1.0 = unaffected)
+    /// Keeps decay from cancelling out slow refills
+    pub refill_decay_multiplier: f32,
+
+    /// Simulation ticks per second - needs, decisions, steering and physics run in FixedUpdate at this rate
+    /// so their behaviour does not depend on the render frame rate
+    pub simulation_tick_hz: f32,
+
+    /// Rapier substeps per simulation tick - more substeps shorten each physics step so fast bodies resolve contacts
+    pub physics_substeps: usize,
+
+    /// Whether NPC bodies use Rapier's continuous collision detection, which stops fast agents tunneling through thin walls
+    pub npc_ccd_enabled: bool,
+}
+
+/// A single constant that failed validation, with the value it was corrected to
+/// Values are reported as f32 so every violation is numerically observable
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstantViolation {
+    /// Name of the offending field
+    pub field: &'static str,
+    /// Value found before correction
+    pub original_value: f32,
+    /// Value the field holds after correction
+    pub corrected_value: f32,
+}
+
+impl GameConstants {
+    /// Checks every constant against its sane range and corrects invalid values in place
+    /// Non-finite or out-of-range values are reset to their defaults or clamped into range
+    /// Returns every violation found so callers can log and report them
+    pub fn validate(&mut self) -> Vec<ConstantViolation> {
+        let defaults = GameConstants::default();
+        let mut violations = Vec::new();
+
+        // Sizes, speeds and distances must be strictly positive for movement and collision to make sense
+        correct_positive("npc_radius", &mut self.npc_radius, defaults.npc_radius, &mut violations);
+        correct_positive("resource_radius", &mut self.resource_radius, defaults.resource_radius, &mut violations);
+        correct_positive("room_half_extents.x", &mut self.room_half_extents.x, defaults.room_half_extents.x, &mut violations);
+        correct_positive("room_half_extents.y", &mut self.room_half_extents.y, defaults.room_half_extents.y, &mut violations);
+        correct_positive("wander_boundary_margin", &mut self.wander_boundary_margin, defaults.wander_boundary_margin, &mut violations);
+        correct_positive("npc_speed", &mut self.npc_speed, defaults.npc_speed, &mut violations);
+        correct_positive("npc_linear_damping", &mut self.npc_linear_damping, defaults.npc_linear_damping, &mut violations);
+        correct_positive("npc_size_scale", &mut self.npc_size_scale, defaults.npc_size_scale, &mut violations);
+        correct_positive("npc_turn_rate", &mut self.npc_turn_rate, defaults.npc_turn_rate, &mut violations);
+        correct_positive("facing_min_speed", &mut self.facing_min_speed, defaults.facing_min_speed, &mut violations);
+        correct_positive("social_distance", &mut self.social_distance, defaults.social_distance, &mut violations);
+        correct_positive("social_interaction_duration", &mut self.social_interaction_duration, defaults.social_interaction_duration, &mut violations);
+        correct_positive("simulation_tick_hz", &mut self.simulation_tick_hz, defaults.simulation_tick_hz, &mut violations);
+        correct_positive("stuck_distance_threshold", &mut self.stuck_distance_threshold, defaults.stuck_distance_threshold, &mut violations);
+        correct_positive("default_action_timeout", &mut self.default_action_timeout, defaults.default_action_timeout, &mut violations);
+        correct_positive("relax_recovery_multiplier", &mut self.relax_recovery_multiplier, defaults.relax_recovery_multiplier, &mut violations);
+
+        // Decay rates are per-second changes on the normalized 0.0-1.0 need scale
+        clamp_unit("hunger_decay", &mut self.hunger_decay, &mut violations);
+        clamp_unit("thirst_decay", &mut self.thirst_decay, &mut violations);
+        clamp_unit("fatigue_regen", &mut self.fatigue_regen, &mut violations);
+        clamp_unit("safety_decay", &mut self.safety_decay, &mut violations);
+        clamp_unit("loneliness_decay", &mut self.loneliness_decay, &mut violations);
+        clamp_unit("confirmation_bias_strength", &mut self.confirmation_bias_strength, &mut violations);
+        clamp_unit("willpower_depletion_rate", &mut self.willpower_depletion_rate, &mut violations);
+        clamp_unit("willpower_recovery_rate", &mut self.willpower_recovery_rate, &mut violations);
+        clamp_unit("refill_decay_multiplier", &mut self.refill_decay_multiplier, &mut violations);
+
+        // At least one attempt is required before cognitive flexibility can kick in
+        if self.max_failure_attempts == 0 {
+            violations.push(ConstantViolation {
+                field: "max_failure_attempts",
+                original_value: 0.0,
+                corrected_value: defaults.max_failure_attempts as f32,
+            });
+            self.max_failure_attempts = defaults.max_failure_attempts;
+        }
+
+        // Rapier needs at least one step per tick to advance the physics at all
+        if self.physics_substeps == 0 {
+            violations.push(ConstantViolation {
+                field: "physics_substeps",
+                original_value: 0.0,
+                corrected_value: defaults.physics_substeps as f32,
+            });
+            self.physics_substeps = defaults.physics_substeps;
+        }
+
+        // Adaptive patience never shrinks on retry, so the multiplier must be at least 1.0
+        if !self.timeout_retry_multiplier.is_finite() || self.timeout_retry_multiplier < 1.0 {
+            let corrected = if self.timeout_retry_multiplier.is_finite() { 1.0 } else { defaults.timeout_retry_multiplier };
+            violations.push(ConstantViolation {
+                field: "timeout_retry_multiplier",
+                original_value: self.timeout_retry_multiplier,
+                corrected_value: corrected,
+            });
+            self.timeout_retry_multiplier = corrected;
+        }
+
+        violations
+    }
+}
+
+/// Resets a value that must be strictly positive to its default
+fn correct_positive(field: &'static str, value: &mut f32, default: f32, violations: &mut Vec<ConstantViolation>) {
+    if !value.is_finite() || *value <= 0.0 {
+        violations.push(ConstantViolation { field, original_value: *value, corrected_value: default });
+        *value = default;
+    }
+}
+
+/// Clamps a normalized rate into the 0.0-1.0 range
+fn clamp_unit(field: &'static str, value: &mut f32, violations: &mut Vec<ConstantViolation>) {
+    let corrected = if value.is_finite() { value.clamp(0.0, 1.0) } else { 0.0 };
+    if corrected != *value {
+        violations.push(ConstantViolation { field, original_value: *value, corrected_value: corrected });
+        *value = corrected;
+    }
+}
+
+/// Resource selecting which decision pipeline turns need changes into desires
+/// Only one pipeline is ever scheduled, so desires have a single writer
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Resource)]
+pub enum DecisionPipelineMode {
+    /// Legacy pipeline: threshold_monitoring_system fires ThresholdCrossedEvent and
+    /// desire_update_system switches Desire to whichever need crossed its threshold last
+    Direct,
+    /// Roadmap 1.3.2 pipeline: optimized_threshold_monitoring_system and the periodic trigger request
+    /// EvaluateDecision, and decision_making_system compares ALL competing desires by utility
+    #[default]
+    UtilityArbitrated,
+}
+
+/// Resource selecting how decay_basic_needs reports need changes
+/// Both forms are consumed by the threshold monitoring systems, so crossings are identical either way
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Resource)]
+pub enum NeedEventMode {
+    /// One NeedChangeEvent per changed need per agent per frame (up to five each)
+    #[default]
+    Granular,
+    /// One NeedsChangedBatch per agent per frame holding every changed need
+    Batched,
+}
+
+/// Resource selecting how a limited resource is split between agents claiming it in the same update
+/// Based on Cooperation Theory (Ostrom, 1990) - the rules around a commons shape who thrives under scarcity
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Resource)]
+pub enum ResourceContentionPolicy {
+    /// Each claimant receives an equal share, capped at what it asked for
+    ShareEqually,
+    /// Claimants are served in arrival order until the stock runs out
+    #[default]
+    FirstComeFirstServed,
+    /// Claimants are served in order of SocialInfluence, the standing the others grant them
+    DominanceBased,
+}
+
+/// Resource switching whole AI domains on or off, e.g. for a pure navigation study without social behavior
+/// Systems of a disabled domain stay scheduled but are skipped by their run condition; everything is enabled by default
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Resource)]
+pub struct AiFeatureFlags {
+    /// Decision making, action failure handling, beliefs and adaptive behavior
+    pub cognition: bool,
+    /// Apparent state and vision
+    pub perception: bool,
+    /// Need decay, stress, willpower and resource consumption
+    pub physiology: bool,
+    /// Social interactions, relationships and rumors
+    pub social: bool,
+    /// Resource discovery, target selection, steering and path learning
+    pub navigation: bool,
+}
+
+/// Resource coupling each need's decay rate to the deficits of the others
+/// Based on physiological interdependence - an exhausted body burns through food faster
+/// Entry [affected][source] adds that much of the source need's deficit (1.0 - value) to the affected need's decay multiplier
+/// Rows and columns follow NeedType order (hunger, thirst, rest, safety, social); all zeros, the default, leaves needs independent
+#[derive(Resource, Reflect, Debug, Clone, Default)]
+#[reflect(Resource)]
+pub struct NeedCouplingConfig {
+    pub matrix: [[f32; 5]; 5],
+}
+
+/// Resource configuring when indecisive agents get a commitment bias (see DecisionStability)
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct DecisionStabilityConfig {
+    /// Length of the window desire changes are counted over, in seconds
+    pub window_secs: f32,
+    /// Desire changes per minute above which an agent counts as dithering
+    pub max_changes_per_minute: f32,
+    /// Utility added to a dithering agent's current desire (0.0 disables commitment)
+    pub commitment_bias: f32,
+}
+
+/// Resource configuring the bounded-rationality lookahead of decision_making_system
+/// Deliberate agents project their needs over the time a trip to the nearest remembered resource would take,
+/// so a fast-draining need is addressed before it turns critical rather than after
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct DecisionLookaheadConfig {
+    /// Whether agents look ahead at all
+    pub enabled: bool,
+    /// System 2 engagement (see calculate_system2_engagement) an agent needs before it looks ahead
+    pub min_system2_engagement: f32,
+    /// Seconds a refill is expected to take once the agent has arrived
+    pub expected_refill_secs: f32,
+    /// Furthest the projection reaches, also used when the agent remembers no resource at all
+    pub max_horizon_secs: f32,
+}
+
+/// Resource configuring the fight-or-flight fast path of the utility pipeline
+/// A safety drop of at least sharp_drop in one NeedChangeEvent forces an immediate decision instead of waiting
+/// for the periodic evaluation, and an agent that switches to FindSafety because of it abandons its current path
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct SafetyChannelConfig {
+    /// Whether sharp safety drops bypass the regular decision cadence
+    pub enabled: bool,
+    /// Smallest single drop in safety (0.0-1.0) that counts as a threat
+    pub sharp_drop: f32,
+}
+
+/// Resource configuring the contagious panic safety cascade
+/// Based on Emotional Contagion (Hatfield et al., 1993) - an agent whose safety is critical panics, and agents
+/// that see or hear it feel a sudden safety drop and pass a damped alarm on, so the cascade dies out after a few hops
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct PanicConfig {
+    /// Whether panic spreads between agents
+    pub enabled: bool,
+    /// Safety (0.0-1.0) below which an agent panics at full intensity
+    pub panic_safety_threshold: f32,
+    /// Safety lost per unit of alarm an agent catches, so the hit shrinks with every hop like the alarm does
+    pub contagion_strength: f32,
+    /// Fraction of the perceived alarm an agent passes on (0.0-1.0)
+    pub damping: f32,
+    /// Weakest alarm that still unsettles an agent; anything below it stops the cascade
+    pub min_intensity: f32,
+    /// Seconds a caught panic lasts
+    pub alarm_duration: f32,
+    /// Distance within which a panicking agent is heard even when out of sight
+    pub hearing_distance: f32,
+}
+
+/// Resource configuring per-agent phase jitter for periodic decision evaluation
+/// Agents spawned together share a timer, so without jitter they all re-evaluate on the same frame; a random
+/// starting phase spreads them across the interval while leaving each agent's evaluation rate unchanged
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct DecisionJitterConfig {
+    /// Whether newly spawned agents start their evaluation timer at a random phase
+    pub enabled: bool,
+    /// Fraction of the agent's decision interval (0.0-1.0) the starting phase is drawn from
+    pub phase_fraction: f32,
+}
+
+/// Resource configuring diminishing returns when an agent tops off a need that is already nearly satisfied
+/// Based on Sensory-Specific Satiety (Rolls et al., 1981) - the reward of consuming more falls as the need fills,
+/// so a refill is worth the most when spent on the lowest need
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct SatiationConfig {
+    /// Whether refills are scaled down as the need approaches its high threshold
+    pub enabled: bool,
+    /// Fraction of the need's high threshold below which a refill still gives its full boost (0.0-1.0)
+    pub onset_fraction: f32,
+    /// Fraction of the full boost left for a need at or above its high threshold (0.0-1.0)
+    pub min_fraction: f32,
+}
+
+/// Resource configuring how much the safety of a resting spot affects recovery
+/// Based on the Vigilance Hypothesis of sleep (Lima et al., 2005) - an animal resting where it feels exposed stays
+/// partly alert and recovers less, so rest inside a hotel or safe zone is worth more than rest in the open
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct RestQualityConfig {
+    /// Whether rest recovery depends on the safety of the resting spot
+    pub enabled: bool,
+    /// Fraction of the full recovery (0.0-1.0) gained resting where no shelter offers any safety
+    pub open_ground_quality: f32,
+    /// Distance from a hotel within which an agent counts as resting inside it
+    pub hotel_radius: f32,
+}
+
+/// Subsystems whose log output can be tuned independently
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogCategory {
+    /// Need satisfaction, refilling, desire changes and action failures
+    Needs,
+    /// Target selection, discovery and navigation
+    Pathfinding,
+    /// Social contacts, relationships and conflicts
+    Social,
+    /// Exploration and path learning
+    Learning,
+}
+
+/// How much a log category emits
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogVerbosity {
+    /// Nothing is emitted
+    Off,
+    /// Routine messages are emitted at trace level, hidden unless tracing is enabled
+    #[default]
+    Trace,
+    /// Routine messages are emitted at info level
+    Info,
+}
+
+/// Resource controlling per-category log verbosity for the per-agent, per-frame messages
+/// With hundreds of agents these messages flood the log, so they are demoted to trace by default
+/// Emitted through the sim_log! macro, which checks the category before formatting anything
+#[derive(Resource, Reflect, Debug, Clone, Default)]
+#[reflect(Resource)]
+pub struct SimLogConfig {
+    pub needs: LogVerbosity,
+    pub pathfinding: LogVerbosity,
+    pub social: LogVerbosity,
+    pub learning: LogVerbosity,
+}
+
+impl SimLogConfig {
+    /// Verbosity configured for a category
+    pub fn verbosity(&self, category: LogCategory) -> LogVerbosity {
+        match category {
+            LogCategory::Needs => self.needs,
+            LogCategory::Pathfinding => self.pathfinding,
+            LogCategory::Social => self.social,
+            LogCategory::Learning => self.learning,
+        }
+    }
+}
+
+/// Resource switching all routine simulation logging on or off at runtime
+/// Closing the gate silences every sim_log! message and the periodic debug reports regardless of SimLogConfig
+/// or the tracing filter, so performance can be measured without the cost of formatting messages
+/// Warnings and errors are not routine and always get through
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct LogGate {
+    /// Whether routine messages are emitted at all
+    pub open: bool,
+}
+
+impl LogGate {
+    /// Whether routine messages may be emitted; a world without a LogGate logs as configured
+    pub fn is_open(gate: Option<&LogGate>) -> bool {
+        gate.is_none_or(|gate| gate.open)
+    }
+}
+
+/// Resource configuring the social interaction lifecycle
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct SocialConfig {
+    /// Seconds after an interaction completes before the same two agents may interact again
+    /// Based on Social Satiation research - repeated contact with the same partner yields diminishing reward
+    pub interaction_cooldown: f32,
+    /// Seconds after a pair starts touching during which further contact starts between them are ignored
+    /// Rapier can report the same pair starting contact repeatedly as overlapping bodies jitter apart and back
+    pub contact_debounce_window: f32,
+    /// Seconds between recomputations of every agent's SocialMetrics
+    pub metrics_interval: f32,
+    /// Seconds between recomputations of every agent's SocialInfluence
+    pub leadership_interval: f32,
+    /// How many times the mean influence an agent needs to be flagged as a potential leader
+    pub leadership_ratio: f32,
+    /// Fewest mutually aware agents group_formation_system treats as a gathering
+    pub gathering_min_members: usize,
+    /// Whether interactions start when agents come within interaction_distance instead of on physical contact
+    /// Collisions then only keep bodies apart
+    pub proximity_interactions: bool,
+    /// Centre-to-centre distance at which agents can converse when proximity_interactions is on
+    pub interaction_distance: f32,
+    /// Whether interactions cost SocialEnergy, so agents with a busy social life tire and withdraw for a while
+    /// Based on Social Satiation research - frequent contact brings fatigue as well as reward
+    pub social_fatigue: bool,
+    /// Seconds a completed interaction stays in SocialInteractionState::interaction_history
+    pub fatigue_window: f32,
+    /// How much each recent interaction speeds up energy drain and slows recovery
+    pub fatigue_per_interaction: f32,
+    /// SocialEnergy a completed interaction costs an agent without recent interactions
+    pub social_energy_cost: f32,
+    /// SocialEnergy regained per second while not interacting, for an agent without recent interactions
+    pub social_energy_recovery: f32,
+    /// SocialEnergy below which an agent declines new interactions
+    pub min_social_energy: f32,
+    /// Whether agreeable agents within interaction_distance of a neighbour in critical need share what they know
+    /// Based on the Empathy-Altruism Hypothesis (Batson, 1991) - visible need moves agreeable agents to help
+    pub helping: bool,
+    /// Agreeableness (0.0-1.0) an agent needs before it helps a neighbour
+    pub helping_min_agreeableness: f32,
+    /// Need level (0.0-1.0) below which a neighbour counts as in critical need
+    pub helping_critical_need: f32,
+    /// Level (0.0-1.0) the helper's own matching need must be at, so agents only help with what they can spare
+    pub helping_satisfied_need: f32,
+    /// Whether a completed InformationSharing interaction passes resource locations between the two agents
+    pub knowledge_exchange: bool,
+    /// Most previously unknown locations an agent takes on from its partner in one exchange, at full trust
+    pub max_shared_locations: usize,
+    /// Trust (0.0-1.0) in the partner below which what it says is ignored
+    pub min_sharing_trust: f32,
+}
+
+/// Resource configuring bounded-confidence opinion dynamics over each agent's Attitude
+/// Based on the Deffuant-Weisbuch model (Deffuant et al., 2000) - a completed interaction pulls two agents'
+/// attitudes together only if they already differ by less than the confidence bound
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct OpinionDynamicsConfig {
+    /// Largest attitude difference at which two interacting agents still influence each other
+    pub confidence_bound: f32,
+    /// Fraction of the gap each agent closes toward the other's attitude per interaction (0.0-0.5)
+    pub convergence_rate: f32,
+    /// Fraction of the gap each agent moves away from the other when they are beyond the bound (0.0 = no polarization)
+    pub repulsion_rate: f32,
+}
+
+/// What happens to an NPC whose need stays at 0.0 past NeedFailureConfig::critical_duration_secs
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NeedFailureConsequence {
+    /// Only the NeedCriticalEvent is fired
+    EventOnly,
+    /// The NPC collapses: it stops moving and deciding until the need is restored above 0.0
+    #[default]
+    Incapacitate,
+    /// The NPC dies and is removed through NpcDespawnEvent
+    Despawn,
+}
+
+/// Resource configuring critical need failure - starvation, dehydration and exhaustion
+/// Only hunger, thirst and rest can fail; running out of safety or company is distressing but not lethal
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct NeedFailureConfig {
+    /// Whether needs pinned at 0.0 have any consequence
+    pub enabled: bool,
+    /// Seconds a need must stay at 0.0 without a break before it fails
+    pub critical_duration_secs: f32,
+    /// What a failed need does to the NPC
+    pub consequence: NeedFailureConsequence,
+}
+
+/// Resource configuring what happens when an NPC leaves the simulation
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct LifecycleConfig {
+    /// Whether a despawning NPC passes part of its knowledge on to the agents around it
+    pub share_memory_on_death: bool,
+    /// Distance within which surviving agents receive the shared knowledge
+    pub memory_sharing_radius: f32,
+    /// Fraction (0.0-1.0) of the resource locations and rumors passed on to each nearby agent
+    pub memory_sharing_fraction: f32,
+}
+
+/// Resource configuring live tuning of GameConstants from a JSON file
+/// The file holds an object of GameConstants field names to new values, e.g. {"hunger_decay": 0.02};
+/// fields it does not mention keep their current values
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct ConstantsHotReloadConfig {
+    /// Path of the watched constants file
+    pub path: String,
+    /// Seconds between checks of the file's modification time
+    pub poll_interval_secs: f32,
+}
+
+/// Resource naming the RON scenario file read once at startup
+/// The file holds an optional seed, optional zones and GameConstants field names to values, e.g.
+/// (seed: 7, num_npcs: 20, zones: [(name: "well", min: (-100, -100), max: (0, 0))]);
+/// a missing file simply leaves the defaults in place
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct ScenarioFile {
+    /// Path of the scenario file
+    pub path: String,
+}
+
+/// Scenario read from a ScenarioFile by load_scenario
+#[derive(Debug, Clone)]
+pub struct ScenarioConfig {
+    /// Seed for SimulationRng, when the scenario fixes one
+    pub seed: Option<u64>,
+    /// Defaults with every override from the file applied and validated
+    pub game_constants: GameConstants,
+    /// Named regions for ZoneLayout, when the scenario defines any
+    pub zones: Option<Vec<Zone>>,
+}
+
+/// Why a scenario file could not be loaded
+#[derive(Debug)]
+pub enum ScenarioLoadError {
+    /// The file could not be read
+    Io(std::io::Error),
+    /// The file is not valid RON
+    Parse(ron::error::SpannedError),
+    /// The file is well-formed but names unknown fields or holds values the simulation cannot use
+    Validation(Vec<String>),
+}
+
+impl std::fmt::Display for ScenarioLoadError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScenarioLoadError::Io(error) => write!(formatter, "cannot read scenario file: {error}"),
+            ScenarioLoadError::Parse(error) => write!(formatter, "scenario file is not valid RON: {error}"),
+            ScenarioLoadError::Validation(problems) => write!(formatter, "invalid scenario: {}", problems.join("; ")),
+        }
+    }
+}
+
+impl std::error::Error for ScenarioLoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScenarioLoadError::Io(error) => Some(error),
+            ScenarioLoadError::Parse(error) => Some(error),
+            ScenarioLoadError::Validation(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ScenarioLoadError {
+    fn from(error: std::io::Error) -> Self {
+        ScenarioLoadError::Io(error)
+    }
+}
+
+impl From<ron::error::SpannedError> for ScenarioLoadError {
+    fn from(error: ron::error::SpannedError) -> Self {
+        ScenarioLoadError::Parse(error)
+    }
+}
+
+/// Resource holding the simulation's single seeded random number generator
+/// Systems that draw from this instead of the thread RNG replay identically for the same seed
+#[derive(Resource, Debug, Clone)]
+pub struct SimulationRng {
+    /// Seed the generator was created from, kept for reporting and reproduction
+    pub seed: u64,
+    /// The generator itself
+    pub rng: StdRng,
+}
+
+impl SimulationRng {
+    /// Creates a generator that always produces the same sequence for the same seed
+    pub fn from_seed(seed: u64) -> Self {
+        Self { seed, rng: StdRng::seed_from_u64(seed) }
+    }
 }
 
 /// Resource for color constants
@@ -1,11 +1,24 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
 
-use crate::components::components_constants::{ColorConstants, GameConstants, RumorTimer};
-use crate::components::components_environment::{Hotel, InteractableResource, Resource, ResourceOwnership, ResourceTransfer, ResourceType, Restaurant, SafeZone, Well};
-use crate::components::components_knowledge::KnowledgeBase;
-use crate::components::components_needs::{BasicNeeds, CurrentDesire, Desire, DesirePriorities, DesireThresholds, DualThreshold};
-use crate::components::components_npc::{ApparentState, Npc, PerceivedEntities, Personality, Posture, RefillState, VisionRange};
-use crate::components::components_pathfinding::{PathTarget, ResourceMemory, SteeringBehavior};
+use crate::components::components_camera::{CameraController, SelectedNpc};
+use crate::components::components_constants::{AiFeatureFlags, BoundaryPolicy, ColorConstants, ConstantsHotReloadConfig, DecisionJitterConfig, DecisionPipelineMode, GameConstants, LifecycleConfig, LogCategory, LogGate, LogVerbosity, DecisionLookaheadConfig, DecisionStabilityConfig, NeedCouplingConfig, NeedEventMode, NeedFailureConfig, NeedFailureConsequence, OpinionDynamicsConfig, ResourceContentionPolicy, PanicConfig, RestQualityConfig, RumorTimer, SafetyChannelConfig, SatiationConfig, ScenarioFile, SimLogConfig, SimulationRng, SocialConfig, SpawnDistribution};
+use crate::components::components_environment::{CurrentZone, CustomResourceSite, Hotel, InteractableResource, Resource, ResourceOwnership, ResourceReservation, ResourceTransfer, ResourceType, Restaurant, SafeZone, Wall, Weather, WeatherEffects, Well, Zone, ZoneLayout, ZoneOccupancy};
+use crate::components::components_inspection::{AgentComparison, AgentDiff, AgentSnapshot, FieldDifference};
+use crate::components::components_knowledge::{Beliefs, KnowledgeBase, Proposition, RumorParameters, RumorRecord, RumorRegistry};
+use crate::components::components_learning::{AdaptiveBehavior, CognitiveMap, ForageMemory, Landmark, LandmarkId, PathExperience, PathLearning, PathSegment};
+use crate::components::components_needs::{
+    BasicNeeds, CurrentDesire, CustomDesireDefinition, CustomDesireRegistry, CustomNeeds, DecisionStability, Desire, DesireThresholds, DualProcess,
+    DualThreshold, HomeostaticSetpoints, NeedDepletion, NeedPriorityWeights, NeedSetpoint, SafetyHabituation, Willpower,
+};
+use crate::components::components_npc::{
+    AgentTint, ApparentState, BodySize, Incapacitated, ModeledAgent, Mood, Npc, Panic, PerceivedEntities, Personality, PersonalityTrait, Posture, Proprioception,
+    RefillState, Perception, RelaxState, VisionConeOverlay, VisionRange, VisualizationMode, WorldModel,
+};
+use crate::components::components_performance::{AgentLod, AgentLodConfig, EventBacklog, EventBacklogConfig, FrameBudgetGovernor, FrameTimeMonitor, HistogramBucket, LearningMemoryConfig, ProfilerConfig, ProfilerReport, SystemTimings, TimingSummary};
+use crate::components::components_social::{ActiveInteraction, Attitude, ConflictCause, ContactDebounce, ConflictResolution, GatheringId, SocialProximity, InteractionType, Relationship, SocialEnergy, SocialInfluence, SocialInteractionState, SocialMetrics, SocialRelationships};
+use crate::components::components_pathfinding::{AvoidedTargets, IndexedResource, MemoryTrace, PathTarget, PhysicalContacts, ResourceMemory, ResourceSpatialIndex, SteeringBehavior, StuckEscape};
+use crate::components::components_telemetry::{DesireCensusConfig, DiagnosticOverlay, DiagnosticOverlayText, NeedHistory, NeedHistoryConfig, NeedSample, SocialHierarchy, SocietyWellbeing, TelemetryConfig, WellbeingConfig};
 
 /// Plugin for registering all custom components with Bevy's reflection system
 pub struct CustomComponentsPlugin;
@@ -17,39 +30,159 @@ impl Plugin for CustomComponentsPlugin {
             .register_type::<Npc>()
             .register_type::<Personality>()
             .register_type::<RefillState>()
+            .register_type::<RelaxState>()
+            .register_type::<Incapacitated>()
             // Vision components - NEW for 1.3.1 Perception System
             .register_type::<ApparentState>()
             .register_type::<PerceivedEntities>()
+            .register_type::<WorldModel>()
+            .register_type::<ModeledAgent>()
             .register_type::<VisionRange>()
+            .register_type::<Perception>()
+            .register_type::<Mood>()
+            .register_type::<Panic>()
+            .register_type::<Proprioception>()
+            .register_type::<BodySize>()
+            .register_type::<VisionConeOverlay>()
+            .register_type::<AgentTint>()
+            .register_type::<VisualizationMode>()
+            .register_type::<PersonalityTrait>()
             .register_type::<Posture>()
             // Knowledge components
             .register_type::<KnowledgeBase>()
+            .register_type::<Beliefs>()
+            .register_type::<Proposition>()
+            .register_type::<RumorParameters>()
+            .register_type::<RumorRecord>()
+            .register_type::<RumorRegistry>()
+            // Learning components
+            .register_type::<CognitiveMap>()
+            .register_type::<Landmark>()
+            .register_type::<LandmarkId>()
+            .register_type::<ForageMemory>()
+            .register_type::<AdaptiveBehavior>()
+            .register_type::<PathLearning>()
+            .register_type::<PathSegment>()
+            .register_type::<PathExperience>()
+            // Social components
+            .register_type::<SocialRelationships>()
+            .register_type::<Relationship>()
+            .register_type::<InteractionType>()
+            .register_type::<ConflictCause>()
+            .register_type::<ConflictResolution>()
+            .register_type::<SocialInteractionState>()
+            .register_type::<SocialEnergy>()
+            .register_type::<ContactDebounce>()
+            .register_type::<ActiveInteraction>()
+            .register_type::<SocialMetrics>()
+            .register_type::<SocialInfluence>()
+            .register_type::<GatheringId>()
+            .register_type::<SocialProximity>()
+            .register_type::<Attitude>()
             // Needs components
             .register_type::<BasicNeeds>()
             .register_type::<Desire>()
             .register_type::<DesireThresholds>()
-            .register_type::<DesirePriorities>()
+            .register_type::<NeedPriorityWeights>()
+            .register_type::<HomeostaticSetpoints>()
+            .register_type::<NeedSetpoint>()
             .register_type::<DualThreshold>()
             .register_type::<CurrentDesire>()
+            .register_type::<DualProcess>()
+            .register_type::<Willpower>()
+            .register_type::<SafetyHabituation>()
+            .register_type::<NeedDepletion>()
+            .register_type::<CustomNeeds>()
+            .register_type::<CustomDesireRegistry>()
+            .register_type::<CustomDesireDefinition>()
             // Environment components - New unified resource system
             .register_type::<Resource>()
             .register_type::<ResourceType>()
             .register_type::<ResourceOwnership>()
             .register_type::<ResourceTransfer>()
             .register_type::<InteractableResource>()
+            .register_type::<Wall>()
+            .register_type::<ResourceReservation>()
+            .register_type::<Zone>()
+            .register_type::<ZoneLayout>()
+            .register_type::<CurrentZone>()
+            .register_type::<ZoneOccupancy>()
             // Environment components - Legacy (for backward compatibility)
             .register_type::<Well>()
             .register_type::<Restaurant>()
             .register_type::<Hotel>()
             .register_type::<SafeZone>()
+            .register_type::<CustomResourceSite>()
             // Pathfinding components
             .register_type::<PathTarget>()
             .register_type::<SteeringBehavior>()
             .register_type::<ResourceMemory>()
+            .register_type::<MemoryTrace>()
+            .register_type::<AvoidedTargets>()
+            .register_type::<StuckEscape>()
+            .register_type::<PhysicalContacts>()
+            .register_type::<IndexedResource>()
             // Resources
             .register_type::<RumorTimer>()
             .register_type::<GameConstants>()
             .register_type::<ColorConstants>()
+            .register_type::<DecisionPipelineMode>()
+            .register_type::<NeedEventMode>()
+            .register_type::<SpawnDistribution>()
+            .register_type::<BoundaryPolicy>()
+            .register_type::<DecisionStabilityConfig>()
+            .register_type::<DecisionLookaheadConfig>()
+            .register_type::<SafetyChannelConfig>()
+            .register_type::<PanicConfig>()
+            .register_type::<DecisionJitterConfig>()
+            .register_type::<SatiationConfig>()
+            .register_type::<RestQualityConfig>()
+            .register_type::<ConstantsHotReloadConfig>()
+            .register_type::<ScenarioFile>()
+            .register_type::<DecisionStability>()
+            .register_type::<ResourceContentionPolicy>()
+            .register_type::<NeedCouplingConfig>()
+            .register_type::<AiFeatureFlags>()
+            .register_type::<SimLogConfig>()
+            .register_type::<LogGate>()
+            .register_type::<Weather>()
+            .register_type::<WeatherEffects>()
+            .register_type::<SocialConfig>()
+            .register_type::<OpinionDynamicsConfig>()
+            .register_type::<LifecycleConfig>()
+            .register_type::<NeedFailureConfig>()
+            .register_type::<NeedFailureConsequence>()
+            .register_type::<LogCategory>()
+            .register_type::<LogVerbosity>()
+            .register_type::<ResourceSpatialIndex>()
+            .register_type::<FrameTimeMonitor>()
+            .register_type::<FrameBudgetGovernor>()
+            .register_type::<AgentLodConfig>()
+            .register_type::<LearningMemoryConfig>()
+            .register_type::<AgentLod>()
+            .register_type::<EventBacklogConfig>()
+            .register_type::<EventBacklog>()
+            .register_type::<ProfilerConfig>()
+            .register_type::<SystemTimings>()
+            .register_type::<TimingSummary>()
+            .register_type::<HistogramBucket>()
+            .register_type::<ProfilerReport>()
+            .register_type::<CameraController>()
+            .register_type::<SelectedNpc>()
+            .register_type::<AgentComparison>()
+            .register_type::<AgentSnapshot>()
+            .register_type::<AgentDiff>()
+            .register_type::<FieldDifference>()
+            .register_type::<TelemetryConfig>()
+            .register_type::<WellbeingConfig>()
+            .register_type::<DesireCensusConfig>()
+            .register_type::<NeedHistoryConfig>()
+            .register_type::<NeedHistory>()
+            .register_type::<NeedSample>()
+            .register_type::<SocietyWellbeing>()
+            .register_type::<SocialHierarchy>()
+            .register_type::<DiagnosticOverlay>()
+            .register_type::<DiagnosticOverlayText>()
         ;
     }
 }
@@ -85,18 +218,123 @@ impl Default for DesireThresholds {
                 high_threshold: 0.6,   // Socialize when social satisfaction drops below 60%
                 low_threshold: 0.3,    // Start pathfinding when social satisfaction drops below 30%
             },
-            priority_weights: DesirePriorities::default(),
         }
     }
 }
 
-impl Default for DesirePriorities {
+impl Default for DecisionStabilityConfig {
     fn default() -> Self {
-        // Based on Maslow's hierarchy of needs - normalized 0.0-1.0
         Self {
-            thirst: 1.0,       // Maximum priority - dehydration is fastest killer
+            window_secs: 60.0,
+            max_changes_per_minute: 6.0,  // A change every ten seconds is already restless for needs decaying over minutes
+            commitment_bias: 0.1,         // Outweighs near-ties, not a clearly more urgent need
+        }
+    }
+}
+
+impl Default for LogGate {
+    fn default() -> Self {
+        Self {
+            open: true, // Close it to measure performance without logging overhead
+        }
+    }
+}
+
+impl Default for ScenarioFile {
+    fn default() -> Self {
+        Self {
+            path: "scenario.ron".to_string(), // Next to the binary, like game_constants.json
+        }
+    }
+}
+
+impl Default for ConstantsHotReloadConfig {
+    fn default() -> Self {
+        Self {
+            path: "game_constants.json".to_string(),
+            poll_interval_secs: 1.0, // Edits show up within a second without stat-ing the file every frame
+        }
+    }
+}
+
+impl Default for DecisionLookaheadConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,               // Opt-in: agents react to their current needs unless configured otherwise
+            min_system2_engagement: 0.5,  // Same point where dual-process agents stop satisficing
+            expected_refill_secs: 3.0,
+            max_horizon_secs: 30.0,       // A trip across the default room and back, at npc_speed
+        }
+    }
+}
+
+impl Default for SatiationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,       // Opt-in: refills top needs off in full unless configured otherwise
+            onset_fraction: 0.5,  // Returns start diminishing halfway to the high threshold
+            min_fraction: 0.1,    // Topping off a satisfied need is almost, but not entirely, wasted
+        }
+    }
+}
+
+impl Default for RestQualityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,             // Shelter matters: a hotel bed beats a nap in the open
+            open_ground_quality: 0.5,  // Resting exposed still recovers half as much
+            hotel_radius: 50.0,        // Same reach as a safe zone's influence
+        }
+    }
+}
+
+impl Default for SafetyChannelConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            sharp_drop: 0.2, // Several minutes of regular safety decay arriving at once
+        }
+    }
+}
+
+impl Default for PanicConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,               // Opt-in; one frightened agent can unsettle a whole crowd
+            panic_safety_threshold: 0.2,  // Only agents in real danger start a panic
+            contagion_strength: 1.0,      // Catching half an alarm next to the source costs half the safety scale
+            damping: 0.5,                 // Each hop passes on half the alarm it received
+            min_intensity: 0.1,           // Weaker alarm is shrugged off, ending the cascade
+            alarm_duration: 3.0,          // Seconds a panic lasts before it wears off
+            hearing_distance: 80.0,       // Screams carry a little beyond arm's reach
+        }
+    }
+}
+
+impl Default for DecisionJitterConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,       // Agents spawned on the same frame would otherwise re-evaluate in lockstep
+            phase_fraction: 1.0, // Spread starting phases over the whole interval
+        }
+    }
+}
+
+impl Default for HomeostaticSetpoints {
+    fn default() -> Self {
+        // The universal baseline: every need is comfortable only when full, so needs read at face value
+        let full = NeedSetpoint { target: 1.0, comfort_band: 0.0 };
+        Self { hunger: full, thirst: full, rest: full, safety: full, social: full }
+    }
+}
+
+impl Default for NeedPriorityWeights {
+    fn default() -> Self {
+        // Survival-first ordering based on Maslow's hierarchy of needs - normalized 0.0-1.0
+        Self {
+            safety: 1.0,       // Maximum priority - an immediate threat overrides everything
+            thirst: 0.95,      // Dehydration is the fastest killer among bodily needs
             hunger: 0.85,      // Very high priority - survival need
-            safety: 0.75,      // High priority - security need
             rest: 0.6,         // Medium priority - physiological need
             social: 0.3,       // Lower priority - social need
         }
@@ -112,13 +350,64 @@ impl Default for DualThreshold {
     }
 }
 
+impl Default for DualProcess {
+    fn default() -> Self {
+        Self {
+            stress: 0.0,               // Agents start calm
+            time_since_evaluation: 0.0,
+        }
+    }
+}
+
+impl Default for Willpower {
+    fn default() -> Self {
+        Self {
+            current: 1.0, // Agents start fully rested
+            max: 1.0,
+        }
+    }
+}
+
+impl Default for SafetyHabituation {
+    fn default() -> Self {
+        Self {
+            urgency_factor: 0.25, // A habituated threat still counts, just no longer above every other need
+            cooldown: 30.0,       // Matches AvoidedTargets, so the damping lasts as long as the failed safe zone is skipped
+            habituated_until: 0.0,
+        }
+    }
+}
+
+impl Default for BodySize {
+    fn default() -> Self {
+        // The base NPC: every dimension straight from the default GameConstants
+        let constants = GameConstants::default();
+        Self {
+            scale: 1.0,
+            radius: constants.npc_radius,
+            max_speed: constants.npc_speed,
+            linear_damping: constants.npc_linear_damping,
+        }
+    }
+}
+
 impl Default for GameConstants {
     fn default() -> Self {
         Self {
             num_npcs: 20,
+            spawn_distribution: SpawnDistribution::Uniform,
             npc_radius: 15.0,
+            resource_radius: 25.0,  // Slightly larger than an NPC so contact happens before bodies overlap
+            room_half_extents: Vec2::new(640.0, 360.0), // Fills the default 1280x720 window at zoom 1.0
+            boundary_policy: BoundaryPolicy::Reflect, // A closed room, as enclosed by its walls
+            wander_boundary_margin: 100.0, // Same as the wander distance, so the bias starts one look-ahead from a wall
             npc_speed: 200.0,
+            npc_linear_damping: 0.1,
+            npc_size_scale: 1.0,    // Every agent the size of npc_radius
+            npc_turn_rate: 6.0,     // About a third of a second for a half turn
+            facing_min_speed: 5.0,  // Below this the velocity direction is mostly jitter
             social_distance: 100.0,
+            social_interaction_duration: 2.0, // Long enough to be a conversation, short enough not to stall agents
             // Differentiated decay rates based on physiological urgency
             hunger_decay: 0.008,    // Moderate decay - can survive weeks without food
             thirst_decay: 0.015,    // Faster decay - can only survive days without water
@@ -134,12 +423,118 @@ impl Default for GameConstants {
             // Values based on cognitive psychology research
             max_failure_attempts: 3,        // 3 attempts before cognitive flexibility kicks in
             default_action_timeout: 15.0,   // 15 seconds focused attention span
-            stuck_distance_threshold: 50.0, // 25% of default vision range (200 units)
+            stuck_distance_threshold: 50.0, // An eighth of what full speed covers in a StuckEscape window
             timeout_retry_multiplier: 1.2,  // 20% increase per retry for adaptive patience
+            confirmation_bias_strength: 0.4, // Contradicting evidence counts for 60% of confirming evidence
+            willpower_depletion_rate: 0.2,   // Five consecutive overrides exhaust a full willpower budget
+            willpower_recovery_rate: 0.05,   // Twenty seconds of rest restores a full budget
+            relax_recovery_multiplier: 2.0,  // Idling with nothing to do recovers twice as fast as resting from fatigue
+            refill_decay_multiplier: 0.0,    // The need being refilled does not decay until the refill completes
+            simulation_tick_hz: 60.0,        // Matches a 60 FPS display, decoupled from it
+            physics_substeps: 1,             // One step per tick is enough at the default npc_speed
+            npc_ccd_enabled: true,           // Sweeps each NPC body along its motion so it cannot skip past walls
+        }
+    }
+}
+
+impl Default for AiFeatureFlags {
+    fn default() -> Self {
+        Self {
+            cognition: true,
+            perception: true,
+            physiology: true,
+            social: true,
+            navigation: true,
+        }
+    }
+}
+
+impl Default for SocialConfig {
+    fn default() -> Self {
+        Self {
+            interaction_cooldown: 10.0, // Five interaction lengths, so neighbours do not chat back-to-back
+            contact_debounce_window: 0.5, // Several physics steps, well under one interaction length
+            metrics_interval: 2.0,      // Relationships change once per completed interaction at most
+            leadership_interval: 5.0,   // Hierarchies emerge over many interactions, not single ones
+            leadership_ratio: 2.0,      // Twice the typical agent's standing
+            gathering_min_members: 3,   // A pair is a conversation, three make a crowd
+            proximity_interactions: false, // Off: agents socialize on touch, as they always have
+            interaction_distance: 60.0, // About two body widths of personal space between default NPCs
+            social_fatigue: false,      // Off: agents never tire of company
+            fatigue_window: 60.0,       // About thirty interaction lengths
+            fatigue_per_interaction: 0.5, // Two recent interactions double the drain and halve the recovery
+            social_energy_cost: 0.15,   // A rested agent manages about five back-to-back interactions
+            social_energy_recovery: 0.02, // A rested agent recovers fully in under a minute of solitude
+            min_social_energy: 0.2,     // Leaves room for one more interaction when pressed
+            helping: false,             // Off: agents keep what they know to themselves
+            helping_min_agreeableness: 0.6, // The agreeableness at which contacts already count as Helping
+            helping_critical_need: 0.3, // The need level at which contacts already count as Helping
+            helping_satisfied_need: 0.6, // Comfortably above the default seeking thresholds
+            knowledge_exchange: false,  // Off: agents only know the places they found themselves
+            max_shared_locations: 3,    // A handful of directions per conversation
+            min_sharing_trust: 0.1,     // Anyone past a couple of friendly encounters is believed
         }
     }
 }
 
+impl Default for OpinionDynamicsConfig {
+    fn default() -> Self {
+        Self {
+            confidence_bound: 0.3, // Below the model's 0.5 consensus point, so several opinion clusters survive
+            convergence_rate: 0.3, // Agents meet each other partway; 0.5 would make them agree outright
+            repulsion_rate: 0.0,   // Classic Deffuant model: distant agents ignore each other rather than polarize
+        }
+    }
+}
+
+impl Default for NeedFailureConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,                                     // Opt-in: needs normally bottom out at 0.0 harmlessly
+            critical_duration_secs: 30.0,                       // Several decision ticks to find food after hitting zero
+            consequence: NeedFailureConsequence::Incapacitate,  // Recoverable, so other agents can still help
+        }
+    }
+}
+
+impl Default for LifecycleConfig {
+    fn default() -> Self {
+        Self {
+            share_memory_on_death: false, // Opt-in: knowledge normally dies with the agent
+            memory_sharing_radius: 100.0, // Same as the social distance - only agents close enough to talk
+            memory_sharing_fraction: 0.5,
+        }
+    }
+}
+
+impl Default for Weather {
+    fn default() -> Self {
+        Self {
+            temperature: 20.0,    // Mild - inside the comfortable range, so needs decay at their normal rates
+            light_level: 1.0,     // Full daylight until scripted or cycled
+            day_length_secs: 0.0, // No day/night cycle by default
+        }
+    }
+}
+
+impl Default for WeatherEffects {
+    fn default() -> Self {
+        Self {
+            min_vision_fraction: 0.3,          // Agents still make out nearby shapes at night
+            comfortable_min_temperature: 10.0,
+            comfortable_max_temperature: 25.0,
+            decay_increase_per_degree: 0.05,   // A 45 degree day doubles thirst and fatigue
+            max_decay_multiplier: 3.0,
+        }
+    }
+}
+
+impl Default for SimulationRng {
+    fn default() -> Self {
+        Self::from_seed(42)
+    }
+}
+
 impl Default for ColorConstants {
     fn default() -> Self {
         Self {
@@ -161,12 +556,19 @@ impl Default for InteractableResource {
     }
 }
 
+impl Default for ResourceReservation {
+    fn default() -> Self {
+        Self::with_capacity(3) // A few agents can share a resource before others look elsewhere
+    }
+}
+
 impl Default for PathTarget {
     fn default() -> Self {
         Self {
             target_position: Vec2::ZERO,
+            origin_position: Vec2::ZERO,
             target_entity: None,
-            arrival_threshold: 30.0,
+            arrival_threshold: 30.0, // Two default NPC radii, replaced once a target is chosen
             has_target: false,
             target_set_time: 0.0,
             max_pursuit_time: 10.0, // 10 seconds max pursuit
@@ -184,6 +586,27 @@ impl Default for SteeringBehavior {
             wander_weight: 0.3,
             wander_angle: 0.0,
             wander_angle_change: 0.1,
+            avoidance_weight: 0.0, // Walls only steer wanderers, until an agent gets stuck
+            max_acceleration: 400.0, // Full reversal at npc_speed takes about a second
+        }
+    }
+}
+
+impl Default for ZoneLayout {
+    fn default() -> Self {
+        Self {
+            zones: Vec::new(),
+            cell_size: 100.0, // Same grid as ResourceSpatialIndex; a room-sized zone spans about a hundred cells
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl Default for ResourceSpatialIndex {
+    fn default() -> Self {
+        Self {
+            cell_size: 100.0, // Matches the default discovery radius so a query touches at most 3x3 cells
+            cells: default(),
         }
     }
 }
@@ -195,8 +618,86 @@ impl Default for ResourceMemory {
             known_restaurants: Vec::new(),
             known_hotels: Vec::new(),
             known_safe_zones: Vec::new(),
+            known_custom: HashMap::new(),
             discovery_radius: 100.0,
             memory_decay_rate: 0.01,
+            capacity_per_type: 16, // Only agents roaming a large world ever reach it
+            traces: Vec::new(),
+        }
+    }
+}
+
+impl Default for AvoidedTargets {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            cooldown: 30.0, // Two action timeouts - long enough to try every other known resource first
+        }
+    }
+}
+
+impl Default for StuckEscape {
+    fn default() -> Self {
+        Self {
+            anchor_position: Vec2::ZERO,
+            anchor_time: 0.0,
+            window: 2.0,          // At full speed an agent covers 400 units in this time
+            impulse_speed: 150.0, // Three quarters of the default NPC speed
+            duration: 0.5,
+            avoidance_boost: 2.0,
+            escaping_until: 0.0,
+            restore_avoidance_weight: None,
+        }
+    }
+}
+
+impl Default for CognitiveMap {
+    fn default() -> Self {
+        Self {
+            cell_size: 100.0,       // Same grid as the resource spatial index
+            activations: HashMap::new(),
+            activation_gain: 0.5,   // Two seconds inside a cell makes it fully familiar
+            activation_decay: 0.01, // Familiarity fades over roughly a hundred seconds away
+            landmarks: Vec::new(),
+            landmarks_named: 0,
+            landmark_merge_radius: 25.0, // The default resource radius - closer than that is the same place
+        }
+    }
+}
+
+impl Default for ForageMemory {
+    fn default() -> Self {
+        Self {
+            cell_size: 100.0,          // Same grid as the CognitiveMap
+            rewards: HashMap::new(),
+            reward_per_discovery: 1.0, // One find is enough to pull the agent back at full strength
+            reward_decay: 0.005,       // A single find is forgotten after a few minutes
+            attraction: 0.5,           // Leans the wander, never overrides it entirely
+        }
+    }
+}
+
+impl Default for AdaptiveBehavior {
+    fn default() -> Self {
+        Self {
+            exploration_rate: 0.1,
+            min_exploration_rate: 0.02,  // Even successful agents occasionally look around
+            max_exploration_rate: 0.5,   // Failing agents set off exploring about every two seconds
+            recent_success_rate: 0.8,    // Agents start out trusting what they know
+            outcome_smoothing: 0.2,      // Roughly the last five outcomes dominate
+            exploration_radius_cells: 2, // Consider the surrounding 5x5 cells
+        }
+    }
+}
+
+impl Default for PathLearning {
+    fn default() -> Self {
+        Self {
+            path_memory: Vec::new(),
+            path_values: HashMap::new(),
+            learning_rate: 0.3,     // A handful of consistent outcomes dominate a segment's value
+            max_path_memory: 50,
+            last_recorded_target_time: None,
         }
     }
 }
@@ -208,6 +709,15 @@ impl Default for ApparentState {
             is_carrying_item: false,
             posture: Posture::Neutral,
             is_interacting: false,
+            alarm: 0.0,
+        }
+    }
+}
+
+impl Default for Perception {
+    fn default() -> Self {
+        Self {
+            acuity: 0.5, // Average acuity, so discovery and vision ranges are used as configured
         }
     }
 }
@@ -224,6 +734,18 @@ impl Default for VisionRange {
     }
 }
 
+impl Default for VisionConeOverlay {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            selected_only: false,
+            arc_segments: 16, // Smooth enough for a 120 degree cone without drawing many lines per agent
+            seeing_color: Color::srgba(1.0, 0.8, 0.2, 0.6),
+            idle_color: Color::srgba(0.5, 0.7, 1.0, 0.3),
+        }
+    }
+}
+
 impl Default for PerceivedEntities {
     fn default() -> Self {
         Self {
@@ -233,3 +755,157 @@ impl Default for PerceivedEntities {
         }
     }
 }
+
+impl Default for WorldModel {
+    fn default() -> Self {
+        Self {
+            agents: HashMap::new(),
+            agent_memory_secs: 10.0, // Long enough to walk toward someone who just turned out of sight
+        }
+    }
+}
+
+impl Default for SocialEnergy {
+    fn default() -> Self {
+        Self { level: 1.0 } // Agents start rested
+    }
+}
+
+impl Default for FrameTimeMonitor {
+    fn default() -> Self {
+        Self {
+            budget_ms: 16.7,      // 60 FPS target
+            smoothed_ms: 0.0,
+            smoothing: 0.1,       // Roughly a 10-frame moving average
+            recovery_ratio: 0.8,  // Recover only once comfortably under budget
+            alert_active: false,
+        }
+    }
+}
+
+impl Default for CameraController {
+    fn default() -> Self {
+        Self {
+            pan_speed: 500.0,        // Crosses a 1280 px window in under three seconds
+            zoom_speed: 0.1,         // 10% per scroll line
+            zoom: 1.0,
+            min_zoom: 0.25,          // Close enough to read individual agents
+            max_zoom: 4.0,           // Far enough to see a room four times the window size
+            follow_selected: false,
+            follow_smoothing: 5.0,   // Catches up within a fraction of a second
+        }
+    }
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,                             // Opt-in, so ordinary runs leave no file behind
+            interval_secs: 5.0,                         // Fine enough to see need cycles, coarse enough to stay small
+            output_path: "telemetry.jsonl".to_string(),
+            flush_batch_size: 12,                       // Roughly one write per simulated minute
+            max_pending_lines: 720,                     // An hour of snapshots before the oldest are dropped
+        }
+    }
+}
+
+impl Default for FrameBudgetGovernor {
+    fn default() -> Self {
+        Self {
+            degraded: false,
+            decision_interval_multiplier: 1.0,
+            perception_frame_stride: 1,
+            degraded_decision_multiplier: 2.0, // Agents think half as often under load
+            degraded_perception_stride: 3,     // Vision updates every third frame under load
+        }
+    }
+}
+
+impl Default for AgentLodConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            degraded_stride: 4, // Learning catches up four ticks at a time while frames run over budget
+        }
+    }
+}
+
+impl Default for LearningMemoryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_map_cells: 400,             // A 20x20 patch of territory at the default cell size
+            max_path_values: 200,           // Routes between a few dozen landmarks
+            pressure_fraction: 0.5,         // Under memory pressure, halve what every agent keeps
+            consolidated_activation: 0.8,   // Well-trodden cells stay familiar
+            consolidated_value: 0.5,        // Routes with a clear learned verdict, good or bad, are kept
+            memory_alert_entries: 100_000,  // Roughly a thousand agents at their caps
+        }
+    }
+}
+
+impl Default for AgentLod {
+    fn default() -> Self {
+        Self {
+            stride: 1, // Full detail until the governor degrades
+            phase: 0,
+        }
+    }
+}
+
+impl Default for EventBacklogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            retained_updates: 2, // Bevy keeps each event for two event updates
+        }
+    }
+}
+
+impl Default for ProfilerConfig {
+    fn default() -> Self {
+        Self {
+            report_path: "profiler_report.json".to_string(),
+            max_samples: 36_000,       // Ten minutes at 60 FPS
+            histogram_bucket_ms: 2.0,
+            histogram_buckets: 20,     // Up to 40 ms, 25 FPS, before frames land in the open bucket
+        }
+    }
+}
+
+impl Default for RumorParameters {
+    fn default() -> Self {
+        Self {
+            transmission_rate: 0.5,
+            modification_probability: 0.05, // One retelling in twenty changes the story
+            transmission_decay: 0.8,         // Second-hand accounts are believed a little less
+            interference_strength: 0.3,
+        }
+    }
+}
+
+impl Default for DesireCensusConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: 1.0, // Desires change on decision ticks, so a census per second tracks them closely enough
+        }
+    }
+}
+
+impl Default for NeedHistoryConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: 1.0,                  // Needs drift over tens of seconds, so one sample a second keeps the shape
+            capacity: 120,                       // Two minutes of history per agent
+            plot_size: Vec2::new(120.0, 60.0),   // A few NPC widths, readable at the default zoom
+        }
+    }
+}
+
+impl Default for WellbeingConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: 1.0, // Needs move by about a percent per second, so finer updates add nothing
+        }
+    }
+}
@@ -1,4 +1,7 @@
+use crate::utils::helpers::pathfinding_helpers::world_to_cell;
 use bevy::prelude::*;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
 
 /// Unified resource component representing any type of resource in the environment
 /// Based on Environmental Psychology - resource availability affects behavior
@@ -35,6 +38,8 @@ pub enum ResourceType {
     Safety,
     /// Social gathering spaces - satisfies loneliness by providing social interaction
     Loneliness,
+    /// Satisfies the custom need registered under this id in the CustomDesireRegistry
+    Custom(u8),
 }
 
 /// Component for entities that can own and transfer resources
@@ -83,6 +88,12 @@ pub struct InteractableResource {
     pub regeneration_timer: f32,
 }
 
+/// Marker component for the static walls enclosing the simulation room
+/// Based on Environmental Psychology - bounded spaces shape movement and encounter patterns
+#[derive(Component, Debug, Reflect, Default)]
+#[reflect(Component)]
+pub struct Wall;
+
 // ================================
 // LEGACY COMPONENTS FOR BACKWARD COMPATIBILITY
 // These will be phased out in favor of the unified Resource system
@@ -124,6 +135,14 @@ pub struct Hotel {
     pub comfort_level: f64,
 }
 
+/// Component for places that satisfy a custom need (see CustomDesireRegistry)
+#[derive(Component, Debug, Reflect, Default)]
+#[reflect(Component)]
+pub struct CustomResourceSite {
+    /// Id of the custom desire this site satisfies
+    pub id: u8,
+}
+
 /// Legacy component for safety zones
 /// Based on Environmental Psychology - safe spaces for security needs
 #[derive(Component, Debug, Reflect, Default)]
@@ -136,3 +155,153 @@ pub struct SafeZone {
     pub capacity: i32,
     pub current_occupancy: i32,
 }
+
+/// Limited number of concurrent slots on a resource
+/// Based on Congestion Theory - agents claim a slot before committing to a target, so a popular well
+/// does not draw every thirsty agent at once
+#[derive(Component, Debug, Reflect, Clone)]
+#[reflect(Component)]
+pub struct ResourceReservation {
+    /// Maximum number of agents holding a slot at the same time
+    pub capacity: u8,
+    /// Agents currently holding a slot
+    pub holders: Vec<Entity>,
+}
+
+impl ResourceReservation {
+    pub fn with_capacity(capacity: u8) -> Self {
+        Self { capacity, holders: Vec::new() }
+    }
+
+    /// Claims a slot for the agent, returns false when every slot is taken by someone else
+    pub fn try_reserve(&mut self, agent: Entity) -> bool {
+        if self.holders.contains(&agent) {
+            return true;
+        }
+        if self.holders.len() >= self.capacity as usize {
+            return false;
+        }
+        self.holders.push(agent);
+        true
+    }
+
+    /// Frees the agent's slot, if it held one
+    pub fn release(&mut self, agent: Entity) {
+        self.holders.retain(|&holder| holder != agent);
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.holders.len() >= self.capacity as usize
+    }
+}
+
+/// A named rectangular region of the room used for spatial analytics
+/// Zones may overlap; an agent belongs to the first zone in ZoneLayout order that contains it
+#[derive(Reflect, Debug, Clone, PartialEq)]
+pub struct Zone {
+    pub name: String,
+    /// Lower-left corner in world units
+    pub min: Vec2,
+    /// Upper-right corner in world units
+    pub max: Vec2,
+}
+
+impl Zone {
+    pub fn new(name: impl Into<String>, min: Vec2, max: Vec2) -> Self {
+        Self { name: name.into(), min: min.min(max), max: min.max(max) }
+    }
+
+    /// Whether a position lies inside the zone, edges included
+    pub fn contains(&self, position: Vec2) -> bool {
+        position.cmpge(self.min).all() && position.cmple(self.max).all()
+    }
+}
+
+/// Resource dividing the room into named zones
+/// Based on Environmental Psychology - behaviour settings (Barker, 1968) shape where people congregate
+/// Empty by default, in which case every agent is unzoned; a scenario file can define the zones
+/// Zones are bucketed into a uniform grid like ResourceSpatialIndex, so locating an agent only tests the zones
+/// overlapping its cell; agent_zone_system rebuilds the grid whenever the layout changes
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct ZoneLayout {
+    pub zones: Vec<Zone>,
+    /// Side length of each grid cell in world units
+    pub cell_size: f32,
+    /// Indices into zones of every zone overlapping each grid cell, in layout order
+    pub cells: HashMap<IVec2, Vec<usize>>,
+}
+
+impl ZoneLayout {
+    /// Re-buckets every zone into the grid cells it overlaps
+    pub fn rebuild_index(&mut self) {
+        self.cells.clear();
+        for (index, zone) in self.zones.iter().enumerate() {
+            let min_cell = world_to_cell(zone.min, self.cell_size);
+            let max_cell = world_to_cell(zone.max, self.cell_size);
+            for x in min_cell.x..=max_cell.x {
+                for y in min_cell.y..=max_cell.y {
+                    self.cells.entry(IVec2::new(x, y)).or_default().push(index);
+                }
+            }
+        }
+    }
+
+    /// The first zone containing the position, if any, looked up through the grid
+    pub fn zone_at(&self, position: Vec2) -> Option<&Zone> {
+        self.cells
+            .get(&world_to_cell(position, self.cell_size))?
+            .iter()
+            .map(|&index| &self.zones[index])
+            .find(|zone| zone.contains(position))
+    }
+}
+
+/// Component tagging an agent with the zone it currently stands in
+#[derive(Component, Reflect, Debug, Clone, PartialEq, Default)]
+#[reflect(Component)]
+pub struct CurrentZone {
+    /// Name of the zone, None outside every zone
+    pub zone: Option<String>,
+}
+
+/// Resource counting agents per zone, refreshed every tick by agent_zone_system
+/// ML-HOOK: congregation patterns over time, surfaced in telemetry snapshots
+#[derive(Resource, Reflect, Serialize, Debug, Clone, PartialEq, Default)]
+#[reflect(Resource)]
+pub struct ZoneOccupancy {
+    /// Agents per zone name; every zone of the layout is listed, empty ones with 0
+    pub counts: BTreeMap<String, usize>,
+    /// Agents outside every zone
+    pub unzoned: usize,
+}
+
+/// Resource holding the current environmental conditions
+/// Based on Environmental Psychology - ambient conditions shape perception and physiological strain
+/// Scripted by setting the fields directly, or driven by a day/night cycle when day_length_secs > 0
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct Weather {
+    /// Ambient temperature in degrees Celsius
+    pub temperature: f32,
+    /// Ambient light (0.0 = pitch dark, 1.0 = full daylight)
+    pub light_level: f32,
+    /// Length of a full day/night cycle in seconds, 0.0 leaves light_level as scripted
+    pub day_length_secs: f32,
+}
+
+/// Resource bounding how strongly the Weather affects agents
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct WeatherEffects {
+    /// Fraction of the normal vision range left in complete darkness (0.0-1.0)
+    pub min_vision_fraction: f32,
+    /// Lowest temperature that causes no extra strain
+    pub comfortable_min_temperature: f32,
+    /// Highest temperature that causes no extra strain
+    pub comfortable_max_temperature: f32,
+    /// Extra thirst and rest decay per degree outside the comfortable range (0.05 = +5% per degree)
+    pub decay_increase_per_degree: f32,
+    /// Upper bound on the thirst and rest decay multiplier
+    pub max_decay_multiplier: f32,
+}
@@ -0,0 +1,110 @@
+use bevy::prelude::*;
+use serde::Serialize;
+use std::collections::BTreeSet;
+
+use crate::components::components_constants::{AiFeatureFlags, GameConstants, SocialConfig};
+
+/// One parameter set an experiment is run under
+/// Everything else about the scenario (seed, population placement, tick count) is shared between variants
+#[derive(Debug, Clone)]
+pub struct ExperimentVariant {
+    /// Label used in the comparison report
+    pub name: String,
+    /// Constants the headless simulation runs with; num_npcs sets the population size
+    pub game_constants: GameConstants,
+    /// Social lifecycle configuration the headless simulation runs with
+    pub social_config: SocialConfig,
+    /// AI domains the headless simulation runs
+    pub feature_flags: AiFeatureFlags,
+}
+
+impl ExperimentVariant {
+    /// Creates a variant running with the default configuration
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            game_constants: GameConstants::default(),
+            social_config: SocialConfig::default(),
+            feature_flags: AiFeatureFlags::default(),
+        }
+    }
+}
+
+/// Resource accumulating experiment measurements while a headless run is in progress
+#[derive(Resource, Debug, Default)]
+pub struct ExperimentRecorder {
+    /// Simulation ticks measured so far
+    pub ticks: u32,
+    /// Sum over all measured ticks of the population's mean need satisfaction
+    pub satisfaction_sum: f64,
+    /// Social interactions that started during the run
+    pub interactions_started: usize,
+    /// Social interactions that ran to completion during the run
+    pub interactions_completed: usize,
+    /// Number of NPCs alive when the run began
+    pub initial_population: usize,
+}
+
+/// Resource collecting every simulation event of a headless run as one line of text per event, in emission order
+/// Only present in runs started through ExperimentRunner::record_event_stream, which compare against a golden file
+/// Floats are written at full precision, so any divergence between two runs shows up as a changed line
+#[derive(Resource, Debug, Default)]
+pub struct EventStreamRecorder {
+    /// Recorded events, each prefixed with the tick it was observed on
+    pub lines: Vec<String>,
+}
+
+/// Resource holding the NPC pairs touching at the end of the previous headless tick
+/// Stands in for Rapier's contact tracking, so contacts are reported once when they begin
+#[derive(Resource, Debug, Default)]
+pub struct HeadlessContacts {
+    /// Touching pairs in canonical order (lowest entity first)
+    pub pairs: BTreeSet<(Entity, Entity)>,
+}
+
+/// Aggregate outcome of one headless run
+#[derive(Serialize, Debug, Clone, PartialEq, Default)]
+pub struct ExperimentMetrics {
+    /// Simulation ticks the run lasted
+    pub ticks: u32,
+    /// Mean need satisfaction (0.0-1.0) over all agents, needs and ticks
+    pub mean_satisfaction: f32,
+    /// Social interactions that started during the run
+    pub interactions_started: usize,
+    /// Social interactions that ran to completion during the run
+    pub interactions_completed: usize,
+    /// Agents that left the simulation during the run
+    pub deaths: usize,
+}
+
+/// Side-by-side report of two variants run on the same seeded scenario
+/// Deltas are candidate minus baseline
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ExperimentComparison {
+    pub seed: u64,
+    pub baseline_name: String,
+    pub baseline: ExperimentMetrics,
+    pub candidate_name: String,
+    pub candidate: ExperimentMetrics,
+    pub mean_satisfaction_delta: f32,
+    pub interactions_started_delta: i64,
+    pub interactions_completed_delta: i64,
+    pub deaths_delta: i64,
+}
+
+impl ExperimentComparison {
+    /// Builds the report for two finished runs
+    pub fn new(seed: u64, baseline: &ExperimentVariant, baseline_metrics: ExperimentMetrics, candidate: &ExperimentVariant, candidate_metrics: ExperimentMetrics) -> Self {
+        Self {
+            seed,
+            baseline_name: baseline.name.clone(),
+            candidate_name: candidate.name.clone(),
+            mean_satisfaction_delta: candidate_metrics.mean_satisfaction - baseline_metrics.mean_satisfaction,
+            interactions_started_delta: candidate_metrics.interactions_started as i64 - baseline_metrics.interactions_started as i64,
+            interactions_completed_delta: candidate_metrics.interactions_completed as i64 - baseline_metrics.interactions_completed as i64,
+            deaths_delta: candidate_metrics.deaths as i64 - baseline_metrics.deaths as i64,
+            baseline: baseline_metrics,
+            candidate: candidate_metrics,
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+
+use crate::components::components_knowledge::KnowledgeBase;
+use crate::components::components_needs::{BasicNeeds, Desire};
+use crate::components::components_pathfinding::ResourceMemory;
+use crate::components::components_social::SocialRelationships;
+
+/// Behavioural state of one agent at one moment, captured for side-by-side debugging
+/// Collections are summarised as counts and means so two agents can be compared field by field
+#[derive(Reflect, Debug, Clone, Default)]
+pub struct AgentSnapshot {
+    pub needs: BasicNeeds,
+    pub desire: Desire,
+    /// Number of agents this agent has a relationship with
+    pub relationship_count: usize,
+    /// Mean affinity over all relationships (0.0 without any)
+    pub mean_affinity: f32,
+    /// Mean trust over all relationships (0.0 without any)
+    pub mean_trust: f32,
+    pub known_wells: usize,
+    pub known_restaurants: usize,
+    pub known_hotels: usize,
+    pub known_safe_zones: usize,
+    /// Rumors and rumor variants the agent has heard
+    pub known_rumors: usize,
+}
+
+impl AgentSnapshot {
+    /// Captures an agent's state; components the agent lacks are recorded as empty
+    pub fn capture(
+        needs: &BasicNeeds,
+        desire: &Desire,
+        relationships: Option<&SocialRelationships>,
+        memory: Option<&ResourceMemory>,
+        knowledge: Option<&KnowledgeBase>,
+    ) -> Self {
+        let relationships: Vec<_> = relationships.map_or_else(Vec::new, |social| social.relationships.values().collect());
+        let count = relationships.len().max(1) as f32;
+        let mean_affinity = relationships.iter().map(|relationship| relationship.affinity).sum::<f32>() / count;
+        let mean_trust = relationships.iter().map(|relationship| relationship.trust).sum::<f32>() / count;
+
+        Self {
+            needs: *needs,
+            desire: *desire,
+            relationship_count: relationships.len(),
+            mean_affinity,
+            mean_trust,
+            known_wells: memory.map_or(0, |memory| memory.known_wells.len()),
+            known_restaurants: memory.map_or(0, |memory| memory.known_restaurants.len()),
+            known_hotels: memory.map_or(0, |memory| memory.known_hotels.len()),
+            known_safe_zones: memory.map_or(0, |memory| memory.known_safe_zones.len()),
+            known_rumors: knowledge.map_or(0, |knowledge| knowledge.known_rumors.len()),
+        }
+    }
+
+    /// Lists every field whose value differs between this snapshot and another
+    /// Values are compared exactly, so even a last-bit divergence between seeded agents shows up
+    pub fn diff(&self, other: &AgentSnapshot) -> AgentDiff {
+        let mut differences = Vec::new();
+        let mut compare = |field: &str, first: String, second: String| {
+            if first != second {
+                differences.push(FieldDifference { field: field.to_string(), first, second });
+            }
+        };
+
+        compare("needs.hunger", self.needs.hunger.to_string(), other.needs.hunger.to_string());
+        compare("needs.thirst", self.needs.thirst.to_string(), other.needs.thirst.to_string());
+        compare("needs.rest", self.needs.rest.to_string(), other.needs.rest.to_string());
+        compare("needs.safety", self.needs.safety.to_string(), other.needs.safety.to_string());
+        compare("needs.social", self.needs.social.to_string(), other.needs.social.to_string());
+        compare("desire", format!("{:?}", self.desire), format!("{:?}", other.desire));
+        compare("relationship_count", self.relationship_count.to_string(), other.relationship_count.to_string());
+        compare("mean_affinity", self.mean_affinity.to_string(), other.mean_affinity.to_string());
+        compare("mean_trust", self.mean_trust.to_string(), other.mean_trust.to_string());
+        compare("known_wells", self.known_wells.to_string(), other.known_wells.to_string());
+        compare("known_restaurants", self.known_restaurants.to_string(), other.known_restaurants.to_string());
+        compare("known_hotels", self.known_hotels.to_string(), other.known_hotels.to_string());
+        compare("known_safe_zones", self.known_safe_zones.to_string(), other.known_safe_zones.to_string());
+        compare("known_rumors", self.known_rumors.to_string(), other.known_rumors.to_string());
+
+        AgentDiff { differences }
+    }
+}
+
+/// One field on which two agent snapshots disagree
+#[derive(Reflect, Debug, Clone, PartialEq)]
+pub struct FieldDifference {
+    /// Snapshot field path, e.g. "needs.thirst"
+    pub field: String,
+    /// Value in the first snapshot
+    pub first: String,
+    /// Value in the second snapshot
+    pub second: String,
+}
+
+/// Every difference between two agent snapshots, in snapshot field order
+#[derive(Reflect, Debug, Clone, Default, PartialEq)]
+pub struct AgentDiff {
+    pub differences: Vec<FieldDifference>,
+}
+
+impl AgentDiff {
+    /// Whether the two snapshots were identical
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+
+/// Resource backing the inspector's compare mode
+/// Shift-click picks the agent compared against the SelectedNpc; the diff is refreshed every frame
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+pub struct AgentComparison {
+    /// Agent compared against the selected one, None when compare mode is off
+    pub compared: Option<Entity>,
+    /// Snapshot of the selected agent
+    pub selected_snapshot: Option<AgentSnapshot>,
+    /// Snapshot of the compared agent
+    pub compared_snapshot: Option<AgentSnapshot>,
+    /// Differences from the selected agent to the compared one
+    pub diff: Option<AgentDiff>,
+}
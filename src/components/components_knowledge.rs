@@ -3,12 +3,112 @@ use std::collections::HashMap;
 
 /// Component representing an NPC's knowledge base
 /// Based on Cognitive Science - memory as associative networks
-#[derive(Component, Reflect, PartialEq, Debug, Default)]
+#[derive(Component, Reflect, PartialEq, Debug, Default, Clone)]
 #[reflect(Component)]
 pub struct KnowledgeBase {
     /// Whether the NPC knows the rumor (legacy field for backward compatibility)
     pub knows_rumor: bool,
-    /// Map of rumor content to belief strength (0.0-1.0)
+    /// Map of rumor ID to belief strength (0.0-1.0), one entry per rumor or variant the agent knows
     /// Based on Social Psychology - rumors have varying belief levels
     pub known_rumors: HashMap<String, f32>,
-}
\ No newline at end of file
+}
+
+/// Spread parameters carried by one rumor
+/// Based on the roadmap's TransmissionRule - each rumor has its own transmission, mutation and decay rates
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+pub struct RumorParameters {
+    /// Base chance of passing the rumor on in one contact, before distance and personality scaling
+    pub transmission_rate: f32,
+    /// Chance (0.0-1.0) that the receiver hears an altered version, which circulates as a new variant
+    pub modification_probability: f32,
+    /// Fraction of the sender's belief the receiver starts with
+    pub transmission_decay: f32,
+    /// Fraction (0.0-1.0) by which adopting one version weakens belief in competing versions of the same rumor
+    pub interference_strength: f32,
+}
+
+/// One rumor in circulation
+#[derive(Reflect, Debug, Clone, PartialEq)]
+pub struct RumorRecord {
+    /// Spread parameters of this rumor (variants inherit their parent's)
+    pub parameters: RumorParameters,
+    /// ID of the original rumor this one descends from, its own ID if it is an original
+    pub origin: String,
+    /// ID of the version this one was altered from, None for originals
+    pub parent: Option<String>,
+}
+
+/// Resource registering every rumor in circulation and the variants transmission has produced
+/// Rumors spreading without a record use default_parameters and count as their own origin
+#[derive(Resource, Reflect, Debug, Clone, Default)]
+#[reflect(Resource)]
+pub struct RumorRegistry {
+    /// Records keyed by rumor ID (the key used in KnowledgeBase::known_rumors)
+    pub rumors: HashMap<String, RumorRecord>,
+    /// Parameters of rumors injected without explicit ones
+    pub default_parameters: RumorParameters,
+    /// Variants created so far, numbers each new variant ID
+    pub variants_created: u32,
+}
+
+impl RumorRegistry {
+    /// Registers an original rumor with its own spread parameters
+    pub fn register(&mut self, rumor: impl Into<String>, parameters: RumorParameters) {
+        let rumor = rumor.into();
+        self.rumors.insert(rumor.clone(), RumorRecord { parameters, origin: rumor, parent: None });
+    }
+
+    /// Spread parameters of a rumor
+    pub fn parameters(&self, rumor: &str) -> RumorParameters {
+        self.rumors.get(rumor).map_or(self.default_parameters, |record| record.parameters)
+    }
+
+    /// ID of the original a rumor descends from
+    pub fn origin<'a>(&'a self, rumor: &'a str) -> &'a str {
+        self.rumors.get(rumor).map_or(rumor, |record| record.origin.as_str())
+    }
+
+    /// Records an altered version of `parent` and returns its new ID
+    /// IDs are numbered in creation order, so replays of a seeded run produce the same IDs
+    pub fn register_variant(&mut self, parent: &str) -> String {
+        self.variants_created += 1;
+        let origin = self.origin(parent).to_string();
+        let variant = format!("{origin}#v{}", self.variants_created);
+        let record = RumorRecord { parameters: self.parameters(parent), origin, parent: Some(parent.to_string()) };
+        self.rumors.insert(variant.clone(), record);
+        variant
+    }
+}
+
+/// Propositions an NPC can hold beliefs about
+/// Kept deliberately small so every belief stays observable and numerically trackable
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Proposition {
+    /// "The rumors circulating around me are true"
+    RumorsAreCredible,
+    /// "Water can be found in this world"
+    WaterAvailable,
+    /// "Food can be found in this world"
+    FoodAvailable,
+    /// "There is somewhere to rest"
+    RestAvailable,
+    /// "There is somewhere safe to go"
+    SafetyAvailable,
+}
+
+/// Component representing an NPC's beliefs about the world
+/// Based on Bayesian Belief Updating with Confirmation Bias (Nickerson, 1998)
+/// - evidence that agrees with the current belief is accepted more readily than evidence against it
+#[derive(Component, Reflect, PartialEq, Debug, Default)]
+#[reflect(Component)]
+pub struct Beliefs {
+    /// Map of proposition to certainty (0.0 = certainly false, 0.5 = undecided, 1.0 = certainly true)
+    pub certainties: HashMap<Proposition, f32>,
+}
+
+impl Beliefs {
+    /// Certainty held about a proposition, undecided (0.5) when the NPC has no opinion yet
+    pub fn certainty(&self, proposition: Proposition) -> f32 {
+        self.certainties.get(&proposition).copied().unwrap_or(0.5)
+    }
+}
@@ -0,0 +1,179 @@
+use crate::utils::helpers::pathfinding_helpers::world_to_cell;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Component representing an NPC's grid-based map of where it has been
+/// Based on Grid Cell research (Hafting et al., 2005) - familiarity with a region builds up while
+/// the agent occupies it and slowly fades when it stays away
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct CognitiveMap {
+    /// Side length of each grid cell in world units
+    pub cell_size: f32,
+    /// Activation (familiarity) per grid cell, 0.0 = never visited, 1.0 = fully familiar
+    pub activations: HashMap<IVec2, f32>,
+    /// Activation gained per second while the agent is inside a cell
+    pub activation_gain: f32,
+    /// Activation lost per second by every cell the agent is not in
+    pub activation_decay: f32,
+    /// Places the agent has singled out, each under the reference it knows it by
+    pub landmarks: Vec<Landmark>,
+    /// Landmarks the agent names itself, so each gets a fresh LandmarkId
+    pub landmarks_named: u32,
+    /// Distance within which two landmarks are taken to be the same place
+    pub landmark_merge_radius: f32,
+}
+
+/// Identity of a landmark: the agent that first named it and its index among that agent's landmarks
+/// Agents that mapped a place independently know it under different ids until they reconcile them
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LandmarkId {
+    pub origin: Entity,
+    pub index: u32,
+}
+
+/// A remembered place an agent navigates by
+/// Based on Landmark-based Navigation research (Siegel & White, 1975) - salient places anchor a cognitive map,
+/// and shared landmarks let agents give each other directions
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+pub struct Landmark {
+    pub id: LandmarkId,
+    pub position: Vec2,
+}
+
+impl CognitiveMap {
+    /// Activation of a grid cell, 0.0 for cells the agent has never visited
+    pub fn activation(&self, cell: IVec2) -> f32 {
+        self.activations.get(&cell).copied().unwrap_or(0.0)
+    }
+
+    /// Landmark within landmark_merge_radius of a position, if the agent already knows one there
+    pub fn landmark_near(&self, position: Vec2) -> Option<&Landmark> {
+        self.landmarks.iter().find(|landmark| landmark.position.distance(position) <= self.landmark_merge_radius)
+    }
+
+    /// Grid cell containing a world position
+    pub fn cell_at(&self, position: Vec2) -> IVec2 {
+        world_to_cell(position, self.cell_size)
+    }
+
+    /// World position of the center of a grid cell
+    pub fn cell_center(&self, cell: IVec2) -> Vec2 {
+        (cell.as_vec2() + Vec2::splat(0.5)) * self.cell_size
+    }
+}
+
+/// Component remembering which grid cells an NPC has found resources in
+/// Based on Optimal Foraging Theory (Charnov, 1976) - foragers return to patches that paid off before
+/// Idle wandering drifts toward the most rewarding cell, blending learned exploitation into exploration
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct ForageMemory {
+    /// Side length of each grid cell in world units
+    pub cell_size: f32,
+    /// Remembered reward per grid cell; cells never rewarded are absent
+    pub rewards: HashMap<IVec2, f32>,
+    /// Reward added to a cell each time a resource is discovered in it
+    pub reward_per_discovery: f32,
+    /// Reward every cell loses per second, so stale patches are eventually forgotten
+    pub reward_decay: f32,
+    /// Strength (0.0-1.0) of the pull toward the best cell relative to the random wander
+    pub attraction: f32,
+}
+
+impl ForageMemory {
+    /// Grid cell containing a world position
+    pub fn cell_at(&self, position: Vec2) -> IVec2 {
+        world_to_cell(position, self.cell_size)
+    }
+
+    /// World position of the center of a grid cell
+    pub fn cell_center(&self, cell: IVec2) -> Vec2 {
+        (cell.as_vec2() + Vec2::splat(0.5)) * self.cell_size
+    }
+}
+
+/// Component holding the learned exploration/exploitation balance of an NPC
+/// Based on the Exploration-Exploitation Tradeoff (Cohen et al., 2007) - agents whose actions keep
+/// failing explore more, agents whose actions keep succeeding stick to what they know
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct AdaptiveBehavior {
+    /// Chance per second (0.0-1.0) that an idle agent sets off toward unexplored territory
+    pub exploration_rate: f32,
+    /// Exploration rate of an agent whose actions always succeed
+    pub min_exploration_rate: f32,
+    /// Exploration rate of an agent whose actions always fail
+    pub max_exploration_rate: f32,
+    /// Exponentially smoothed fraction of recent actions that succeeded (0.0-1.0)
+    pub recent_success_rate: f32,
+    /// Weight given to each new outcome when smoothing the success rate (0.0-1.0)
+    pub outcome_smoothing: f32,
+    /// How many cells around the agent are considered when choosing where to explore
+    pub exploration_radius_cells: i32,
+}
+
+/// A route between two landmarks, identified by the CognitiveMap cells it starts and ends in
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PathSegment {
+    /// Cell the agent set off from
+    pub from: IVec2,
+    /// Cell containing the destination
+    pub to: IVec2,
+}
+
+/// One remembered attempt at traversing a PathSegment
+/// ML-HOOK: (segment, reward) pairs are the training signal for learned path values
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+pub struct PathExperience {
+    /// The route that was attempted
+    pub segment: PathSegment,
+    /// Seconds spent on the attempt
+    pub traversal_time: f32,
+    /// Whether the destination was reached
+    pub success: bool,
+    /// Reward assigned to the attempt (positive for fast arrivals, negative for failures)
+    pub reward: f32,
+    /// Whether path_learning_system has already folded this experience into path_values
+    pub learned: bool,
+}
+
+/// Component holding an NPC's experience of routes and the values it has learned for them
+/// Based on Temporal Difference Learning (Sutton, 1988) - each experience nudges the segment's value
+/// toward the reward it produced
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct PathLearning {
+    /// Most recent traversal experiences, oldest first
+    pub path_memory: Vec<PathExperience>,
+    /// Learned value per segment (expected reward of taking that route)
+    pub path_values: HashMap<PathSegment, f32>,
+    /// Fraction of the prediction error applied per experience (0.0-1.0)
+    pub learning_rate: f32,
+    /// Maximum number of experiences kept in path_memory
+    pub max_path_memory: usize,
+    /// target_set_time of the last pursuit recorded, so an arrival is only recorded once
+    pub last_recorded_target_time: Option<f32>,
+}
+
+impl PathLearning {
+    /// Learned value of a segment, 0.0 (no expectation either way) for untried routes
+    pub fn value(&self, segment: PathSegment) -> f32 {
+        self.path_values.get(&segment).copied().unwrap_or(0.0)
+    }
+}
+
+/// Portable snapshot of what an agent has learned: its route values and the territory it knows
+/// Cells are stored with the cell size they were recorded at, so the model can be loaded into a body whose
+/// CognitiveMap uses a different grid; nothing in it refers to entities, so no IDs need remapping
+/// ML-HOOK: lets a trained agent's knowledge be saved, compared and transplanted between runs
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LearnedModel {
+    /// Side length of the grid cells below, in world units
+    pub cell_size: f32,
+    /// Learned value per route as (from cell, to cell, value)
+    pub path_values: Vec<([i32; 2], [i32; 2], f32)>,
+    /// CognitiveMap activation per cell
+    pub activations: Vec<([i32; 2], f32)>,
+}
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
 
 /// Component representing an NPC's basic needs
 /// All values are normalized between 0.0-1.0 for ML compatibility
@@ -36,6 +37,8 @@ pub enum Desire {
     Rest,
     /// Socialize with other NPCs
     Socialize,
+    /// Desire registered at runtime, identified by its index in the CustomDesireRegistry
+    Custom(u8),
 }
 
 /// Component that defines thresholds for when desires should be activated
@@ -54,8 +57,6 @@ pub struct DesireThresholds {
     pub safety_threshold: DualThreshold,
     /// Social threshold below which Socialize desire is triggered
     pub social_threshold: DualThreshold,
-    /// Priority weights for competing desires (higher = more important)
-    pub priority_weights: DesirePriorities,
 }
 
 #[derive(Reflect, Debug)]
@@ -64,17 +65,51 @@ pub struct DualThreshold {
     pub low_threshold: f32,  // Threshold below which pathfinding starts (urgent action)
 }
 
-/// Priority system for resolving competing desires
-/// Based on Maslow's hierarchy with physiological needs having highest priority
-#[derive(Reflect, Debug)]
-pub struct DesirePriorities {
-    pub hunger: f32,    // Highest priority - survival need
-    pub thirst: f32,    // Highest priority - survival need
-    pub safety: f32,    // High priority - security need
+/// Component weighting each need's urgency before competing desires are compared
+/// Based on Maslow's hierarchy with survival needs first by default - scenarios can override it
+/// per agent to model atypical priorities (e.g. a workaholic who deprioritizes social contact)
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct NeedPriorityWeights {
+    pub safety: f32,    // Highest priority - immediate threat to survival
+    pub thirst: f32,    // Very high priority - survival need
+    pub hunger: f32,    // High priority - survival need
     pub rest: f32,      // Medium priority - physiological need
     pub social: f32,    // Lower priority - social need
 }
 
+/// Component holding the level each of an agent's needs settles at when it feels comfortable
+/// Based on Set-Point Theory (Keesey & Powley, 1986) - individuals defend different baselines, so one agent
+/// shrugs off a hunger level that sends another looking for food
+/// Thresholds and utilities are read relative to these setpoints (see calculate_relative_needs), and decay
+/// sheds any surplus above the comfort band (see calculate_setpoint_decay_multipliers)
+#[derive(Component, Reflect, PartialEq, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct HomeostaticSetpoints {
+    pub hunger: NeedSetpoint,
+    pub thirst: NeedSetpoint,
+    pub rest: NeedSetpoint,
+    pub safety: NeedSetpoint,
+    pub social: NeedSetpoint,
+}
+
+/// Comfort level of a single need
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+pub struct NeedSetpoint {
+    /// Need level (0.0-1.0) the agent is comfortable at
+    pub target: f32,
+    /// Distance either side of the target that still feels comfortable
+    pub comfort_band: f32,
+}
+
+impl HomeostaticSetpoints {
+    /// Setpoints drawn per need: targets between 0.8 and 1.0, comfort bands up to 0.1
+    pub fn from_rng<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        let mut draw = || NeedSetpoint { target: rng.random_range(0.8..=1.0), comfort_band: rng.random_range(0.0..=0.1) };
+        Self { hunger: draw(), thirst: draw(), rest: draw(), safety: draw(), social: draw() }
+    }
+}
+
 /// Component that stores an agent's current decision/desire after evaluation
 /// This is the missing component from roadmap 1.3.2 that gets set by the decision_making_system
 #[derive(Component, Debug, Reflect, Default, Clone, Copy)]
@@ -96,3 +131,164 @@ pub struct CurrentDesire {
     /// NEW: Last known target entity (resource, NPC, etc.) for this desire
     pub last_target: Option<Entity>,
 }
+
+/// Component gating how an agent deliberates, following Dual-Process Theory
+/// Based on "Thinking, Fast and Slow" (Kahneman, 2011) - stress and depleted willpower push agents
+/// toward fast heuristic System 1 decisions, calm and high willpower enable deliberate System 2 decisions
+/// Willpower itself is read from the agent's Willpower component (see calculate_system2_engagement)
+#[derive(Component, Debug, Reflect, Clone, Copy)]
+#[reflect(Component)]
+pub struct DualProcess {
+    /// Current stress level (0.0 = calm, 1.0 = overwhelmed)
+    pub stress: f32,
+    /// Seconds since this agent last re-evaluated its decision
+    pub time_since_evaluation: f32,
+}
+
+/// Component measuring how often an agent changes its mind
+/// Based on Commitment research (Gollwitzer, 1990) - once deliberation turns into a decision, the chosen
+/// goal is shielded from near-tied alternatives; agents that keep switching earn a commitment bias
+/// ML-HOOK: changes_per_minute exposes indecisive agents for analysis
+#[derive(Component, Debug, Reflect, Clone, PartialEq, Default)]
+#[reflect(Component)]
+pub struct DecisionStability {
+    /// Elapsed simulation time of every desire change still inside the measuring window
+    pub recent_changes: Vec<f32>,
+    /// Desire changes per minute over the measuring window
+    pub changes_per_minute: f32,
+    /// Utility added to the current desire when it competes with the others (0.0 = not dithering)
+    pub commitment_bias: f32,
+}
+
+/// Component modelling willpower as a limited resource
+/// Based on Ego Depletion Theory (Baumeister et al., 1998) - resisting the strongest need
+/// consumes willpower, and an exhausted agent can no longer override its physiological drives
+#[derive(Component, Debug, Reflect, Clone, Copy)]
+#[reflect(Component)]
+pub struct Willpower {
+    /// Remaining willpower (0.0 = depleted, up to max)
+    pub current: f32,
+    /// Willpower capacity when fully rested
+    pub max: f32,
+}
+
+/// Component damping FindSafety for a while after an agent gave up fleeing a threat it could not escape
+/// Based on Habituation research (Thompson & Spencer, 1966) - a threat that persists without harm feels less urgent,
+/// so the damping lowers the desire's priority weight and never touches the safety need itself
+#[derive(Component, Debug, Reflect, Clone, Copy)]
+#[reflect(Component)]
+pub struct SafetyHabituation {
+    /// Multiplier on the safety priority weight while habituated (0.0-1.0)
+    pub urgency_factor: f32,
+    /// Seconds the damping lasts after giving up
+    pub cooldown: f32,
+    /// Elapsed time until which the agent is habituated
+    pub habituated_until: f32,
+}
+
+impl SafetyHabituation {
+    /// Starts the damping for the cooldown
+    pub fn habituate(&mut self, current_time: f32) {
+        self.habituated_until = current_time + self.cooldown;
+    }
+
+    /// Whether the damping is still in effect
+    pub fn is_habituated(&self, current_time: f32) -> bool {
+        self.habituated_until > current_time
+    }
+
+    /// Priority weights with the safety weight damped while habituated
+    pub fn damp_weights(&self, weights: &NeedPriorityWeights, current_time: f32) -> NeedPriorityWeights {
+        if !self.is_habituated(current_time) {
+            return *weights;
+        }
+        NeedPriorityWeights { safety: weights.safety * self.urgency_factor.clamp(0.0, 1.0), ..*weights }
+    }
+}
+
+/// One user-defined need and the desire that satisfies it
+/// The desire is Desire::Custom(id) and it is satisfied at CustomResourceSite entities of the same id,
+/// which resource discovery remembers and pathfinding targets like any built-in resource
+#[derive(Reflect, Debug)]
+pub struct CustomDesireDefinition {
+    /// Display name, e.g. "Play"
+    pub name: String,
+    /// Need level below which the desire competes (high) and becomes urgent (low)
+    pub threshold: DualThreshold,
+    /// Priority weight applied to the desire's urgency, like NeedPriorityWeights for built-in needs
+    pub weight: f32,
+    /// Need lost per second
+    pub decay_rate: f32,
+    /// Need regained per second while refilling at a matching CustomResourceSite
+    pub satisfaction_rate: f32,
+}
+
+/// Resource listing the needs and desires added on top of the built-in five
+/// Based on the Open/Closed Principle - scenarios add needs such as "Work" or "Play" here instead of
+/// extending the Desire and ResourceType match arms
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+pub struct CustomDesireRegistry {
+    /// Definitions indexed by their id
+    pub desires: Vec<CustomDesireDefinition>,
+}
+
+impl CustomDesireRegistry {
+    /// Adds a custom desire and returns the id its Desire::Custom and ResourceType::Custom variants use
+    /// Ids are a u8, so registration fails once 256 desires are registered
+    pub fn register(&mut self, definition: CustomDesireDefinition) -> Result<u8, CustomDesireRegistryFull> {
+        let id = u8::try_from(self.desires.len()).map_err(|_| CustomDesireRegistryFull)?;
+        self.desires.push(definition);
+        Ok(id)
+    }
+
+    pub fn get(&self, id: u8) -> Option<&CustomDesireDefinition> {
+        self.desires.get(usize::from(id))
+    }
+
+    /// Every registered definition paired with its id
+    pub fn iter(&self) -> impl Iterator<Item = (u8, &CustomDesireDefinition)> {
+        (0..=u8::MAX).zip(self.desires.iter())
+    }
+}
+
+/// Error returned by CustomDesireRegistry::register when every u8 id is taken
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CustomDesireRegistryFull;
+
+impl std::fmt::Display for CustomDesireRegistryFull {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "at most {} custom desires can be registered", usize::from(u8::MAX) + 1)
+    }
+}
+
+impl std::error::Error for CustomDesireRegistryFull {}
+
+/// Component holding an agent's level (0.0-1.0, higher = better satisfied) of each registered custom need
+/// Needs without an entry are fully satisfied, so agents spawned before a registration start content
+#[derive(Component, Debug, Reflect, Clone, Default)]
+#[reflect(Component)]
+pub struct CustomNeeds {
+    pub values: HashMap<u8, f32>,
+}
+
+impl CustomNeeds {
+    pub fn value(&self, id: u8) -> f32 {
+        self.values.get(&id).copied().unwrap_or(1.0)
+    }
+}
+
+/// Component tracking how long each physiological need has been pinned at 0.0
+/// Read and advanced by need_failure_system; a need that rises above 0.0 restarts its count
+#[derive(Component, Reflect, Debug, Default, Clone, Copy, PartialEq)]
+#[reflect(Component)]
+pub struct NeedDepletion {
+    /// Seconds hunger has been at 0.0
+    pub hunger_secs: f32,
+    /// Seconds thirst has been at 0.0
+    pub thirst_secs: f32,
+    /// Seconds rest has been at 0.0
+    pub rest_secs: f32,
+    /// Whether a need has failed during the current depletion; cleared once none is at 0.0
+    pub critical: bool,
+}
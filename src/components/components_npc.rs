@@ -1,4 +1,6 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
+
 
 /// Marker component for NPCs
 #[derive(Component, Reflect, PartialEq, Debug)]
@@ -27,6 +29,87 @@ pub struct Personality {
     pub neuroticism: f32,
 }
 
+/// Trait-derived modifiers read by the systems personality influences
+/// Keeping every trait-to-behavior mapping here means systems never interpret raw trait values themselves
+impl Personality {
+    /// Personality with every trait drawn uniformly from 0.0-1.0
+    pub fn from_rng<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        Self {
+            openness: rng.random_range(0.0..1.0),
+            extraversion: rng.random_range(0.0..1.0),
+            agreeableness: rng.random_range(0.0..1.0),
+            conscientiousness: rng.random_range(0.0..1.0),
+            neuroticism: rng.random_range(0.0..1.0),
+        }
+    }
+
+    /// Chance (0.2-1.0) that this agent turns a chance contact into an interaction
+    /// Based on Extraversion research (Eysenck, 1967) - extraverts seek out social contact
+    pub fn interaction_initiation_chance(&self) -> f32 {
+        0.2 + 0.8 * self.extraversion.clamp(0.0, 1.0)
+    }
+
+    /// Multiplier (0.5-1.5) on the social satisfaction gained from a completed interaction
+    /// Extraverts are energized by company, introverts gain less from the same exchange
+    pub fn social_energy_multiplier(&self) -> f32 {
+        0.5 + self.extraversion.clamp(0.0, 1.0)
+    }
+
+    /// Multiplier (0.5-1.0) on how far ahead a deliberating agent projects its needs
+    /// Based on Conscientiousness research (Roberts et al., 2009) - conscientious agents plan further ahead
+    pub fn deliberation_depth(&self) -> f32 {
+        0.5 + 0.5 * self.conscientiousness.clamp(0.0, 1.0)
+    }
+
+    /// Multiplier (0.5-1.5) on the stress produced by unmet needs
+    /// Based on Neuroticism research (Eysenck, 1967) - neurotic agents mount a faster cortisol response
+    pub fn stress_sensitivity(&self) -> f32 {
+        0.5 + self.neuroticism.clamp(0.0, 1.0)
+    }
+
+    /// Factor (0.5-1.0) on how readily this agent passes on or believes a rumor
+    /// High openness = more likely to share and believe new information
+    pub fn rumor_receptivity(&self) -> f32 {
+        0.5 + 0.5 * self.openness.clamp(0.0, 1.0)
+    }
+
+    /// Raw value (0.0-1.0) of one trait, for displays that let the user pick which trait to show
+    pub fn trait_value(&self, personality_trait: PersonalityTrait) -> f32 {
+        match personality_trait {
+            PersonalityTrait::Openness => self.openness,
+            PersonalityTrait::Extraversion => self.extraversion,
+            PersonalityTrait::Agreeableness => self.agreeableness,
+            PersonalityTrait::Conscientiousness => self.conscientiousness,
+            PersonalityTrait::Neuroticism => self.neuroticism,
+        }
+        .clamp(0.0, 1.0)
+    }
+}
+
+/// One of the five Personality traits, used to pick which trait a display shows
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PersonalityTrait {
+    Openness,
+    #[default]
+    Extraversion,
+    Agreeableness,
+    Conscientiousness,
+    Neuroticism,
+}
+
+impl PersonalityTrait {
+    /// The trait after this one, wrapping around, for cycling with a key
+    pub fn next(self) -> Self {
+        match self {
+            Self::Openness => Self::Extraversion,
+            Self::Extraversion => Self::Agreeableness,
+            Self::Agreeableness => Self::Conscientiousness,
+            Self::Conscientiousness => Self::Neuroticism,
+            Self::Neuroticism => Self::Openness,
+        }
+    }
+}
+
 /// Component tracking NPC's refilling/interaction state with resources
 /// Based on Behavioral State Theory - agents have distinct behavioral modes
 #[derive(Component, Reflect, PartialEq, Debug, Default)]
@@ -42,6 +125,26 @@ pub struct RefillState {
     pub resource_entity: Option<Entity>,
 }
 
+/// Component tracking whether a fully satisfied NPC is resting in place instead of wandering
+/// Based on Homeostasis - with every need met there is no drive to move, so the agent idles and recovers
+#[derive(Component, Reflect, PartialEq, Debug, Default)]
+#[reflect(Component)]
+pub struct RelaxState {
+    /// Whether the NPC has come to a stop because all of its needs are satisfied
+    pub is_relaxing: bool,
+    /// Time when relaxing started (for duration tracking)
+    pub relaxed_since: f32,
+}
+
+/// Component marking an NPC that has collapsed from a failed need
+/// Incapacitated agents neither move nor make decisions until the need is restored above 0.0
+#[derive(Component, Reflect, PartialEq, Debug, Default)]
+#[reflect(Component)]
+pub struct Incapacitated {
+    /// Time the NPC collapsed
+    pub since: f32,
+}
+
 /// Component representing externally visible state of an entity
 /// Based on Theory of Mind - what others can observe about an agent
 /// CRITICAL: This contains ONLY externally apparent information, never internal state
@@ -56,6 +159,8 @@ pub struct ApparentState {
     pub posture: Posture,
     /// Whether the entity appears to be interacting with something
     pub is_interacting: bool,
+    /// How alarmed the entity appears (0.0 = calm, 1.0 = fleeing in terror)
+    pub alarm: f32,
 }
 
 /// Enum representing observable postures/stances
@@ -87,6 +192,38 @@ pub struct PerceivedEntities {
     pub attention_limit: usize,
 }
 
+/// Last perceived position of another agent, kept in a WorldModel
+#[derive(Reflect, PartialEq, Debug, Clone, Copy)]
+pub struct ModeledAgent {
+    /// Where the agent was when it was last in sight
+    pub position: Vec2,
+    /// Elapsed time of that sighting, in seconds
+    pub last_seen: f32,
+}
+
+/// Component holding what an agent believes about the other agents around it
+/// Based on the "Mantle of Ignorance" - decision systems read other agents from here and resources from
+/// ResourceMemory, never from the world itself, and only perception writes to either
+/// Agents out of sight for longer than agent_memory_secs are forgotten
+#[derive(Component, Reflect, PartialEq, Debug, Clone)]
+#[reflect(Component)]
+pub struct WorldModel {
+    /// Agents seen recently, keyed by entity
+    pub agents: HashMap<Entity, ModeledAgent>,
+    /// Seconds an agent stays in the model after it was last seen
+    pub agent_memory_secs: f32,
+}
+
+impl WorldModel {
+    /// Position of the remembered agent closest to `position`
+    pub fn nearest_agent(&self, position: Vec2) -> Option<Vec2> {
+        self.agents
+            .values()
+            .map(|agent| agent.position)
+            .min_by(|a, b| position.distance(*a).total_cmp(&position.distance(*b)))
+    }
+}
+
 /// Component defining vision capabilities and parameters
 /// Based on Human Visual Perception research
 #[derive(Component, Reflect, PartialEq, Debug)]
@@ -100,4 +237,145 @@ pub struct VisionRange {
     pub field_of_view: f32,
     /// Whether line-of-sight checking is enabled
     pub requires_line_of_sight: bool,
-}
\ No newline at end of file
+}
+
+/// Component holding an agent's current affect
+/// Based on the Circumplex Model of Affect (Russell, 1980) - mood is pleasantness (valence) by activation (arousal)
+/// Derived by mood_update_system from need satisfaction and stress
+#[derive(Component, Reflect, PartialEq, Debug, Default, Clone, Copy)]
+#[reflect(Component)]
+pub struct Mood {
+    /// How pleasant the agent feels (-1.0 = miserable, 0.0 = neutral, 1.0 = elated)
+    pub valence: f32,
+    /// How activated the agent feels (0.0 = calm, 1.0 = agitated)
+    pub arousal: f32,
+}
+
+/// Component holding an agent's transient panic
+/// Based on Emotional Contagion (Hatfield et al., 1993) - fear spreads to those who see or hear it
+/// Set by panic_contagion_system and shown to others through ApparentState::alarm
+#[derive(Component, Reflect, PartialEq, Debug, Default, Clone, Copy)]
+#[reflect(Component)]
+pub struct Panic {
+    /// How strongly the agent is panicking (0.0 = calm, 1.0 = in critical danger itself)
+    pub intensity: f32,
+    /// Seconds until the panic wears off
+    pub remaining: f32,
+    /// Whether the panic was caught from another agent rather than started by the agent's own danger
+    pub caught: bool,
+}
+
+/// Component holding how keenly an agent notices its surroundings
+/// Based on Individual Differences in perceptual acuity - some people spot things from much further away
+/// Scales both ResourceMemory::discovery_radius and VisionRange::max_distance
+#[derive(Component, Reflect, PartialEq, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct Perception {
+    /// Perceptual acuity (0.0 = barely notices anything, 1.0 = exceptionally sharp)
+    /// Range: 0.0-1.0 (normalized for ML compatibility)
+    pub acuity: f32,
+}
+
+impl Perception {
+    /// Perception with acuity drawn uniformly from 0.0-1.0
+    pub fn from_rng<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        Self { acuity: rng.random_range(0.0..1.0) }
+    }
+
+    /// Multiplier (0.5-1.5) on how far the agent discovers resources and sees other agents
+    /// Average acuity leaves the configured ranges unchanged
+    pub fn range_multiplier(&self) -> f32 {
+        0.5 + self.acuity.clamp(0.0, 1.0)
+    }
+}
+
+/// Component holding the body dimensions an agent was spawned with
+/// Based on Allometric Scaling - size sets collider, sprite, top speed and damping together,
+/// so agents of different sizes stay physically coherent (see calculate_body_size)
+#[derive(Component, Reflect, PartialEq, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct BodySize {
+    /// Size relative to GameConstants::npc_radius
+    pub scale: f32,
+    /// Collider radius, half the sprite size
+    pub radius: f32,
+    /// Top speed steering may reach
+    pub max_speed: f32,
+    /// Linear damping of the rigid body
+    pub linear_damping: f32,
+}
+
+/// Component holding an agent's sense of its own motion
+/// Based on Path Integration research (Etienne & Jeffery, 2004) - animals dead-reckon from self-motion cues
+/// Estimated from frame-to-frame Transform deltas, never from the physics velocity, so it keeps working
+/// when no landmark is in sight
+#[derive(Component, Reflect, PartialEq, Debug, Default, Clone, Copy)]
+#[reflect(Component)]
+pub struct Proprioception {
+    /// Position at the previous update, None until the first one
+    pub last_position: Option<Vec2>,
+    /// Estimated speed in world units per second
+    pub speed_estimate: f32,
+    /// Estimated heading in radians (0.0 = +X, counter-clockwise), held while stationary
+    pub current_heading: f32,
+    /// Displacement from where the agent was first sensed, integrated from speed and heading
+    pub integrated_displacement: Vec2,
+    /// Total distance travelled
+    pub distance_travelled: f32,
+}
+
+/// Resource controlling the vision cone debug overlay
+/// Shows each agent's field of view and effective range, to check perception coverage
+/// While disabled the drawing system does not run at all
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct VisionConeOverlay {
+    /// Whether cones are drawn (toggled with V)
+    pub enabled: bool,
+    /// Draw only the NPC selected by clicking instead of every agent
+    pub selected_only: bool,
+    /// Straight segments approximating each cone's arc
+    pub arc_segments: u32,
+    /// Cone color while the agent perceives at least one other agent
+    pub seeing_color: Color,
+    /// Cone color while nothing is in sight
+    pub idle_color: Color,
+}
+
+/// What NPC sprite tints encode
+/// The color for each mode comes from color_helpers::agent_tint_color
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VisualizationMode {
+    /// One distinct color per current desire
+    #[default]
+    ByDesire,
+    /// Red for deprived agents through green for satisfied ones, from the mean of the five needs
+    ByNeed,
+    /// Cool blue for a low value of AgentTint::personality_trait through warm orange for a high one
+    ByPersonality,
+    /// Grey for agents nobody looks up to through gold for potential leaders, from SocialInfluence
+    ByReputation,
+}
+
+impl VisualizationMode {
+    /// The mode after this one, wrapping around, for cycling with a key
+    pub fn next(self) -> Self {
+        match self {
+            Self::ByDesire => Self::ByNeed,
+            Self::ByNeed => Self::ByPersonality,
+            Self::ByPersonality => Self::ByReputation,
+            Self::ByReputation => Self::ByDesire,
+        }
+    }
+}
+
+/// Resource choosing how NPC sprites are tinted, switchable at runtime
+/// Lets clusters of similar agents - same desire, same mood, same temperament - stand out spatially
+#[derive(Resource, Reflect, Debug, Clone, Default)]
+#[reflect(Resource)]
+pub struct AgentTint {
+    /// What the tint encodes (cycled with C)
+    pub mode: VisualizationMode,
+    /// Trait shown in VisualizationMode::ByPersonality (cycled with T)
+    pub personality_trait: PersonalityTrait,
+}
@@ -1,4 +1,7 @@
+use crate::components::components_environment::ResourceType;
+use crate::utils::helpers::pathfinding_helpers::world_to_cell;
 use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
 
 /// Component representing an NPC's pathfinding target and navigation state
 /// System based on Goal-Oriented Action Planning (GOAP) theory
@@ -7,9 +10,12 @@ use bevy::prelude::*;
 pub struct PathTarget {
     /// Target position to navigate towards
     pub target_position: Vec2,
+    /// Position the NPC was at when the target was set (start of the traversal)
+    pub origin_position: Vec2,
     /// Target entity (if navigating to a specific entity like a well/restaurant)
     pub target_entity: Option<Entity>,
     /// Distance threshold to consider target "reached"
+    /// Set from the target's size by calculate_arrival_threshold whenever a target is chosen
     pub arrival_threshold: f32,
     /// Whether the NPC has a valid path target
     pub has_target: bool,
@@ -38,11 +44,15 @@ pub struct SteeringBehavior {
     pub wander_angle: f32,
     /// How much the wander angle changes per frame
     pub wander_angle_change: f32,
+    /// Weight of the push away from the room walls, raised temporarily by StuckEscape
+    pub avoidance_weight: f32,
+    /// Maximum change in velocity per second, so steering turns and brakes instead of reversing instantly
+    pub max_acceleration: f32,
 }
 
 /// Component tracking NPC's knowledge of resource locations
 /// System based on Spatial Cognition and Mental Maps theory
-#[derive(Component, Debug, Reflect)]
+#[derive(Component, Debug, Reflect, Clone)]
 #[reflect(Component)]
 pub struct ResourceMemory {
     /// Known well locations
@@ -53,8 +63,153 @@ pub struct ResourceMemory {
     pub known_hotels: Vec<Vec2>,
     /// Known safe zone locations
     pub known_safe_zones: Vec<Vec2>,
+    /// Known custom resource site locations, keyed by custom desire id
+    pub known_custom: HashMap<u8, Vec<Vec2>>,
     /// Discovery radius - how close NPC needs to be to "discover" a resource
     pub discovery_radius: f32,
-    /// Memory decay factor - how quickly forgotten locations become less reliable
+    /// Memory decay factor - how quickly locations that are not re-confirmed fade from memory
     pub memory_decay_rate: f32,
+    /// Maximum locations remembered per resource type, the most faded one is forgotten beyond it
+    pub capacity_per_type: usize,
+    /// Retention bookkeeping for every remembered location
+    pub traces: Vec<MemoryTrace>,
+}
+
+/// How well one remembered resource location is retained
+/// Based on Ebbinghaus' forgetting curve - retention falls exponentially with time since the location was
+/// last confirmed, more slowly the more often it has been confirmed
+#[derive(Debug, Reflect, Clone, Copy, PartialEq)]
+pub struct MemoryTrace {
+    /// Remembered location
+    pub position: Vec2,
+    /// Kind of resource at the location
+    pub resource_type: ResourceType,
+    /// Elapsed time the agent last saw the resource there
+    pub last_confirmed: f32,
+    /// Retention strength, 1.0 when first learned and growing with every confirmation
+    pub strength: f32,
+}
+
+/// Component holding targets an agent recently failed to reach
+/// Based on Learned Helplessness research in its adaptive, short-lived form - a failed goal is
+/// avoided for a while instead of being re-selected immediately, then reconsidered once the cooldown passes
+#[derive(Component, Debug, Reflect, Clone)]
+#[reflect(Component)]
+pub struct AvoidedTargets {
+    /// Failed target positions and the elapsed time until which each is skipped
+    pub entries: Vec<(Vec2, f32)>,
+    /// Seconds a failed target is skipped for
+    pub cooldown: f32,
+}
+
+impl AvoidedTargets {
+    /// Avoids a target for the cooldown, dropping entries that have already expired
+    pub fn avoid(&mut self, position: Vec2, current_time: f32) {
+        self.entries.retain(|&(avoided, until)| until > current_time && avoided != position);
+        self.entries.push((position, current_time + self.cooldown));
+    }
+
+    /// Whether a target is still being avoided
+    pub fn is_avoided(&self, position: Vec2, current_time: f32) -> bool {
+        self.entries.iter().any(|&(avoided, until)| avoided == position && until > current_time)
+    }
+}
+
+/// Component letting an agent break free when it stops making headway toward its target
+/// Progress is measured over a sliding window; an agent that moved less than
+/// GameConstants::stuck_distance_threshold in a whole window gets a randomized sideways impulse
+/// and a boosted avoidance_weight for a short escape, then resumes seeking the same target
+#[derive(Component, Debug, Reflect, Clone)]
+#[reflect(Component)]
+pub struct StuckEscape {
+    /// Position at the start of the current progress window
+    pub anchor_position: Vec2,
+    /// Elapsed time at the start of the current progress window
+    pub anchor_time: f32,
+    /// Length of a progress window in seconds
+    pub window: f32,
+    /// Speed of the sideways escape impulse
+    pub impulse_speed: f32,
+    /// Seconds the escape lasts before seeking resumes
+    pub duration: f32,
+    /// avoidance_weight used while escaping
+    pub avoidance_boost: f32,
+    /// Elapsed time until which the agent is escaping
+    pub escaping_until: f32,
+    /// avoidance_weight to restore once the escape ends, Some while boosted
+    pub restore_avoidance_weight: Option<f32>,
+}
+
+impl StuckEscape {
+    /// Whether the agent is currently escaping
+    pub fn is_escaping(&self, current_time: f32) -> bool {
+        current_time < self.escaping_until
+    }
+
+    /// Starts a new progress window at the agent's current position
+    pub fn restart_window(&mut self, position: Vec2, current_time: f32) {
+        self.anchor_position = position;
+        self.anchor_time = current_time;
+    }
+}
+
+/// Component listing the colliders an agent's body is currently touching, from Rapier collision events
+/// Agents carrying it treat contact with their target entity as arrival, even outside arrival_threshold,
+/// so steering agrees with the physics; agents without it arrive by distance alone
+#[derive(Component, Debug, Reflect, Default, Clone)]
+#[reflect(Component)]
+pub struct PhysicalContacts {
+    /// Entities whose colliders overlap the agent's body
+    pub touching: HashSet<Entity>,
+}
+
+/// A single resource entry stored in the spatial index
+#[derive(Debug, Reflect, Clone, Copy, PartialEq)]
+pub struct IndexedResource {
+    /// The resource entity
+    pub entity: Entity,
+    /// World position of the resource
+    pub position: Vec2,
+    /// What need the resource satisfies
+    pub resource_type: ResourceType,
+    /// Radius of the resource's collider, sets how close agents must come to reach it
+    pub radius: f32,
+}
+
+/// Resource holding a uniform grid of environmental resources for radius queries
+/// Based on Spatial Hashing - resources are mostly static, so the grid is rebuilt only when they change
+/// and discovery queries touch a handful of cells instead of every resource
+#[derive(Resource, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct ResourceSpatialIndex {
+    /// Side length of each grid cell in world units
+    pub cell_size: f32,
+    /// Resources bucketed by grid cell, under Bevy's faster hasher since every query probes up to nine cells
+    pub cells: bevy::platform::collections::HashMap<IVec2, Vec<IndexedResource>>,
+}
+
+impl ResourceSpatialIndex {
+    /// Removes every indexed resource, keeping the cell size
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+
+    /// Adds a resource to the cell containing its position
+    pub fn insert(&mut self, resource: IndexedResource) {
+        let cell = world_to_cell(resource.position, self.cell_size);
+        self.cells.entry(cell).or_default().push(resource);
+    }
+
+    /// Returns every indexed resource within `radius` of `position`
+    pub fn query_radius(&self, position: Vec2, radius: f32) -> impl Iterator<Item = &IndexedResource> {
+        let min_cell = world_to_cell(position - Vec2::splat(radius), self.cell_size);
+        let max_cell = world_to_cell(position + Vec2::splat(radius), self.cell_size);
+        let radius_squared = radius * radius;
+
+        (min_cell.x..=max_cell.x)
+            .flat_map(move |x| (min_cell.y..=max_cell.y).map(move |y| IVec2::new(x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .filter(move |resource| resource.position.distance_squared(position) <= radius_squared)
+    }
 }
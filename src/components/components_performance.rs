@@ -0,0 +1,208 @@
+use bevy::prelude::*;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::time::Instant;
+
+/// Resource tracking recent frame times against the frame budget
+/// Based on Real-Time Systems practice - a smoothed frame time avoids reacting to single spikes
+#[derive(Resource, Reflect, Debug)]
+#[reflect(Resource)]
+pub struct FrameTimeMonitor {
+    /// Target frame time in milliseconds (16.7 ms = 60 FPS)
+    pub budget_ms: f32,
+    /// Exponentially smoothed frame time in milliseconds
+    pub smoothed_ms: f32,
+    /// Smoothing factor applied to each new sample (0.0-1.0, higher = more reactive)
+    pub smoothing: f32,
+    /// Fraction of the budget the smoothed frame time must drop below before recovery is reported
+    pub recovery_ratio: f32,
+    /// Whether a HighFrameTime alert is currently active
+    pub alert_active: bool,
+}
+
+/// Resource scaling down optional work while the frame budget is exceeded
+/// Adaptive degradation: expensive but non-essential systems run less often until frame time recovers
+#[derive(Resource, Reflect, Debug)]
+#[reflect(Resource)]
+pub struct FrameBudgetGovernor {
+    /// Whether the simulation is currently running in degraded mode
+    pub degraded: bool,
+    /// Multiplier applied to every agent's periodic decision interval
+    pub decision_interval_multiplier: f32,
+    /// Perception runs once every this many frames (1 = every frame)
+    pub perception_frame_stride: u32,
+    /// Decision interval multiplier used while degraded
+    pub degraded_decision_multiplier: f32,
+    /// Perception stride used while degraded
+    pub degraded_perception_stride: u32,
+}
+
+impl FrameBudgetGovernor {
+    /// Switches optional systems to their reduced workload
+    pub fn degrade(&mut self) {
+        self.degraded = true;
+        self.decision_interval_multiplier = self.degraded_decision_multiplier;
+        self.perception_frame_stride = self.degraded_perception_stride;
+    }
+
+    /// Restores full workload for optional systems
+    pub fn restore(&mut self) {
+        self.degraded = false;
+        self.decision_interval_multiplier = 1.0;
+        self.perception_frame_stride = 1;
+    }
+}
+
+/// Resource configuring the per-agent level of detail of expensive learning systems
+/// While the FrameBudgetGovernor is degraded every agent updates its cognitive map and learned path values only
+/// every few ticks; the stride never depends on the camera, so every agent learns at the same rate
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct AgentLodConfig {
+    /// Whether learning is slowed down while the governor is degraded
+    pub enabled: bool,
+    /// Expensive systems run once every this many ticks while the FrameBudgetGovernor is degraded
+    pub degraded_stride: u32,
+}
+
+/// Component holding the level of detail an agent is simulated at, set every tick by agent_lod_update_system
+/// Expensive systems skip the agent unless it is_due, and then cover every tick they skipped at once
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component)]
+pub struct AgentLod {
+    /// Expensive systems update the agent once every this many ticks (1 = every tick)
+    pub stride: u32,
+    /// Position of the current tick within the stride, the agent is due at 0
+    pub phase: u32,
+}
+
+impl AgentLod {
+    /// Whether expensive systems update this agent on the current tick
+    pub fn is_due(&self) -> bool {
+        self.phase == 0
+    }
+}
+
+/// Resource configuring how learned data is kept in bounds over long runs
+/// Each agent's CognitiveMap and PathLearning are trimmed to these caps, and to pressure_fraction of them after a
+/// HighMemoryUsage alert. The weakest entries go first and consolidated ones never do, so strong knowledge
+/// survives memory pressure
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct LearningMemoryConfig {
+    /// Whether learned data is pruned at all
+    pub enabled: bool,
+    /// Most grid cells a CognitiveMap keeps activations for
+    pub max_map_cells: usize,
+    /// Most routes a PathLearning keeps learned values for
+    pub max_path_values: usize,
+    /// Fraction (0.0-1.0) of every cap, PathLearning::max_path_memory included, kept after a HighMemoryUsage alert
+    pub pressure_fraction: f32,
+    /// Cell activation at or above which familiarity is consolidated and never pruned
+    pub consolidated_activation: f32,
+    /// Absolute learned path value at or above which a route is consolidated and never pruned
+    pub consolidated_value: f32,
+    /// Learned entries across all agents above which a HighMemoryUsage alert is raised
+    pub memory_alert_entries: usize,
+}
+
+/// Resource configuring the event backlog diagnostic
+/// Bevy events are double-buffered: anything not read within two event updates is dropped silently
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct EventBacklogConfig {
+    /// Whether monitored event types are checked at all
+    pub enabled: bool,
+    /// Event updates an event survives before it is dropped (2 for Bevy's double buffer)
+    pub retained_updates: usize,
+}
+
+/// Resource totalling the events dropped unread per monitored event type
+/// ML-HOOK: silent event loss introduced by adaptive degradation shows up here and in the inspector
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+pub struct EventBacklog {
+    /// Events dropped before their consumer ran, keyed by event type name
+    pub dropped: BTreeMap<String, usize>,
+}
+
+/// Resource configuring the timing samples kept for the profiler report
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct ProfilerConfig {
+    /// File the report is written to when the app exits or the P key is pressed
+    pub report_path: String,
+    /// Samples kept per tracked system and for frame times, the oldest are dropped first
+    pub max_samples: usize,
+    /// Width of each frame-time histogram bucket in milliseconds
+    pub histogram_bucket_ms: f32,
+    /// Number of histogram buckets, the last one also counts every longer frame
+    pub histogram_buckets: usize,
+}
+
+/// Resource registering timing samples per tracked system and per frame
+/// Tracked systems are wrapped in timing_span_start / timing_span_end (see systems_performance)
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+pub struct SystemTimings {
+    /// Span durations in milliseconds per tracked system, oldest first
+    pub samples: BTreeMap<String, Vec<f32>>,
+    /// Frame times in milliseconds, oldest first
+    pub frame_times_ms: Vec<f32>,
+    /// Start of every span currently open
+    #[reflect(ignore)]
+    pub open_spans: HashMap<&'static str, Instant>,
+}
+
+impl SystemTimings {
+    /// Adds a span duration for a system, keeping at most max_samples
+    pub fn record(&mut self, system: &str, duration_ms: f32, max_samples: usize) {
+        let samples = self.samples.entry(system.to_string()).or_default();
+        push_bounded(samples, duration_ms, max_samples);
+    }
+
+    /// Adds a frame time, keeping at most max_samples
+    pub fn record_frame(&mut self, frame_time_ms: f32, max_samples: usize) {
+        push_bounded(&mut self.frame_times_ms, frame_time_ms, max_samples);
+    }
+}
+
+fn push_bounded(samples: &mut Vec<f32>, sample: f32, max_samples: usize) {
+    samples.push(sample);
+    if samples.len() > max_samples.max(1) {
+        let excess = samples.len() - max_samples.max(1);
+        samples.drain(..excess);
+    }
+}
+
+/// Summary statistics of one series of timing samples, in milliseconds
+#[derive(Reflect, Serialize, Debug, Clone, PartialEq, Default)]
+pub struct TimingSummary {
+    /// Tracked system the samples belong to, "frame" for frame times
+    pub name: String,
+    pub samples: usize,
+    pub mean_ms: f32,
+    /// Nearest-rank 95th percentile
+    pub p95_ms: f32,
+    pub max_ms: f32,
+}
+
+/// One bucket of the frame-time histogram
+#[derive(Reflect, Serialize, Debug, Clone, Copy, PartialEq)]
+pub struct HistogramBucket {
+    /// Lower edge of the bucket in milliseconds, inclusive
+    pub from_ms: f32,
+    /// Upper edge of the bucket in milliseconds, exclusive; infinite for the last bucket
+    pub to_ms: f32,
+    pub frames: usize,
+}
+
+/// Performance profile of a run, written to ProfilerConfig::report_path
+/// ML-HOOK: per-system cost of the simulation, for comparing populations and feature sets
+#[derive(Reflect, Serialize, Debug, Clone, PartialEq, Default)]
+pub struct ProfilerReport {
+    /// One summary per tracked system, sorted by name
+    pub systems: Vec<TimingSummary>,
+    pub frame_time: TimingSummary,
+    pub frame_time_histogram: Vec<HistogramBucket>,
+}
@@ -0,0 +1,197 @@
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Kinds of social interaction agents can have with each other
+/// Based on Social Exchange Theory (Homans, 1958) - different exchanges carry different relational weight
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum InteractionType {
+    /// Brief acknowledgement between agents
+    #[default]
+    Greeting,
+    /// Casual exchange of information
+    Conversation,
+    /// Acquainted agents trade what they know about where to find things, when one of them is in want
+    InformationSharing,
+    /// One agent assists another with an unmet need
+    Helping,
+    /// One agent comforts a lonely or stressed partner
+    EmotionalSupport,
+    /// Both agents pursue the same goal together
+    Cooperation,
+    /// Agents clash over distrust or a contested resource
+    Conflict,
+}
+
+/// Why two agents entered a conflict
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ConflictCause {
+    /// The agents already distrust and dislike each other
+    MutualDistrust,
+    /// Both agents want the same scarce resource
+    ScarceResource,
+}
+
+/// How a conflict was resolved
+/// Based on the Thomas-Kilmann Conflict Mode model (Thomas & Kilmann, 1974)
+#[derive(Reflect, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ConflictResolution {
+    /// One side backs down - the assertive agent comes out ahead
+    Avoidance,
+    /// Both sides give ground - nobody is fully satisfied, but damage is limited
+    Compromise,
+}
+
+/// A single directed relationship toward another agent
+/// Based on Interpersonal Attraction research - affinity and trust develop separately
+#[derive(Reflect, Clone, Copy, PartialEq, Debug, Default)]
+pub struct Relationship {
+    /// How much this agent likes the other (-1.0 = hostile, 1.0 = close friend)
+    pub affinity: f32,
+    /// How much this agent trusts the other (0.0 = no trust, 1.0 = complete trust)
+    pub trust: f32,
+    /// Number of interactions that left this agent better off
+    pub positive_interactions: u32,
+    /// Number of interactions that left this agent worse off
+    pub negative_interactions: u32,
+    /// Elapsed simulation time of the most recent interaction
+    pub last_interaction: f32,
+    /// Running mean of how every interaction felt (-1.0 = all awful, 1.0 = all great)
+    pub mean_outcome: f32,
+}
+
+/// Component holding an agent's relationships with everyone it has met
+/// ML-HOOK: Relationship values form the social graph for network analysis
+#[derive(Component, Reflect, PartialEq, Debug, Default)]
+#[reflect(Component)]
+pub struct SocialRelationships {
+    /// Relationship toward each known agent
+    pub relationships: HashMap<Entity, Relationship>,
+}
+
+/// An interaction currently in progress with a partner
+#[derive(Reflect, Clone, Copy, PartialEq, Debug)]
+pub struct ActiveInteraction {
+    /// The agent on the other side of the interaction
+    pub partner: Entity,
+    /// Elapsed simulation time when the interaction started
+    pub started_at: f32,
+    /// How long the interaction lasts before it completes, in seconds
+    pub duration: f32,
+    /// Kind of exchange, from this agent's perspective
+    pub interaction_type: InteractionType,
+    /// How satisfying this agent finds the exchange (0.0-1.0), scaling the social need it restores
+    pub satisfaction: f32,
+}
+
+/// Component tracking where an agent is in the social interaction lifecycle
+/// Contact starts an interaction, and only a completed interaction satisfies the social need
+/// Based on Social Penetration Theory (Altman & Taylor, 1973) - social reward comes from sustained exchange
+#[derive(Component, Reflect, PartialEq, Debug, Default)]
+#[reflect(Component)]
+pub struct SocialInteractionState {
+    /// The interaction in progress, if any
+    pub active: Option<ActiveInteraction>,
+    /// Number of interactions this agent has completed
+    pub completed_interactions: u32,
+    /// Elapsed simulation time each partner's most recent interaction with this agent completed
+    pub last_completed_with: HashMap<Entity, f32>,
+    /// Completion times of this agent's interactions within SocialConfig::fatigue_window, oldest first
+    /// Kept by social_energy_system while SocialConfig::social_fatigue is on
+    pub interaction_history: VecDeque<f32>,
+}
+
+impl SocialInteractionState {
+    /// Whether the agent is free to start a new interaction
+    pub fn is_available(&self) -> bool {
+        self.active.is_none()
+    }
+
+    /// Whether an interaction with `partner` completed less than `cooldown` seconds ago
+    pub fn on_cooldown_with(&self, partner: Entity, current_time: f32, cooldown: f32) -> bool {
+        self.last_completed_with
+            .get(&partner)
+            .is_some_and(|completed_at| current_time - completed_at < cooldown)
+    }
+}
+
+/// Component holding how much social capacity an agent has left (0.0-1.0)
+/// Based on Social Satiation research - interactions drain it, solitude restores it, and the more an
+/// agent has socialized lately the faster it drains and the slower it comes back (see social_energy_system)
+#[derive(Component, Reflect, PartialEq, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct SocialEnergy {
+    pub level: f32,
+}
+
+/// Resource remembering when each pair of agents last started touching
+/// Keys are canonical (lowest entity first) pairs; entries older than SocialConfig::contact_debounce_window are dropped
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+pub struct ContactDebounce {
+    /// Elapsed simulation time of each pair's most recent contact start
+    pub last_contact: HashMap<(Entity, Entity), f32>,
+}
+
+/// Resource tracking which pairs of agents are within SocialConfig::interaction_distance of each other
+/// Maintained by social_proximity_system; pairs are canonical (lowest entity first)
+#[derive(Resource, Reflect, Debug, Default)]
+#[reflect(Resource)]
+pub struct SocialProximity {
+    /// Pairs currently within conversation range
+    pub in_range: HashSet<(Entity, Entity)>,
+    /// Pairs that came into range since handle_social_interactions last ran, in canonical order
+    pub entered: Vec<(Entity, Entity)>,
+}
+
+/// Component summarising an agent's position in the social network
+/// Based on Social Network Analysis (Wasserman & Faust, 1994) - recomputed periodically by social_metrics_system
+/// ML-HOOK: Network position features for studying cohesion and information flow
+#[derive(Component, Reflect, PartialEq, Debug, Default)]
+#[reflect(Component)]
+pub struct SocialMetrics {
+    /// Number of agents this agent has a relationship with
+    pub degree: usize,
+    /// Fraction of possible links among this agent's contacts that actually exist (0.0-1.0)
+    pub local_density: f32,
+    /// Mean trust toward this agent's contacts (0.0-1.0)
+    pub average_trust: f32,
+}
+
+/// Component scoring how much of the network's trust and affinity flows toward an agent
+/// Based on Status Hierarchy research (Magee & Galinsky, 2008) - influence is conferred by others, not claimed
+/// Recomputed periodically by leadership_detection_system; read-only with respect to relationships
+#[derive(Component, Reflect, PartialEq, Debug, Default, Clone, Copy)]
+#[reflect(Component)]
+pub struct SocialInfluence {
+    /// Mean incoming (trust + positive affinity) / 2 over every other agent in the population (0.0-1.0)
+    pub score: f32,
+    /// Number of agents holding a relationship toward this one
+    pub in_degree: usize,
+    /// Whether the score exceeds SocialConfig::leadership_ratio times the population mean
+    pub is_potential_leader: bool,
+}
+
+/// Component holding an agent's position on one continuous opinion axis
+/// Based on Opinion Dynamics (Deffuant et al., 2000) - unlike binary rumors, attitudes shift by degrees
+/// and only in response to agents whose views are already close enough to be taken seriously
+#[derive(Component, Reflect, PartialEq, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct Attitude {
+    /// Opinion held (0.0 and 1.0 are the two extremes)
+    /// Range: 0.0-1.0 (normalized for ML compatibility)
+    pub value: f32,
+}
+
+impl Attitude {
+    /// Attitude drawn uniformly from the whole opinion axis
+    pub fn from_rng<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        Self { value: rng.random_range(0.0..=1.0) }
+    }
+}
+
+/// Component tagging an agent as a member of a transient gathering
+/// Gatherings form only among agents that perceive each other, so a crowd is something its members know they are in;
+/// group_formation_system assigns the shared id and removes it once the members disperse
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Component)]
+pub struct GatheringId(pub u32);
@@ -0,0 +1,178 @@
+use bevy::prelude::*;
+use serde::Serialize;
+use std::collections::{BTreeMap, VecDeque};
+
+use crate::components::components_needs::{BasicNeeds, Desire};
+
+/// Resource configuring periodic whole-population telemetry
+/// ML-HOOK: the JSONL output is a time series of the society for offline analysis
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct TelemetryConfig {
+    /// Whether snapshots are recorded at all
+    pub enabled: bool,
+    /// Simulated seconds between snapshots
+    pub interval_secs: f32,
+    /// JSONL file snapshots are appended to, one record per line
+    pub output_path: String,
+    /// Snapshots buffered in memory before they are written out together
+    pub flush_batch_size: usize,
+    /// Most snapshots kept in memory while writes keep failing; the oldest are dropped beyond this
+    pub max_pending_lines: usize,
+}
+
+/// Resource holding telemetry state between snapshots
+/// Snapshots are buffered and appended to the output file in batches, so the file is not
+/// reopened on every snapshot
+#[derive(Resource, Debug, Default)]
+pub struct TelemetryRecorder {
+    /// Elapsed simulation time of the last snapshot, None before the first one
+    pub last_snapshot_time: Option<f32>,
+    /// Serialized snapshots not yet written to the output file
+    pub pending_lines: Vec<String>,
+    /// Number of snapshots recorded so far
+    pub snapshots_recorded: usize,
+    /// Number of buffered snapshots dropped because the output could not be written
+    pub snapshots_dropped: usize,
+}
+
+/// Resource configuring how often SocietyWellbeing is recomputed
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct WellbeingConfig {
+    /// Simulated seconds between recomputations
+    pub interval_secs: f32,
+}
+
+/// Resource configuring how often society_desire_census_system counts the population's desires
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct DesireCensusConfig {
+    /// Simulated seconds between censuses
+    pub interval_secs: f32,
+}
+
+/// Resource configuring how often need_history_sampling_system records each agent's needs
+/// Sampling is downsampled from the per-frame decay so a long history stays cheap to keep and to plot
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource)]
+pub struct NeedHistoryConfig {
+    /// Simulated seconds between samples
+    pub interval_secs: f32,
+    /// Samples kept per agent; the oldest is dropped once the history is full
+    pub capacity: usize,
+    /// World-space width and height of the plot drawn above the selected NPC
+    pub plot_size: Vec2,
+}
+
+/// One recorded point of an agent's NeedHistory
+#[derive(Reflect, Debug, Clone, Copy)]
+pub struct NeedSample {
+    /// Elapsed simulation time the sample was taken at
+    pub time: f32,
+    /// The agent's needs at that time
+    pub needs: BasicNeeds,
+}
+
+/// Component holding a ring buffer of an agent's past needs, oldest first, for graphing wellbeing over time
+/// Filled by need_history_sampling_system, capped at NeedHistoryConfig::capacity samples
+#[derive(Component, Reflect, Debug, Clone, Default)]
+#[reflect(Component)]
+pub struct NeedHistory {
+    pub samples: VecDeque<NeedSample>,
+}
+
+/// Resource summarising the whole society's need satisfaction in a few numbers
+/// Each agent's satisfaction is the mean of its five needs (0.0-1.0, higher = better satisfied)
+/// ML-HOOK: single-number health signal for tuning and reward shaping
+#[derive(Resource, Reflect, Serialize, Debug, Clone, Copy, PartialEq, Default)]
+#[reflect(Resource)]
+pub struct SocietyWellbeing {
+    /// Mean satisfaction across all agents
+    pub mean_satisfaction: f32,
+    /// Satisfaction of the worst-off agent
+    pub min_satisfaction: f32,
+    /// Gini coefficient of satisfaction across agents (0.0 = perfectly equal, towards 1.0 = concentrated)
+    pub inequality: f32,
+    /// Number of agents the aggregate was computed over
+    pub population: usize,
+}
+
+/// Resource holding the live global figures shown by the diagnostic overlay panel
+/// A whole-society view for demos, separate from the per-agent inspector; the figures are copied from the latest
+/// DesireCensus event, SocietyWellbeing and FrameTimeMonitor as they change, never recomputed from the agents
+#[derive(Resource, Reflect, Debug, Clone, Default)]
+#[reflect(Resource)]
+pub struct DiagnosticOverlay {
+    /// Whether the panel is shown (toggled with F3)
+    pub enabled: bool,
+    /// Frames per second from the smoothed frame time, refreshed only while the panel is shown
+    pub fps: f32,
+    /// Agents counted by the latest census
+    pub population: usize,
+    /// Agents holding each desire in the latest census, most common first
+    pub desire_counts: Vec<(Desire, usize)>,
+    /// Society wellbeing as of its latest recomputation
+    pub wellbeing: SocietyWellbeing,
+}
+
+/// Marker component for the UI text node the diagnostic overlay is written into
+#[derive(Component, Reflect, Debug, Default)]
+#[reflect(Component)]
+pub struct DiagnosticOverlayText;
+
+/// Resource summarising the influence hierarchy that has emerged in the social network
+/// Recomputed together with every agent's SocialInfluence by leadership_detection_system
+/// ML-HOOK: hierarchy emergence signal over time
+#[derive(Resource, Reflect, Serialize, Debug, Clone, Copy, PartialEq, Default)]
+#[reflect(Resource)]
+pub struct SocialHierarchy {
+    /// Highest SocialInfluence score in the population
+    pub top_influence: f32,
+    /// Mean SocialInfluence score
+    pub mean_influence: f32,
+    /// Gini coefficient of influence (0.0 = flat society, towards 1.0 = one dominant agent)
+    pub influence_inequality: f32,
+    /// Number of agents flagged as potential leaders
+    pub potential_leaders: usize,
+}
+
+/// Mean and population variance of one need across all agents
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct NeedStatistics {
+    pub mean: f32,
+    pub variance: f32,
+}
+
+/// Aggregate statistics for every basic need
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct NeedsSummary {
+    pub hunger: NeedStatistics,
+    pub thirst: NeedStatistics,
+    pub rest: NeedStatistics,
+    pub safety: NeedStatistics,
+    pub social: NeedStatistics,
+}
+
+/// One aggregate record of the whole population, written as a single JSONL line
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct TelemetrySnapshot {
+    /// Elapsed simulation time in seconds
+    pub time: f32,
+    /// Number of NPCs alive
+    pub population: usize,
+    /// Mean and variance of each need (0.0-1.0, higher = better satisfied)
+    pub needs: NeedsSummary,
+    /// How many agents currently hold each desire, keyed by desire name
+    pub desire_histogram: BTreeMap<String, usize>,
+    /// Mean social need satisfaction scaled by each agent's Personality::social_energy_multiplier
+    pub mean_social_energy: f32,
+    /// Number of social interactions currently in progress (each pair counted once)
+    pub active_interactions: usize,
+    /// Whole-society satisfaction aggregate at the time of the snapshot
+    pub wellbeing: SocietyWellbeing,
+    /// Influence hierarchy as of the latest leadership detection
+    pub hierarchy: SocialHierarchy,
+    /// Agents per ZoneLayout zone as of the latest zone tagging, empty without a layout
+    pub zone_occupancy: BTreeMap<String, usize>,
+}
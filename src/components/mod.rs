@@ -1,11 +1,18 @@
 #![allow(unused_imports)]
 
+pub mod components_camera;
 pub mod components_default;
 pub mod components_environment;
+pub mod components_experiment;
+pub mod components_inspection;
 pub mod components_knowledge;
+pub mod components_learning;
 pub mod components_needs;
 pub mod components_npc;
 pub mod components_pathfinding;
+pub mod components_performance;
+pub mod components_social;
+pub mod components_telemetry;
 pub mod components_constants;
 
 pub use components_constants::*;
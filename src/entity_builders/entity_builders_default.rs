@@ -4,7 +4,13 @@ use crate::entity_builders::generic_type_safe_builder::EmptyBuilder;
 // Import ALL the domain-specific extension traits
 use crate::entity_builders::environmental_entity_domains::*;
 
+use crate::components::components_environment::Wall;
+use crate::components::components_needs::{CustomDesireRegistry, DesireThresholds};
+use crate::components::components_npc::{Perception, Personality};
+use crate::utils::helpers::physics_helpers::wall_collision_groups;
+use crate::utils::helpers::spawn_helpers::{calculate_resource_spawn_area, calculate_spawn_positions};
 use bevy::prelude::*;
+use bevy_rapier2d::prelude::{Collider, RigidBody};
 use rand::prelude::*;
 
 /// Centralized entity creation using the truly generic type-safe builder
@@ -16,37 +22,102 @@ use rand::prelude::*;
 
 /// Creates a complete NPC entity using the proper generic type-safe builder pattern
 /// This demonstrates the full fluent chain following the Type-State Builder Pattern
+/// Random traits are drawn from rng; pass SimulationRng::rng to keep seeded runs reproducible
 pub fn create_npc_entity(
     commands: &mut Commands,
     asset_server: &Res<AssetServer>,
     game_constants: &GameConstants,
+    custom_registry: &CustomDesireRegistry,
     position: Vec2,
+    rng: &mut impl Rng,
 ) -> Entity {
     // Use the proper extension trait pattern for type-safe entity building
     // This follows the "Generic Foundation -> Specific Implementation" principle
     let entity = EmptyBuilder::new(commands)
-        .create_complete_npc(commands, asset_server, game_constants);
+        .create_complete_npc(commands, asset_server, game_constants, custom_registry, rng);
 
     // Set custom position after building - this is a post-build modification
     commands.entity(entity).insert(Transform::from_xyz(position.x, position.y, 0.0));
     entity
 }
 
+// Archetype presets - each runs the full validated chain through create_npc_entity, then overrides only
+// the values that define the archetype, so the remaining traits stay randomly drawn
+
+/// Spawns an average NPC: every personality trait and its perceptual acuity at the population midpoint
+pub fn spawn_default_npc(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    game_constants: &GameConstants,
+    custom_registry: &CustomDesireRegistry,
+    position: Vec2,
+    rng: &mut impl Rng,
+) -> Entity {
+    let entity = create_npc_entity(commands, asset_server, game_constants, custom_registry, position, rng);
+    commands.entity(entity).insert((
+        Personality { openness: 0.5, extraversion: 0.5, agreeableness: 0.5, conscientiousness: 0.5, neuroticism: 0.5 },
+        Perception { acuity: 0.5 },
+    ));
+    entity
+}
+
+/// Spawns a sociable NPC: extraverted and agreeable, it seeks company before loneliness becomes pressing
+pub fn spawn_social_npc(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    game_constants: &GameConstants,
+    custom_registry: &CustomDesireRegistry,
+    position: Vec2,
+    rng: &mut impl Rng,
+) -> Entity {
+    let entity = create_npc_entity(commands, asset_server, game_constants, custom_registry, position, rng);
+    let mut npc = commands.entity(entity);
+    npc.entry::<Personality>().and_modify(|mut personality| {
+        personality.extraversion = 0.9;
+        personality.agreeableness = 0.8;
+    });
+    npc.entry::<DesireThresholds>().and_modify(|mut thresholds| {
+        thresholds.social_threshold.high_threshold = 0.8; // Socialize while still 80% satisfied
+        thresholds.social_threshold.low_threshold = 0.5;  // Go looking for others at 50%
+    });
+    entity
+}
+
+/// Spawns an exploring NPC: open to experience and keen-eyed, it notices resources from further away
+pub fn spawn_explorer_npc(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    game_constants: &GameConstants,
+    custom_registry: &CustomDesireRegistry,
+    position: Vec2,
+    rng: &mut impl Rng,
+) -> Entity {
+    let entity = create_npc_entity(commands, asset_server, game_constants, custom_registry, position, rng);
+    let mut npc = commands.entity(entity);
+    npc.entry::<Personality>().and_modify(|mut personality| personality.openness = 0.9);
+    npc.insert(Perception { acuity: 0.9 });
+    entity
+}
+
 /// Legacy-compatible function expected by main.rs
+/// Starting positions follow GameConstants::spawn_distribution, drawn from the given generator
 pub fn spawn_test_npcs(
     commands: &mut Commands,
     asset_server: &Res<AssetServer>,
     game_constants: &GameConstants,
+    custom_registry: &CustomDesireRegistry,
+    rng: &mut impl Rng,
 ) {
-    let mut rng = rand::rng();
+    let positions = calculate_spawn_positions(
+        &game_constants.spawn_distribution,
+        game_constants.num_npcs,
+        game_constants.room_half_extents,
+        game_constants.npc_radius,
+        rng,
+    );
 
-    for i in 0..game_constants.num_npcs {
-        let position = Vec2::new(
-            rng.random_range(-400.0..=400.0),
-            rng.random_range(-300.0..=300.0),
-        );
-
-        let entity = create_npc_entity(commands, asset_server, game_constants, position);
+    for (i, position) in positions.into_iter().enumerate() {
+        let entity = create_npc_entity(commands, asset_server, game_constants, custom_registry, position, rng);
 
         // Add custom name for this specific NPC
         commands.entity(entity).insert(Name::new(format!("NPC {}", i + 1)));
@@ -63,11 +134,12 @@ pub fn spawn_test_npcs(
 pub fn create_well_entity(
     commands: &mut Commands,
     asset_server: &Res<AssetServer>,
+    game_constants: &GameConstants,
     position: Vec2,
 ) -> Entity {
     EmptyBuilder::new(commands)
         .with_well_resource(commands)
-        .with_well_visual(commands, asset_server, position)
+        .with_well_visual(commands, asset_server, game_constants, position)
         .build()
 }
 
@@ -75,11 +147,12 @@ pub fn create_well_entity(
 pub fn create_restaurant_entity(
     commands: &mut Commands,
     asset_server: &Res<AssetServer>,
+    game_constants: &GameConstants,
     position: Vec2,
 ) -> Entity {
     EmptyBuilder::new(commands)
         .with_restaurant_resource(commands)
-        .with_restaurant_visual(commands, asset_server, position)
+        .with_restaurant_visual(commands, asset_server, game_constants, position)
         .build()
 }
 
@@ -87,11 +160,12 @@ pub fn create_restaurant_entity(
 pub fn create_hotel_entity(
     commands: &mut Commands,
     asset_server: &Res<AssetServer>,
+    game_constants: &GameConstants,
     position: Vec2,
 ) -> Entity {
     EmptyBuilder::new(commands)
         .with_hotel_resource(commands)
-        .with_hotel_visual(commands, asset_server, position)
+        .with_hotel_visual(commands, asset_server, game_constants, position)
         .with_hotel_comfort(commands)
         .build()
 }
@@ -100,31 +174,27 @@ pub fn create_hotel_entity(
 pub fn create_safe_zone_entity(
     commands: &mut Commands,
     asset_server: &Res<AssetServer>,
+    game_constants: &GameConstants,
     position: Vec2,
 ) -> Entity {
     EmptyBuilder::new(commands)
         .with_safety_zone(commands)
-        .with_safezone_visual(commands, asset_server, position)
+        .with_safezone_visual(commands, asset_server, game_constants, position)
         .build()
 }
 
 /// Legacy-compatible function expected by main.rs
-/// Spawns environmental resources randomly across the map
+/// Spawns environmental resources randomly across the room (GameConstants::room_half_extents)
 pub fn spawn_environmental_resources(
     commands: &mut Commands,
     asset_server: &Res<AssetServer>,
-    _game_constants: &GameConstants,
-    window_width: f32,
-    window_height: f32,
+    game_constants: &GameConstants,
 ) {
     let mut rng = rand::rng();
 
-    // Calculate spawn boundaries (leave some margin from edges)
-    let margin = 50.0;
-    let min_x = -window_width / 2.0 + margin;
-    let max_x = window_width / 2.0 - margin;
-    let min_y = -window_height / 2.0 + margin;
-    let max_y = window_height / 2.0 - margin;
+    // Calculate spawn boundaries (leave some margin from the walls)
+    let area = calculate_resource_spawn_area(game_constants.room_half_extents, 50.0);
+    let (min_x, max_x, min_y, max_y) = (area.min.x, area.max.x, area.min.y, area.max.y);
 
     // Spawn Wells (3-5 wells)
     let num_wells = rng.random_range(3..=5);
@@ -133,7 +203,7 @@ pub fn spawn_environmental_resources(
             rng.random_range(min_x..=max_x),
             rng.random_range(min_y..=max_y),
         );
-        create_well_entity(commands, asset_server, position);
+        create_well_entity(commands, asset_server, game_constants, position);
     }
 
     // Spawn Restaurants (2-4 restaurants)
@@ -143,7 +213,7 @@ pub fn spawn_environmental_resources(
             rng.random_range(min_x..=max_x),
             rng.random_range(min_y..=max_y),
         );
-        create_restaurant_entity(commands, asset_server, position);
+        create_restaurant_entity(commands, asset_server, game_constants, position);
     }
 
     // Spawn Hotels (1-3 hotels)
@@ -153,7 +223,7 @@ pub fn spawn_environmental_resources(
             rng.random_range(min_x..=max_x),
             rng.random_range(min_y..=max_y),
         );
-        create_hotel_entity(commands, asset_server, position);
+        create_hotel_entity(commands, asset_server, game_constants, position);
     }
 
     // Spawn Safe Zones (1-2 safe zones)
@@ -163,9 +233,44 @@ pub fn spawn_environmental_resources(
             rng.random_range(min_x..=max_x),
             rng.random_range(min_y..=max_y),
         );
-        create_safe_zone_entity(commands, asset_server, position);
+        create_safe_zone_entity(commands, asset_server, game_constants, position);
     }
 
     println!("Environmental resources spawned: {} wells, {} restaurants, {} hotels, {} safe zones",
              num_wells, num_restaurants, num_hotels, num_safe_zones);
 }
+
+// =============================================================================
+// ROOM CREATION
+// =============================================================================
+
+/// Thickness of each room wall in world units
+pub const WALL_THICKNESS: f32 = 20.0;
+
+/// Creates a single static wall centered at `center` spanning `half_size`
+/// Walls have no validation requirements, so they use the simple generic build
+pub fn create_wall_entity(commands: &mut Commands, center: Vec2, half_size: Vec2) -> Entity {
+    EmptyBuilder::new(commands)
+        .add_bundle(commands, (
+            Wall,
+            Name::new("Wall"),
+            RigidBody::Fixed,
+            Collider::cuboid(half_size.x, half_size.y),
+            wall_collision_groups(),
+            Transform::from_xyz(center.x, center.y, 0.0),
+        ))
+        .build_simple()
+}
+
+/// Spawns the four walls enclosing a room with the given half extents
+/// Walls sit just outside the room so the full interior stays walkable
+pub fn spawn_room_walls(commands: &mut Commands, room_half_extents: Vec2) {
+    let half_thickness = WALL_THICKNESS / 2.0;
+    let horizontal = Vec2::new(room_half_extents.x + WALL_THICKNESS, half_thickness);
+    let vertical = Vec2::new(half_thickness, room_half_extents.y + WALL_THICKNESS);
+
+    create_wall_entity(commands, Vec2::new(0.0, room_half_extents.y + half_thickness), horizontal);
+    create_wall_entity(commands, Vec2::new(0.0, -room_half_extents.y - half_thickness), horizontal);
+    create_wall_entity(commands, Vec2::new(room_half_extents.x + half_thickness, 0.0), vertical);
+    create_wall_entity(commands, Vec2::new(-room_half_extents.x - half_thickness, 0.0), vertical);
+}
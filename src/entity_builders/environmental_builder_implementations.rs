@@ -1,10 +1,13 @@
 use crate::entity_builders::environmental_entity_domains::*;
 use crate::entity_builders::generic_type_safe_builder::{validation_states::*, EmptyBuilder};
+use crate::utils::helpers::physics_helpers::resource_collision_groups;
 use bevy::prelude::*;
+use bevy_rapier2d::prelude::{Collider, Sensor};
 
 // Import all environmental-related components
+use crate::components::components_constants::GameConstants;
 use crate::components::components_environment::{
-    Hotel, Resource, ResourceType, Restaurant, SafeZone, Well,
+    Hotel, Resource, ResourceReservation, ResourceType, Restaurant, SafeZone, Well,
 };
 
 // =============================================================================
@@ -18,6 +21,7 @@ impl WellBuilderExt for EmptyBuilder {
                 water_capacity: 1.0,
                 consumption_rate: 0.02,
             },
+            ResourceReservation::default(),
             Resource {
                 resource_type: ResourceType::Water,
                 availability: 1.0,
@@ -33,7 +37,7 @@ impl WellBuilderExt for EmptyBuilder {
 }
 
 impl WellResourceExt for WellBuilder<Present, Missing> {
-    fn with_well_visual(self, commands: &mut Commands, asset_server: &Res<AssetServer>, position: Vec2) -> ValidatedWell {
+    fn with_well_visual(self, commands: &mut Commands, asset_server: &Res<AssetServer>, game_constants: &GameConstants, position: Vec2) -> ValidatedWell {
         let builder = self.add_bundle(commands, (
             Sprite {
                 image: asset_server.load("well.png"),
@@ -41,6 +45,10 @@ impl WellResourceExt for WellBuilder<Present, Missing> {
             },
             Name::new("Well"),
             Transform::from_xyz(position.x, position.y, 0.0),
+            // Sensor collider so NPCs register contact without being physically pushed
+            Collider::ball(game_constants.resource_radius),
+            Sensor,
+            resource_collision_groups(),
         ));
 
         builder.transform_to()
@@ -64,6 +72,7 @@ impl RestaurantBuilderExt for EmptyBuilder {
                 food_capacity: 1.0,
                 consumption_rate: 0.01,
             },
+            ResourceReservation::default(),
             Resource {
                 resource_type: ResourceType::Food,
                 availability: 1.0,
@@ -79,7 +88,7 @@ impl RestaurantBuilderExt for EmptyBuilder {
 }
 
 impl RestaurantResourceExt for RestaurantBuilder<Present, Missing> {
-    fn with_restaurant_visual(self, commands: &mut Commands, asset_server: &Res<AssetServer>, position: Vec2) -> ValidatedRestaurant {
+    fn with_restaurant_visual(self, commands: &mut Commands, asset_server: &Res<AssetServer>, game_constants: &GameConstants, position: Vec2) -> ValidatedRestaurant {
         let builder = self.add_bundle(commands, (
             Sprite {
                 image: asset_server.load("restaurant.png"),
@@ -87,6 +96,10 @@ impl RestaurantResourceExt for RestaurantBuilder<Present, Missing> {
             },
             Name::new("Restaurant"),
             Transform::from_xyz(position.x, position.y, 0.0),
+            // Sensor collider so NPCs register contact without being physically pushed
+            Collider::ball(game_constants.resource_radius),
+            Sensor,
+            resource_collision_groups(),
         ));
 
         builder.transform_to()
@@ -121,7 +134,7 @@ impl HotelBuilderExt for EmptyBuilder {
 }
 
 impl HotelResourceExt for HotelBuilder<Present, Missing, Missing> {
-    fn with_hotel_visual(self, commands: &mut Commands, asset_server: &Res<AssetServer>, position: Vec2) -> HotelBuilder<Present, Present, Missing> {
+    fn with_hotel_visual(self, commands: &mut Commands, asset_server: &Res<AssetServer>, game_constants: &GameConstants, position: Vec2) -> HotelBuilder<Present, Present, Missing> {
         let builder = self.add_bundle(commands, (
             Sprite {
                 image: asset_server.load("hotel.png"),
@@ -129,6 +142,10 @@ impl HotelResourceExt for HotelBuilder<Present, Missing, Missing> {
             },
             Name::new("Hotel"),
             Transform::from_xyz(position.x, position.y, 0.0),
+            // Sensor collider so NPCs register contact without being physically pushed
+            Collider::ball(game_constants.resource_radius),
+            Sensor,
+            resource_collision_groups(),
         ));
 
         builder.transform_to()
@@ -144,6 +161,7 @@ impl HotelVisualExt for HotelBuilder<Present, Present, Missing> {
                 max_beds: 10,
                 comfort_level: 0.8,
             },
+            ResourceReservation::with_capacity(10), // One slot per bed
         ));
 
         builder.transform_to()
@@ -169,6 +187,7 @@ impl SafeZoneBuilderExt for EmptyBuilder {
                 capacity: 15,
                 current_occupancy: 0,
             },
+            ResourceReservation::with_capacity(15),
         ));
 
         builder.transform_to()
@@ -176,7 +195,7 @@ impl SafeZoneBuilderExt for EmptyBuilder {
 }
 
 impl SafetyZoneExt for SafeZoneBuilder<Present, Missing> {
-    fn with_safezone_visual(self, commands: &mut Commands, asset_server: &Res<AssetServer>, position: Vec2) -> ValidatedSafeZone {
+    fn with_safezone_visual(self, commands: &mut Commands, asset_server: &Res<AssetServer>, game_constants: &GameConstants, position: Vec2) -> ValidatedSafeZone {
         let builder = self.add_bundle(commands, (
             Sprite {
                 image: asset_server.load("safezone.png"),
@@ -184,6 +203,10 @@ impl SafetyZoneExt for SafeZoneBuilder<Present, Missing> {
             },
             Name::new("Safe Zone"),
             Transform::from_xyz(position.x, position.y, 0.0),
+            // Sensor collider so NPCs register contact without being physically pushed
+            Collider::ball(game_constants.resource_radius),
+            Sensor,
+            resource_collision_groups(),
         ));
 
         builder.transform_to()
@@ -1,4 +1,5 @@
 use crate::entity_builders::generic_type_safe_builder::{validation_states::*, TypeSafeEntityBuilder};
+use crate::components::components_constants::GameConstants;
 use bevy::prelude::*;
 use std::marker::PhantomData;
 
@@ -28,7 +29,7 @@ pub trait WellBuilderExt {
 }
 
 pub trait WellResourceExt {
-    fn with_well_visual(self, commands: &mut Commands, asset_server: &Res<AssetServer>, position: Vec2) -> ValidatedWell;
+    fn with_well_visual(self, commands: &mut Commands, asset_server: &Res<AssetServer>, game_constants: &GameConstants, position: Vec2) -> ValidatedWell;
 }
 
 // =============================================================================
@@ -54,7 +55,7 @@ pub trait RestaurantBuilderExt {
 }
 
 pub trait RestaurantResourceExt {
-    fn with_restaurant_visual(self, commands: &mut Commands, asset_server: &Res<AssetServer>, position: Vec2) -> ValidatedRestaurant;
+    fn with_restaurant_visual(self, commands: &mut Commands, asset_server: &Res<AssetServer>, game_constants: &GameConstants, position: Vec2) -> ValidatedRestaurant;
 }
 
 // =============================================================================
@@ -81,7 +82,7 @@ pub trait HotelBuilderExt {
 }
 
 pub trait HotelResourceExt {
-    fn with_hotel_visual(self, commands: &mut Commands, asset_server: &Res<AssetServer>, position: Vec2) -> HotelBuilder<Present, Present, Missing>;
+    fn with_hotel_visual(self, commands: &mut Commands, asset_server: &Res<AssetServer>, game_constants: &GameConstants, position: Vec2) -> HotelBuilder<Present, Present, Missing>;
 }
 
 pub trait HotelVisualExt {
@@ -111,7 +112,7 @@ pub trait SafeZoneBuilderExt {
 }
 
 pub trait SafetyZoneExt {
-    fn with_safezone_visual(self, commands: &mut Commands, asset_server: &Res<AssetServer>, position: Vec2) -> ValidatedSafeZone;
+    fn with_safezone_visual(self, commands: &mut Commands, asset_server: &Res<AssetServer>, game_constants: &GameConstants, position: Vec2) -> ValidatedSafeZone;
 }
 
 // =============================================================================
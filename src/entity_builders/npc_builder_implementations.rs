@@ -5,12 +5,18 @@ use bevy::prelude::*;
 // Import all NPC-related components
 use crate::components::{
     components_constants::GameConstants,
-    components_knowledge::KnowledgeBase,
-    components_needs::{Desire, DesireThresholds},
-    components_npc::{ApparentState, Npc, PerceivedEntities, Personality, RefillState, VisionRange},
-    components_pathfinding::{PathTarget, ResourceMemory, SteeringBehavior},
+    components_environment::CurrentZone,
+    components_knowledge::{Beliefs, KnowledgeBase},
+    components_learning::{AdaptiveBehavior, CognitiveMap, ForageMemory, PathLearning},
+    components_needs::{CurrentDesire, CustomDesireRegistry, CustomNeeds, DecisionStability, Desire, DesireThresholds, DualProcess, HomeostaticSetpoints, NeedDepletion, NeedPriorityWeights, SafetyHabituation, Willpower},
+    components_npc::{ApparentState, Mood, Npc, Panic, PerceivedEntities, Perception, Personality, Proprioception, RefillState, RelaxState, VisionRange, WorldModel},
+    components_pathfinding::{AvoidedTargets, PathTarget, PhysicalContacts, ResourceMemory, SteeringBehavior, StuckEscape},
+    components_performance::AgentLod,
+    components_social::{Attitude, SocialEnergy, SocialInfluence, SocialInteractionState, SocialMetrics, SocialRelationships},
+    components_telemetry::NeedHistory,
 };
-use crate::utils::helpers::needs_helpers::create_random_basic_needs;
+use crate::utils::helpers::needs_helpers::random_basic_needs_from_rng;
+use crate::utils::helpers::physics_helpers::{npc_body_size, npc_ccd, npc_collision_groups};
 use bevy_rapier2d::prelude::*;
 use rand::prelude::*;
 
@@ -19,24 +25,28 @@ use rand::prelude::*;
 // =============================================================================
 
 /// Implementation for starting NPC building from EmptyBuilder
+/// Traits are drawn from the given generator, so a seeded SimulationRng spawns the same individuals every run
 impl NpcBuilderExt for EmptyBuilder {
-    fn with_npc_core(self, commands: &mut Commands) -> NpcBuilder<Present, Missing, Missing, Missing, Missing, Missing> {
-        let mut rng = rand::rng();
-
+    fn with_npc_core(self, commands: &mut Commands, rng: &mut impl Rng) -> NpcBuilder<Present, Missing, Missing, Missing, Missing, Missing> {
         let builder = self.add_bundle(commands, (
             Npc,
-            Personality {
-                openness: rng.random_range(0.0..1.0),
-                extraversion: rng.random_range(0.0..1.0),
-                agreeableness: rng.random_range(0.0..1.0),
-                conscientiousness: rng.random_range(0.0..1.0),
-                neuroticism: rng.random_range(0.0..1.0),
-            },
+            Personality::from_rng(rng),
+            Perception::from_rng(rng),
+            (Mood::default(), Panic::default()),
+            Attitude::from_rng(rng),
             RefillState::default(),
+            RelaxState::default(),
             KnowledgeBase {
                 knows_rumor: false,
                 known_rumors: std::collections::HashMap::new(),
             },
+            Beliefs::default(),
+            SocialRelationships::default(),
+            SocialInteractionState::default(),
+            SocialEnergy::default(),
+            SocialMetrics::default(),
+            SocialInfluence::default(),
+            CurrentZone::default(),
         ));
 
         builder.transform_to()
@@ -44,14 +54,34 @@ impl NpcBuilderExt for EmptyBuilder {
 }
 
 /// Implementation for adding needs after core is present
+/// Starting needs and setpoints come from the given generator, so seeded runs decay and decide identically
+/// CustomNeeds is only added when the registry holds custom desires, and starts with every one of them satisfied
 impl NpcCoreExt for NpcBuilder<Present, Missing, Missing, Missing, Missing, Missing> {
-    fn with_needs(self, commands: &mut Commands) -> NpcBuilder<Present, Present, Missing, Missing, Missing, Missing> {
+    fn with_needs(
+        self,
+        commands: &mut Commands,
+        rng: &mut impl Rng,
+        custom_registry: &CustomDesireRegistry,
+    ) -> NpcBuilder<Present, Present, Missing, Missing, Missing, Missing> {
         let builder = self.add_bundle(commands, (
-            create_random_basic_needs(),
+            random_basic_needs_from_rng(rng),
             Desire::default(),
             DesireThresholds::default(),
+            HomeostaticSetpoints::from_rng(rng),
+            NeedPriorityWeights::default(),
+            CurrentDesire::default(),
+            DualProcess::default(),
+            Willpower::default(),
+            SafetyHabituation::default(),
+            DecisionStability::default(),
+            NeedDepletion::default(),
+            NeedHistory::default(),
         ));
 
+        if !custom_registry.desires.is_empty() {
+            commands.entity(builder.entity()).insert(CustomNeeds::default());
+        }
+
         builder.transform_to()
     }
 }
@@ -63,6 +93,13 @@ impl NpcNeedsExt for NpcBuilder<Present, Present, Missing, Missing, Missing, Mis
             PathTarget::default(),
             SteeringBehavior::default(),
             ResourceMemory::default(),
+            AvoidedTargets::default(),
+            StuckEscape::default(),
+            CognitiveMap::default(),
+            ForageMemory::default(),
+            AdaptiveBehavior::default(),
+            PathLearning::default(),
+            AgentLod::default(),
         ));
 
         builder.transform_to()
@@ -78,7 +115,8 @@ impl NpcPathfindingExt for NpcBuilder<Present, Present, Present, Missing, Missin
         asset_server: &Res<AssetServer>,
         game_constants: &GameConstants,
     ) -> NpcBuilder<Present, Present, Present, Present, Missing, Missing> {
-        let sprite_size = Vec2::splat(game_constants.npc_radius * 2.0);
+        let body = npc_body_size(game_constants);
+        let sprite_size = Vec2::splat(body.radius * 2.0);
 
         let builder = self.add_bundle(commands, (
             // Visual rendering components
@@ -92,7 +130,10 @@ impl NpcPathfindingExt for NpcBuilder<Present, Present, Present, Missing, Missin
             // NEW: Vision System 1.3.1 components following "Mantle of Ignorance"
             ApparentState::default(),      // What others can observe about this agent
             PerceivedEntities::default(),  // What this agent perceives about others
+            WorldModel::default(),         // What this agent believes about the world beyond its sight
             VisionRange::default(),        // This agent's visual perception capabilities
+            Proprioception::default(),     // This agent's sense of its own motion
+            body,                          // Size shared by sprite, collider and movement
         ));
 
         builder.transform_to()
@@ -110,12 +151,14 @@ impl NpcVisualExt for NpcBuilder<Present, Present, Present, Present, Missing, Mi
         let builder = self.add_bundle(commands, (
             RigidBody::Dynamic,
             GravityScale(0.0),
-            Collider::ball(game_constants.npc_radius),
+            Collider::ball(npc_body_size(game_constants).radius),
             Restitution::coefficient(0.0), // No bouncing to reduce pushing
             Friction::coefficient(1.0),    // High friction to prevent sliding
             ActiveEvents::COLLISION_EVENTS,
+            npc_collision_groups(),        // Collide with NPCs, resources and walls
             LockedAxes::ROTATION_LOCKED,   // Prevent rotation
-            Ccd::enabled(),                // Continuous collision detection for better stability
+            npc_ccd(game_constants),       // Continuous collision detection when npc_ccd_enabled, so fast agents cannot tunnel
+            PhysicalContacts::default(),   // Touching the target resource counts as arrival
         ));
 
         builder.transform_to()
@@ -128,12 +171,13 @@ impl NpcPhysicsExt for NpcBuilder<Present, Present, Present, Present, Present, M
         self,
         commands: &mut Commands,
         game_constants: &GameConstants,
+        rng: &mut impl Rng,
     ) -> ValidatedNpc {
-        let mut rng = rand::rng();
+        let body = npc_body_size(game_constants);
         let initial_velocity = Vec2::new(
             rng.random_range(-1.0..=1.0),
             rng.random_range(-1.0..=1.0),
-        ).normalize_or_zero() * game_constants.npc_speed;
+        ).normalize_or_zero() * body.max_speed;
 
         let builder = self.add_bundle(commands, (
             Velocity {
@@ -141,7 +185,7 @@ impl NpcPhysicsExt for NpcBuilder<Present, Present, Present, Present, Present, M
                 angvel: 0.0,
             },
             Damping {
-                linear_damping: 0.1,
+                linear_damping: body.linear_damping,
                 angular_damping: 0.8,
             },
         ));
@@ -164,13 +208,15 @@ impl EmptyBuilder {
         commands: &mut Commands,
         asset_server: &Res<AssetServer>,
         game_constants: &GameConstants,
+        custom_registry: &CustomDesireRegistry,
+        rng: &mut impl Rng,
     ) -> Entity {
-        self.with_npc_core(commands)
-            .with_needs(commands)
+        self.with_npc_core(commands, rng)
+            .with_needs(commands, rng, custom_registry)
             .with_pathfinding(commands)
             .with_visual(commands, asset_server, game_constants)
             .with_physics(commands, game_constants)
-            .with_movement(commands, game_constants)
+            .with_movement(commands, game_constants, rng)
             .build()
     }
 }
@@ -24,12 +24,17 @@ pub type ValidatedNpc = NpcBuilder<Present, Present, Present, Present, Present,
 
 /// Extension trait for NPC building - no component imports here, just state transitions
 pub trait NpcBuilderExt {
-    fn with_npc_core(self, commands: &mut Commands) -> NpcBuilder<Present, Missing, Missing, Missing, Missing, Missing>;
+    fn with_npc_core(self, commands: &mut Commands, rng: &mut impl rand::Rng) -> NpcBuilder<Present, Missing, Missing, Missing, Missing, Missing>;
 }
 
 /// NPC chaining methods - each advances the state machine
 pub trait NpcCoreExt {
-    fn with_needs(self, commands: &mut Commands) -> NpcBuilder<Present, Present, Missing, Missing, Missing, Missing>;
+    fn with_needs(
+        self,
+        commands: &mut Commands,
+        rng: &mut impl rand::Rng,
+        custom_registry: &crate::components::components_needs::CustomDesireRegistry,
+    ) -> NpcBuilder<Present, Present, Missing, Missing, Missing, Missing>;
 }
 
 pub trait NpcNeedsExt {
@@ -58,6 +63,7 @@ pub trait NpcPhysicsExt {
         self,
         commands: &mut Commands,
         game_constants: &crate::components::components_constants::GameConstants,
+        rng: &mut impl rand::Rng,
     ) -> ValidatedNpc;
 }
 
@@ -0,0 +1,4 @@
+pub mod components;
+pub mod entity_builders;
+pub mod systems;
+pub mod utils;
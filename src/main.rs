@@ -1,49 +1,129 @@
-mod components;
-mod systems;
-mod entity_builders;
-mod utils;
-
-use crate::components::components_constants::{ColorConstants, GameConstants, RumorTimer};
-use crate::components::components_default::CustomComponentsPlugin;
-use crate::entity_builders::entity_builders_default::{spawn_environmental_resources, spawn_test_npcs};
-use crate::systems::events::events_environment::{ResourceDepletionEvent, ResourceInteractionAttemptEvent, ResourceInteractionEvent, ResourceInteractionSuccessEvent, ResourceProximityEvent, ResourceRegenerationEvent};
-use crate::systems::events::events_needs::{ActionCompleted, CurrentDesireSet, DesireChangeEvent, DesireFulfillmentAttemptEvent, EvaluateDecision, NeedChangeEvent, NeedDecayEvent, NeedSatisfactionEvent, SocialInteractionEvent, ThresholdCrossedEvent};
-use crate::systems::systems_environment::{
+use artificial_society::components::components_camera::{CameraController, SelectedNpc};
+use artificial_society::components::components_constants::{AiFeatureFlags, ColorConstants, GameConstants, LifecycleConfig, LogGate, ConstantsHotReloadConfig, DecisionLookaheadConfig, DecisionStabilityConfig, NeedCouplingConfig, NeedEventMode, NeedFailureConfig, OpinionDynamicsConfig, PanicConfig, ResourceContentionPolicy, RestQualityConfig, RumorTimer, SatiationConfig, ScenarioFile, SimLogConfig, SimulationRng, SocialConfig};
+use artificial_society::components::components_default::CustomComponentsPlugin;
+use artificial_society::components::components_environment::{Weather, WeatherEffects, ZoneLayout, ZoneOccupancy};
+use artificial_society::components::components_inspection::AgentComparison;
+use artificial_society::components::components_knowledge::RumorRegistry;
+use artificial_society::components::components_needs::CustomDesireRegistry;
+use artificial_society::components::components_npc::{AgentTint, VisionConeOverlay};
+use artificial_society::components::components_pathfinding::ResourceSpatialIndex;
+use artificial_society::components::components_social::{ContactDebounce, SocialProximity};
+use artificial_society::components::components_performance::{AgentLodConfig, EventBacklog, EventBacklogConfig, FrameBudgetGovernor, FrameTimeMonitor, LearningMemoryConfig, ProfilerConfig, SystemTimings};
+use artificial_society::components::components_telemetry::{DesireCensusConfig, DiagnosticOverlay, NeedHistoryConfig, SocialHierarchy, SocietyWellbeing, TelemetryConfig, TelemetryRecorder, WellbeingConfig};
+use artificial_society::entity_builders::entity_builders_default::{spawn_environmental_resources, spawn_test_npcs, WALL_THICKNESS};
+use artificial_society::systems::events::events_environment::{ResourceContentionResolved, ResourceDepletionEvent, ResourceInteractionAttemptEvent, ResourceInteractionEvent, ResourceInteractionSuccessEvent, ResourceProximityEvent, ResourceRegenerationEvent};
+use artificial_society::systems::events::events_knowledge::{BeliefEvidenceEvent, BeliefUpdated};
+use artificial_society::systems::events::events_lifecycle::NpcDespawnEvent;
+use artificial_society::systems::events::events_needs::{ActionCompleted, ActionOutcome, CurrentDesireSet, DesireCensus, DesireChangeEvent, DesireFulfillmentAttemptEvent, EvaluateDecision, NeedChangeEvent, NeedCriticalEvent, NeedDecayEvent, NeedSatisfactionEvent, NeedsChangedBatch, SocialInteractionEvent, ThresholdCrossedEvent};
+use artificial_society::systems::systems_camera::{agent_comparison_system, agent_picking_system, camera_controller_system};
+use artificial_society::systems::systems_environment::{
+    agent_zone_system,
+    create_empty_room_system,
     refill_management_system,
     resource_interaction_system,
     resource_regeneration_system,
+    weather_cycle_system,
 };
-use crate::systems::systems_movement::{
+use artificial_society::systems::systems_features::{
+    cognition_enabled,
+    navigation_enabled,
+    perception_enabled,
+    physiology_enabled,
+    social_enabled,
+};
+use artificial_society::systems::systems_knowledge::{belief_evidence_system, belief_update_system};
+use artificial_society::systems::systems_learning::{
+    action_outcome_system,
+    adaptive_behavior_system,
+    cognitive_map_update_system,
+    curiosity_exploration_system,
+    forage_memory_system,
+    landmark_recording_system,
+    landmark_sharing_system,
+    learning_memory_pruning_system,
+    path_experience_system,
+    path_learning_system,
+};
+use artificial_society::systems::systems_lifecycle::npc_despawn_system;
+use artificial_society::systems::systems_movement::{
     boundary_collision_system,
+    face_velocity_system,
     movement_analytics_system,
     movement_pattern_analysis_system,
     physics_movement_system,
 };
-use crate::systems::systems_needs::{
+use artificial_society::systems::systems_needs::{
+    DecisionPipelinePlugin,
     action_failure_handling_system,
+    custom_needs_system,
     debug_npc_status,
     decay_basic_needs,
-    decision_making_system,
+    decision_stability_system,
     desire_fulfillment_system,
-    desire_update_system,
+    dual_process_update_system,
     handle_social_interactions,
-    optimized_threshold_monitoring_system,
-    periodic_decision_trigger_system,
-    threshold_monitoring_system,
+    mood_update_system,
+    need_deficit_injection_system,
+    need_failure_system,
+    relax_state_system,
+    social_need_fulfillment_system,
+    willpower_recovery_system,
 };
-use crate::systems::systems_pathfinding::{
+use artificial_society::systems::systems_pathfinding::{
     desire_pathfinding_system,
+    physical_contact_system,
+    reservation_release_system,
     resource_discovery_system,
+    resource_spatial_index_system,
     steering_behavior_system,
 };
 // Import all the systems we need
-use crate::systems::systems_rumor::{
+use artificial_society::systems::systems_performance::{
+    agent_lod_update_system,
+    configure_simulation_tick_rate_system,
+    event_backlog_system,
+    frame_budget_governor_system,
+    frame_time_monitor_system,
+    frame_time_sample_system,
+    learning_memory_monitor_system,
+    perception_budget_allows,
+    profiler_report_system,
+    timing_span_end,
+    timing_span_start,
+};
+use artificial_society::systems::systems_rumor::{
     rumor_decay_system,
     rumor_injection_system,
     rumor_interaction_detection_system,
     rumor_transmission_system,
 };
-use crate::systems::systems_visual::{color_system, update_apparent_state_system, vision_system};
+use artificial_society::systems::systems_schedule::SimulationSet;
+use artificial_society::systems::systems_social::{group_formation_system, helping_system, knowledge_exchange_system, leadership_detection_system, panic_contagion_system, social_proximity_system, opinion_dynamics_system, social_energy_system, social_interaction_lifecycle_system, social_interaction_system, social_metrics_system};
+use artificial_society::systems::systems_telemetry::{
+    diagnostic_overlay_render_system, diagnostic_overlay_toggle_system, diagnostic_overlay_update_system, need_history_sampling_system, society_desire_census_system,
+    society_wellbeing_system, spawn_diagnostic_overlay_system, telemetry_flush_on_exit_system, telemetry_snapshot_system,
+};
+use artificial_society::systems::systems_validation::{
+    constants_file_watch_system,
+    constants_hot_reload_system,
+    load_scenario_system,
+    validate_basic_needs_system,
+    validate_desire_thresholds_system,
+    validate_game_constants_system,
+};
+use artificial_society::systems::systems_visual::{
+    agent_tint_system,
+    agent_tint_toggle_system,
+    color_system,
+    need_history_plot_system,
+    proprioception_system,
+    update_apparent_state_system,
+    vision_cone_gizmo_system,
+    vision_cone_overlay_enabled,
+    vision_cone_toggle_system,
+    vision_system,
+    world_model_update_system,
+};
 use bevy::input::common_conditions::input_toggle_active;
 use bevy::prelude::*;
 use bevy_inspector_egui::{
@@ -51,152 +131,345 @@ use bevy_inspector_egui::{
     quick::WorldInspectorPlugin,
 };
 use bevy_rapier2d::prelude::*;
-use systems::events::events_movement::{BoundaryCollisionEvent, MovementBehaviorEvent};
-use systems::events::events_pathfinding::{PathTargetReachedEvent, PathTargetSetEvent, ResourceDiscoveredEvent};
-use systems::events::events_rumor::{RumorInjectionEvent, RumorSpreadAttemptEvent, RumorSpreadEvent};
+use artificial_society::systems::events::events_movement::{BoundaryCollisionEvent, MovementBehaviorEvent};
+use artificial_society::systems::events::events_pathfinding::{PathTargetReachedEvent, PathTargetSetEvent, ReservationDenied, ResourceDiscoveredEvent};
+use artificial_society::systems::events::events_performance::PerformanceAlertEvent;
+use artificial_society::systems::events::events_rumor::{RumorInjectionEvent, RumorMutationEvent, RumorSpreadAttemptEvent, RumorSpreadEvent};
+use artificial_society::systems::events::events_social::{ConflictResolved, GatheringDissolved, GatheringFormed, InteractionCompleted, InteractionStarted, RelationshipChangedEvent};
+use artificial_society::systems::events::events_validation::{ConstantsFileChanged, GenericComponentEvent};
+use artificial_society::utils::helpers::camera_helpers::calculate_world_fit_scaling;
+use artificial_society::utils::helpers::physics_helpers::physics_timestep_mode;
 
 fn setup_simulation(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     game_constants: Res<GameConstants>,
-    windows: Query<&Window>,
+    custom_registry: Res<CustomDesireRegistry>,
+    mut simulation_rng: ResMut<SimulationRng>,
 ) {
-    commands.spawn(Camera2d);
-
-    // Spawn NPCs first
-    spawn_test_npcs(&mut commands, &asset_server, &game_constants);
-
-    // Spawn environmental resources randomly across the space
-    if let Ok(window) = windows.single() {
-        spawn_environmental_resources(
-            &mut commands,
-            &asset_server,
-            &game_constants,
-            window.width(),
-            window.height(),
-        );
-    }
+    // The camera fits the whole room whatever the window size; scrolling zooms relative to that fit
+    commands.spawn((
+        Camera2d,
+        Projection::Orthographic(OrthographicProjection {
+            scaling_mode: calculate_world_fit_scaling(game_constants.room_half_extents, WALL_THICKNESS),
+            ..OrthographicProjection::default_2d()
+        }),
+    ));
+
+    // Spawn NPCs first, laid out by GameConstants::spawn_distribution
+    spawn_test_npcs(&mut commands, &asset_server, &game_constants, &custom_registry, &mut simulation_rng.rng);
+
+    // Spawn environmental resources randomly across the room
+    spawn_environmental_resources(&mut commands, &asset_server, &game_constants);
 }
 
 fn main() {
     App::new()
+        // Fixed stepping from the start so Rapier does not warn; configure_simulation_tick_rate_system applies the validated constants
+        .insert_resource(physics_timestep_mode(&GameConstants::default()))
         .add_plugins((
             DefaultPlugins,
             EguiPlugin::default(),
             WorldInspectorPlugin::default().run_if(input_toggle_active(true, KeyCode::Escape)),
             CustomComponentsPlugin,
-            RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(100.0),
+            DecisionPipelinePlugin::default(), // UtilityArbitrated - see DecisionPipelineMode for the alternative
+            RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(100.0).in_fixed_schedule(), // Steps with the fixed simulation tick
             RapierDebugRenderPlugin::default(),
         ))
         // Resources initialization
         .insert_resource(RumorTimer(Timer::from_seconds(3.0, TimerMode::Once)))
         .insert_resource(GameConstants::default())
         .insert_resource(ColorConstants::default())
+        .init_resource::<SimulationRng>()
+        .init_resource::<AiFeatureFlags>() // Every domain enabled; switch domains off to isolate experiments
+        .init_resource::<RumorRegistry>()
+        .init_resource::<SocialConfig>()
+        .init_resource::<OpinionDynamicsConfig>() // Deffuant bounded confidence without polarization
+        .init_resource::<PanicConfig>() // Off; enable so fear spreads through crowds
+        .init_resource::<ContactDebounce>()
+        .init_resource::<SocialProximity>() // Filled only with SocialConfig::proximity_interactions
+        .init_resource::<LifecycleConfig>()
+        .init_resource::<Weather>()
+        .init_resource::<WeatherEffects>()
+        .init_resource::<NeedEventMode>() // Granular; switch to Batched for large populations
+        .init_resource::<ResourceContentionPolicy>() // First come, first served at contested wells and restaurants
+        .init_resource::<DecisionStabilityConfig>()
+        .init_resource::<ConstantsHotReloadConfig>() // Edit game_constants.json to tune constants live
+        .init_resource::<ScenarioFile>() // Read once at startup; scenario.ron may fix the seed and constants
+        .init_resource::<DecisionLookaheadConfig>() // Off; enable so deliberate agents plan a trip ahead
+        .init_resource::<CustomDesireRegistry>()    // Empty; register needs such as "Work" or "Play" here
+        .init_resource::<NeedCouplingConfig>() // All zeros: needs decay independently
+        .init_resource::<NeedFailureConfig>() // Off; enable so needs left at zero incapacitate or kill
+        .init_resource::<SatiationConfig>() // Off; enable so topping off nearly full needs is mostly wasted
+        .init_resource::<RestQualityConfig>() // Resting inside a hotel or safe zone recovers more than in the open
+        .init_resource::<SimLogConfig>() // Routine per-agent messages at trace level, raise categories to Info to debug
+        .init_resource::<LogGate>() // Open; close it to silence all routine logging while measuring performance
+        .init_resource::<ResourceSpatialIndex>()
+        .init_resource::<FrameTimeMonitor>()
+        .init_resource::<FrameBudgetGovernor>()
+        .init_resource::<AgentLodConfig>() // Agents learn every fourth tick while frames run over budget
+        .init_resource::<LearningMemoryConfig>() // Caps per-agent learned data, consolidated knowledge is kept
+        .init_resource::<EventBacklogConfig>()
+        .init_resource::<EventBacklog>()
+        .init_resource::<ProfilerConfig>() // Report written to profiler_report.json on exit or P
+        .init_resource::<SystemTimings>()
+        .init_resource::<CameraController>()
+        .init_resource::<SelectedNpc>()
+        .init_resource::<AgentComparison>()
+        .init_resource::<VisionConeOverlay>() // Hidden until toggled with V
+        .init_resource::<AgentTint>() // Sprites tinted by desire; C cycles the mode, T the personality trait
+        .init_resource::<TelemetryConfig>()
+        .init_resource::<TelemetryRecorder>()
+        .init_resource::<WellbeingConfig>()
+        .init_resource::<DesireCensusConfig>()
+        .init_resource::<DesireCensus>() // Latest census, replaced every DesireCensusConfig::interval_secs
+        .init_resource::<NeedHistoryConfig>() // One sample a second, two minutes kept per agent
+        .init_resource::<DiagnosticOverlay>() // Hidden until toggled with F3
+        .init_resource::<SocietyWellbeing>()
+        .init_resource::<SocialHierarchy>()
+        .init_resource::<ZoneLayout>() // No zones; populate to tag agents with named regions
+        .init_resource::<ZoneOccupancy>()
 
         // Register Rapier debug render context for inspector control
         .register_type::<DebugRenderContext>()
 
         // ML-HOOK: Register all events for quantifiable behavior tracking
         .add_event::<NeedDecayEvent>()
+        .add_event::<NeedCriticalEvent>()
         .add_event::<DesireChangeEvent>()
+        .add_event::<DesireCensus>()
         .add_event::<SocialInteractionEvent>()
         .add_event::<ThresholdCrossedEvent>()
         .add_event::<DesireFulfillmentAttemptEvent>()
         .add_event::<NeedSatisfactionEvent>()
         .add_event::<NeedChangeEvent>()
+        .add_event::<NeedsChangedBatch>()
         // NEW: Decision-making events from roadmap 1.3.2
         .add_event::<EvaluateDecision>()
         .add_event::<CurrentDesireSet>()
         // NEW: Action Management events from roadmap 1.3.3
         .add_event::<ActionCompleted>()
+        .add_event::<ActionOutcome>()
         .add_event::<RumorInjectionEvent>()
         .add_event::<RumorSpreadEvent>()
         .add_event::<RumorSpreadAttemptEvent>()
+        .add_event::<RumorMutationEvent>()
         .add_event::<BoundaryCollisionEvent>()
         .add_event::<MovementBehaviorEvent>()
         .add_event::<ResourceInteractionEvent>()
         .add_event::<ResourceDepletionEvent>()
         .add_event::<ResourceInteractionAttemptEvent>()
+        .add_event::<ResourceContentionResolved>()
         .add_event::<ResourceInteractionSuccessEvent>()
         .add_event::<ResourceRegenerationEvent>()
         .add_event::<ResourceProximityEvent>()
         .add_event::<PathTargetSetEvent>()
         .add_event::<PathTargetReachedEvent>()
         .add_event::<ResourceDiscoveredEvent>()
+        .add_event::<ReservationDenied>()
+        .add_event::<GenericComponentEvent>()
+        .add_event::<ConstantsFileChanged>()
+        .add_event::<BeliefEvidenceEvent>()
+        .add_event::<BeliefUpdated>()
+        .add_event::<PerformanceAlertEvent>()
+        .add_event::<RelationshipChangedEvent>()
+        .add_event::<ConflictResolved>()
+        .add_event::<GatheringFormed>()
+        .add_event::<GatheringDissolved>()
+        .add_event::<InteractionStarted>()
+        .add_event::<InteractionCompleted>()
+        .add_event::<NpcDespawnEvent>()
 
 
-        // Startup systems
-        .add_systems(Startup, setup_simulation)
+        // Startup systems - the scenario is applied and constants are validated before anything is spawned from them,
+        // and the room is built before anything is placed inside it
+        .add_systems(Startup, (load_scenario_system, validate_game_constants_system, configure_simulation_tick_rate_system, create_empty_room_system, setup_simulation, spawn_diagnostic_overlay_system).chain())
 
         // Update systems organized by event flow and dependencies for optimal performance
-        .add_systems(Update, (
+        // Simulation systems run on the fixed clock (GameConstants::simulation_tick_hz), so need decay,
+        // decision cadence and steering are identical at any render rate. Events written in a fixed tick
+        // stay readable until the next tick has run, so no consumer misses them
+        // Each phase runs in its own SimulationSet and every phase is chained, so the execution order, and with it
+        // the order events are written in, is the same on every run
+        .add_systems(FixedUpdate, (
             // PHASE 0: Decision Triggers (Event Producers)
-            // NEW: Periodic decision evaluation system from roadmap 1.3.2
-            periodic_decision_trigger_system,   // Fires EvaluateDecision events periodically
+            // Decision triggers themselves are registered by DecisionPipelinePlugin
+            npc_despawn_system                      // Removes NPCs and purges references before anyone looks them up
+                .in_set(SimulationSet::Lifecycle),
 
             // PHASE 1: Core State Updates and Perception (Event Producers)
             // NEW: Vision System 1.3.1 - Must run early to populate perception data
             (
-                update_apparent_state_system,           // NEW: Updates externally visible state
-                vision_system,                          // NEW: Populates perception data using spatial queries
-                decay_basic_needs,                      // Produces NeedChangeEvent, NeedDecayEvent
-                optimized_threshold_monitoring_system,  // NEW: Optimized version that triggers decision evaluation
-            ),
+                validate_desire_thresholds_system,      // Corrects inverted dual thresholds on newly spawned agents
+                validate_basic_needs_system,            // Clamps and reports need values written outside 0.0-1.0
+                weather_cycle_system,                   // Advances daylight when a day/night cycle is configured
+                agent_zone_system,                      // Tags agents with their ZoneLayout zone and recounts occupancy
+                (
+                    update_apparent_state_system,       // NEW: Updates externally visible state
+                    (
+                        timing_span_start("vision_system"),
+                        vision_system,                  // NEW: Populates perception data, skips ticks under load
+                        timing_span_end("vision_system"),
+                    ).chain().run_if(perception_budget_allows),
+                    proprioception_system,              // Senses self-motion from position changes for path integration
+                ).chain().run_if(perception_enabled),
+                (
+                    (
+                        timing_span_start("decay_basic_needs"),
+                        decay_basic_needs,              // Produces NeedChangeEvent, NeedDecayEvent
+                        timing_span_end("decay_basic_needs"),
+                    ).chain(),
+                    dual_process_update_system,         // Derives stress from unmet needs for System 1/2 gating
+                    mood_update_system,                 // Derives valence and arousal from needs and stress
+                    relax_state_system,                 // Brings fully satisfied idle agents to rest in place
+                    willpower_recovery_system,          // Restores willpower while resting or relaxing
+                    custom_needs_system,                // Decays and refills needs added through CustomDesireRegistry
+                    need_failure_system,                // Fails needs left at zero, produces NeedCriticalEvent
+                ).chain().run_if(physiology_enabled),
+                panic_contagion_system.run_if(social_enabled), // Spreads damped safety drops from panicking agents, produces NeedChangeEvent
+                (
+                    resource_spatial_index_system,      // Rebuilds the resource grid only when resources change
+                    agent_lod_update_system,            // Lowers every agent's learning rate while frames run over budget
+                    cognitive_map_update_system,        // Builds familiarity with the cells agents occupy
+                ).chain().run_if(navigation_enabled),
+            ).chain().in_set(SimulationSet::StateUpdate),
 
             // PHASE 2: Decision Making (Event Consumers → Event Producers)
-            // NEW: Core decision-making system from roadmap 1.3.2
+            // Threshold monitoring and desire selection come from DecisionPipelinePlugin, in SimulationSet::Decision
             (
-                decision_making_system,         // NEW: Uses evaluate_most_urgent_desire for holistic decisions
-                threshold_monitoring_system,    // Legacy: Still used for logging/debugging threshold crossings
-                desire_update_system,           // Legacy: Individual desire updates (less optimal)
-                resource_discovery_system,      // Produces ResourceDiscoveredEvent, PathTargetSetEvent
-            ),
+                resource_discovery_system.run_if(navigation_enabled), // Produces ResourceDiscoveredEvent, PathTargetSetEvent
+                world_model_update_system,                            // Folds the agents in sight into each WorldModel
+                group_formation_system.run_if(social_enabled),        // Groups mutually aware agents into gatherings
+                forage_memory_system.run_if(navigation_enabled),      // Consumes ResourceDiscoveredEvent, remembers rewarding cells
+                landmark_recording_system.run_if(navigation_enabled), // Consumes ResourceDiscoveredEvent, names new landmarks
+                decision_stability_system.run_if(cognition_enabled),  // Rates desire changes per minute, biases dithering agents to commit
+            ).chain().in_set(SimulationSet::Cognition),
 
             // PHASE 3: Action Execution (Event Consumers)
             // These systems execute the decisions made in Phase 2
             (
                 // Movement systems - execute movement decisions
-                desire_pathfinding_system,      // Consumes DesireChangeEvent, PathTargetSetEvent
-                steering_behavior_system,       // Consumes pathfinding data, applies weighted utility
+                (
+                    reservation_release_system,     // Frees resource slots agents no longer use
+                    desire_pathfinding_system,      // Consumes DesireChangeEvent, PathTargetSetEvent; produces ReservationDenied
+                    curiosity_exploration_system,   // Sends idle wanderers toward unexplored cells
+                    physical_contact_system,        // Consumes CollisionEvent, tracks what each body touches
+                    steering_behavior_system,       // Consumes pathfinding data, applies weighted utility
+                ).chain().run_if(navigation_enabled),
                 physics_movement_system,        // Executes actual movement
+                face_velocity_system,           // Turns sprites, and with them vision, toward the velocity
                 boundary_collision_system,      // Handles movement constraints
-            ),
+            ).chain().in_set(SimulationSet::Movement),
 
             // PHASE 4: Interaction Systems (Event Consumers → Event Producers)
             // These systems handle entity interactions based on movement/proximity
             (
                 // Refill management - handles NPC refilling state
-                refill_management_system,            // Manages refilling state transitions
+                refill_management_system.run_if(physiology_enabled), // Manages refilling state transitions
 
                 // NEW: Action failure handling - makes characters adaptive when desires fail
-                action_failure_handling_system,      // NEW: Handles failed desires with adaptive retry/switching
+                action_failure_handling_system.run_if(cognition_enabled), // NEW: Handles failed desires with adaptive retry/switching
 
                 // Social interactions - handle NPC-to-NPC interactions
-                rumor_interaction_detection_system,  // Detects proximity for rumors
-                rumor_transmission_system,           // Handles rumor spread events
-                handle_social_interactions,          // Processes social need fulfillment
+                (
+                    rumor_interaction_detection_system,  // Detects proximity for rumors
+                    rumor_transmission_system,           // Handles rumor spread events
+                    social_proximity_system,             // Queues pairs coming within interaction_distance, when enabled
+                    handle_social_interactions,          // Starts interactions on NPC-NPC contact or proximity
+                    social_interaction_lifecycle_system, // Completes interactions that have run their course
+                    social_energy_system,                // Drains and restores social energy, when social fatigue is on
+                    social_need_fulfillment_system,      // Satisfies the social need from completed interactions
+                    social_interaction_system,           // Updates relationships from completed interactions
+                    opinion_dynamics_system,             // Pulls close attitudes together after completed interactions
+                    landmark_sharing_system,             // Reconciles landmarks both partners know after they talk
+                    helping_system,                      // Points neighbours in critical need to known resources, when enabled
+                    knowledge_exchange_system,           // Passes resource locations on after information sharing, when enabled
+                    social_metrics_system,               // Periodically summarises each agent's network position
+                    leadership_detection_system,         // Periodically scores influence and flags emergent leaders
+                ).chain().run_if(social_enabled),
 
                 // Resource interactions - handle NPC-to-resource interactions
-                resource_interaction_system,         // Processes resource interaction attempts
-                desire_fulfillment_system,           // Handles desire satisfaction from interactions
-            ),
+                (
+                    resource_interaction_system,         // Processes resource interaction attempts
+                    desire_fulfillment_system,           // Handles desire satisfaction from interactions
+                ).chain().run_if(physiology_enabled),
+            ).chain().in_set(SimulationSet::Interaction),
 
             // PHASE 5: World State Management (Event Consumers)
             // These systems update world state based on interactions
             (
                 resource_regeneration_system,   // Regenerates depleted resources
-                rumor_injection_system,         // Injects new rumors into the system
-                rumor_decay_system,             // Decays existing rumors over time
-            ),
+                (
+                    rumor_injection_system,     // Injects new rumors into the system
+                    rumor_decay_system,         // Decays existing rumors over time
+                ).chain().run_if(social_enabled),
+                (
+                    belief_evidence_system,     // Turns rumors and resource use into belief evidence
+                    belief_update_system,       // Applies evidence to beliefs with confirmation bias
+                    adaptive_behavior_system,   // Tunes exploration rate from action outcomes
+                    action_outcome_system,      // Consolidates completed actions into ActionOutcome for learners
+                ).chain().run_if(cognition_enabled),
+                society_wellbeing_system,       // Periodically aggregates population need satisfaction
+                society_desire_census_system,   // Periodically counts desires, produces DesireCensus
+                need_history_sampling_system,   // Periodically records each agent's needs for graphing
+                cognition_enabled.pipe(event_backlog_system::<NeedChangeEvent>), // Warns when the decision pipeline misses need changes
+                (
+                    path_experience_system,     // Records arrivals as successful path experiences
+                    path_learning_system,       // Folds new experiences into learned path values
+                    learning_memory_monitor_system,  // Raises HighMemoryUsage when learned data outgrows its budget
+                    learning_memory_pruning_system,  // Trims the weakest learned data, harder after a memory alert
+                ).chain().run_if(navigation_enabled),
+            ).chain().in_set(SimulationSet::WorldUpdate),
+        ))
+
+        // Presentation, input and frame-time monitoring follow the render rate
+        .add_systems(Update, (
+            // Frame budget - measures render frames, its multipliers are read by the fixed simulation
+            (
+                frame_time_monitor_system,          // Raises HighFrameTime alerts when over budget
+                frame_budget_governor_system,       // Degrades optional systems while over budget
+                frame_time_sample_system,           // Keeps frame times for the profiler report
+            ).chain(),
+
+            // Live tuning - applied between fixed ticks, so every simulation tick sees one consistent set of constants
+            (
+                constants_file_watch_system,        // Produces ConstantsFileChanged when the constants file is edited
+                constants_hot_reload_system,        // Applies and validates the new values
+            ).chain(),
 
             // PHASE 6: Feedback and Analysis (Event Consumers, Low Priority)
             // These systems provide visual feedback and analytics
             (
                 color_system,                   // Visual feedback based on current state
+                (
+                    agent_tint_toggle_system,   // C and T switch what the sprite tint shows
+                    agent_tint_system,          // Tints sprites by desire, needs, personality or reputation
+                ).chain(),
                 movement_pattern_analysis_system, // Analytics for movement patterns
                 movement_analytics_system,      // General movement analytics
                 debug_npc_status,              // Debug information display
+                telemetry_snapshot_system,     // Appends periodic population snapshots to JSONL
+                (
+                    agent_picking_system,       // Click selects the NPC under the cursor, Shift-click picks one to compare
+                    camera_controller_system,   // Pans, zooms and follows the selected NPC
+                    agent_comparison_system,    // Diffs the selected and compared NPCs for the inspector
+                ).chain(),
+                need_deficit_injection_system,  // Keys 1-5 drop a need of the selected NPC to test the decision chain
+                (
+                    vision_cone_toggle_system,  // V shows or hides the vision cones
+                    vision_cone_gizmo_system.run_if(vision_cone_overlay_enabled), // Draws perception coverage
+                ).chain(),
+                need_history_plot_system,       // Graphs the selected NPC's need history above it
+                (
+                    diagnostic_overlay_toggle_system, // F3 shows or hides the global stats panel
+                    diagnostic_overlay_update_system, // Copies the latest census, wellbeing and FPS
+                    diagnostic_overlay_render_system.run_if(resource_changed::<DiagnosticOverlay>), // Rewrites the panel text
+                ).chain(),
             ),
         ))
+        .add_systems(Last, (
+            profiler_report_system,        // Sees AppExit from every earlier schedule of the frame
+            telemetry_flush_on_exit_system, // Writes snapshots still short of a full batch
+        ))
         .run();
 }
@@ -1,3 +1,4 @@
+use crate::components::components_constants::ResourceContentionPolicy;
 use crate::components::components_environment::ResourceType;
 use bevy::prelude::{Entity, Event};
 
@@ -31,6 +32,33 @@ pub struct ResourceInteractionSuccessEvent {
     pub resource_availability_after: f32,
 }
 
+/// One claimant's share of a contested resource
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResourceAllocation {
+    /// The NPC entity claiming the resource
+    pub npc_entity: Entity,
+    /// Amount of stock the NPC asked for
+    pub requested: f32,
+    /// Amount of stock the NPC received
+    pub allocated: f32,
+}
+
+/// Fired when several NPCs claim the same limited resource in one update
+/// Based on Cooperation Theory - records how the commons was split under the active ResourceContentionPolicy
+#[derive(Event, Debug)]
+pub struct ResourceContentionResolved {
+    /// The contested resource entity
+    pub resource_entity: Entity,
+    /// Type of resource contested
+    pub resource_type: ResourceType,
+    /// Policy the stock was split under
+    pub policy: ResourceContentionPolicy,
+    /// Stock before any claimant was served (0.0-1.0)
+    pub stock_before: f32,
+    /// Every claimant's request and allocation, in arrival order
+    pub allocations: Vec<ResourceAllocation>,
+}
+
 /// Fired when a resource regenerates capacity
 /// Based on Resource Economics - regeneration cycles
 #[derive(Event, Debug)]
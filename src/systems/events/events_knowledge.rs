@@ -0,0 +1,30 @@
+use crate::components::components_knowledge::Proposition;
+use bevy::prelude::{Entity, Event};
+
+/// Fired when an NPC observes something that supports or contradicts a proposition
+/// Based on Bayesian Belief Updating - beliefs only change in response to evidence
+#[derive(Event, Debug, Clone)]
+pub struct BeliefEvidenceEvent {
+    /// The NPC receiving the evidence
+    pub entity: Entity,
+    /// The proposition the evidence is about
+    pub proposition: Proposition,
+    /// Signed evidence strength (-1.0 = strongly against, 1.0 = strongly for)
+    pub strength: f32,
+}
+
+/// Fired after an NPC's certainty in a proposition changes
+/// ML-HOOK: Quantifiable belief trajectories for confirmation bias analysis
+#[derive(Event, Debug, Clone)]
+pub struct BeliefUpdated {
+    /// The NPC whose belief changed
+    pub entity: Entity,
+    /// The proposition that was updated
+    pub proposition: Proposition,
+    /// Certainty before the update (0.0-1.0)
+    pub old_certainty: f32,
+    /// Certainty after the update (0.0-1.0)
+    pub new_certainty: f32,
+    /// Whether the evidence agreed with the belief held before the update
+    pub confirming: bool,
+}
@@ -0,0 +1,9 @@
+use bevy::prelude::{Entity, Event};
+
+/// Request to remove an NPC from the running simulation
+/// Handled by npc_despawn_system, which also purges every reference other agents hold to it
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct NpcDespawnEvent {
+    /// The NPC to remove
+    pub entity: Entity,
+}
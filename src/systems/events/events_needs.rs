@@ -1,5 +1,7 @@
+use crate::components::components_constants::NeedFailureConsequence;
 use crate::components::components_needs::Desire;
-use bevy::prelude::{Entity, Event};
+use bevy::prelude::{Entity, Event, Resource};
+use std::collections::HashMap;
 
 // ML-HOOK: Events for quantifiable behavior tracking and reward calculation
 
@@ -13,6 +15,22 @@ pub struct NeedChangeEvent {
     pub change_amount: f32,
 }
 
+/// A single need transition carried inside a NeedsChangedBatch
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NeedChange {
+    pub need_type: NeedType,
+    pub old_value: f32,
+    pub new_value: f32,
+}
+
+/// Event carrying all of one agent's need changes for a frame
+/// Fired by decay_basic_needs instead of one NeedChangeEvent per need when NeedEventMode::Batched is selected
+#[derive(Event)]
+pub struct NeedsChangedBatch {
+    pub entity: Entity,
+    pub changes: Vec<NeedChange>,
+}
+
 /// Event fired when a need crosses a threshold (either above or below)
 #[derive(Event)]
 pub struct ThresholdCrossedEvent {
@@ -82,6 +100,34 @@ pub struct ActionCompleted {
     pub success: bool, // Whether the action achieved its goal
 }
 
+/// Event summarising one completed action for external learners: what drove it, what was done and what it changed
+/// Consolidated by action_outcome_system from NeedSatisfactionEvent (successes) and ActionCompleted (failures),
+/// one per agent and action, so learners need not correlate the two
+/// ML-HOOK: (desire, action, need_deltas, reward) is a single reinforcement learning transition
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct ActionOutcome {
+    pub entity: Entity,
+    /// Desire that motivated the action
+    pub desire: Desire,
+    /// What the agent did about it
+    pub action: ActionTaken,
+    /// Change the action made to each need it affected
+    pub need_deltas: Vec<(NeedType, f32)>,
+    /// Whether the action achieved its goal
+    pub success: bool,
+    /// Scalar reward for the action (see calculate_action_reward)
+    pub reward: f32,
+}
+
+/// What an agent did in response to a desire, as reported by ActionOutcome
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ActionTaken {
+    /// Used a resource to satisfy a need, if known which one
+    UseResource { resource_entity: Option<Entity> },
+    /// Gave up on the desire
+    Abandon(ActionCompletionReason),
+}
+
 /// Event that triggers decision-making evaluation for an agent
 /// This is the missing event from roadmap 1.3.2 that should trigger the decision_making_system
 #[derive(Event)]
@@ -101,6 +147,8 @@ pub enum DecisionTrigger {
     Stimulus,
     /// Forced re-evaluation (e.g., from ML agent)
     Forced,
+    /// Sharp safety drop taking the fight-or-flight fast path
+    Threat,
 }
 
 /// Event fired when an agent's current desire is set after decision evaluation
@@ -112,6 +160,17 @@ pub struct CurrentDesireSet {
     pub competing_desires: Vec<(Desire, f32)>, // ML-HOOK: All evaluated desires for observation space
 }
 
+/// Event fired when a need has stayed at 0.0 for NeedFailureConfig::critical_duration_secs
+/// Fired once per depletion, together with the configured consequence being applied
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct NeedCriticalEvent {
+    pub entity: Entity,
+    pub need_type: NeedType,
+    /// How long the need had been at 0.0
+    pub secs_at_zero: f32,
+    pub consequence: NeedFailureConsequence,
+}
+
 /// Types of needs that can change
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NeedType {
@@ -143,7 +202,7 @@ pub enum DesireChangeReason {
 }
 
 /// Enum describing why an action was completed
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ActionCompletionReason {
     /// Action succeeded - need was satisfied
     Success,
@@ -154,3 +213,31 @@ pub enum ActionCompletionReason {
     /// Action timed out
     Timeout,
 }
+
+/// Event fired every DesireCensusConfig::interval_secs with how many agents hold each desire
+/// The latest census is also kept as a resource, so telemetry and dashboards can read it between firings
+/// ML-HOOK: population-level goal distribution, e.g. "40% seeking water"
+#[derive(Event, Resource, Debug, Clone, PartialEq, Default)]
+pub struct DesireCensus {
+    /// Elapsed simulation time the census was taken at
+    pub time: f32,
+    /// Number of agents counted
+    pub population: usize,
+    /// Agents holding each desire; desires nobody holds are absent
+    pub counts: HashMap<Desire, usize>,
+}
+
+impl DesireCensus {
+    /// Number of agents holding the desire
+    pub fn count(&self, desire: Desire) -> usize {
+        self.counts.get(&desire).copied().unwrap_or(0)
+    }
+
+    /// Fraction of the population (0.0-1.0) holding the desire, 0.0 for an empty population
+    pub fn share(&self, desire: Desire) -> f32 {
+        if self.population == 0 {
+            return 0.0;
+        }
+        self.count(desire) as f32 / self.population as f32
+    }
+}
@@ -27,4 +27,12 @@ pub struct ResourceDiscoveredEvent {
     pub resource_entity: Entity,
     pub resource_type: ResourceType,
     pub discovery_distance: f32, // ML-HOOK: Spatial cognition metrics
-}
\ No newline at end of file
+}
+/// Sent when an agent wanted a resource whose reservation slots were all taken
+/// The agent re-evaluates its other known resources before committing a PathTarget
+#[derive(Event)]
+pub struct ReservationDenied {
+    pub npc_entity: Entity,
+    pub resource_entity: Entity,
+    pub resource_position: Vec2,
+}
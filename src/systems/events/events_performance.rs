@@ -0,0 +1,35 @@
+use bevy::prelude::Event;
+
+/// Alerts raised by the frame time monitor, the event backlog diagnostic and the learning memory monitor
+/// ML-HOOK: Performance events allow correlating simulation load with agent behaviour
+#[derive(Event, Debug, Clone, PartialEq)]
+pub enum PerformanceAlertEvent {
+    /// The smoothed frame time exceeded the frame budget
+    HighFrameTime {
+        /// Smoothed frame time in milliseconds
+        frame_time_ms: f32,
+        /// Frame budget in milliseconds
+        budget_ms: f32,
+    },
+    /// The smoothed frame time dropped back below the recovery threshold
+    FrameTimeRecovered {
+        /// Smoothed frame time in milliseconds
+        frame_time_ms: f32,
+        /// Frame budget in milliseconds
+        budget_ms: f32,
+    },
+    /// Events of a monitored type aged out of Bevy's double buffer before their consumer read them
+    EventBacklog {
+        /// Short type name of the dropped events
+        event_type: &'static str,
+        /// Number of events dropped unread since the last alert
+        dropped: usize,
+    },
+    /// The learned data of all agents together outgrew LearningMemoryConfig::memory_alert_entries
+    HighMemoryUsage {
+        /// Cognitive map cells, learned route values and path experiences held across all agents
+        learned_entries: usize,
+        /// Entry budget the total exceeded
+        budget: usize,
+    },
+}
@@ -33,4 +33,12 @@ pub struct RumorSpreadAttemptEvent {
     pub transmission_probability: f32,     // Calculated transmission probability
     pub success: bool,
     pub calculated_probability: f32,       // ML-HOOK: Track all attempts for learning
-}
\ No newline at end of file
+}
+
+#[derive(Event)]
+pub struct RumorMutationEvent {
+    pub sender: Entity,             // NPC that told the rumor
+    pub receiver: Entity,           // NPC that heard the altered version
+    pub original_rumor: String,     // Version the sender told
+    pub variant_rumor: String,      // New variant the receiver now holds
+}
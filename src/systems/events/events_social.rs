@@ -0,0 +1,82 @@
+use crate::components::components_social::{ConflictCause, ConflictResolution, InteractionType};
+use bevy::prelude::{Entity, Event};
+
+/// Fired when an interaction changes how one agent regards another
+/// ML-HOOK: Quantifiable relationship dynamics for social network analysis
+#[derive(Event, Debug, Clone)]
+pub struct RelationshipChangedEvent {
+    /// The agent whose relationship changed
+    pub entity: Entity,
+    /// The agent the relationship is directed at
+    pub other: Entity,
+    /// Kind of interaction that caused the change
+    pub interaction_type: InteractionType,
+    /// Affinity after the interaction (-1.0-1.0)
+    pub affinity: f32,
+    /// Trust after the interaction (0.0-1.0)
+    pub trust: f32,
+}
+
+/// Fired when a conflict between two agents has been resolved
+/// ML-HOOK: Conflict frequency and outcomes for studying competition under scarcity
+#[derive(Event, Debug, Clone)]
+pub struct ConflictResolved {
+    /// First agent in the conflict
+    pub entity_1: Entity,
+    /// Second agent in the conflict
+    pub entity_2: Entity,
+    /// What started the conflict
+    pub cause: ConflictCause,
+    /// How it was resolved
+    pub resolution: ConflictResolution,
+    /// How the outcome felt to the first agent (0.0-1.0)
+    pub satisfaction_1: f32,
+    /// How the outcome felt to the second agent (0.0-1.0)
+    pub satisfaction_2: f32,
+}
+
+/// Fired when two agents in contact begin a social interaction
+/// ML-HOOK: Interaction initiation rate for studying sociability
+#[derive(Event, Debug, Clone)]
+pub struct InteractionStarted {
+    /// First agent in the interaction (lowest entity)
+    pub entity_1: Entity,
+    /// Second agent in the interaction
+    pub entity_2: Entity,
+    /// Elapsed simulation time when the interaction started
+    pub started_at: f32,
+}
+
+/// Fired when a social interaction has run its full duration
+/// The social need is satisfied from this event, not from the initial contact, in proportion to each side's satisfaction
+#[derive(Event, Debug, Clone)]
+pub struct InteractionCompleted {
+    /// First agent in the interaction (lowest entity)
+    pub entity_1: Entity,
+    /// Second agent in the interaction
+    pub entity_2: Entity,
+    /// How long the interaction lasted, in seconds
+    pub duration: f32,
+    /// Kind of exchange, from the first agent's perspective
+    pub interaction_type: InteractionType,
+    /// How satisfying the exchange was for the first agent (0.0-1.0)
+    pub satisfaction_1: f32,
+    /// How satisfying the exchange was for the second agent (0.0-1.0)
+    pub satisfaction_2: f32,
+}
+
+/// Fired when agents that perceive each other come together as a new gathering
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct GatheringFormed {
+    /// The GatheringId shared by the members
+    pub id: u32,
+    /// The agents in the gathering, sorted by entity
+    pub members: Vec<Entity>,
+}
+
+/// Fired when a gathering has dispersed below SocialConfig::gathering_min_members mutually aware agents
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct GatheringDissolved {
+    /// The GatheringId that is no longer in use
+    pub id: u32,
+}
@@ -0,0 +1,31 @@
+use bevy::prelude::{Entity, Event};
+
+// ML-HOOK: Events for quantifiable data-integrity tracking
+
+/// Generic events describing the health of component and resource data
+/// Systems fire these instead of panicking so corrupted values stay observable
+#[derive(Event, Debug, Clone)]
+pub enum GenericComponentEvent {
+    /// A value failed validation and was corrected in place
+    ValidationFailed {
+        /// Entity owning the component, None for global Bevy resources like GameConstants
+        entity: Option<Entity>,
+        /// Name of the component or resource type
+        component: &'static str,
+        /// Name of the offending field
+        field: &'static str,
+        /// Value found before correction
+        original_value: f32,
+        /// Value the field holds after correction
+        corrected_value: f32,
+    },
+}
+
+/// Fired when the watched constants file (see ConstantsHotReloadConfig) appears or changes on disk
+#[derive(Event, Debug, Clone)]
+pub struct ConstantsFileChanged {
+    /// Path the contents were read from
+    pub path: String,
+    /// Full contents of the file
+    pub contents: String,
+}
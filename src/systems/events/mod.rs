@@ -1,6 +1,11 @@
 pub mod events_environment;
+pub mod events_knowledge;
+pub mod events_lifecycle;
 pub mod events_movement;
 pub mod events_needs;
 pub mod events_pathfinding;
+pub mod events_performance;
 pub mod events_rumor;
+pub mod events_social;
+pub mod events_validation;
 pub mod events_visual;
\ No newline at end of file
@@ -1,8 +1,19 @@
 pub mod events;
+pub mod systems_camera;
 pub mod systems_environment;
+pub mod systems_experiment;
+pub mod systems_features;
+pub mod systems_knowledge;
+pub mod systems_learning;
+pub mod systems_lifecycle;
 pub mod systems_movement;
 pub mod systems_needs;
 pub mod systems_pathfinding;
+pub mod systems_performance;
 pub mod systems_rumor;
+pub mod systems_schedule;
+pub mod systems_social;
+pub mod systems_telemetry;
+pub mod systems_validation;
 pub mod systems_visual;
 
@@ -0,0 +1,130 @@
+use bevy::input::mouse::{AccumulatedMouseScroll, MouseScrollUnit};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::components::components_camera::{CameraController, SelectedNpc};
+use crate::components::components_constants::GameConstants;
+use crate::components::components_inspection::{AgentComparison, AgentSnapshot};
+use crate::components::components_knowledge::KnowledgeBase;
+use crate::components::components_needs::{BasicNeeds, Desire};
+use crate::components::components_npc::Npc;
+use crate::components::components_pathfinding::ResourceMemory;
+use crate::components::components_social::SocialRelationships;
+use crate::utils::helpers::camera_helpers::{calculate_follow_position, calculate_zoom, pick_nearest_agent};
+
+/// Pixel-precise scroll devices (touchpads) report this many pixels per scroll-wheel line
+const PIXELS_PER_SCROLL_LINE: f32 = 20.0;
+
+/// System moving and zooming the scene camera
+/// WASD/arrow keys pan, the scroll wheel zooms (clamped to the controller's range) and F toggles
+/// following the NPC selected by agent_picking_system; panning is ignored while following
+pub fn camera_controller_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    scroll: Res<AccumulatedMouseScroll>,
+    mut controller: ResMut<CameraController>,
+    selected: Res<SelectedNpc>,
+    mut camera_query: Query<(&mut Transform, &mut Projection), With<Camera2d>>,
+    npc_query: Query<&Transform, (With<Npc>, Without<Camera2d>)>,
+    time: Res<Time>,
+) {
+    let Ok((mut camera_transform, mut projection)) = camera_query.single_mut() else {
+        return;
+    };
+    let delta_time = time.delta_secs();
+
+    if keyboard.just_pressed(KeyCode::KeyF) {
+        controller.follow_selected = !controller.follow_selected;
+    }
+
+    let scroll_lines = match scroll.unit {
+        MouseScrollUnit::Line => scroll.delta.y,
+        MouseScrollUnit::Pixel => scroll.delta.y / PIXELS_PER_SCROLL_LINE,
+    };
+    controller.zoom = calculate_zoom(controller.zoom, scroll_lines, controller.zoom_speed, controller.min_zoom, controller.max_zoom);
+    if let Projection::Orthographic(orthographic) = projection.as_mut() {
+        orthographic.scale = controller.zoom;
+    }
+
+    let camera_position = camera_transform.translation.truncate();
+    let new_position = if controller.follow_selected {
+        match selected.0.and_then(|entity| npc_query.get(entity).ok()) {
+            Some(npc_transform) => calculate_follow_position(
+                camera_position,
+                npc_transform.translation.truncate(),
+                controller.follow_smoothing,
+                delta_time,
+            ),
+            None => camera_position,
+        }
+    } else {
+        let pressed = |keys: [KeyCode; 2]| keyboard.any_pressed(keys) as i32 as f32;
+        let direction = Vec2::new(
+            pressed([KeyCode::KeyD, KeyCode::ArrowRight]) - pressed([KeyCode::KeyA, KeyCode::ArrowLeft]),
+            pressed([KeyCode::KeyW, KeyCode::ArrowUp]) - pressed([KeyCode::KeyS, KeyCode::ArrowDown]),
+        );
+        camera_position + direction.normalize_or_zero() * controller.pan_speed * controller.zoom * delta_time
+    };
+
+    camera_transform.translation.x = new_position.x;
+    camera_transform.translation.y = new_position.y;
+}
+
+/// System selecting the NPC under the cursor on left click, clicking empty space clears the selection
+/// The cursor is converted through the camera's own projection, so picking stays accurate while zoomed
+/// Holding Shift picks the agent to compare against the selection instead (see AgentComparison)
+pub fn agent_picking_system(
+    mouse: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    npc_query: Query<(Entity, &Transform), With<Npc>>,
+    game_constants: Res<GameConstants>,
+    mut selected: ResMut<SelectedNpc>,
+    mut comparison: ResMut<AgentComparison>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    let (Ok(window), Ok((camera, camera_transform))) = (windows.single(), camera_query.single()) else {
+        return;
+    };
+    let Some(world_position) = window
+        .cursor_position()
+        .and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor).ok())
+    else {
+        return;
+    };
+
+    let picked = pick_nearest_agent(
+        world_position,
+        npc_query.iter().map(|(entity, transform)| (entity, transform.translation.truncate())),
+        game_constants.npc_radius,
+    );
+    if keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        comparison.compared = picked;
+    } else {
+        selected.0 = picked;
+    }
+}
+
+/// System refreshing the compare-two-agents view shown in the inspector
+/// Snapshots the selected and the compared agent and diffs them; clears the view when either is missing
+pub fn agent_comparison_system(
+    selected: Res<SelectedNpc>,
+    mut comparison: ResMut<AgentComparison>,
+    agents_query: Query<(&BasicNeeds, &Desire, Option<&SocialRelationships>, Option<&ResourceMemory>, Option<&KnowledgeBase>), With<Npc>>,
+) {
+    let snapshot = |entity: Option<Entity>| {
+        let (needs, desire, relationships, memory, knowledge) = agents_query.get(entity?).ok()?;
+        Some(AgentSnapshot::capture(needs, desire, relationships, memory, knowledge))
+    };
+
+    let (selected_snapshot, compared_snapshot) = (snapshot(selected.0), snapshot(comparison.compared));
+    comparison.diff = match (&selected_snapshot, &compared_snapshot) {
+        (Some(first), Some(second)) => Some(first.diff(second)),
+        _ => None,
+    };
+    comparison.selected_snapshot = selected_snapshot;
+    comparison.compared_snapshot = compared_snapshot;
+}
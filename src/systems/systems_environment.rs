@@ -1,134 +1,169 @@
+use crate::components::components_constants::{BoundaryPolicy, GameConstants, LogCategory, LogGate, ResourceContentionPolicy, SimLogConfig};
 use crate::components::components_needs::Desire;
 use crate::components::components_npc::{Npc, RefillState};
+use crate::components::components_environment::{CurrentZone, CustomResourceSite, ResourceType, Weather, ZoneLayout, ZoneOccupancy};
+use crate::components::components_social::SocialInfluence;
 use crate::components::{BasicNeeds, Hotel, Restaurant, Well};
+use crate::entity_builders::entity_builders_default::spawn_room_walls;
 use crate::systems::events::events_environment::{
-    ResourceInteractionAttemptEvent, ResourceInteractionSuccessEvent
-    , ResourceRegenerationEvent,
+    ResourceAllocation, ResourceContentionResolved, ResourceInteractionAttemptEvent, ResourceInteractionSuccessEvent,
+    ResourceRegenerationEvent,
 };
 use crate::systems::events::events_needs::{NeedChangeEvent, NeedType};
+use crate::sim_log;
+use crate::utils::helpers::weather_helpers::calculate_daylight;
 use crate::utils::helpers::resource_helpers::{
-    apply_satisfaction_to_needs, calculate_consumption_rate,
+    allocate_contested_stock, apply_satisfaction_to_needs, calculate_consumption_rate,
     calculate_satisfaction_gain, get_need_level_for_resource,
 };
 use bevy::ecs::event::{EventReader, EventWriter};
 use bevy::prelude::*;
+use std::collections::BTreeMap;
+
+/// Startup system that encloses the simulation in a walled room of GameConstants::room_half_extents
+/// The room never depends on the window, so a scenario plays out the same at any window size and headless
+/// A toroidal room (BoundaryPolicy::Wrap) gets no walls, agents pass through its edges instead
+pub fn create_empty_room_system(
+    mut commands: Commands,
+    game_constants: Res<GameConstants>,
+) {
+    if game_constants.boundary_policy != BoundaryPolicy::Wrap {
+        spawn_room_walls(&mut commands, game_constants.room_half_extents);
+    }
+}
 
 /// Event-driven system that handles resource interactions when NPCs desire specific resources
 /// Based on Environmental Psychology - resource interaction affects satisfaction
 /// Replaces the O(n) polling system with event-driven approach for better performance
+/// A well or restaurant claimed by several NPCs in the same update is split under the ResourceContentionPolicy
+/// (see allocate_contested_stock), each claimant's satisfaction scaled by the share of its request it received
 pub fn resource_interaction_system(
     mut interaction_events: EventReader<ResourceInteractionAttemptEvent>,
     mut success_events: EventWriter<ResourceInteractionSuccessEvent>,
+    mut contention_events: EventWriter<ResourceContentionResolved>,
     mut need_change_events: EventWriter<NeedChangeEvent>,
     mut needs_query: Query<&mut BasicNeeds>,
     mut well_query: Query<&mut Well>,
     mut restaurant_query: Query<&mut Restaurant>,
-    mut hotel_query: Query<&mut Hotel>,
+    hotel_query: Query<&Hotel>,
+    influence_query: Query<&SocialInfluence>,
+    policy: Res<ResourceContentionPolicy>,
 ) {
+    // Claimants of each resource in arrival order, resources in the order they were first claimed
+    let mut claims: Vec<(Entity, ResourceType, Vec<(Entity, f32)>)> = Vec::new();
     for event in interaction_events.read() {
-        if let Ok(mut needs) = needs_query.get_mut(event.npc_entity) {
-            let current_need_level = get_need_level_for_resource(&needs, event.resource_type);
+        let Ok(needs) = needs_query.get(event.npc_entity) else {
+            continue;
+        };
+        let current_need_level = get_need_level_for_resource(needs, event.resource_type);
 
-            // Skip if need is already satisfied
-            if current_need_level >= 0.9 {
-                continue;
-            }
+        // Skip if need is already satisfied
+        if current_need_level >= 0.9 {
+            continue;
+        }
 
-            // Try to interact with the resource
-            let interaction_result = match event.resource_type {
-                crate::components::components_environment::ResourceType::Water => {
-                    if let Ok(mut well) = well_query.get_mut(event.resource_entity) {
-                        if well.water_capacity > 0.1 {
-                            let satisfaction = calculate_satisfaction_gain(
-                                event.resource_type,
-                                well.water_capacity,
-                                current_need_level,
-                            );
-                            let consumption = calculate_consumption_rate(
-                                event.resource_type,
-                                1.0 - current_need_level,
-                            );
+        match claims.iter_mut().find(|(resource_entity, _, _)| *resource_entity == event.resource_entity) {
+            Some((_, _, claimants)) => claimants.push((event.npc_entity, current_need_level)),
+            None => claims.push((event.resource_entity, event.resource_type, vec![(event.npc_entity, current_need_level)])),
+        }
+    }
 
-                            well.water_capacity = (well.water_capacity - consumption).clamp(0.0, 1.0);
-                            Some((satisfaction, well.water_capacity))
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                }
-                crate::components::components_environment::ResourceType::Food => {
-                    if let Ok(mut restaurant) = restaurant_query.get_mut(event.resource_entity) {
-                        if restaurant.food_capacity > 0.1 {
-                            let satisfaction = calculate_satisfaction_gain(
-                                event.resource_type,
-                                restaurant.food_capacity,
-                                current_need_level,
-                            );
-                            let consumption = calculate_consumption_rate(
-                                event.resource_type,
-                                1.0 - current_need_level,
-                            );
+    for (resource_entity, resource_type, claimants) in claims {
+        // Stock left in the resource and whether claimants draw it down
+        let stock = match resource_type {
+            ResourceType::Water => well_query.get(resource_entity).ok().map(|well| (well.water_capacity, true)),
+            ResourceType::Food => restaurant_query.get(resource_entity).ok().map(|restaurant| (restaurant.food_capacity, true)),
+            // Hotels provide unlimited rest - they're safe zones
+            ResourceType::Rest => hotel_query.get(resource_entity).ok().map(|_| (1.0, false)),
+            _ => None, // Other resource types not implemented yet
+        };
+        let Some((stock, limited)) = stock else {
+            continue;
+        };
+        if stock <= 0.1 {
+            continue;
+        }
 
-                            restaurant.food_capacity = (restaurant.food_capacity - consumption).clamp(0.0, 1.0);
-                            Some((satisfaction, restaurant.food_capacity))
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
+        let demands: Vec<f32> = claimants
+            .iter()
+            .map(|(_, need_level)| calculate_consumption_rate(resource_type, 1.0 - need_level))
+            .collect();
+        let dominance: Vec<f32> = claimants
+            .iter()
+            .map(|(npc_entity, _)| influence_query.get(*npc_entity).map_or(0.0, |influence| influence.score))
+            .collect();
+        let allocations = allocate_contested_stock(*policy, stock, &demands, &dominance);
+
+        let stock_after = (stock - allocations.iter().sum::<f32>()).clamp(0.0, 1.0);
+        match resource_type {
+            ResourceType::Water => {
+                if let Ok(mut well) = well_query.get_mut(resource_entity) {
+                    well.water_capacity = stock_after;
                 }
-                crate::components::components_environment::ResourceType::Rest => {
-                    if let Ok(_hotel) = hotel_query.get(event.resource_entity) {
-                        // Hotels provide unlimited rest - they're safe zones
-                        let satisfaction = calculate_satisfaction_gain(
-                            event.resource_type,
-                            1.0, // Hotels always at full capacity
-                            current_need_level,
-                        );
-                        Some((satisfaction, 1.0))
-                    } else {
-                        None
-                    }
+            }
+            ResourceType::Food => {
+                if let Ok(mut restaurant) = restaurant_query.get_mut(resource_entity) {
+                    restaurant.food_capacity = stock_after;
                 }
-                _ => None, // Other resource types not implemented yet
-            };
+            }
+            _ => {}
+        }
 
-            // Apply satisfaction if interaction was successful
-            if let Some((satisfaction, resource_availability_after)) = interaction_result {
-                let old_need_level = current_need_level;
-                let actual_change = apply_satisfaction_to_needs(&mut needs, event.resource_type, satisfaction);
+        for (&(npc_entity, current_need_level), (&requested, &allocated)) in claimants.iter().zip(demands.iter().zip(&allocations)) {
+            let share = if requested > 0.0 { allocated / requested } else { 1.0 };
+            if share <= 0.0 {
+                continue;
+            }
+            let Ok(mut needs) = needs_query.get_mut(npc_entity) else {
+                continue;
+            };
 
-                // Fire need change event for threshold monitoring
-                if actual_change > 0.0 {
-                    let need_type = match event.resource_type {
-                        crate::components::components_environment::ResourceType::Water => NeedType::Thirst,
-                        crate::components::components_environment::ResourceType::Food => NeedType::Hunger,
-                        crate::components::components_environment::ResourceType::Rest => NeedType::Rest,
-                        crate::components::components_environment::ResourceType::Safety => NeedType::Safety,
-                        crate::components::components_environment::ResourceType::Loneliness => NeedType::Social,
-                    };
+            let satisfaction = calculate_satisfaction_gain(resource_type, stock, current_need_level) * share;
+            let actual_change = apply_satisfaction_to_needs(&mut needs, resource_type, satisfaction);
 
-                    need_change_events.write(NeedChangeEvent {
-                        entity: event.npc_entity,
-                        need_type,
-                        old_value: old_need_level,
-                        new_value: get_need_level_for_resource(&needs, event.resource_type),
-                        change_amount: actual_change,
-                    });
-                }
+            // Fire need change event for threshold monitoring
+            if actual_change > 0.0 {
+                let need_type = match resource_type {
+                    ResourceType::Water => NeedType::Thirst,
+                    ResourceType::Food => NeedType::Hunger,
+                    ResourceType::Rest => NeedType::Rest,
+                    ResourceType::Safety => NeedType::Safety,
+                    ResourceType::Loneliness => NeedType::Social,
+                    ResourceType::Custom(_) => continue, // Never claimed, custom needs never ask BasicNeeds for more
+                };
 
-                // Fire success event for ML tracking
-                success_events.write(ResourceInteractionSuccessEvent {
-                    npc_entity: event.npc_entity,
-                    resource_entity: event.resource_entity,
-                    resource_type: event.resource_type,
-                    satisfaction_gained: satisfaction,
-                    resource_availability_after,
+                need_change_events.write(NeedChangeEvent {
+                    entity: npc_entity,
+                    need_type,
+                    old_value: current_need_level,
+                    new_value: get_need_level_for_resource(&needs, resource_type),
+                    change_amount: actual_change,
                 });
             }
+
+            // Fire success event for ML tracking
+            success_events.write(ResourceInteractionSuccessEvent {
+                npc_entity,
+                resource_entity,
+                resource_type,
+                satisfaction_gained: satisfaction,
+                resource_availability_after: stock_after,
+            });
+        }
+
+        // ML-HOOK: how scarce stock was split, for comparing contention policies
+        if limited && claimants.len() > 1 {
+            contention_events.write(ResourceContentionResolved {
+                resource_entity,
+                resource_type,
+                policy: *policy,
+                stock_before: stock,
+                allocations: claimants
+                    .iter()
+                    .zip(demands.iter().zip(&allocations))
+                    .map(|(&(npc_entity, _), (&requested, &allocated))| ResourceAllocation { npc_entity, requested, allocated })
+                    .collect(),
+            });
         }
     }
 }
@@ -188,6 +223,9 @@ pub fn refill_management_system(
     restaurant_query: Query<Entity, (With<Restaurant>, Without<Npc>)>,
     hotel_query: Query<Entity, (With<Hotel>, Without<Npc>)>,
     resource_transforms: Query<&Transform, (Without<Npc>, Or<(With<Well>, With<Restaurant>, With<Hotel>)>)>,
+    custom_sites_query: Query<(Entity, &CustomResourceSite, &Transform), Without<Npc>>,
+    log_config: Res<SimLogConfig>,
+    log_gate: Option<Res<LogGate>>,
     time: Res<Time>,
 ) {
     const INTERACTION_DISTANCE: f32 = 40.0;
@@ -204,7 +242,7 @@ pub fn refill_management_system(
                 // Finished refilling
                 refill_state.is_refilling = false;
                 refill_state.resource_entity = None;
-                info!("NPC finished refilling");
+                sim_log!(log_gate, log_config, LogCategory::Needs, "NPC finished refilling");
             }
             continue;
         }
@@ -220,7 +258,7 @@ pub fn refill_management_system(
                             refill_state.refill_start_time = current_time;
                             refill_state.refill_duration = REFILL_DURATION;
                             refill_state.resource_entity = Some(well_entity);
-                            info!("NPC started refilling water");
+                            sim_log!(log_gate, log_config, LogCategory::Needs, "NPC started refilling water");
                             return true;
                         }
                     }
@@ -236,7 +274,7 @@ pub fn refill_management_system(
                             refill_state.refill_start_time = current_time;
                             refill_state.refill_duration = REFILL_DURATION;
                             refill_state.resource_entity = Some(restaurant_entity);
-                            info!("NPC started refilling food");
+                            sim_log!(log_gate, log_config, LogCategory::Needs, "NPC started refilling food");
                             return true;
                         }
                     }
@@ -252,14 +290,67 @@ pub fn refill_management_system(
                             refill_state.refill_start_time = current_time;
                             refill_state.refill_duration = REFILL_DURATION;
                             refill_state.resource_entity = Some(hotel_entity);
-                            info!("NPC started resting");
+                            sim_log!(log_gate, log_config, LogCategory::Needs, "NPC started resting");
                             return true;
                         }
                     }
                     false
                 })
             }
+            Desire::Custom(id) => {
+                custom_sites_query.iter().any(|(site_entity, site, site_transform)| {
+                    let distance = npc_position.distance(site_transform.translation.truncate());
+                    if site.id == id && distance <= INTERACTION_DISTANCE {
+                        refill_state.is_refilling = true;
+                        refill_state.refill_start_time = current_time;
+                        refill_state.refill_duration = REFILL_DURATION;
+                        refill_state.resource_entity = Some(site_entity);
+                        sim_log!(log_gate, log_config, LogCategory::Needs, "NPC started satisfying custom need {}", id);
+                        return true;
+                    }
+                    false
+                })
+            }
             _ => false,
         };
     }
 }
+
+/// System advancing the day/night cycle of the Weather
+/// Only runs the cycle when day_length_secs > 0, otherwise light_level stays wherever it was scripted
+pub fn weather_cycle_system(mut weather: ResMut<Weather>, time: Res<Time>) {
+    if weather.day_length_secs > 0.0 {
+        weather.light_level = calculate_daylight(time.elapsed_secs(), weather.day_length_secs);
+    }
+}
+
+/// System tagging each agent with the ZoneLayout zone it stands in and recounting ZoneOccupancy
+/// Agents are located through the layout's grid, which is rebuilt here only when the layout has changed
+/// Tags are only rewritten when an agent crosses into another zone, so Changed<CurrentZone> marks zone transitions
+pub fn agent_zone_system(
+    mut layout: ResMut<ZoneLayout>,
+    mut agents_query: Query<(&Transform, &mut CurrentZone), With<Npc>>,
+    mut occupancy: ResMut<ZoneOccupancy>,
+) {
+    if layout.is_changed() {
+        layout.bypass_change_detection().rebuild_index();
+    }
+
+    let mut counts: BTreeMap<String, usize> =
+        layout.zones.iter().map(|zone| (zone.name.clone(), 0)).collect();
+    let mut unzoned = 0;
+
+    for (transform, mut current_zone) in agents_query.iter_mut() {
+        let zone = layout.zone_at(transform.translation.truncate());
+        match zone {
+            Some(zone) => *counts.entry(zone.name.clone()).or_insert(0) += 1,
+            None => unzoned += 1,
+        }
+
+        if current_zone.zone.as_deref() != zone.map(|zone| zone.name.as_str()) {
+            current_zone.zone = zone.map(|zone| zone.name.clone());
+        }
+    }
+
+    occupancy.set_if_neq(ZoneOccupancy { counts, unzoned });
+}
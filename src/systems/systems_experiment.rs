@@ -0,0 +1,302 @@
+use bevy::ecs::event::{EventReader, EventWriter};
+use bevy::ecs::schedule::ExecutorKind;
+use bevy::prelude::*;
+use bevy::time::{TimePlugin, TimeUpdateStrategy};
+use bevy_rapier2d::prelude::{CollisionEvent, Velocity};
+use bevy_rapier2d::rapier::geometry::CollisionEventFlags;
+use rand::Rng;
+use std::time::Duration;
+
+use crate::components::components_constants::{GameConstants, LifecycleConfig, NeedCouplingConfig, NeedEventMode, SimLogConfig, SimulationRng};
+use crate::components::components_experiment::{EventStreamRecorder, ExperimentComparison, ExperimentMetrics, ExperimentRecorder, ExperimentVariant, HeadlessContacts};
+use crate::components::components_needs::{BasicNeeds, CurrentDesire, Desire, DesireThresholds, DualProcess, NeedPriorityWeights, SafetyHabituation, Willpower};
+use crate::components::components_npc::{Npc, Perception, Personality, RefillState, RelaxState};
+use crate::components::components_pathfinding::{AvoidedTargets, PathTarget, ResourceMemory, ResourceSpatialIndex, SteeringBehavior, StuckEscape};
+use crate::components::components_social::{ContactDebounce, SocialInteractionState, SocialRelationships};
+use crate::systems::events::events_lifecycle::NpcDespawnEvent;
+use crate::systems::events::events_movement::BoundaryCollisionEvent;
+use crate::systems::events::events_needs::{
+    CurrentDesireSet, DesireChangeEvent, EvaluateDecision, NeedChangeEvent, NeedDecayEvent, NeedsChangedBatch,
+    SocialInteractionEvent, ThresholdCrossedEvent,
+};
+use crate::systems::events::events_pathfinding::{PathTargetReachedEvent, PathTargetSetEvent, ReservationDenied};
+use crate::systems::events::events_social::{ConflictResolved, InteractionCompleted, InteractionStarted, RelationshipChangedEvent};
+use crate::systems::systems_features::{navigation_enabled, physiology_enabled, social_enabled};
+use crate::systems::systems_lifecycle::npc_despawn_system;
+use crate::systems::systems_movement::{boundary_collision_system, physics_movement_system};
+use crate::systems::systems_needs::{
+    decay_basic_needs, dual_process_update_system, handle_social_interactions, relax_state_system, social_need_fulfillment_system,
+    willpower_recovery_system, DecisionPipelinePlugin,
+};
+use crate::systems::systems_pathfinding::{desire_pathfinding_system, steering_behavior_system};
+use crate::systems::systems_schedule::SimulationSet;
+use crate::systems::systems_social::{social_interaction_lifecycle_system, social_interaction_system};
+use crate::utils::helpers::experiment_helpers::{calculate_population_satisfaction, find_touching_pairs};
+use crate::utils::helpers::needs_helpers::random_basic_needs_from_rng;
+use crate::utils::helpers::physics_helpers::npc_collision_groups;
+use crate::utils::helpers::spawn_helpers::calculate_spawn_positions;
+
+/// Runs the same seeded scenario under different parameter sets without a window or physics engine
+/// Each run spawns an identical population from the seed and advances exactly one fixed tick per update,
+/// so two runs with the same variant produce identical metrics and any difference comes from the parameters
+/// ML-HOOK: systematic parameter tuning by comparing aggregate outcomes
+#[derive(Debug, Clone)]
+pub struct ExperimentRunner {
+    /// Seed for the population and every random draw made during the run
+    pub seed: u64,
+    /// Simulation ticks each variant runs for
+    pub ticks: u32,
+}
+
+impl ExperimentRunner {
+    pub fn new(seed: u64, ticks: u32) -> Self {
+        Self { seed, ticks }
+    }
+
+    /// Runs one variant to completion and summarises the outcome
+    pub fn run(&self, variant: &ExperimentVariant) -> ExperimentMetrics {
+        let mut app = build_headless_app(self.seed, variant);
+        for _ in 0..self.ticks {
+            app.update();
+        }
+
+        let world = app.world_mut();
+        let final_population = world.query_filtered::<(), With<Npc>>().iter(world).count();
+        let recorder = world.resource::<ExperimentRecorder>();
+
+        ExperimentMetrics {
+            ticks: recorder.ticks,
+            mean_satisfaction: if recorder.ticks > 0 { (recorder.satisfaction_sum / recorder.ticks as f64) as f32 } else { 0.0 },
+            interactions_started: recorder.interactions_started,
+            interactions_completed: recorder.interactions_completed,
+            deaths: recorder.initial_population.saturating_sub(final_population),
+        }
+    }
+
+    /// Runs one variant to completion and returns every event it emitted, one line per event
+    /// Two runs of the same seed and variant must return identical streams; see first_event_stream_divergence
+    pub fn record_event_stream(&self, variant: &ExperimentVariant) -> Vec<String> {
+        let mut app = build_headless_app(self.seed, variant);
+        app.init_resource::<EventStreamRecorder>();
+        for _ in 0..self.ticks {
+            app.update();
+        }
+
+        std::mem::take(&mut app.world_mut().resource_mut::<EventStreamRecorder>().lines)
+    }
+
+    /// Runs both variants on the same seeded scenario and reports the difference
+    pub fn compare(&self, baseline: &ExperimentVariant, candidate: &ExperimentVariant) -> ExperimentComparison {
+        let baseline_metrics = self.run(baseline);
+        let candidate_metrics = self.run(candidate);
+        ExperimentComparison::new(self.seed, baseline, baseline_metrics, candidate, candidate_metrics)
+    }
+}
+
+/// Assembles a headless app running the needs, decision, movement and social systems on the fixed clock
+/// Systems sit in the same SimulationSet stages as the full app, so the decision pipeline runs between need decay
+/// and movement; they also run on a single thread, so every draw from SimulationRng is reproducible
+fn build_headless_app(seed: u64, variant: &ExperimentVariant) -> App {
+    let mut game_constants = variant.game_constants.clone();
+    game_constants.validate();
+    let tick = Duration::from_secs_f64(1.0 / game_constants.simulation_tick_hz as f64);
+
+    let mut simulation_rng = SimulationRng::from_seed(seed);
+    let mut app = App::new();
+    let positions = calculate_spawn_positions(
+        &game_constants.spawn_distribution,
+        game_constants.num_npcs,
+        game_constants.room_half_extents,
+        game_constants.npc_radius,
+        &mut simulation_rng.rng,
+    );
+    for position in positions {
+        spawn_headless_npc(app.world_mut(), position, &mut simulation_rng.rng, &game_constants);
+    }
+
+    app.add_plugins(TimePlugin)
+        .insert_resource(TimeUpdateStrategy::ManualDuration(tick))
+        .insert_resource(Time::<Fixed>::from_duration(tick))
+        .insert_resource(ExperimentRecorder { initial_population: game_constants.num_npcs, ..default() })
+        .insert_resource(game_constants)
+        .insert_resource(variant.social_config.clone())
+        .insert_resource(variant.feature_flags)
+        .insert_resource(simulation_rng)
+        .init_resource::<LifecycleConfig>()
+        .init_resource::<NeedEventMode>()
+        .init_resource::<NeedCouplingConfig>()
+        .init_resource::<SimLogConfig>()
+        .init_resource::<HeadlessContacts>()
+        .init_resource::<ContactDebounce>()
+        .init_resource::<ResourceSpatialIndex>()
+        .add_event::<NeedDecayEvent>()
+        .add_event::<NeedChangeEvent>()
+        .add_event::<NeedsChangedBatch>()
+        .add_event::<ThresholdCrossedEvent>()
+        .add_event::<EvaluateDecision>()
+        .add_event::<CurrentDesireSet>()
+        .add_event::<DesireChangeEvent>()
+        .add_event::<PathTargetSetEvent>()
+        .add_event::<PathTargetReachedEvent>()
+        .add_event::<ReservationDenied>()
+        .add_event::<BoundaryCollisionEvent>()
+        .add_event::<CollisionEvent>()
+        .add_event::<InteractionStarted>()
+        .add_event::<InteractionCompleted>()
+        .add_event::<SocialInteractionEvent>()
+        .add_event::<RelationshipChangedEvent>()
+        .add_event::<ConflictResolved>()
+        .add_event::<NpcDespawnEvent>()
+        .add_plugins(DecisionPipelinePlugin::default())
+        .add_systems(FixedUpdate, (
+            npc_despawn_system.in_set(SimulationSet::Lifecycle),
+            (decay_basic_needs, dual_process_update_system, relax_state_system, willpower_recovery_system)
+                .chain()
+                .run_if(physiology_enabled)
+                .in_set(SimulationSet::StateUpdate),
+            (
+                (desire_pathfinding_system, steering_behavior_system).chain().run_if(navigation_enabled),
+                physics_movement_system,
+                boundary_collision_system,
+                headless_contact_system,
+            ).chain().in_set(SimulationSet::Movement),
+            (
+                handle_social_interactions,
+                social_interaction_lifecycle_system,
+                social_need_fulfillment_system,
+                social_interaction_system,
+            ).chain().run_if(social_enabled).in_set(SimulationSet::Interaction),
+            (experiment_metrics_system, event_stream_recording_system).chain().in_set(SimulationSet::WorldUpdate),
+        ))
+        .edit_schedule(FixedUpdate, |schedule| {
+            schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        });
+
+    app
+}
+
+/// Spawns an NPC with the simulation components of the full builder but no sprite or rigid body
+/// Needs, personality and heading are all drawn from the run's seeded generator
+fn spawn_headless_npc(world: &mut World, position: Vec2, rng: &mut impl Rng, game_constants: &GameConstants) {
+    let heading = Vec2::new(rng.random_range(-1.0..=1.0), rng.random_range(-1.0..=1.0)).normalize_or_zero();
+
+    world.spawn((
+        (
+            Npc,
+            Personality::from_rng(rng),
+            Perception::from_rng(rng),
+            RefillState::default(),
+            RelaxState::default(),
+            SocialRelationships::default(),
+            SocialInteractionState::default(),
+        ),
+        (
+            random_basic_needs_from_rng(rng),
+            Desire::default(),
+            DesireThresholds::default(),
+            NeedPriorityWeights::default(),
+            CurrentDesire::default(),
+            DualProcess::default(),
+            Willpower::default(),
+            SafetyHabituation::default(),
+        ),
+        (
+            PathTarget::default(),
+            SteeringBehavior::default(),
+            ResourceMemory::default(),
+            AvoidedTargets::default(),
+            StuckEscape::default(),
+        ),
+        Transform::from_xyz(position.x, position.y, 0.0),
+        Velocity { linvel: heading * game_constants.npc_speed, angvel: 0.0 },
+        npc_collision_groups(),
+    ));
+}
+
+/// System standing in for Rapier's narrow phase in headless runs
+/// Reports a CollisionEvent when two NPC bodies start or stop overlapping, in canonical pair order
+pub fn headless_contact_system(
+    agents_query: Query<(Entity, &Transform), With<Npc>>,
+    mut contacts: ResMut<HeadlessContacts>,
+    mut collision_events: EventWriter<CollisionEvent>,
+    game_constants: Res<GameConstants>,
+) {
+    let positions: Vec<(Entity, Vec2)> = agents_query
+        .iter()
+        .map(|(entity, transform)| (entity, transform.translation.truncate()))
+        .collect();
+    let touching = find_touching_pairs(&positions, game_constants.npc_radius * 2.0);
+
+    for &(first, second) in touching.difference(&contacts.pairs) {
+        collision_events.write(CollisionEvent::Started(first, second, CollisionEventFlags::empty()));
+    }
+    for &(first, second) in contacts.pairs.difference(&touching) {
+        collision_events.write(CollisionEvent::Stopped(first, second, CollisionEventFlags::empty()));
+    }
+
+    contacts.pairs = touching;
+}
+
+/// System accumulating the per-tick measurements an ExperimentRunner reports
+pub fn experiment_metrics_system(
+    mut recorder: ResMut<ExperimentRecorder>,
+    mut started_events: EventReader<InteractionStarted>,
+    mut completed_events: EventReader<InteractionCompleted>,
+    needs_query: Query<&BasicNeeds, With<Npc>>,
+) {
+    recorder.ticks += 1;
+    recorder.satisfaction_sum += calculate_population_satisfaction(needs_query.iter()) as f64;
+    recorder.interactions_started += started_events.read().count();
+    recorder.interactions_completed += completed_events.read().count();
+}
+
+/// System appending this tick's simulation events to the EventStreamRecorder, when one is present
+/// Events are written per type in a fixed order and within a type in emission order, so the stream of a
+/// deterministic run never changes; the tick prefix comes from the ExperimentRecorder
+pub fn event_stream_recording_system(
+    recorder: Option<ResMut<EventStreamRecorder>>,
+    experiment: Res<ExperimentRecorder>,
+    mut evaluations: EventReader<EvaluateDecision>,
+    mut desire_changes: EventReader<DesireChangeEvent>,
+    mut path_targets: EventReader<PathTargetSetEvent>,
+    mut boundary_collisions: EventReader<BoundaryCollisionEvent>,
+    mut interactions_started: EventReader<InteractionStarted>,
+    mut interactions_completed: EventReader<InteractionCompleted>,
+    mut despawns: EventReader<NpcDespawnEvent>,
+) {
+    let Some(mut recorder) = recorder else {
+        return;
+    };
+    let tick = experiment.ticks;
+
+    for event in evaluations.read() {
+        recorder.lines.push(format!("{tick} evaluate {} {:?}", event.entity, event.trigger_reason));
+    }
+    for event in desire_changes.read() {
+        recorder.lines.push(format!(
+            "{tick} desire {} {:?} -> {:?} urgency {:?} {:?}",
+            event.entity, event.old_desire, event.new_desire, event.urgency_score, event.trigger_reason
+        ));
+    }
+    for event in path_targets.read() {
+        recorder.lines.push(format!(
+            "{tick} target {} {:?} at {:?} distance {:?}",
+            event.npc_entity, event.target_type, event.target_position, event.distance_to_target
+        ));
+    }
+    for event in boundary_collisions.read() {
+        recorder.lines.push(format!("{tick} boundary {} at {:?} heading {:?}", event.entity, event.position, event.new_direction));
+    }
+    for event in interactions_started.read() {
+        recorder.lines.push(format!("{tick} interaction-start {} {}", event.entity_1, event.entity_2));
+    }
+    for event in interactions_completed.read() {
+        recorder.lines.push(format!(
+            "{tick} interaction-end {} {} {:?} after {:?}",
+            event.entity_1, event.entity_2, event.interaction_type, event.duration
+        ));
+    }
+    for event in despawns.read() {
+        recorder.lines.push(format!("{tick} despawn {}", event.entity));
+    }
+}
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+use crate::components::components_constants::AiFeatureFlags;
+
+// Run conditions gating each AI domain on AiFeatureFlags
+// A missing AiFeatureFlags resource leaves every domain enabled, so apps that never configure it are unaffected
+
+/// Run condition for decision making, action failure handling, beliefs and adaptive behavior
+pub fn cognition_enabled(flags: Option<Res<AiFeatureFlags>>) -> bool {
+    flags.is_none_or(|flags| flags.cognition)
+}
+
+/// Run condition for apparent state and vision
+pub fn perception_enabled(flags: Option<Res<AiFeatureFlags>>) -> bool {
+    flags.is_none_or(|flags| flags.perception)
+}
+
+/// Run condition for need decay, stress, willpower and resource consumption
+pub fn physiology_enabled(flags: Option<Res<AiFeatureFlags>>) -> bool {
+    flags.is_none_or(|flags| flags.physiology)
+}
+
+/// Run condition for social interactions, relationships and rumors
+pub fn social_enabled(flags: Option<Res<AiFeatureFlags>>) -> bool {
+    flags.is_none_or(|flags| flags.social)
+}
+
+/// Run condition for resource discovery, target selection, steering and path learning
+pub fn navigation_enabled(flags: Option<Res<AiFeatureFlags>>) -> bool {
+    flags.is_none_or(|flags| flags.navigation)
+}
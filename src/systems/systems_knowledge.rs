@@ -0,0 +1,117 @@
+use bevy::ecs::event::{EventReader, EventWriter};
+use bevy::prelude::*;
+
+use crate::components::components_constants::GameConstants;
+use crate::components::components_environment::ResourceType;
+use crate::components::components_knowledge::{Beliefs, Proposition};
+use crate::components::components_needs::Desire;
+use crate::components::components_npc::Npc;
+use crate::systems::events::events_environment::ResourceInteractionSuccessEvent;
+use crate::systems::events::events_knowledge::{BeliefEvidenceEvent, BeliefUpdated};
+use crate::systems::events::events_needs::{ActionCompleted, ActionCompletionReason};
+use crate::systems::events::events_rumor::RumorSpreadEvent;
+use crate::utils::helpers::knowledge_helpers::{apply_belief_evidence, is_confirming_evidence};
+
+/// Helper function mapping a resource type to the proposition it provides evidence for
+/// Social gathering spaces and custom sites have no matching proposition, so using one is not belief evidence
+fn proposition_for_resource(resource_type: ResourceType) -> Option<Proposition> {
+    match resource_type {
+        ResourceType::Water => Some(Proposition::WaterAvailable),
+        ResourceType::Food => Some(Proposition::FoodAvailable),
+        ResourceType::Rest => Some(Proposition::RestAvailable),
+        ResourceType::Safety => Some(Proposition::SafetyAvailable),
+        ResourceType::Loneliness | ResourceType::Custom(_) => None,
+    }
+}
+
+/// Helper function mapping a seeking desire to the proposition that the sought resource exists
+fn proposition_for_desire(desire: Desire) -> Option<Proposition> {
+    match desire {
+        Desire::FindWater => Some(Proposition::WaterAvailable),
+        Desire::FindFood => Some(Proposition::FoodAvailable),
+        Desire::Rest => Some(Proposition::RestAvailable),
+        Desire::FindSafety => Some(Proposition::SafetyAvailable),
+        Desire::Wander | Desire::Socialize | Desire::Custom(_) => None,
+    }
+}
+
+/// System translating world events into belief evidence
+/// Based on Social Learning Theory - hearing a rumor, finding a resource and searching for one in vain are all observations
+/// **Single Responsibility:** Only produces BeliefEvidenceEvent, never touches Beliefs directly
+pub fn belief_evidence_system(
+    mut rumor_events: EventReader<RumorSpreadEvent>,
+    mut resource_events: EventReader<ResourceInteractionSuccessEvent>,
+    mut completed_events: EventReader<ActionCompleted>,
+    mut evidence_events: EventWriter<BeliefEvidenceEvent>,
+) {
+    for event in rumor_events.read() {
+        // A rumor heard from someone who believes it is mild evidence that rumors are credible
+        evidence_events.write(BeliefEvidenceEvent {
+            entity: event.receiver,
+            proposition: Proposition::RumorsAreCredible,
+            strength: event.belief_strength * 0.2,
+        });
+    }
+
+    for event in resource_events.read() {
+        let Some(proposition) = proposition_for_resource(event.resource_type) else {
+            continue;
+        };
+
+        // Successfully using a resource is strong first-hand evidence that it exists
+        evidence_events.write(BeliefEvidenceEvent {
+            entity: event.npc_entity,
+            proposition,
+            strength: 0.3,
+        });
+    }
+
+    for event in completed_events.read() {
+        // An interrupted search says nothing about the resource, the agent just had something more urgent to do
+        let searched_in_vain = matches!(event.completion_reason, ActionCompletionReason::Failed | ActionCompletionReason::Timeout);
+        let Some(proposition) = proposition_for_desire(event.completed_desire).filter(|_| searched_in_vain && !event.success) else {
+            continue;
+        };
+
+        // Searching without finding is weaker evidence than finding, the resource may simply be elsewhere
+        evidence_events.write(BeliefEvidenceEvent {
+            entity: event.entity,
+            proposition,
+            strength: -0.2,
+        });
+    }
+}
+
+/// Event-driven system applying evidence to NPC beliefs with confirmation bias
+/// Based on Confirmation Bias (Nickerson, 1998) - contradicting evidence is discounted
+/// ML-HOOK: Fires BeliefUpdated for every certainty change
+pub fn belief_update_system(
+    mut evidence_events: EventReader<BeliefEvidenceEvent>,
+    mut beliefs_query: Query<&mut Beliefs, With<Npc>>,
+    mut updated_events: EventWriter<BeliefUpdated>,
+    game_constants: Res<GameConstants>,
+) {
+    for evidence in evidence_events.read() {
+        let Ok(mut beliefs) = beliefs_query.get_mut(evidence.entity) else {
+            continue;
+        };
+
+        let old_certainty = beliefs.certainty(evidence.proposition);
+        let new_certainty = apply_belief_evidence(
+            old_certainty,
+            evidence.strength,
+            game_constants.confirmation_bias_strength,
+        );
+
+        if (new_certainty - old_certainty).abs() > f32::EPSILON {
+            beliefs.certainties.insert(evidence.proposition, new_certainty);
+            updated_events.write(BeliefUpdated {
+                entity: evidence.entity,
+                proposition: evidence.proposition,
+                old_certainty,
+                new_certainty,
+                confirming: is_confirming_evidence(old_certainty, evidence.strength),
+            });
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use bevy::ecs::event::EventReader;
+use bevy::prelude::*;
+
+use crate::components::components_constants::{GameConstants, LogCategory, LogGate, SimLogConfig, SimulationRng};
+use crate::components::components_learning::{AdaptiveBehavior, CognitiveMap, ForageMemory, PathLearning};
+use crate::components::components_needs::Desire;
+use crate::components::components_npc::Npc;
+use crate::components::components_performance::{AgentLod, LearningMemoryConfig};
+use crate::components::components_pathfinding::PathTarget;
+use crate::components::components_social::InteractionType;
+use crate::sim_log;
+use crate::systems::events::events_environment::ResourceInteractionSuccessEvent;
+use crate::systems::events::events_needs::{ActionCompleted, ActionOutcome, ActionTaken, NeedSatisfactionEvent};
+use crate::systems::events::events_pathfinding::{PathTargetReachedEvent, ResourceDiscoveredEvent};
+use crate::systems::events::events_performance::PerformanceAlertEvent;
+use crate::systems::events::events_social::InteractionCompleted;
+use crate::utils::helpers::learning_helpers::{
+    calculate_action_reward, decay_forage_memory, exploration_chance, find_least_explored_cell, learn_from_path_memory,
+    merge_shared_landmarks, prune_cognitive_map, prune_path_learning, record_forage_reward, record_landmark, record_path_experience, update_adaptive_behavior,
+    update_cognitive_map,
+};
+use crate::utils::helpers::needs_helpers::desire_for_need;
+use crate::utils::helpers::pathfinding_helpers::{has_reached_target, should_timeout_pursuit};
+use rand::Rng;
+
+/// System recording where each NPC has been in its CognitiveMap
+/// Based on Grid Cell research (Hafting et al., 2005) - occupancy builds familiarity, absence fades it
+/// While the governor slows learning down agents update only when their AgentLod is due, crediting their current cell
+/// with every tick they skipped
+pub fn cognitive_map_update_system(
+    mut npc_query: Query<(&Transform, &mut CognitiveMap, Option<&AgentLod>), With<Npc>>,
+    time: Res<Time>,
+) {
+    let delta_time = time.delta_secs();
+
+    for (transform, mut map, lod) in npc_query.iter_mut() {
+        if lod.is_some_and(|lod| !lod.is_due()) {
+            continue;
+        }
+        let covered_ticks = lod.map_or(1, |lod| lod.stride) as f32;
+        update_cognitive_map(&mut map, transform.translation.truncate(), delta_time * covered_ticks);
+    }
+}
+
+/// System crediting each NPC's ForageMemory with the cells it discovers resources in, and fading old rewards
+/// Based on Optimal Foraging Theory (Charnov, 1976) - patches that paid off are worth revisiting
+pub fn forage_memory_system(
+    mut discovery_events: EventReader<ResourceDiscoveredEvent>,
+    mut memory_query: Query<&mut ForageMemory, With<Npc>>,
+    time: Res<Time>,
+) {
+    let delta_time = time.delta_secs();
+
+    for mut memory in memory_query.iter_mut() {
+        decay_forage_memory(&mut memory, delta_time);
+    }
+
+    for event in discovery_events.read() {
+        if let Ok(mut memory) = memory_query.get_mut(event.npc_entity) {
+            record_forage_reward(&mut memory, event.resource_position);
+        }
+    }
+}
+
+/// System marking every resource an NPC discovers as a landmark in its CognitiveMap
+/// Agents name their landmarks themselves, so two agents finding the same well know it under different ids
+/// until landmark_sharing_system reconciles them
+pub fn landmark_recording_system(
+    mut discovery_events: EventReader<ResourceDiscoveredEvent>,
+    mut map_query: Query<&mut CognitiveMap, With<Npc>>,
+) {
+    for event in discovery_events.read() {
+        if let Ok(mut map) = map_query.get_mut(event.npc_entity) {
+            record_landmark(&mut map, event.npc_entity, event.resource_position);
+        }
+    }
+}
+
+/// Event-driven system letting agents who exchanged information agree on the landmarks they both know
+/// Based on Shared Mental Models (Cannon-Bowers et al., 1993) - talking a place over gives it a common name
+/// Only conversations, information sharing and cooperation share knowledge; landmarks known to one agent alone are not passed on
+pub fn landmark_sharing_system(
+    mut completed_events: EventReader<InteractionCompleted>,
+    mut map_query: Query<&mut CognitiveMap, With<Npc>>,
+) {
+    for event in completed_events.read() {
+        if !matches!(event.interaction_type, InteractionType::Conversation | InteractionType::InformationSharing | InteractionType::Cooperation) {
+            continue;
+        }
+        if let Ok([mut first, mut second]) = map_query.get_many_mut([event.entity_1, event.entity_2]) {
+            merge_shared_landmarks(&mut first, &mut second);
+        }
+    }
+}
+
+/// System adjusting each NPC's exploration rate from the outcome of its actions
+/// Successful resource use counts as a success, any ActionCompleted failure counts against it
+/// ML-HOOK: exploration_rate is the observable result of the agent's exploration-exploitation balance
+pub fn adaptive_behavior_system(
+    mut success_events: EventReader<ResourceInteractionSuccessEvent>,
+    mut completed_events: EventReader<ActionCompleted>,
+    mut behavior_query: Query<&mut AdaptiveBehavior, With<Npc>>,
+) {
+    for event in success_events.read() {
+        if let Ok(mut behavior) = behavior_query.get_mut(event.npc_entity) {
+            update_adaptive_behavior(&mut behavior, true);
+        }
+    }
+
+    for event in completed_events.read() {
+        if let Ok(mut behavior) = behavior_query.get_mut(event.entity) {
+            update_adaptive_behavior(&mut behavior, event.success);
+        }
+    }
+}
+
+/// System consolidating NeedSatisfactionEvent and ActionCompleted into one ActionOutcome per completed action
+/// An agent's satisfaction events in one update are the same refill and fold into a single successful outcome,
+/// which a successful ActionCompleted for the agent names the desire of; each failed ActionCompleted is its own outcome
+/// ML-HOOK: ActionOutcome is the one signal external reinforcement learners need to read
+pub fn action_outcome_system(
+    mut satisfaction_events: EventReader<NeedSatisfactionEvent>,
+    mut completed_events: EventReader<ActionCompleted>,
+    mut outcome_events: EventWriter<ActionOutcome>,
+) {
+    let mut outcomes: Vec<ActionOutcome> = Vec::new();
+
+    for event in satisfaction_events.read() {
+        let position = outcomes.iter().position(|outcome| outcome.entity == event.entity);
+        let outcome = match position {
+            Some(index) => &mut outcomes[index],
+            None => {
+                outcomes.push(ActionOutcome {
+                    entity: event.entity,
+                    desire: desire_for_need(event.need_type),
+                    action: ActionTaken::UseResource { resource_entity: event.resource_entity },
+                    need_deltas: Vec::new(),
+                    success: true,
+                    reward: 0.0,
+                });
+                outcomes.last_mut().unwrap()
+            }
+        };
+        outcome.need_deltas.push((event.need_type, event.satisfaction_amount));
+    }
+
+    for event in completed_events.read() {
+        if event.success {
+            match outcomes.iter_mut().find(|outcome| outcome.entity == event.entity && outcome.success) {
+                Some(outcome) => outcome.desire = event.completed_desire,
+                None => outcomes.push(ActionOutcome {
+                    entity: event.entity,
+                    desire: event.completed_desire,
+                    action: ActionTaken::UseResource { resource_entity: None },
+                    need_deltas: Vec::new(),
+                    success: true,
+                    reward: 0.0,
+                }),
+            }
+        } else {
+            outcomes.push(ActionOutcome {
+                entity: event.entity,
+                desire: event.completed_desire,
+                action: ActionTaken::Abandon(event.completion_reason),
+                need_deltas: Vec::new(),
+                success: false,
+                reward: 0.0,
+            });
+        }
+    }
+
+    for mut outcome in outcomes {
+        outcome.reward = calculate_action_reward(outcome.success, &outcome.need_deltas);
+        outcome_events.write(outcome);
+    }
+}
+
+/// System turning idle wandering into directed exploration
+/// Based on Curiosity-driven Exploration (Berlyne, 1960) - when no need is urgent the agent seeks out
+/// the least familiar territory around it instead of drifting at random
+/// The chance of setting off each second is the agent's AdaptiveBehavior.exploration_rate, drawn from SimulationRng
+/// so a seeded run explores identically
+pub fn curiosity_exploration_system(
+    mut npc_query: Query<(Entity, &Transform, &Desire, &AdaptiveBehavior, &CognitiveMap, &mut PathTarget), With<Npc>>,
+    game_constants: Res<GameConstants>,
+    log_config: Res<SimLogConfig>,
+    log_gate: Option<Res<LogGate>>,
+    mut simulation_rng: ResMut<SimulationRng>,
+    time: Res<Time>,
+) {
+    let current_time = time.elapsed_secs();
+    let delta_time = time.delta_secs();
+
+    for (entity, transform, desire, behavior, map, mut path_target) in npc_query.iter_mut() {
+        if *desire != Desire::Wander {
+            continue;
+        }
+
+        let position = transform.translation.truncate();
+
+        if path_target.has_target {
+            // Arriving at (or giving up on) a target frees the agent to pick the next unexplored region
+            if has_reached_target(position, &path_target) || should_timeout_pursuit(&path_target, current_time) {
+                path_target.has_target = false;
+            }
+            continue;
+        }
+
+        if simulation_rng.rng.random::<f32>() >= exploration_chance(behavior.exploration_rate, delta_time) {
+            continue;
+        }
+
+        if let Some(target_position) = find_least_explored_cell(
+            map,
+            position,
+            behavior.exploration_radius_cells,
+            game_constants.room_half_extents,
+        ) {
+            path_target.target_position = target_position;
+            path_target.origin_position = position;
+            path_target.target_entity = None;
+            path_target.has_target = true;
+            path_target.target_set_time = current_time;
+            sim_log!(log_gate, log_config, LogCategory::Learning, "NPC {:?} sets off to explore around {:?}", entity, target_position);
+        }
+    }
+}
+
+/// System turning navigation arrivals into PathExperience entries
+/// Reached events repeat every frame the agent stays at its target, so each pursuit
+/// (identified by its target_set_time) is recorded only once
+/// Timeouts are recorded as failed experiences by action_failure_handling_system
+pub fn path_experience_system(
+    mut reached_events: EventReader<PathTargetReachedEvent>,
+    mut npc_query: Query<(&PathTarget, &CognitiveMap, &mut PathLearning), With<Npc>>,
+) {
+    for event in reached_events.read() {
+        let Ok((path_target, map, mut learning)) = npc_query.get_mut(event.npc_entity) else {
+            continue;
+        };
+
+        if learning.last_recorded_target_time == Some(path_target.target_set_time) {
+            continue;
+        }
+        learning.last_recorded_target_time = Some(path_target.target_set_time);
+
+        record_path_experience(
+            &mut learning,
+            map,
+            path_target.origin_position,
+            event.target_position,
+            event.time_to_reach,
+            true,
+            path_target.max_pursuit_time,
+        );
+    }
+}
+
+/// System applying newly recorded path experiences to learned path values
+/// While the governor slows learning down agents learn only when their AgentLod is due; experiences wait in path_memory
+/// until then, so none are lost
+/// ML-HOOK: path_values are the learned model that target selection can exploit
+pub fn path_learning_system(mut npc_query: Query<(&mut PathLearning, Option<&AgentLod>), With<Npc>>) {
+    for (mut learning, lod) in npc_query.iter_mut() {
+        if lod.is_some_and(|lod| !lod.is_due()) {
+            continue;
+        }
+        if learning.path_memory.iter().any(|experience| !experience.learned) {
+            learn_from_path_memory(&mut learning);
+        }
+    }
+}
+
+/// System keeping each agent's learned data within LearningMemoryConfig's caps
+/// After a HighMemoryUsage alert every cap shrinks to pressure_fraction for one pass, so the whole population
+/// gives memory back at once; the weakest cells, routes and oldest experiences go first and consolidated ones stay
+pub fn learning_memory_pruning_system(
+    mut npc_query: Query<(Option<&mut CognitiveMap>, Option<&mut PathLearning>), With<Npc>>,
+    mut alert_events: EventReader<PerformanceAlertEvent>,
+    config: Res<LearningMemoryConfig>,
+) {
+    let under_pressure = alert_events.read().any(|alert| matches!(alert, PerformanceAlertEvent::HighMemoryUsage { .. }));
+    if !config.enabled {
+        return;
+    }
+    let cap = |cap: usize| if under_pressure { (cap as f32 * config.pressure_fraction.clamp(0.0, 1.0)) as usize } else { cap };
+
+    for (map, learning) in npc_query.iter_mut() {
+        if let Some(mut map) = map.filter(|map| map.activations.len() > cap(config.max_map_cells)) {
+            prune_cognitive_map(&mut map, cap(config.max_map_cells), config.consolidated_activation);
+        }
+        if let Some(mut learning) = learning.filter(|learning| {
+            learning.path_values.len() > cap(config.max_path_values) || learning.path_memory.len() > cap(learning.max_path_memory)
+        }) {
+            let max_path_memory = cap(learning.max_path_memory);
+            prune_path_learning(&mut learning, cap(config.max_path_values), max_path_memory, config.consolidated_value);
+        }
+    }
+}
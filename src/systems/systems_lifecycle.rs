@@ -0,0 +1,97 @@
+use bevy::ecs::event::EventReader;
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+use crate::components::components_constants::LifecycleConfig;
+use crate::components::components_knowledge::KnowledgeBase;
+use crate::components::components_npc::{Npc, WorldModel};
+use crate::components::components_pathfinding::ResourceMemory;
+use crate::components::components_social::{SocialInteractionState, SocialRelationships};
+use crate::systems::events::events_lifecycle::NpcDespawnEvent;
+use crate::utils::helpers::knowledge_helpers::{inherit_resource_memory, inherit_rumors};
+
+/// System removing NPCs at runtime
+/// Surviving agents forget the despawned ones: relationships toward them are dropped and any
+/// interaction in progress with them ends without completing, so no later system looks them up
+/// With LifecycleConfig::share_memory_on_death, agents near a despawning NPC first inherit part of its
+/// resource memory and rumors, so the knowledge is handed on while the NPC still exists
+/// Requests for entities that are already gone or are not NPCs are ignored
+pub fn npc_despawn_system(
+    mut commands: Commands,
+    mut despawn_events: EventReader<NpcDespawnEvent>,
+    npc_query: Query<(), With<Npc>>,
+    mut knowledge_query: Query<(Entity, &Transform, Option<&mut ResourceMemory>, Option<&mut KnowledgeBase>), With<Npc>>,
+    mut states_query: Query<&mut SocialInteractionState>,
+    mut relationships_query: Query<&mut SocialRelationships>,
+    mut world_model_query: Query<&mut WorldModel>,
+    lifecycle_config: Res<LifecycleConfig>,
+) {
+    let despawned: HashSet<Entity> = despawn_events
+        .read()
+        .map(|event| event.entity)
+        .filter(|entity| npc_query.contains(*entity))
+        .collect();
+
+    if despawned.is_empty() {
+        return;
+    }
+
+    if lifecycle_config.share_memory_on_death {
+        for entity in &despawned {
+            share_knowledge_with_neighbours(*entity, &despawned, &mut knowledge_query, &lifecycle_config);
+        }
+    }
+
+    for entity in &despawned {
+        commands.entity(*entity).despawn();
+    }
+
+    for mut state in states_query.iter_mut() {
+        if state.active.is_some_and(|active| despawned.contains(&active.partner)) {
+            state.active = None;
+        }
+        if state.last_completed_with.keys().any(|partner| despawned.contains(partner)) {
+            state.last_completed_with.retain(|partner, _| !despawned.contains(partner));
+        }
+    }
+
+    for mut relationships in relationships_query.iter_mut() {
+        if relationships.relationships.keys().any(|other| despawned.contains(other)) {
+            relationships.relationships.retain(|other, _| !despawned.contains(other));
+        }
+    }
+
+    for mut model in world_model_query.iter_mut() {
+        if model.agents.keys().any(|other| despawned.contains(other)) {
+            model.agents.retain(|other, _| !despawned.contains(other));
+        }
+    }
+}
+
+/// Hands part of one despawning NPC's knowledge to every surviving NPC within the sharing radius
+/// Agents despawning in the same frame do not inherit from each other
+fn share_knowledge_with_neighbours(
+    deceased: Entity,
+    despawned: &HashSet<Entity>,
+    knowledge_query: &mut Query<(Entity, &Transform, Option<&mut ResourceMemory>, Option<&mut KnowledgeBase>), With<Npc>>,
+    config: &LifecycleConfig,
+) {
+    let Ok((_, transform, memory, knowledge)) = knowledge_query.get(deceased) else {
+        return;
+    };
+    let position = transform.translation.truncate();
+    let memory = memory.cloned();
+    let knowledge = knowledge.cloned();
+
+    for (heir, heir_transform, heir_memory, heir_knowledge) in knowledge_query.iter_mut() {
+        if despawned.contains(&heir) || heir_transform.translation.truncate().distance(position) > config.memory_sharing_radius {
+            continue;
+        }
+        if let (Some(mut heir_memory), Some(memory)) = (heir_memory, memory.as_ref()) {
+            inherit_resource_memory(&mut heir_memory, memory, config.memory_sharing_fraction);
+        }
+        if let (Some(mut heir_knowledge), Some(knowledge)) = (heir_knowledge, knowledge.as_ref()) {
+            inherit_rumors(&mut heir_knowledge, knowledge, config.memory_sharing_fraction);
+        }
+    }
+}
@@ -3,36 +3,38 @@ use bevy::math::Vec2;
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::Velocity;
 
-use crate::components::{components_constants::GameConstants, components_npc::Npc, Desire};
+use crate::components::{components_constants::{BoundaryPolicy, GameConstants, LogGate}, components_npc::Npc, Desire};
 use crate::systems::events::events_movement::{BoundaryCollisionEvent, MovementBehaviorEvent};
 use crate::utils::helpers::{
-    calculate_boundary_reflection, calculate_movement_efficiency,
+    calculate_boundary_reflection, calculate_facing_rotation, calculate_movement_efficiency,
     detect_boundary_collision, get_normalized_direction, reflect_velocity_off_boundary,
-    safe_normalize,
+    safe_normalize, stop_velocity_at_boundary, wrap_position,
 };
 
 /// System for detecting and handling boundary collisions
 /// **Single Responsibility:** Only handles boundary physics and collision detection
 /// Based on Classical Physics - elastic collision model for boundary interactions
+/// GameConstants::boundary_policy picks the response: Reflect bounces agents back, Clamp stops them at the edge
+/// and Wrap carries them across to the opposite edge; wrapping is not a collision and fires no event
 pub fn boundary_collision_system(
     mut query: Query<(Entity, &mut Transform, &mut Velocity), With<Npc>>,
     game_constants: Res<GameConstants>,
-    windows: Query<&Window>,
     mut boundary_events: EventWriter<BoundaryCollisionEvent>,
 ) {
-    let Ok(window) = windows.single() else {
-        return; // Exit early if no window found
-    };
-
-    let bounds = Vec2::new(
-        window.width() / 2.0 - game_constants.npc_radius,
-        window.height() / 2.0 - game_constants.npc_radius,
-    );
+    let bounds = game_constants.room_half_extents - Vec2::splat(game_constants.npc_radius);
 
     for (entity, mut transform, mut velocity) in query.iter_mut() {
         let position = transform.translation.truncate();
         let current_direction = get_normalized_direction(velocity.linvel);
 
+        if game_constants.boundary_policy == BoundaryPolicy::Wrap {
+            let wrapped = wrap_position(position, game_constants.room_half_extents);
+            if wrapped != position {
+                transform.translation = wrapped.extend(transform.translation.z);
+            }
+            continue;
+        }
+
         if let Some(collision_normal) = detect_boundary_collision(
             position,
             velocity.linvel,
@@ -41,9 +43,12 @@ pub fn boundary_collision_system(
             game_constants.npc_radius,
             0.1, // prediction time
         ) {
-            // Reflect velocity using helper function
+            // Reflect or stop the velocity using helper functions
             let old_direction = current_direction;
-            velocity.linvel = reflect_velocity_off_boundary(velocity.linvel, collision_normal);
+            velocity.linvel = match game_constants.boundary_policy {
+                BoundaryPolicy::Clamp => stop_velocity_at_boundary(velocity.linvel, collision_normal),
+                _ => reflect_velocity_off_boundary(velocity.linvel, collision_normal),
+            };
 
             // Ensure NPC stays within boundaries
             let clamped_position = position.clamp(
@@ -57,7 +62,7 @@ pub fn boundary_collision_system(
                 entity,
                 position,
                 old_direction,
-                new_direction: velocity.linvel.normalize(),
+                new_direction: velocity.linvel.normalize_or_zero(),
                 collision_normal,
             });
         }
@@ -78,6 +83,28 @@ pub fn physics_movement_system(
     }
 }
 
+/// System rotating NPC sprites to face the direction they move in
+/// **Single Responsibility:** Only turns the Transform toward the Rapier velocity, at GameConstants::npc_turn_rate
+/// Nearly stationary agents hold their last facing, which vision also uses as the agent's heading
+pub fn face_velocity_system(
+    mut query: Query<(&mut Transform, &Velocity), With<Npc>>,
+    game_constants: Res<GameConstants>,
+    time: Res<Time>,
+) {
+    for (mut transform, velocity) in query.iter_mut() {
+        let rotation = calculate_facing_rotation(
+            transform.rotation,
+            velocity.linvel,
+            game_constants.npc_turn_rate,
+            time.delta_secs(),
+            game_constants.facing_min_speed,
+        );
+        if rotation != transform.rotation {
+            transform.rotation = rotation;
+        }
+    }
+}
+
 /// System for tracking movement behavior metrics for ML
 /// **Single Responsibility:** Only collects and reports movement analytics
 /// ML-HOOK: Provides quantifiable movement analytics for learning optimization
@@ -106,11 +133,17 @@ pub fn movement_analytics_system(
 /// System for analyzing movement patterns over time
 /// **Single Responsibility:** Only handles periodic movement pattern analysis
 /// ML-HOOK: Provides quantifiable movement analytics for learning optimization
+/// Silent while the LogGate is closed
 pub fn movement_pattern_analysis_system(
     query: Query<(&Transform, &Velocity, &Desire), With<Npc>>,
     mut last_analysis_time: Local<f32>,
+    log_gate: Option<Res<LogGate>>,
     time: Res<Time>,
 ) {
+    if !LogGate::is_open(log_gate.as_deref()) {
+        return;
+    }
+
     // Analyze every 3 seconds to track movement patterns
     *last_analysis_time += time.delta_secs();
     if *last_analysis_time >= 3.0 {
@@ -1,87 +1,139 @@
-use crate::components::components_needs::{BasicNeeds, CurrentDesire, Desire, DesireThresholds};
-use crate::components::components_pathfinding::PathTarget;
-use crate::components::{components_constants::GameConstants, components_npc::{Npc, RefillState}};
+use crate::components::components_environment::{CustomResourceSite, Hotel, ResourceType, Restaurant, SafeZone, Weather, WeatherEffects, Well};
+use crate::components::components_camera::SelectedNpc;
+use crate::components::components_learning::{CognitiveMap, PathLearning};
+use crate::components::components_needs::{
+    BasicNeeds, CurrentDesire, CustomDesireRegistry, CustomNeeds, DecisionStability, Desire, DesireThresholds, DualProcess, DualThreshold, HomeostaticSetpoints, NeedDepletion, NeedPriorityWeights,
+    SafetyHabituation, Willpower,
+};
+use crate::components::components_pathfinding::{AvoidedTargets, PathTarget, ResourceMemory, SteeringBehavior, StuckEscape};
+use crate::components::components_social::{ActiveInteraction, ContactDebounce, SocialEnergy, SocialInteractionState, SocialProximity, SocialRelationships};
+use crate::components::components_performance::FrameBudgetGovernor;
+use crate::components::{components_constants::{DecisionJitterConfig, DecisionLookaheadConfig, DecisionPipelineMode, DecisionStabilityConfig, GameConstants, LogCategory, LogGate, NeedCouplingConfig, NeedEventMode, NeedFailureConfig, NeedFailureConsequence, RestQualityConfig, SafetyChannelConfig, SatiationConfig, SimLogConfig, SimulationRng, SocialConfig}, components_npc::{BodySize, Incapacitated, Mood, Npc, Personality, RefillState, RelaxState, VisionRange}};
+use crate::sim_log;
 use crate::systems::events::events_needs::{
     ActionCompleted, ActionCompletionReason, CurrentDesireSet, DecisionTrigger, DesireChangeEvent, DesireChangeReason,
-    DesireFulfillmentAttemptEvent, EvaluateDecision, NeedChangeEvent, NeedDecayEvent,
+    DesireFulfillmentAttemptEvent, EvaluateDecision, NeedChange, NeedChangeEvent, NeedCriticalEvent, NeedDecayEvent, NeedsChangedBatch,
     NeedSatisfactionEvent, NeedType, SocialInteractionEvent, ThresholdCrossedEvent, ThresholdDirection,
 };
+use crate::systems::events::events_lifecycle::NpcDespawnEvent;
+use crate::systems::events::events_social::{InteractionCompleted, InteractionStarted};
+use crate::systems::systems_features::cognition_enabled;
+use crate::systems::systems_schedule::{SimulationSchedulePlugin, SimulationSet};
 use crate::utils::helpers::needs_helpers::{
-    calculate_desire_utility, decay_needs, evaluate_most_urgent_desire, get_satisfaction_level,
-    increase_social_satisfaction, should_activate_desire, should_deactivate_desire,
+    advance_need_depletion, apply_commitment_bias, apply_refill_decay_immunity, are_all_needs_satisfied, calculate_mood, calculate_rest_quality, calculate_rest_site_safety, calculate_satiation_multiplier, calculate_decision_interval, calculate_desire_utility, calculate_need_stress, calculate_system2_engagement,
+    calculate_coupled_decay_multipliers, calculate_relative_need, calculate_relative_needs, calculate_setpoint_decay_multipliers, decay_needs_with_multipliers, evaluate_desire_dual_process, evaluate_desire_with_lookahead, evaluate_most_urgent_custom_desire, evaluate_most_urgent_desire,
+    get_satisfaction_level,
+    increase_social_satisfaction, need_value_mut, resolve_desire_with_willpower, should_activate_desire, should_deactivate_desire,
+    update_decision_stability,
+};
+use crate::utils::helpers::learning_helpers::record_path_experience;
+use crate::utils::helpers::pathfinding_helpers::{calculate_escape_impulse, estimate_lookahead_horizon, is_stuck};
+use crate::utils::helpers::physics_helpers::is_npc_npc_contact;
+use crate::utils::helpers::social_helpers::{
+    calculate_initiation_chance, calculate_partner_preference, debounce_contact_starts, is_socially_drained, judge_interaction, ordered_entity_pair, rank_contacts_by_preference,
+    sees_partner,
 };
+use crate::utils::helpers::weather_helpers::calculate_temperature_decay_multiplier;
 use bevy::ecs::event::{EventReader, EventWriter};
 use bevy::prelude::*;
-use bevy_rapier2d::prelude::CollisionEvent;
+use bevy_rapier2d::prelude::{CollisionEvent, CollisionGroups, Velocity};
+use rand::Rng;
+use std::collections::HashSet;
 
 /// System implementing homeostatic need decay over time
 /// System based on Homeostatic Drive Theory - maintains internal physiological balance
 /// Now fires NeedChangeEvent for event-driven threshold monitoring
 /// FIXED: All needs now use "higher = better satisfied" semantics
+/// Temperatures outside the comfortable range accelerate thirst and rest decay when a Weather resource exists
+/// NeedCouplingConfig lets one need's deficit speed up (or slow down) the decay of another
+/// While an agent refills, the need its resource satisfies decays at GameConstants::refill_decay_multiplier
+/// Agents with HomeostaticSetpoints shed any surplus above their comfort band faster
 pub fn decay_basic_needs(
-    mut query: Query<(Entity, &mut BasicNeeds), With<Npc>>,
+    mut query: Query<(Entity, &mut BasicNeeds, Option<&RefillState>, Option<&HomeostaticSetpoints>), With<Npc>>,
+    resource_query: Query<AnyOf<(&Well, &Restaurant, &Hotel, &SafeZone)>>,
     game_constants: Res<GameConstants>,
+    coupling: Res<NeedCouplingConfig>,
+    weather: Option<Res<Weather>>,
+    weather_effects: Option<Res<WeatherEffects>>,
+    event_mode: Res<NeedEventMode>,
     mut need_decay_events: EventWriter<NeedDecayEvent>,
     mut need_change_events: EventWriter<NeedChangeEvent>,
+    mut batch_events: EventWriter<NeedsChangedBatch>,
     time: Res<Time>,
 ) {
     let delta_time = time.delta_secs();
+    let strain = match (weather, weather_effects) {
+        (Some(weather), Some(effects)) => calculate_temperature_decay_multiplier(weather.temperature, &effects),
+        _ => 1.0,
+    };
 
-    for (entity, mut needs) in query.iter_mut() {
+    for (entity, mut needs, refill_state, setpoints) in query.iter_mut() {
         let old_needs = *needs; // Capture old values for event firing
 
-        let (hunger_change, thirst_change, rest_change, safety_change, social_change) =
-            decay_needs(&mut needs, &game_constants, delta_time);
-
-        // Fire individual need change events for threshold monitoring
-        if hunger_change != 0.0 {
-            need_change_events.write(NeedChangeEvent {
-                entity,
-                need_type: NeedType::Hunger,
-                old_value: old_needs.hunger,
-                new_value: needs.hunger,
-                change_amount: hunger_change,
-            });
-        }
-
-        if thirst_change != 0.0 {
-            need_change_events.write(NeedChangeEvent {
-                entity,
-                need_type: NeedType::Thirst,
-                old_value: old_needs.thirst,
-                new_value: needs.thirst,
-                change_amount: thirst_change,
-            });
-        }
+        let coupled = calculate_coupled_decay_multipliers(&needs, &coupling);
+        let surplus = setpoints.map_or([1.0; 5], |setpoints| calculate_setpoint_decay_multipliers(&needs, setpoints));
+        let mut multipliers = [
+            coupled[0] * surplus[0],
+            coupled[1] * strain * surplus[1],
+            coupled[2] * strain * surplus[2],
+            coupled[3] * surplus[3],
+            coupled[4] * surplus[4],
+        ];
 
-        if rest_change != 0.0 {
-            need_change_events.write(NeedChangeEvent {
-                entity,
-                need_type: NeedType::Rest,
-                old_value: old_needs.rest,
-                new_value: needs.rest,
-                change_amount: rest_change,
+        let refilled = refill_state
+            .filter(|refill| refill.is_refilling)
+            .and_then(|refill| refill.resource_entity)
+            .and_then(|resource| resource_query.get(resource).ok())
+            .map(|(well, restaurant, hotel, _safe_zone)| {
+                if well.is_some() {
+                    ResourceType::Water
+                } else if restaurant.is_some() {
+                    ResourceType::Food
+                } else if hotel.is_some() {
+                    ResourceType::Rest
+                } else {
+                    ResourceType::Safety
+                }
             });
+        if let Some(resource_type) = refilled {
+            apply_refill_decay_immunity(&mut multipliers, resource_type, game_constants.refill_decay_multiplier);
         }
 
-        if safety_change != 0.0 {
-            need_change_events.write(NeedChangeEvent {
-                entity,
-                need_type: NeedType::Safety,
-                old_value: old_needs.safety,
-                new_value: needs.safety,
-                change_amount: safety_change,
-            });
-        }
+        let (hunger_change, thirst_change, rest_change, safety_change, social_change) =
+            decay_needs_with_multipliers(&mut needs, &game_constants, delta_time, multipliers);
+
+        let changes = [
+            (NeedType::Hunger, hunger_change, old_needs.hunger, needs.hunger),
+            (NeedType::Thirst, thirst_change, old_needs.thirst, needs.thirst),
+            (NeedType::Rest, rest_change, old_needs.rest, needs.rest),
+            (NeedType::Safety, safety_change, old_needs.safety, needs.safety),
+            (NeedType::Social, social_change, old_needs.social, needs.social),
+        ];
+        let changed = changes.into_iter().filter(|(_, change_amount, _, _)| *change_amount != 0.0);
 
-        if social_change != 0.0 {
-            need_change_events.write(NeedChangeEvent {
-                entity,
-                need_type: NeedType::Social,
-                old_value: old_needs.social,
-                new_value: needs.social,
-                change_amount: social_change,
-            });
+        match *event_mode {
+            // Fire individual need change events for threshold monitoring
+            NeedEventMode::Granular => {
+                for (need_type, change_amount, old_value, new_value) in changed {
+                    need_change_events.write(NeedChangeEvent {
+                        entity,
+                        need_type,
+                        old_value,
+                        new_value,
+                        change_amount,
+                    });
+                }
+            }
+            // Coalesce this frame's changes into a single event per agent
+            NeedEventMode::Batched => {
+                let changes: Vec<NeedChange> = changed
+                    .map(|(need_type, _, old_value, new_value)| NeedChange { need_type, old_value, new_value })
+                    .collect();
+
+                if !changes.is_empty() {
+                    batch_events.write(NeedsChangedBatch { entity, changes });
+                }
+            }
         }
 
         // ML-HOOK: Fire event for quantifiable state change tracking
@@ -96,57 +148,209 @@ pub fn decay_basic_needs(
     }
 }
 
-/// Event-driven system that handles social interactions based on Social Exchange Theory
-/// System based on Social Exchange Theory - positive interactions increase social satisfaction
-/// Only triggers when collision events occur, not on every frame
-/// FIXED: Now uses correct social field and function name
+/// System decaying each agent's registered custom needs and restoring the one its refill site satisfies
+/// Crossing a custom desire's high threshold in either direction fires EvaluateDecision, so custom needs
+/// reach the utility pipeline the way NeedChangeEvent brings the built-in ones there
+pub fn custom_needs_system(
+    mut query: Query<(Entity, &mut CustomNeeds, Option<&RefillState>), With<Npc>>,
+    sites_query: Query<&CustomResourceSite>,
+    registry: Res<CustomDesireRegistry>,
+    mut evaluation_events: EventWriter<EvaluateDecision>,
+    time: Res<Time>,
+) {
+    let delta_time = time.delta_secs();
+
+    for (entity, mut custom_needs, refill_state) in query.iter_mut() {
+        let refilled = refill_state
+            .filter(|refill| refill.is_refilling)
+            .and_then(|refill| refill.resource_entity)
+            .and_then(|site| sites_query.get(site).ok())
+            .map(|site| site.id);
+
+        let mut crossed = false;
+        for (id, definition) in registry.iter() {
+            let rate = if refilled == Some(id) { definition.satisfaction_rate } else { -definition.decay_rate };
+            let old_value = custom_needs.value(id);
+            let new_value = (old_value + rate * delta_time).clamp(0.0, 1.0);
+            custom_needs.values.insert(id, new_value);
+
+            let high = definition.threshold.high_threshold;
+            crossed |= (old_value < high) != (new_value < high);
+        }
+
+        if crossed {
+            evaluation_events.write(EvaluateDecision { entity, trigger_reason: DecisionTrigger::NeedChanged });
+        }
+    }
+}
+
+/// Helper function flattening granular and batched need change events into (entity, change) pairs
+/// Lets every consumer treat both NeedEventMode variants identically
+fn read_need_changes<'a>(
+    granular: &'a mut EventReader<NeedChangeEvent>,
+    batched: &'a mut EventReader<NeedsChangedBatch>,
+) -> impl Iterator<Item = (Entity, NeedChange)> + 'a {
+    granular
+        .read()
+        .map(|event| (event.entity, NeedChange { need_type: event.need_type, old_value: event.old_value, new_value: event.new_value }))
+        .chain(batched.read().flat_map(|batch| batch.changes.iter().map(move |change| (batch.entity, *change))))
+}
+
+/// Event-driven system starting social interactions from NPC-NPC contacts, or SocialProximity pairs with proximity_interactions
+/// Each encounter passes the gates in social_helpers (debounce, preference order, fatigue, facing, cooldown, initiation chance)
+/// and starts an interaction in both agents' SocialInteractionState; the social need is satisfied when it completes
 pub fn handle_social_interactions(
     mut collision_events: EventReader<CollisionEvent>,
-    mut social_events: EventWriter<SocialInteractionEvent>,
-    mut need_change_events: EventWriter<NeedChangeEvent>,
-    mut needs_query: Query<&mut BasicNeeds, With<Npc>>,
+    mut started_events: EventWriter<InteractionStarted>,
+    mut states_query: Query<(&mut SocialInteractionState, Option<&Personality>, Option<&Mood>, Option<&SocialEnergy>, Option<&Transform>, Option<&VisionRange>), With<Npc>>,
+    relationships_query: Query<&SocialRelationships>,
+    needs_query: Query<(&BasicNeeds, Option<&CurrentDesire>)>,
+    groups_query: Query<&CollisionGroups>,
+    game_constants: Res<GameConstants>,
+    social_config: Res<SocialConfig>,
+    mut contact_debounce: ResMut<ContactDebounce>,
+    proximity: Option<ResMut<SocialProximity>>,
+    mut sim_rng: ResMut<SimulationRng>,
+    time: Res<Time>,
 ) {
-    const SOCIAL_INTERACTION_BOOST: f32 = 0.1; // Normalized boost for 0.0-1.0 scale
+    let current_time = time.elapsed_secs();
+
+    // Rapier reports contacts in whatever order its broad phase found them, so pairs are put in canonical
+    // order and sorted by entity: an agent touching several others always pairs with the same partner
+    let mut contacts: Vec<(Entity, Entity)> = if social_config.proximity_interactions {
+        // Collisions only keep bodies apart; encounters are the pairs social_proximity_system saw come into range
+        collision_events.clear();
+        proximity.map(|mut proximity| std::mem::take(&mut proximity.entered)).unwrap_or_default()
+    } else {
+        collision_events
+            .read()
+            .filter_map(|collision_event| match collision_event {
+                CollisionEvent::Started(contact1, contact2, _flags) => Some(ordered_entity_pair(*contact1, *contact2)),
+                CollisionEvent::Stopped(..) => None,
+            })
+            // Only contacts between two NPC-layer colliders count as social encounters
+            .filter(|(entity1, entity2)| is_npc_npc_contact(groups_query.get(*entity1).ok(), groups_query.get(*entity2).ok()))
+            .collect()
+    };
+    contacts.sort_unstable();
+    contacts.dedup();
+
+    // Jittering re-reports are dropped, then the most mutually satisfying pairs claim their agents first
+    debounce_contact_starts(&mut contacts, &mut contact_debounce.last_contact, current_time, social_config.contact_debounce_window);
+    rank_contacts_by_preference(&mut contacts, |actor, partner| {
+        calculate_partner_preference(relationships_query.get(actor).ok().and_then(|r| r.relationships.get(&partner)))
+    });
+
+    for (entity1, entity2) in contacts {
+        // Both agents must exist and be free - agents already mid-interaction ignore new contacts
+        let Ok([(mut state1, personality1, mood1, energy1, transform1, vision1), (mut state2, personality2, mood2, energy2, transform2, vision2)]) =
+            states_query.get_many_mut([entity1, entity2])
+        else {
+            continue;
+        };
+        if !state1.is_available()
+            || !state2.is_available()
+            || is_socially_drained(energy1, &social_config)
+            || is_socially_drained(energy2, &social_config)
+            || !sees_partner(transform1, vision1, transform2)
+            || !sees_partner(transform2, vision2, transform1)
+            || state1.on_cooldown_with(entity2, current_time, social_config.interaction_cooldown)
+        {
+            continue;
+        }
 
-    for collision_event in collision_events.read() {
-        if let CollisionEvent::Started(entity1, entity2, _flags) = collision_event {
-            // Try to get both entities' BasicNeeds components
-            if let Ok([mut needs1, mut needs2]) = needs_query.get_many_mut([*entity1, *entity2]) {
-                let old_social_1 = needs1.social;
-                let old_social_2 = needs2.social;
+        let initiation_chance = calculate_initiation_chance(personality1, personality2);
+        if initiation_chance < 1.0 && sim_rng.rng.random::<f32>() >= initiation_chance {
+            continue;
+        }
 
-                // Both NPCs gain social satisfaction from the interaction using helper
-                let boost1 = increase_social_satisfaction(&mut needs1, SOCIAL_INTERACTION_BOOST);
-                let boost2 = increase_social_satisfaction(&mut needs2, SOCIAL_INTERACTION_BOOST);
+        // How the exchange looks from each side; missing moods count as neutral
+        let desire = |entity: Entity| needs_query.get(entity).ok().and_then(|(_, desire)| desire).map(|d| d.desire);
+        let shared_goal = matches!((desire(entity1), desire(entity2)), (Some(a), Some(b)) if a == b);
+        let partner_needs = |entity: Entity| needs_query.get(entity).ok().map(|(needs, _)| needs);
+        let acquainted = |actor: Entity, partner: Entity| relationships_query.get(actor).is_ok_and(|r| r.relationships.contains_key(&partner));
+        let (mood1, mood2) = (mood1.copied().unwrap_or_default(), mood2.copied().unwrap_or_default());
+        let (type1, satisfaction1) =
+            judge_interaction(personality1, personality2, partner_needs(entity2), shared_goal, acquainted(entity1, entity2), (&mood1, &mood2));
+        let (type2, satisfaction2) =
+            judge_interaction(personality2, personality1, partner_needs(entity1), shared_goal, acquainted(entity2, entity1), (&mood2, &mood1));
+
+        let duration = game_constants.social_interaction_duration;
+        state1.active = Some(ActiveInteraction {
+            partner: entity2,
+            started_at: current_time,
+            duration,
+            interaction_type: type1,
+            satisfaction: satisfaction1,
+        });
+        state2.active = Some(ActiveInteraction {
+            partner: entity1,
+            started_at: current_time,
+            duration,
+            interaction_type: type2,
+            satisfaction: satisfaction2,
+        });
 
-                // Fire individual need change events for threshold monitoring
-                if boost1 > 0.0 {
-                    need_change_events.write(NeedChangeEvent {
-                        entity: *entity1,
-                        need_type: NeedType::Social,
-                        old_value: old_social_1,
-                        new_value: needs1.social,
-                        change_amount: boost1,
-                    });
-                }
+        // ML-HOOK: Fire event for quantifiable interaction initiation tracking
+        started_events.write(InteractionStarted {
+            entity_1: entity1,
+            entity_2: entity2,
+            started_at: current_time,
+        });
+    }
+}
 
-                if boost2 > 0.0 {
-                    need_change_events.write(NeedChangeEvent {
-                        entity: *entity2,
-                        need_type: NeedType::Social,
-                        old_value: old_social_2,
-                        new_value: needs2.social,
-                        change_amount: boost2,
-                    });
-                }
+/// Event-driven system satisfying the social need when an interaction completes
+/// System based on Social Exchange Theory - positive interactions increase social satisfaction
+/// The boost is proportional to each side's satisfaction: a warm conversation restores more than a perfunctory greeting
+/// Fires SocialInteractionEvent so relationships are updated from the same completed exchange
+pub fn social_need_fulfillment_system(
+    mut completed_events: EventReader<InteractionCompleted>,
+    mut social_events: EventWriter<SocialInteractionEvent>,
+    mut need_change_events: EventWriter<NeedChangeEvent>,
+    mut needs_query: Query<(&mut BasicNeeds, Option<&Personality>), With<Npc>>,
+) {
+    const MAX_SOCIAL_INTERACTION_BOOST: f32 = 0.2; // Normalized boost for 0.0-1.0 scale, a neutral (0.5) exchange restores 0.1
+
+    for event in completed_events.read() {
+        // Try to get both entities' BasicNeeds components
+        if let Ok([(mut needs1, personality1), (mut needs2, personality2)]) = needs_query.get_many_mut([event.entity_1, event.entity_2]) {
+            let old_social_1 = needs1.social;
+            let old_social_2 = needs2.social;
+
+            // Both NPCs gain social satisfaction from the interaction, extraverts more than introverts
+            let energy1 = personality1.map_or(1.0, Personality::social_energy_multiplier);
+            let energy2 = personality2.map_or(1.0, Personality::social_energy_multiplier);
+            let boost1 = increase_social_satisfaction(&mut needs1, MAX_SOCIAL_INTERACTION_BOOST * event.satisfaction_1.clamp(0.0, 1.0) * energy1);
+            let boost2 = increase_social_satisfaction(&mut needs2, MAX_SOCIAL_INTERACTION_BOOST * event.satisfaction_2.clamp(0.0, 1.0) * energy2);
+
+            // Fire individual need change events for threshold monitoring
+            if boost1 > 0.0 {
+                need_change_events.write(NeedChangeEvent {
+                    entity: event.entity_1,
+                    need_type: NeedType::Social,
+                    old_value: old_social_1,
+                    new_value: needs1.social,
+                    change_amount: boost1,
+                });
+            }
 
-                // ML-HOOK: Fire events for quantifiable interaction tracking
-                social_events.write(SocialInteractionEvent {
-                    entity_1: *entity1,
-                    entity_2: *entity2,
-                    social_boost: (boost1 + boost2) / 2.0, // Average boost for symmetric interaction
+            if boost2 > 0.0 {
+                need_change_events.write(NeedChangeEvent {
+                    entity: event.entity_2,
+                    need_type: NeedType::Social,
+                    old_value: old_social_2,
+                    new_value: needs2.social,
+                    change_amount: boost2,
                 });
             }
+
+            // ML-HOOK: Fire events for quantifiable interaction tracking
+            social_events.write(SocialInteractionEvent {
+                entity_1: event.entity_1,
+                entity_2: event.entity_2,
+                social_boost: (boost1 + boost2) / 2.0, // Average boost for symmetric interaction
+            });
         }
     }
 }
@@ -154,23 +358,43 @@ pub fn handle_social_interactions(
 /// Event-driven system that handles desire fulfillment attempts
 /// Triggers when NPCs with specific desires attempt to satisfy them
 /// Much more performant than polling all NPCs every frame
+/// With SatiationConfig enabled, boosts shrink as the need nears its high threshold (see calculate_satiation_multiplier)
+/// With RestQualityConfig enabled, rest recovers fully only inside a hotel or safe zone (see calculate_rest_quality)
 pub fn desire_fulfillment_system(
     mut desire_events: EventReader<DesireChangeEvent>,
     mut fulfillment_events: EventWriter<DesireFulfillmentAttemptEvent>,
     mut satisfaction_events: EventWriter<NeedSatisfactionEvent>,
     mut need_change_events: EventWriter<NeedChangeEvent>,
     mut needs_query: Query<&mut BasicNeeds>,
+    thresholds_query: Query<&DesireThresholds>,
+    transform_query: Query<&Transform>,
+    shelter_query: Query<(&Transform, AnyOf<(&Hotel, &SafeZone)>)>,
+    satiation_config: Option<Res<SatiationConfig>>,
+    rest_quality_config: Option<Res<RestQualityConfig>>,
+    log_config: Res<SimLogConfig>,
+    log_gate: Option<Res<LogGate>>,
 ) {
+    let default_thresholds = DesireThresholds::default();
+    let satiation_config = satiation_config.filter(|config| config.enabled);
+    let rest_quality_config = rest_quality_config.filter(|config| config.enabled);
+
     for event in desire_events.read() {
         // Only process desires that indicate seeking behavior
         if matches!(event.new_desire, Desire::FindFood | Desire::FindWater | Desire::Rest | Desire::FindSafety)
             && let Ok(mut needs) = needs_query.get_mut(event.entity)
         {
+            let thresholds = thresholds_query.get(event.entity).unwrap_or(&default_thresholds);
+            let satiation = |need_level: f32, threshold: &DualThreshold| {
+                satiation_config
+                    .as_deref()
+                    .map_or(1.0, |config| calculate_satiation_multiplier(need_level, threshold.high_threshold, config))
+            };
+
             // Simulate resource interaction based on desire type
             let (need_type, satisfaction_amount, success) = match event.new_desire {
                 Desire::FindFood => {
                     let old_hunger = needs.hunger;
-                    let boost = 0.4;
+                    let boost = 0.4 * satiation(old_hunger, &thresholds.hunger_threshold);
                     needs.hunger = (needs.hunger + boost).clamp(0.0, 1.0);
                     let actual_boost = needs.hunger - old_hunger;
 
@@ -184,12 +408,12 @@ pub fn desire_fulfillment_system(
                         });
                     }
 
-                    info!("NPC found food! Hunger increased to {:.2}", needs.hunger);
+                    sim_log!(log_gate, log_config, LogCategory::Needs, "NPC found food! Hunger increased to {:.2}", needs.hunger);
                     (NeedType::Hunger, actual_boost, true)
                 }
                 Desire::FindWater => {
                     let old_thirst = needs.thirst;
-                    let boost = 0.5;
+                    let boost = 0.5 * satiation(old_thirst, &thresholds.thirst_threshold);
                     needs.thirst = (needs.thirst + boost).clamp(0.0, 1.0);
                     let actual_boost = needs.thirst - old_thirst;
 
@@ -203,12 +427,27 @@ pub fn desire_fulfillment_system(
                         });
                     }
 
-                    info!("NPC found water! Thirst increased to {:.2}", needs.thirst);
+                    sim_log!(log_gate, log_config, LogCategory::Needs, "NPC found water! Thirst increased to {:.2}", needs.thirst);
                     (NeedType::Thirst, actual_boost, true)
                 }
                 Desire::Rest => {
                     let old_rest = needs.rest;
-                    let recovery = 0.3;
+                    let quality = rest_quality_config.as_deref().map_or(1.0, |config| {
+                        // Resting somewhere without a position counts as resting in the open
+                        let site_safety = transform_query.get(event.entity).map_or(0.0, |transform| {
+                            let shelters = shelter_query.iter().map(|(shelter_transform, (hotel, safe_zone))| {
+                                let (radius, safety) = match (hotel, safe_zone) {
+                                    (Some(_), _) => (config.hotel_radius, 1.0),
+                                    (None, Some(safe_zone)) => (safe_zone.influence_radius, safe_zone.safety_level),
+                                    (None, None) => (0.0, 0.0),
+                                };
+                                (shelter_transform.translation.truncate(), radius, safety)
+                            });
+                            calculate_rest_site_safety(transform.translation.truncate(), shelters)
+                        });
+                        calculate_rest_quality(site_safety, config)
+                    });
+                    let recovery = 0.3 * quality * satiation(old_rest, &thresholds.rest_threshold);
                     needs.rest = (needs.rest + recovery).clamp(0.0, 1.0);
                     let actual_recovery = needs.rest - old_rest;
 
@@ -222,12 +461,12 @@ pub fn desire_fulfillment_system(
                         });
                     }
 
-                    info!("NPC is resting! Rest level increased to {:.2}", needs.rest);
+                    sim_log!(log_gate, log_config, LogCategory::Needs, "NPC is resting! Rest level increased to {:.2} (rest quality {:.2})", needs.rest, quality);
                     (NeedType::Rest, actual_recovery, true)
                 }
                 Desire::FindSafety => {
                     let old_safety = needs.safety;
-                    let boost = 0.35;
+                    let boost = 0.35 * satiation(old_safety, &thresholds.safety_threshold);
                     needs.safety = (needs.safety + boost).clamp(0.0, 1.0);
                     let actual_boost = needs.safety - old_safety;
 
@@ -241,7 +480,7 @@ pub fn desire_fulfillment_system(
                         });
                     }
 
-                    info!("NPC found safety! Safety increased to {:.2}", needs.safety);
+                    sim_log!(log_gate, log_config, LogCategory::Needs, "NPC found safety! Safety increased to {:.2}", needs.safety);
                     (NeedType::Safety, actual_boost, true)
                 }
                 _ => return, // Should not happen given our filter above
@@ -271,35 +510,43 @@ pub fn desire_fulfillment_system(
 /// This replaces the polling-based threshold checking for better performance
 /// Now uses DualThreshold structure with high/low thresholds for hysteresis
 /// FIXED: Updated to use new field names and correct threshold logic
+/// Need values are compared relative to the agent's HomeostaticSetpoints, when it has them
 pub fn threshold_monitoring_system(
     mut need_change_events: EventReader<NeedChangeEvent>,
+    mut batch_events: EventReader<NeedsChangedBatch>,
     mut threshold_events: EventWriter<ThresholdCrossedEvent>,
     thresholds_query: Query<&DesireThresholds>,
+    setpoints_query: Query<&HomeostaticSetpoints>,
 ) {
-    for event in need_change_events.read() {
+    let default_setpoints = HomeostaticSetpoints::default();
+
+    for (entity, event) in read_need_changes(&mut need_change_events, &mut batch_events) {
         // Direct entity access - no iteration needed since we have the entity from the event
-        if let Ok(thresholds) = thresholds_query.get(event.entity) {
-            let dual_threshold = match event.need_type {
-                NeedType::Hunger => &thresholds.hunger_threshold,
-                NeedType::Thirst => &thresholds.thirst_threshold,
-                NeedType::Rest => &thresholds.rest_threshold,
-                NeedType::Safety => &thresholds.safety_threshold,
-                NeedType::Social => &thresholds.social_threshold,
+        if let Ok(thresholds) = thresholds_query.get(entity) {
+            let setpoints = setpoints_query.get(entity).unwrap_or(&default_setpoints);
+            let (dual_threshold, setpoint) = match event.need_type {
+                NeedType::Hunger => (&thresholds.hunger_threshold, &setpoints.hunger),
+                NeedType::Thirst => (&thresholds.thirst_threshold, &setpoints.thirst),
+                NeedType::Rest => (&thresholds.rest_threshold, &setpoints.rest),
+                NeedType::Safety => (&thresholds.safety_threshold, &setpoints.safety),
+                NeedType::Social => (&thresholds.social_threshold, &setpoints.social),
             };
+            let old_value = calculate_relative_need(event.old_value, setpoint);
+            let new_value = calculate_relative_need(event.new_value, setpoint);
 
             // NEW LOGIC: For "higher = better" semantics
             // - Activate desires when satisfaction drops BELOW high_threshold
             // - Start pathfinding when satisfaction drops BELOW low_threshold
             // - Deactivate when satisfaction rises ABOVE high_threshold
-            let old_below_high = event.old_value < dual_threshold.high_threshold;
-            let new_below_high = event.new_value < dual_threshold.high_threshold;
-            let old_below_low = event.old_value < dual_threshold.low_threshold;
-            let new_below_low = event.new_value < dual_threshold.low_threshold;
+            let old_below_high = old_value < dual_threshold.high_threshold;
+            let new_below_high = new_value < dual_threshold.high_threshold;
+            let old_below_low = old_value < dual_threshold.low_threshold;
+            let new_below_low = new_value < dual_threshold.low_threshold;
 
             // Fire events when crossing BELOW high threshold (desire activation)
             if !old_below_high && new_below_high {
                 threshold_events.write(ThresholdCrossedEvent {
-                    entity: event.entity,
+                    entity,
                     need_type: event.need_type,
                     threshold_value: dual_threshold.high_threshold,
                     current_value: event.new_value,
@@ -311,7 +558,7 @@ pub fn threshold_monitoring_system(
             // Fire events when crossing ABOVE high threshold (desire deactivation)
             if old_below_high && !new_below_high {
                 threshold_events.write(ThresholdCrossedEvent {
-                    entity: event.entity,
+                    entity,
                     need_type: event.need_type,
                     threshold_value: dual_threshold.high_threshold,
                     current_value: event.new_value,
@@ -323,7 +570,7 @@ pub fn threshold_monitoring_system(
             // Fire events when crossing BELOW low threshold (urgent pathfinding)
             if !old_below_low && new_below_low {
                 threshold_events.write(ThresholdCrossedEvent {
-                    entity: event.entity,
+                    entity,
                     need_type: event.need_type,
                     threshold_value: dual_threshold.low_threshold,
                     current_value: event.new_value,
@@ -342,17 +589,24 @@ pub fn threshold_monitoring_system(
 pub fn desire_update_system(
     mut threshold_events: EventReader<ThresholdCrossedEvent>,
     mut desire_events: EventWriter<DesireChangeEvent>,
-    needs_query: Query<&BasicNeeds>,
+    needs_query: Query<(&BasicNeeds, Option<&HomeostaticSetpoints>)>,
     thresholds_query: Query<&DesireThresholds>,
+    weights_query: Query<&NeedPriorityWeights>,
     mut desires_query: Query<&mut Desire>,
+    log_config: Res<SimLogConfig>,
+    log_gate: Option<Res<LogGate>>,
 ) {
+    let default_weights = NeedPriorityWeights::default();
+
     for event in threshold_events.read() {
         // Direct entity access - no iteration needed since we have the entity from the event
-        if let (Ok(needs), Ok(thresholds), Ok(mut current_desire)) = (
+        if let (Ok((needs, setpoints)), Ok(thresholds), Ok(mut current_desire)) = (
             needs_query.get(event.entity),
             thresholds_query.get(event.entity),
             desires_query.get_mut(event.entity)
         ) {
+            // Utilities and deactivation read needs relative to the agent's own comfort levels
+            let needs = &setpoints.map_or(*needs, |setpoints| calculate_relative_needs(needs, setpoints));
             if event.should_trigger_desire {
                 // Determine new desire based on need type
                 let new_desire = match event.need_type {
@@ -364,11 +618,17 @@ pub fn desire_update_system(
                 };
 
                 // Calculate weighted utility score using the new formula
-                let utility = calculate_desire_utility(new_desire, &needs, &thresholds);
+                let weights = weights_query.get(event.entity).unwrap_or(&default_weights);
+                let utility = calculate_desire_utility(new_desire, needs, thresholds, weights);
 
                 if *current_desire != new_desire {
-                    info!("NPC desire changed from {:?} to {:?} due to {:?} threshold crossing",
-                          *current_desire, new_desire, event.need_type);
+                    sim_log!(
+                        log_gate,
+                        log_config,
+                        LogCategory::Needs,
+                        "NPC desire changed from {:?} to {:?} due to {:?} threshold crossing",
+                        *current_desire, new_desire, event.need_type
+                    );
 
                     desire_events.write(DesireChangeEvent {
                         entity: event.entity,
@@ -401,13 +661,18 @@ pub fn desire_update_system(
 }
 
 /// System that logs current status of NPCs for debugging
-/// Can be disabled in production builds
+/// Can be disabled in production builds, or at runtime by closing the LogGate
 /// FIXED: Updated to use correct field names
 pub fn debug_npc_status(
     query: Query<(&BasicNeeds, &Desire), With<Npc>>,
     mut last_debug_time: Local<f32>,
+    log_gate: Option<Res<LogGate>>,
     time: Res<Time>,
 ) {
+    if !LogGate::is_open(log_gate.as_deref()) {
+        return;
+    }
+
     // Log status every 5 seconds to avoid spam
     *last_debug_time += time.delta_secs();
     if *last_debug_time >= 5.0 {
@@ -421,36 +686,172 @@ pub fn debug_npc_status(
     }
 }
 
+/// Debug system dropping one need of the selected NPC to a critical level on a number key press
+/// Keys 1-5 pick hunger, thirst, rest, safety and social; the change is reported as a NeedChangeEvent,
+/// so thresholds and decisions respond exactly as they would to organic decay
+pub fn need_deficit_injection_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    selected: Res<SelectedNpc>,
+    mut needs_query: Query<&mut BasicNeeds, With<Npc>>,
+    mut need_change_events: EventWriter<NeedChangeEvent>,
+    log_config: Res<SimLogConfig>,
+    log_gate: Option<Res<LogGate>>,
+) {
+    const INJECTED_NEED_LEVEL: f32 = 0.05; // Below every default low threshold
+
+    let Some(need_type) = [
+        (KeyCode::Digit1, NeedType::Hunger),
+        (KeyCode::Digit2, NeedType::Thirst),
+        (KeyCode::Digit3, NeedType::Rest),
+        (KeyCode::Digit4, NeedType::Safety),
+        (KeyCode::Digit5, NeedType::Social),
+    ]
+    .into_iter()
+    .find_map(|(key, need_type)| keyboard.just_pressed(key).then_some(need_type)) else {
+        return;
+    };
+    let Some(entity) = selected.0 else {
+        return;
+    };
+    let Ok(mut needs) = needs_query.get_mut(entity) else {
+        return;
+    };
+
+    let value = need_value_mut(&mut needs, need_type);
+    let old_value = *value;
+    *value = INJECTED_NEED_LEVEL;
+
+    need_change_events.write(NeedChangeEvent {
+        entity,
+        need_type,
+        old_value,
+        new_value: INJECTED_NEED_LEVEL,
+        change_amount: INJECTED_NEED_LEVEL - old_value,
+    });
+    sim_log!(log_gate, log_config, LogCategory::Needs, "Injected {:?} deficit: {:.2} -> {:.2}", need_type, old_value, INJECTED_NEED_LEVEL);
+}
+
 /// The missing decision_making_system from roadmap 1.3.2
 /// Event-driven system that evaluates all competing desires and selects the highest utility one
 /// Triggered by EvaluateDecision events for better performance than polling
 /// Uses the existing evaluate_most_urgent_desire helper function for proper decision-making
+/// With DecisionLookaheadConfig enabled, sufficiently deliberate agents instead decide on their needs projected over
+/// a trip to the nearest remembered resource (see evaluate_desire_with_lookahead)
+/// Incapacitated agents are skipped and keep their current desire
 pub fn decision_making_system(
     mut evaluation_events: EventReader<EvaluateDecision>,
     mut current_desire_events: EventWriter<CurrentDesireSet>,
     mut desire_change_events: EventWriter<DesireChangeEvent>,
-    needs_query: Query<&BasicNeeds>,
+    needs_query: Query<(&BasicNeeds, Option<&HomeostaticSetpoints>, Option<&SafetyHabituation>), Without<Incapacitated>>,
     thresholds_query: Query<&DesireThresholds>,
     mut current_desires_query: Query<&mut CurrentDesire>,
-    time: Res<Time>,
+    mut desires_query: Query<&mut Desire>,
+    weights_query: Query<&NeedPriorityWeights>,
+    dual_process_query: Query<&DualProcess>,
+    personality_query: Query<&Personality>,
+    mut willpower_query: Query<&mut Willpower>,
+    stability_query: Query<&DecisionStability>,
+    (lookahead_config, lookahead_query): (Option<Res<DecisionLookaheadConfig>>, Query<(&Transform, &ResourceMemory, Option<&BodySize>)>),
+    (custom_registry, custom_needs_query): (Option<Res<CustomDesireRegistry>>, Query<&CustomNeeds>),
+    game_constants: Res<GameConstants>,
+    (log_config, log_gate, time): (Res<SimLogConfig>, Option<Res<LogGate>>, Res<Time>),
 ) {
+    let default_weights = NeedPriorityWeights::default();
+    let lookahead_config = lookahead_config.filter(|config| config.enabled);
+
     for event in evaluation_events.read() {
         // Direct entity access - no iteration needed since we have the entity from the event
-        if let (Ok(needs), Ok(thresholds), Ok(mut current_desire)) = (
+        if let (Ok((needs, setpoints, habituation)), Ok(thresholds), Ok(mut current_desire)) = (
             needs_query.get(event.entity),
             thresholds_query.get(event.entity),
             current_desires_query.get_mut(event.entity)
         ) {
-            // Use the existing helper function that evaluates ALL competing desires
-            let (best_desire, utility_score) = evaluate_most_urgent_desire(&needs, &thresholds);
+            // Every desire is weighed on needs read relative to the agent's own comfort levels
+            let needs = &setpoints.map_or(*needs, |setpoints| calculate_relative_needs(needs, setpoints));
+
+            // Agents without their own priorities fall back to the survival-first defaults,
+            // and a threat the agent gave up fleeing weighs less until its habituation wears off
+            let weights = weights_query.get(event.entity).unwrap_or(&default_weights);
+            let weights = &habituation.map_or(*weights, |habituation| habituation.damp_weights(weights, time.elapsed_secs()));
+
+            // Dual-process agents decide at the depth their stress, willpower and conscientiousness allow,
+            // everyone else uses the existing helper that evaluates ALL competing desires
+            let system2_engagement = dual_process_query
+                .get(event.entity)
+                .ok()
+                .map(|dual_process| calculate_system2_engagement(dual_process, willpower_query.get(event.entity).ok()));
+            let (best_desire, utility_score) = match system2_engagement {
+                Some(system2_engagement) => evaluate_desire_dual_process(
+                    needs,
+                    thresholds,
+                    weights,
+                    &game_constants,
+                    system2_engagement,
+                    personality_query.get(event.entity).map_or(1.0, Personality::deliberation_depth),
+                ),
+                None => evaluate_most_urgent_desire(needs, thresholds, weights),
+            };
+
+            // Bounded lookahead: only agents with System 2 available think a whole trip ahead
+            let looks_ahead = lookahead_config.as_ref().zip(system2_engagement).filter(|(config, system2_engagement)| {
+                *system2_engagement >= config.min_system2_engagement
+            });
+            let (best_desire, utility_score) = match (looks_ahead, lookahead_query.get(event.entity)) {
+                (Some((config, _)), Ok((transform, memory, body))) => {
+                    let horizon = estimate_lookahead_horizon(
+                        transform.translation.truncate(),
+                        memory,
+                        body.map_or(game_constants.npc_speed, |body| body.max_speed),
+                        config.expected_refill_secs,
+                        config.max_horizon_secs,
+                    );
+                    evaluate_desire_with_lookahead(needs, thresholds, weights, &game_constants, horizon)
+                }
+                _ => (best_desire, utility_score),
+            };
+
+            // Ego depletion: overriding the dominant need costs willpower until it runs out
+            let (best_desire, utility_score) = match willpower_query.get_mut(event.entity) {
+                Ok(mut willpower) => resolve_desire_with_willpower(
+                    current_desire.desire,
+                    (best_desire, utility_score),
+                    needs,
+                    thresholds,
+                    weights,
+                    &mut willpower,
+                    game_constants.willpower_depletion_rate,
+                ),
+                Err(_) => (best_desire, utility_score),
+            };
+
+            // Commitment: a dithering agent sticks with its current desire against near-tied alternatives
+            let (best_desire, utility_score) = match stability_query.get(event.entity) {
+                Ok(stability) => apply_commitment_bias(
+                    (current_desire.desire, calculate_desire_utility(current_desire.desire, needs, thresholds, weights)),
+                    (best_desire, utility_score),
+                    stability.commitment_bias,
+                ),
+                Err(_) => (best_desire, utility_score),
+            };
+
+            // Registered custom desires compete on the same utility scale as whatever the built-in
+            // arbitration above settled on; willpower and commitment only weigh the physiological drives
+            let custom_choice = custom_registry
+                .as_ref()
+                .zip(custom_needs_query.get(event.entity).ok())
+                .and_then(|(registry, custom_needs)| evaluate_most_urgent_custom_desire(registry, custom_needs));
+            let (best_desire, utility_score) = match custom_choice {
+                Some((custom_desire, custom_utility)) if custom_utility > utility_score => (custom_desire, custom_utility),
+                _ => (best_desire, utility_score),
+            };
 
             // ML-HOOK: Calculate utility for all desires for observation space
             let competing_desires = vec![
-                (Desire::FindSafety, calculate_desire_utility(Desire::FindSafety, &needs, &thresholds)),
-                (Desire::FindWater, calculate_desire_utility(Desire::FindWater, &needs, &thresholds)),
-                (Desire::FindFood, calculate_desire_utility(Desire::FindFood, &needs, &thresholds)),
-                (Desire::Rest, calculate_desire_utility(Desire::Rest, &needs, &thresholds)),
-                (Desire::Socialize, calculate_desire_utility(Desire::Socialize, &needs, &thresholds)),
+                (Desire::FindSafety, calculate_desire_utility(Desire::FindSafety, needs, thresholds, weights)),
+                (Desire::FindWater, calculate_desire_utility(Desire::FindWater, needs, thresholds, weights)),
+                (Desire::FindFood, calculate_desire_utility(Desire::FindFood, needs, thresholds, weights)),
+                (Desire::Rest, calculate_desire_utility(Desire::Rest, needs, thresholds, weights)),
+                (Desire::Socialize, calculate_desire_utility(Desire::Socialize, needs, thresholds, weights)),
             ];
 
             // Only update if the desire actually changed
@@ -476,35 +877,96 @@ pub fn decision_making_system(
                     new_desire: best_desire,
                     urgency_score: utility_score,
                     trigger_reason: match event.trigger_reason {
-                        DecisionTrigger::NeedChanged => DesireChangeReason::ThresholdCrossed,
+                        DecisionTrigger::NeedChanged | DecisionTrigger::Threat => DesireChangeReason::ThresholdCrossed,
                         _ => DesireChangeReason::ManualOverride,
                     },
                 });
 
-                info!("Decision made for NPC: {:?} -> {:?} (utility: {:.2})",
-                      old_desire, best_desire, utility_score);
+                sim_log!(
+                    log_gate,
+                    log_config,
+                    LogCategory::Needs,
+                    "Decision made for NPC: {:?} -> {:?} (utility: {:.2})",
+                    old_desire, best_desire, utility_score
+                );
             }
+
+            // Keep the movement-facing Desire in sync - this is its only writer in this pipeline, and an unchanged
+            // decision still repairs a Desire left behind while CurrentDesire moved on
+            if let Ok(mut desire) = desires_query.get_mut(event.entity) && *desire != best_desire {
+                *desire = best_desire;
+            }
+        }
+    }
+}
+
+/// Event-driven system rating how often each agent changes its desire
+/// Every DesireChangeEvent is recorded; all agents are re-rated each tick so the commitment bias
+/// applied by decision_making_system lifts once an agent has stopped dithering
+pub fn decision_stability_system(
+    mut desire_events: EventReader<DesireChangeEvent>,
+    mut stability_query: Query<&mut DecisionStability>,
+    config: Res<DecisionStabilityConfig>,
+    time: Res<Time>,
+) {
+    let current_time = time.elapsed_secs();
+
+    for event in desire_events.read() {
+        if let Ok(mut stability) = stability_query.get_mut(event.entity) {
+            stability.recent_changes.push(current_time);
         }
     }
+
+    for mut stability in stability_query.iter_mut() {
+        update_decision_stability(
+            &mut stability,
+            current_time,
+            config.window_secs,
+            config.max_changes_per_minute,
+            config.commitment_bias,
+        );
+    }
+}
+
+/// System giving each newly spawned agent a random starting phase in its decision interval
+/// Agents spawned on the same frame would otherwise reach their interval together and flood one frame with
+/// evaluations; the offset only shifts the first evaluation, so every agent keeps its average rate
+pub fn decision_phase_jitter_system(
+    mut query: Query<(&mut DualProcess, Option<&Willpower>), Added<DualProcess>>,
+    config: Res<DecisionJitterConfig>,
+    mut sim_rng: ResMut<SimulationRng>,
+) {
+    if !config.enabled {
+        return;
+    }
+
+    let phase_fraction = config.phase_fraction.clamp(0.0, 1.0);
+    for (mut dual_process, willpower) in query.iter_mut() {
+        let interval = calculate_decision_interval(calculate_system2_engagement(&dual_process, willpower));
+        dual_process.time_since_evaluation += sim_rng.rng.random::<f32>() * interval * phase_fraction;
+    }
 }
 
 /// System that triggers periodic decision re-evaluation
 /// Replaces the need to poll all NPCs every frame by firing EvaluateDecision events
 /// Based on bounded rationality theory - agents don't constantly re-evaluate
+/// Each agent's interval comes from its DualProcess state: stressed System 1 agents re-evaluate more often
+/// The FrameBudgetGovernor stretches every interval while the frame budget is exceeded
 pub fn periodic_decision_trigger_system(
-    query: Query<Entity, With<CurrentDesire>>,
+    mut query: Query<(Entity, &mut DualProcess, Option<&Willpower>), With<CurrentDesire>>,
     mut evaluation_events: EventWriter<EvaluateDecision>,
-    mut last_evaluation: Local<f32>,
+    governor: Option<Res<FrameBudgetGovernor>>,
     time: Res<Time>,
 ) {
-    const EVALUATION_INTERVAL: f32 = 2.0; // Re-evaluate every 2 seconds
+    let interval_multiplier = governor.map_or(1.0, |governor| governor.decision_interval_multiplier);
 
-    *last_evaluation += time.delta_secs();
-    if *last_evaluation >= EVALUATION_INTERVAL {
-        *last_evaluation = 0.0;
+    for (entity, mut dual_process, willpower) in query.iter_mut() {
+        dual_process.time_since_evaluation += time.delta_secs();
+
+        let interval = calculate_decision_interval(calculate_system2_engagement(&dual_process, willpower)) * interval_multiplier;
+        if dual_process.time_since_evaluation >= interval {
+            dual_process.time_since_evaluation = 0.0;
 
-        // Trigger evaluation for all agents
-        for entity in query.iter() {
             evaluation_events.write(EvaluateDecision {
                 entity,
                 trigger_reason: DecisionTrigger::Periodic,
@@ -513,18 +975,137 @@ pub fn periodic_decision_trigger_system(
     }
 }
 
+/// System keeping each agent's stress level in line with its unmet needs
+/// Based on Allostatic Load Theory - neglected needs accumulate as stress
+/// Neurotic agents feel the same shortfall more strongly (see Personality::stress_sensitivity)
+pub fn dual_process_update_system(
+    mut query: Query<(&BasicNeeds, Option<&Personality>, &mut DualProcess), Changed<BasicNeeds>>,
+) {
+    for (needs, personality, mut dual_process) in query.iter_mut() {
+        let sensitivity = personality.map_or(1.0, Personality::stress_sensitivity);
+        dual_process.stress = (calculate_need_stress(needs) * sensitivity).clamp(0.0, 1.0);
+    }
+}
+
+/// System keeping each agent's Mood in line with its needs and stress (see calculate_mood)
+/// Agents without a DualProcess are treated as calm
+pub fn mood_update_system(
+    mut query: Query<(&BasicNeeds, Option<&DualProcess>, &mut Mood), Or<(Changed<BasicNeeds>, Changed<DualProcess>)>>,
+) {
+    for (needs, dual_process, mut mood) in query.iter_mut() {
+        mood.set_if_neq(calculate_mood(needs, dual_process.map_or(0.0, |dual_process| dual_process.stress)));
+    }
+}
+
+/// System deciding which idle agents relax in place instead of wandering
+/// An agent relaxes only while it has no target and every basic and custom need is at or above its high threshold,
+/// and stops relaxing as soon as any need drops below it so the decision pipeline can send it somewhere
+/// Basic needs are read relative to the agent's HomeostaticSetpoints, when it has them
+pub fn relax_state_system(
+    mut query: Query<(&BasicNeeds, Option<&HomeostaticSetpoints>, &DesireThresholds, &Desire, &PathTarget, &RefillState, Option<&CustomNeeds>, &mut RelaxState), With<Npc>>,
+    custom_registry: Option<Res<CustomDesireRegistry>>,
+    time: Res<Time>,
+) {
+    for (needs, setpoints, thresholds, desire, path_target, refill_state, custom_needs, mut relax_state) in query.iter_mut() {
+        let needs = &setpoints.map_or(*needs, |setpoints| calculate_relative_needs(needs, setpoints));
+        let custom_satisfied = match (custom_registry.as_deref(), custom_needs) {
+            (Some(registry), Some(custom_needs)) => registry.iter().all(|(id, definition)| {
+                custom_needs.value(id) >= definition.threshold.high_threshold
+            }),
+            _ => true,
+        };
+        let should_relax = *desire == Desire::Wander
+            && !path_target.has_target
+            && !refill_state.is_refilling
+            && custom_satisfied
+            && are_all_needs_satisfied(needs, thresholds);
+
+        if should_relax && !relax_state.is_relaxing {
+            relax_state.is_relaxing = true;
+            relax_state.relaxed_since = time.elapsed_secs();
+        } else if !should_relax && relax_state.is_relaxing {
+            relax_state.is_relaxing = false;
+        }
+    }
+}
+
+/// System restoring willpower while agents rest or relax in place
+/// Based on Ego Depletion Theory (Baumeister et al., 1998) - rest replenishes self-control
+pub fn willpower_recovery_system(
+    mut query: Query<(&CurrentDesire, &mut Willpower, Option<&RelaxState>)>,
+    game_constants: Res<GameConstants>,
+    time: Res<Time>,
+) {
+    for (current_desire, mut willpower, relax_state) in query.iter_mut() {
+        let relaxing = relax_state.is_some_and(|relax_state| relax_state.is_relaxing);
+        let recovery_rate = if relaxing {
+            game_constants.willpower_recovery_rate * game_constants.relax_recovery_multiplier
+        } else if current_desire.desire == Desire::Rest {
+            game_constants.willpower_recovery_rate
+        } else {
+            continue;
+        };
+
+        if willpower.current < willpower.max {
+            willpower.current = (willpower.current + recovery_rate * time.delta_secs()).min(willpower.max);
+        }
+    }
+}
+
+/// System applying NeedFailureConfig to needs pinned at 0.0 - starvation, dehydration and exhaustion
+/// Once a need has stayed at zero for critical_duration_secs it fires a NeedCriticalEvent and, depending on the
+/// consequence, incapacitates the NPC or requests its despawn; an incapacitated NPC recovers once no
+/// physiological need is at zero any more
+pub fn need_failure_system(
+    mut commands: Commands,
+    mut query: Query<(Entity, &BasicNeeds, &mut NeedDepletion, Has<Incapacitated>), With<Npc>>,
+    config: Res<NeedFailureConfig>,
+    mut critical_events: EventWriter<NeedCriticalEvent>,
+    mut despawn_events: EventWriter<NpcDespawnEvent>,
+    log_config: Res<SimLogConfig>,
+    log_gate: Option<Res<LogGate>>,
+    time: Res<Time>,
+) {
+    if !config.enabled {
+        return;
+    }
+
+    for (entity, needs, mut depletion, incapacitated) in query.iter_mut() {
+        let Some((need_type, secs_at_zero)) = advance_need_depletion(&mut depletion, needs, time.delta_secs(), config.critical_duration_secs) else {
+            if incapacitated && !depletion.critical {
+                commands.entity(entity).remove::<Incapacitated>();
+                sim_log!(log_gate, log_config, LogCategory::Needs, "NPC {:?} recovered from incapacitation", entity);
+            }
+            continue;
+        };
+
+        critical_events.write(NeedCriticalEvent { entity, need_type, secs_at_zero, consequence: config.consequence });
+        sim_log!(log_gate, log_config, LogCategory::Needs, "NPC {:?} {:?} failed after {:.1}s at zero: {:?}", entity, need_type, secs_at_zero, config.consequence);
+        match config.consequence {
+            NeedFailureConsequence::EventOnly => {}
+            NeedFailureConsequence::Incapacitate => {
+                commands.entity(entity).insert(Incapacitated { since: time.elapsed_secs() });
+            }
+            NeedFailureConsequence::Despawn => {
+                despawn_events.write(NpcDespawnEvent { entity });
+            }
+        }
+    }
+}
+
 /// Optimized threshold monitoring system that triggers decision evaluation
 /// Instead of directly setting desires, it triggers the decision_making_system
 /// This allows for proper utility comparison between all competing desires
 pub fn optimized_threshold_monitoring_system(
     mut need_change_events: EventReader<NeedChangeEvent>,
+    mut batch_events: EventReader<NeedsChangedBatch>,
     mut threshold_events: EventWriter<ThresholdCrossedEvent>,
     mut evaluation_events: EventWriter<EvaluateDecision>,
     thresholds_query: Query<&DesireThresholds>,
 ) {
-    for event in need_change_events.read() {
+    for (entity, event) in read_need_changes(&mut need_change_events, &mut batch_events) {
         // Direct entity access - no iteration needed since we have the entity from the event
-        if let Ok(thresholds) = thresholds_query.get(event.entity) {
+        if let Ok(thresholds) = thresholds_query.get(entity) {
             let dual_threshold = match event.need_type {
                 NeedType::Hunger => &thresholds.hunger_threshold,
                 NeedType::Thirst => &thresholds.thirst_threshold,
@@ -544,7 +1125,7 @@ pub fn optimized_threshold_monitoring_system(
             if significant_change {
                 // Fire threshold crossed event for logging/debugging
                 threshold_events.write(ThresholdCrossedEvent {
-                    entity: event.entity,
+                    entity,
                     need_type: event.need_type,
                     threshold_value: dual_threshold.high_threshold,
                     current_value: event.new_value,
@@ -558,7 +1139,7 @@ pub fn optimized_threshold_monitoring_system(
 
                 // Trigger decision evaluation instead of directly setting desires
                 evaluation_events.write(EvaluateDecision {
-                    entity: event.entity,
+                    entity,
                     trigger_reason: DecisionTrigger::NeedChanged,
                 });
             }
@@ -566,15 +1147,81 @@ pub fn optimized_threshold_monitoring_system(
     }
 }
 
+/// Fight-or-flight fast path: a sharp safety drop forces an immediate EvaluateDecision
+/// Runs ahead of the periodic and threshold triggers so the agent reacts in the same tick the threat arrives
+pub fn safety_threat_detection_system(
+    mut need_change_events: EventReader<NeedChangeEvent>,
+    mut batch_events: EventReader<NeedsChangedBatch>,
+    mut evaluation_events: EventWriter<EvaluateDecision>,
+    config: Res<SafetyChannelConfig>,
+) {
+    let mut threatened: Vec<Entity> = read_need_changes(&mut need_change_events, &mut batch_events)
+        .filter(|(_, change)| {
+            config.enabled && change.need_type == NeedType::Safety && change.old_value - change.new_value >= config.sharp_drop
+        })
+        .map(|(entity, _)| entity)
+        .collect();
+    threatened.sort();
+    threatened.dedup();
+
+    for entity in threatened {
+        evaluation_events.write(EvaluateDecision { entity, trigger_reason: DecisionTrigger::Threat });
+    }
+}
+
+/// Abandons the current path of agents that a threat evaluation has just switched to FindSafety
+/// Whatever non-safety action was underway is dropped so desire_pathfinding_system heads for a safe zone
+/// in the same tick; safety reached through the regular cadence finishes the current trip first
+pub fn safety_preemption_system(
+    mut evaluation_events: EventReader<EvaluateDecision>,
+    mut desire_events: EventReader<DesireChangeEvent>,
+    mut path_query: Query<&mut PathTarget>,
+) {
+    let threatened: HashSet<Entity> = evaluation_events
+        .read()
+        .filter(|event| event.trigger_reason == DecisionTrigger::Threat)
+        .map(|event| event.entity)
+        .collect();
+
+    for event in desire_events.read() {
+        if event.new_desire != Desire::FindSafety || !threatened.contains(&event.entity) {
+            continue;
+        }
+        if let Ok(mut path_target) = path_query.get_mut(event.entity) {
+            *path_target = PathTarget::default();
+        }
+    }
+}
+
 /// NEW: Action Failure Handling System (1.3.3+)
 /// Makes characters look for alternative ways to fulfill desires or switch to different desires
 /// Based on Adaptive Goal Management and Cognitive Flexibility research
+/// The target being pursued when an attempt fails goes into AvoidedTargets, so the retry picks another one
+/// Agents wedged against an obstacle are not failed but knocked loose through StuckEscape
+/// An agent giving up on FindSafety habituates to the threat it could not escape through SafetyHabituation,
+/// which damps the desire for a while without touching the safety need
 pub fn action_failure_handling_system(
     mut commands: Commands,
-    mut query: Query<(Entity, &mut CurrentDesire, &BasicNeeds, &DesireThresholds, &PathTarget, &RefillState)>,
+    mut query: Query<(
+        Entity,
+        &mut CurrentDesire,
+        Option<&mut Desire>,
+        &BasicNeeds,
+        &DesireThresholds,
+        Option<&NeedPriorityWeights>,
+        &PathTarget,
+        &RefillState,
+        Option<(&CognitiveMap, &mut PathLearning)>,
+        Option<&mut AvoidedTargets>,
+        Option<&mut SafetyHabituation>,
+        Option<(&Transform, &mut Velocity, &mut SteeringBehavior, &mut StuckEscape)>,
+    )>,
     mut action_completed_events: EventWriter<ActionCompleted>,
     mut evaluation_events: EventWriter<EvaluateDecision>,
     game_constants: Res<GameConstants>,
+    log_config: Res<SimLogConfig>,
+    log_gate: Option<Res<LogGate>>,
+    mut simulation_rng: ResMut<SimulationRng>,
     time: Res<Time>,
 ) {
     // Use scientifically-grounded constants from GameConstants instead of hardcoded values
@@ -583,9 +1230,37 @@ pub fn action_failure_handling_system(
     let stuck_distance_threshold = game_constants.stuck_distance_threshold;
     let timeout_multiplier = game_constants.timeout_retry_multiplier;
 
-    for (entity, mut current_desire, needs, thresholds, path_target, refill_state) in query.iter_mut() {
+    let default_weights = NeedPriorityWeights::default();
+
+    for (entity, mut current_desire, desire, needs, thresholds, weights, path_target, refill_state, path_learning, avoided_targets, safety_habituation, stuck_escape) in query.iter_mut() {
         let current_time = time.elapsed_secs();
 
+        // STUCK: pursuing a target without making headway - escape sideways instead of giving up
+        // Headway is measured from the anchor StuckEscape set at the start of the current window
+        if let Some((transform, mut velocity, mut steering, mut escape)) = stuck_escape {
+            let position = transform.translation.truncate();
+            if !escape.is_escaping(current_time) && let Some(avoidance_weight) = escape.restore_avoidance_weight.take() {
+                steering.avoidance_weight = avoidance_weight;
+            }
+
+            let pursuing = path_target.has_target && !refill_state.is_refilling && !escape.is_escaping(current_time);
+            let window_elapsed = current_time - escape.anchor_time;
+            if !pursuing {
+                escape.restart_window(position, current_time);
+            } else if is_stuck(position, escape.anchor_position, window_elapsed, escape.window, stuck_distance_threshold) {
+                velocity.linvel = calculate_escape_impulse(position, path_target.target_position, escape.impulse_speed, &mut simulation_rng.rng);
+                if escape.restore_avoidance_weight.is_none() {
+                    escape.restore_avoidance_weight = Some(steering.avoidance_weight);
+                }
+                steering.avoidance_weight = escape.avoidance_boost;
+                escape.escaping_until = current_time + escape.duration;
+                escape.restart_window(position, current_time);
+                sim_log!(log_gate, log_config, LogCategory::Pathfinding, "NPC {:?} appears stuck trying to reach {:?}, escaping", entity, path_target.target_position);
+            } else if window_elapsed >= escape.window {
+                escape.restart_window(position, current_time);
+            }
+        }
+
         // Initialize timeout duration if not set
         if current_desire.timeout_duration <= 0.0 {
             current_desire.timeout_duration = default_timeout;
@@ -607,33 +1282,40 @@ pub fn action_failure_handling_system(
         if attempt_duration > current_desire.timeout_duration {
             should_handle_failure = true;
             failure_reason = ActionCompletionReason::Timeout;
-            info!("NPC {:?} timed out on desire {:?} after {:.1}s", entity, current_desire.desire, attempt_duration);
+            sim_log!(log_gate, log_config, LogCategory::Needs, "NPC {:?} timed out on desire {:?} after {:.1}s", entity, current_desire.desire, attempt_duration);
         }
 
-        // 2. STUCK: Has a target but hasn't reached it and isn't making progress
-        // Based on spatial cognition research - targets beyond 25% of vision range are "far"
-        else if let Some(target_entity) = path_target.target_entity {
-            if path_target.has_target && !refill_state.is_refilling {
-                let distance_to_target = path_target.target_position.distance(Vec2::ZERO); // Simplified check
-                if distance_to_target > stuck_distance_threshold {
-                    should_handle_failure = true;
-                    failure_reason = ActionCompletionReason::Failed;
-                    info!("NPC {:?} appears stuck trying to reach target {:?}", entity, target_entity);
-                }
-            }
-        }
-
-        // 3. TARGET LOST: Had a target but it no longer exists or is unreachable
+        // 2. TARGET LOST: Had a target but it no longer exists or is unreachable
         else if current_desire.last_target.is_some() && !path_target.has_target && !refill_state.is_refilling {
             // Lost target and not currently pathing to a new one
             should_handle_failure = true;
             failure_reason = ActionCompletionReason::Failed;
-            info!("NPC {:?} lost target for desire {:?}", entity, current_desire.desire);
+            sim_log!(log_gate, log_config, LogCategory::Pathfinding, "NPC {:?} lost target for desire {:?}", entity, current_desire.desire);
         }
 
         if should_handle_failure {
             current_desire.failure_count += 1;
 
+            if path_target.has_target && let Some(mut avoided_targets) = avoided_targets {
+                avoided_targets.avoid(path_target.target_position, current_time);
+            }
+
+            // A pursuit that timed out is remembered as a failed traversal of its route
+            if matches!(failure_reason, ActionCompletionReason::Timeout)
+                && path_target.has_target
+                && let Some((map, mut learning)) = path_learning
+            {
+                record_path_experience(
+                    &mut learning,
+                    map,
+                    path_target.origin_position,
+                    path_target.target_position,
+                    current_time - path_target.target_set_time,
+                    false,
+                    path_target.max_pursuit_time,
+                );
+            }
+
             // Send ActionCompleted event to track the failure (ML-HOOK)
             action_completed_events.write(ActionCompleted {
                 entity,
@@ -646,28 +1328,35 @@ pub fn action_failure_handling_system(
             // ADAPTIVE STRATEGY: Based on Cognitive Flexibility research (Miyake et al., 2000)
             if current_desire.failure_count >= max_failure_count {
                 // Too many failures - switch to a different desire or fallback to wandering
-                info!("NPC {:?} giving up on {:?} after {} failures, switching desires",
-                      entity, current_desire.desire, current_desire.failure_count);
+                sim_log!(
+                    log_gate,
+                    log_config,
+                    LogCategory::Needs,
+                    "NPC {:?} giving up on {:?} after {} failures, switching desires",
+                    entity, current_desire.desire, current_desire.failure_count
+                );
+
+                // Fleeing a threat that cannot be escaped wears off instead of locking the agent into FindSafety
+                if current_desire.desire == Desire::FindSafety && let Some(mut habituation) = safety_habituation {
+                    habituation.habituate(current_time);
+                }
 
                 // Find the next most urgent desire or fall back to wandering
-                let fallback_desire = find_alternative_desire(current_desire.desire, needs, thresholds);
+                let weights = weights.unwrap_or(&default_weights);
+                let fallback_desire = find_alternative_desire(current_desire.desire, needs, thresholds, weights);
 
-                // Reset for new desire
+                // Reset for new desire, keeping the movement-facing Desire in step
                 current_desire.desire = fallback_desire;
+                if let Some(mut desire) = desire {
+                    *desire = fallback_desire;
+                }
                 current_desire.failure_count = 0;
                 current_desire.attempt_start_time = current_time;
                 current_desire.last_target = None;
                 current_desire.timeout_duration = default_timeout;
 
                 // Clear current pathfinding target to force new target search
-                commands.entity(entity).insert(PathTarget {
-                    has_target: false,
-                    target_entity: None,
-                    target_position: Vec2::ZERO,
-                    arrival_threshold: 30.0,
-                    target_set_time: 0.0,
-                    max_pursuit_time: 10.0,
-                });
+                commands.entity(entity).insert(PathTarget::default());
 
                 // Trigger immediate re-evaluation for the new desire
                 evaluation_events.write(EvaluateDecision {
@@ -677,8 +1366,13 @@ pub fn action_failure_handling_system(
             } else {
                 // Try again with the same desire but look for alternative targets
                 // Based on adaptive patience research (Anderson & Lebiere, 1998)
-                info!("NPC {:?} retrying {:?} (attempt {} of {})",
-                      entity, current_desire.desire, current_desire.failure_count + 1, max_failure_count);
+                sim_log!(
+                    log_gate,
+                    log_config,
+                    LogCategory::Needs,
+                    "NPC {:?} retrying {:?} (attempt {} of {})",
+                    entity, current_desire.desire, current_desire.failure_count + 1, max_failure_count
+                );
 
                 // Reset attempt timing but keep the same desire
                 current_desire.attempt_start_time = current_time;
@@ -688,14 +1382,7 @@ pub fn action_failure_handling_system(
                 current_desire.timeout_duration *= timeout_multiplier;
 
                 // Clear current pathfinding target to force new target search
-                commands.entity(entity).insert(PathTarget {
-                    has_target: false,
-                    target_entity: None,
-                    target_position: Vec2::ZERO,
-                    arrival_threshold: 30.0,
-                    target_set_time: 0.0,
-                    max_pursuit_time: 10.0,
-                });
+                commands.entity(entity).insert(PathTarget::default());
 
                 // Trigger resource discovery to find alternative targets
                 evaluation_events.write(EvaluateDecision {
@@ -709,11 +1396,16 @@ pub fn action_failure_handling_system(
 
 /// Helper function to find an alternative desire when the current one repeatedly fails
 /// Based on Cognitive Flexibility and Goal Hierarchy research
-fn find_alternative_desire(failed_desire: Desire, needs: &BasicNeeds, thresholds: &DesireThresholds) -> Desire {
+fn find_alternative_desire(
+    failed_desire: Desire,
+    needs: &BasicNeeds,
+    thresholds: &DesireThresholds,
+    weights: &NeedPriorityWeights,
+) -> Desire {
     use crate::utils::helpers::needs_helpers::evaluate_most_urgent_desire;
 
     // Get the most urgent desire based on current needs
-    let (most_urgent, _utility) = evaluate_most_urgent_desire(needs, thresholds);
+    let (most_urgent, _utility) = evaluate_most_urgent_desire(needs, thresholds, weights);
 
     // If the most urgent desire is the same one that failed, find the second most urgent
     if most_urgent == failed_desire {
@@ -740,6 +1432,7 @@ fn find_alternative_desire(failed_desire: Desire, needs: &BasicNeeds, thresholds
                     Desire::Rest => need_level < thresholds.rest_threshold.high_threshold,
                     Desire::Socialize => need_level < thresholds.social_threshold.high_threshold,
                     Desire::Wander => true, // Always available as fallback
+                    Desire::Custom(_) => false, // Never listed above
                 };
 
                 if should_activate {
@@ -755,3 +1448,55 @@ fn find_alternative_desire(failed_desire: Desire, needs: &BasicNeeds, thresholds
     // Ultimate fallback
     Desire::Wander
 }
+
+/// Run condition for systems that belong to the Direct decision pipeline
+pub fn direct_pipeline_active(mode: Res<DecisionPipelineMode>) -> bool {
+    *mode == DecisionPipelineMode::Direct
+}
+
+/// Run condition for systems that belong to the UtilityArbitrated decision pipeline
+pub fn utility_pipeline_active(mode: Res<DecisionPipelineMode>) -> bool {
+    *mode == DecisionPipelineMode::UtilityArbitrated
+}
+
+/// Plugin registering exactly one decision pipeline in FixedUpdate, next to the rest of the simulation
+/// The pipeline runs in SimulationSet::Decision, after this tick's need changes and before movement
+/// Running both would let desire_update_system and decision_making_system overwrite each other's desires
+/// - Direct: need changes switch desires immediately, whichever threshold crossed last wins
+/// - UtilityArbitrated: need changes and periodic triggers request a full utility comparison
+#[derive(Default)]
+pub struct DecisionPipelinePlugin {
+    pub mode: DecisionPipelineMode,
+}
+
+impl Plugin for DecisionPipelinePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.mode);
+        if !app.is_plugin_added::<SimulationSchedulePlugin>() {
+            app.add_plugins(SimulationSchedulePlugin);
+        }
+
+        match self.mode {
+            DecisionPipelineMode::Direct => {
+                app.add_systems(FixedUpdate, (
+                    threshold_monitoring_system,        // Produces ThresholdCrossedEvent
+                    desire_update_system,               // Consumes ThresholdCrossedEvent, writes Desire
+                ).chain().in_set(SimulationSet::Decision).run_if(direct_pipeline_active).run_if(cognition_enabled));
+            }
+            DecisionPipelineMode::UtilityArbitrated => {
+                app.init_resource::<SafetyChannelConfig>();
+                app.init_resource::<DecisionJitterConfig>();
+                app.init_resource::<SimulationRng>();
+                app.add_systems(FixedUpdate, (
+                    decision_phase_jitter_system,               // Offsets new agents' evaluation timers
+                    safety_threat_detection_system,             // Fires EvaluateDecision at once on sharp safety drops
+                    periodic_decision_trigger_system,           // Fires EvaluateDecision periodically
+                    optimized_threshold_monitoring_system,      // Fires EvaluateDecision on significant need changes
+                    decision_making_system,                     // Writes CurrentDesire and Desire from utility comparison
+                    safety_preemption_system,                   // Drops the current path when a threat forces FindSafety
+                ).chain().in_set(SimulationSet::Decision).run_if(utility_pipeline_active).run_if(cognition_enabled));
+            }
+        }
+    }
+}
+
@@ -1,97 +1,94 @@
-use bevy::ecs::event::EventWriter;
+use bevy::ecs::event::{EventReader, EventWriter};
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 
-use crate::components::components_constants::GameConstants;
-use crate::components::components_environment::{Hotel, ResourceType, Restaurant, SafeZone, Well};
+use crate::components::components_constants::{GameConstants, SimulationRng};
+use crate::components::components_environment::{CustomResourceSite, Hotel, ResourceReservation, ResourceType, Restaurant, SafeZone, Well};
+use crate::components::components_learning::{AdaptiveBehavior, CognitiveMap, ForageMemory, PathLearning};
 use crate::components::components_needs::Desire;
-use crate::components::components_npc::{Npc, RefillState};
-use crate::components::components_pathfinding::{PathTarget, ResourceMemory, SteeringBehavior};
-use crate::systems::events::events_pathfinding::{PathTargetReachedEvent, PathTargetSetEvent, ResourceDiscoveredEvent};
+use crate::components::components_npc::{BodySize, Incapacitated, Npc, Perception, RefillState, RelaxState, WorldModel};
+use crate::components::components_pathfinding::{AvoidedTargets, IndexedResource, PathTarget, PhysicalContacts, ResourceMemory, ResourceSpatialIndex, SteeringBehavior, StuckEscape};
+use crate::systems::events::events_pathfinding::{PathTargetReachedEvent, PathTargetSetEvent, ReservationDenied, ResourceDiscoveredEvent};
+use crate::utils::helpers::learning_helpers::{calculate_forage_bias, select_target_epsilon_greedy};
+use crate::utils::helpers::physics_helpers::collider_radius;
 use crate::utils::helpers::{
-    calculate_seek_force, calculate_wander_force, find_nearest_npc_position,
-    find_nearest_resource_position, has_reached_target, should_timeout_pursuit,
+    calculate_arrival_threshold, calculate_boundary_avoidance_bias, calculate_seek_force, calculate_wander_force,
+    find_nearest_resource_position, has_reached_target, is_touching_target, limit_velocity_change, remember_resource_location, should_timeout_pursuit,
 };
 
 
+/// System keeping the resource spatial index in sync with the world
+/// Resources are static, so the grid is only rebuilt when one is added, moved or removed
+pub fn resource_spatial_index_system(
+    mut index: ResMut<ResourceSpatialIndex>,
+    resource_query: Query<(Entity, &Transform, Option<&Collider>, AnyOf<(&Well, &Restaurant, &Hotel, &SafeZone, &CustomResourceSite)>)>,
+    changed_query: Query<(), (Or<(With<Well>, With<Restaurant>, With<Hotel>, With<SafeZone>, With<CustomResourceSite>)>, Changed<Transform>)>,
+    mut removed_wells: RemovedComponents<Well>,
+    mut removed_restaurants: RemovedComponents<Restaurant>,
+    mut removed_hotels: RemovedComponents<Hotel>,
+    mut removed_safe_zones: RemovedComponents<SafeZone>,
+    mut removed_custom_sites: RemovedComponents<CustomResourceSite>,
+    game_constants: Res<GameConstants>,
+) {
+    let removed = removed_wells.read().count()
+        + removed_restaurants.read().count()
+        + removed_hotels.read().count()
+        + removed_safe_zones.read().count()
+        + removed_custom_sites.read().count();
+
+    if changed_query.is_empty() && removed == 0 {
+        return;
+    }
+
+    index.clear();
+    for (entity, transform, collider, (well, restaurant, hotel, safe_zone, custom_site)) in resource_query.iter() {
+        let resource_type = if well.is_some() {
+            ResourceType::Water
+        } else if restaurant.is_some() {
+            ResourceType::Food
+        } else if hotel.is_some() {
+            ResourceType::Rest
+        } else if let (None, Some(site)) = (safe_zone, custom_site) {
+            ResourceType::Custom(site.id)
+        } else {
+            ResourceType::Safety
+        };
+
+        index.insert(IndexedResource {
+            entity,
+            position: transform.translation.truncate(),
+            resource_type,
+            radius: collider.and_then(collider_radius).unwrap_or(game_constants.resource_radius),
+        });
+    }
+}
+
 /// System for discovering resources within range and updating NPCs' memory
 /// Based on Spatial Cognition Theory - agents use spatial memory for resource location
+/// Queries the ResourceSpatialIndex instead of scanning every resource for every NPC
+/// An agent's Perception scales its discovery_radius, so keen-eyed agents notice resources from further away
+/// Seeing a known resource again reinforces its memory; past capacity the most faded location is forgotten
 pub fn resource_discovery_system(
-    mut npc_query: Query<(Entity, &Transform, &mut ResourceMemory), With<Npc>>,
-    well_query: Query<&Transform, (With<Well>, Without<Npc>)>,
-    restaurant_query: Query<&Transform, (With<Restaurant>, Without<Npc>)>,
-    hotel_query: Query<&Transform, (With<Hotel>, Without<Npc>)>,
-    safe_zone_query: Query<&Transform, (With<SafeZone>, Without<Npc>)>,
+    mut npc_query: Query<(Entity, &Transform, &mut ResourceMemory, Option<&Perception>), With<Npc>>,
+    index: Res<ResourceSpatialIndex>,
     mut discovery_events: EventWriter<ResourceDiscoveredEvent>,
+    time: Res<Time>,
 ) {
-    for (entity, npc_transform, mut memory) in npc_query.iter_mut() {
-        let npc_position = npc_transform.translation.truncate();
-
-        // Discover wells within range
-        for well_transform in well_query.iter() {
-            let well_position = well_transform.translation.truncate();
-            if npc_position.distance(well_position) <= memory.discovery_radius {
-                if !memory.known_wells.contains(&well_position) {
-                    memory.known_wells.push(well_position);
-                    discovery_events.write(ResourceDiscoveredEvent {
-                        npc_entity: entity,
-                        resource_position: well_position,
-                        resource_entity: Entity::PLACEHOLDER, // TODO: Get actual well entity
-                        resource_type: ResourceType::Water,
-                        discovery_distance: npc_position.distance(well_position),
-                    });
-                }
-            }
-        }
-
-        // Discover restaurants within range
-        for restaurant_transform in restaurant_query.iter() {
-            let restaurant_position = restaurant_transform.translation.truncate();
-            if npc_position.distance(restaurant_position) <= memory.discovery_radius {
-                if !memory.known_restaurants.contains(&restaurant_position) {
-                    memory.known_restaurants.push(restaurant_position);
-                    discovery_events.write(ResourceDiscoveredEvent {
-                        npc_entity: entity,
-                        resource_position: restaurant_position,
-                        resource_entity: Entity::PLACEHOLDER, // TODO: Get actual restaurant entity
-                        resource_type: ResourceType::Food,
-                        discovery_distance: npc_position.distance(restaurant_position),
-                    });
-                }
-            }
-        }
+    let current_time = time.elapsed_secs();
 
-        // Discover hotels within range
-        for hotel_transform in hotel_query.iter() {
-            let hotel_position = hotel_transform.translation.truncate();
-            if npc_position.distance(hotel_position) <= memory.discovery_radius {
-                if !memory.known_hotels.contains(&hotel_position) {
-                    memory.known_hotels.push(hotel_position);
-                    discovery_events.write(ResourceDiscoveredEvent {
-                        npc_entity: entity,
-                        resource_position: hotel_position,
-                        resource_entity: Entity::PLACEHOLDER, // TODO: Get actual hotel entity
-                        resource_type: ResourceType::Rest,
-                        discovery_distance: npc_position.distance(hotel_position),
-                    });
-                }
-            }
-        }
+    for (entity, npc_transform, mut memory, perception) in npc_query.iter_mut() {
+        let npc_position = npc_transform.translation.truncate();
+        let discovery_radius = memory.discovery_radius * perception.map_or(1.0, Perception::range_multiplier);
 
-        // Discover safe zones within range
-        for safe_zone_transform in safe_zone_query.iter() {
-            let safe_zone_position = safe_zone_transform.translation.truncate();
-            if npc_position.distance(safe_zone_position) <= memory.discovery_radius {
-                if !memory.known_safe_zones.contains(&safe_zone_position) {
-                    memory.known_safe_zones.push(safe_zone_position);
-                    discovery_events.write(ResourceDiscoveredEvent {
-                        npc_entity: entity,
-                        resource_position: safe_zone_position,
-                        resource_entity: Entity::PLACEHOLDER, // TODO: Get actual safe zone entity
-                        resource_type: ResourceType::Safety,
-                        discovery_distance: npc_position.distance(safe_zone_position),
-                    });
-                }
+        for resource in index.query_radius(npc_position, discovery_radius) {
+            if remember_resource_location(&mut memory, resource.resource_type, resource.position, current_time) {
+                discovery_events.write(ResourceDiscoveredEvent {
+                    npc_entity: entity,
+                    resource_position: resource.position,
+                    resource_entity: resource.entity,
+                    resource_type: resource.resource_type,
+                    discovery_distance: npc_position.distance(resource.position),
+                });
             }
         }
     }
@@ -99,15 +96,35 @@ pub fn resource_discovery_system(
 
 /// System for setting pathfinding targets based on NPCs' desires and known resources
 /// Based on Goal-Oriented Action Planning - agents plan paths to satisfy needs
+/// Resources come from ResourceMemory and other agents from the WorldModel, so nothing the agent has not
+/// perceived, discovered or been told about can become a target
+/// The arrival threshold follows the target's size: the indexed resource's radius, or another agent's body
+/// Resources listed in AvoidedTargets are skipped until their cooldown has passed
+/// A resource with a ResourceReservation must grant a slot before it becomes the PathTarget;
+/// a full one sends ReservationDenied and the agent falls back to its next known resource
 pub fn desire_pathfinding_system(
-    mut npc_query: Query<(Entity, &Transform, &Desire, &ResourceMemory, &mut PathTarget), With<Npc>>,
-    other_npcs_query: Query<(Entity, &Transform), (With<Npc>, Without<PathTarget>)>,
+    mut npc_query: Query<(
+        Entity,
+        &Transform,
+        &Desire,
+        &ResourceMemory,
+        &mut PathTarget,
+        Option<(&CognitiveMap, &PathLearning, &AdaptiveBehavior)>,
+        Option<&AvoidedTargets>,
+        Option<&BodySize>,
+        Option<&WorldModel>,
+    ), With<Npc>>,
+    mut reservation_query: Query<&mut ResourceReservation>,
     mut target_events: EventWriter<PathTargetSetEvent>,
+    mut denied_events: EventWriter<ReservationDenied>,
+    mut simulation_rng: ResMut<SimulationRng>,
+    index: Res<ResourceSpatialIndex>,
+    game_constants: Res<GameConstants>,
     time: Res<Time>,
 ) {
     let current_time = time.elapsed_secs();
 
-    for (entity, transform, desire, memory, mut path_target) in npc_query.iter_mut() {
+    for (entity, transform, desire, memory, mut path_target, learned_paths, avoided_targets, body, world_model) in npc_query.iter_mut() {
         let npc_position = transform.translation.truncate();
 
         // Skip if already has a valid target
@@ -115,33 +132,90 @@ pub fn desire_pathfinding_system(
             continue;
         }
 
+        // Agents that learn path values exploit them, everyone else heads for the nearest resource
+        // Candidates whose reservation is full are dropped and the selection runs again
+        let mut select_resource = |known: &[Vec2]| -> Option<(Vec2, Option<Entity>)> {
+            let mut candidates: Vec<Vec2> = known
+                .iter()
+                .copied()
+                .filter(|&position| !avoided_targets.is_some_and(|avoided| avoided.is_avoided(position, current_time)))
+                .collect();
+
+            while !candidates.is_empty() {
+                let position = match learned_paths {
+                    Some((map, learning, behavior)) => select_target_epsilon_greedy(
+                        npc_position,
+                        &candidates,
+                        map,
+                        learning,
+                        behavior.exploration_rate,
+                        &mut simulation_rng.rng,
+                    ),
+                    None => find_nearest_resource_position(npc_position, &candidates),
+                }?;
+
+                let resource_entity = index.query_radius(position, 0.0).next().map(|resource| resource.entity);
+                let granted = resource_entity
+                    .and_then(|resource| reservation_query.get_mut(resource).ok())
+                    .is_none_or(|mut reservation| reservation.try_reserve(entity));
+
+                if granted {
+                    return Some((position, resource_entity));
+                }
+
+                if let Some(resource_entity) = resource_entity {
+                    denied_events.write(ReservationDenied {
+                        npc_entity: entity,
+                        resource_entity,
+                        resource_position: position,
+                    });
+                }
+                candidates.retain(|&candidate| candidate != position);
+            }
+            None
+        };
+
         // Find appropriate target based on desire using helper function
-        let target_position = match *desire {
-            Desire::FindWater => find_nearest_resource_position(npc_position, &memory.known_wells),
-            Desire::FindFood => find_nearest_resource_position(npc_position, &memory.known_restaurants),
-            Desire::Rest => find_nearest_resource_position(npc_position, &memory.known_hotels),
-            Desire::FindSafety => find_nearest_resource_position(npc_position, &memory.known_safe_zones),
+        let target = match *desire {
+            Desire::FindWater => select_resource(&memory.known_wells),
+            Desire::FindFood => select_resource(&memory.known_restaurants),
+            Desire::Rest => select_resource(&memory.known_hotels),
+            Desire::FindSafety => select_resource(&memory.known_safe_zones),
+            Desire::Custom(id) => select_resource(memory.known_custom.get(&id).map_or(&[][..], Vec::as_slice)),
             Desire::Socialize => {
-                // Find nearest other NPC for social interaction
-                find_nearest_npc_position(entity, npc_position, &other_npcs_query)
+                // Head for the nearest agent this one has seen recently
+                world_model.and_then(|model| model.nearest_agent(npc_position)).map(|position| (position, None))
             }
             _ => None, // Wander doesn't have specific targets
         };
 
-        if let Some(target_pos) = target_position {
+        if let Some((target_pos, target_entity)) = target {
+            let target_radius = match *desire {
+                Desire::Socialize => game_constants.npc_radius,
+                _ => index
+                    .query_radius(target_pos, 0.0)
+                    .next()
+                    .map_or(game_constants.resource_radius, |resource| resource.radius),
+            };
+
             path_target.target_position = target_pos;
+            path_target.target_entity = target_entity;
+            path_target.origin_position = npc_position;
+            let own_radius = body.map_or(game_constants.npc_radius, |body| body.radius);
+            path_target.arrival_threshold = calculate_arrival_threshold(target_radius, own_radius);
             path_target.has_target = true;
             path_target.target_set_time = current_time;
 
             target_events.write(PathTargetSetEvent {
                 npc_entity: entity,
                 target_position: target_pos,
-                target_entity: None, // TODO: Store actual target entity for social interactions
+                target_entity,
                 target_type: match *desire {
                     Desire::FindWater => ResourceType::Water,
                     Desire::FindFood => ResourceType::Food,
                     Desire::Rest => ResourceType::Rest,
                     Desire::FindSafety => ResourceType::Safety,
+                    Desire::Custom(id) => ResourceType::Custom(id),
                     Desire::Socialize => ResourceType::Water, // TODO: Add Social resource type
                     _ => ResourceType::Water, // Default
                 },
@@ -151,29 +225,95 @@ pub fn desire_pathfinding_system(
     }
 }
 
+/// System freeing reservation slots held by agents that no longer use the resource
+/// A slot is kept while the agent pursues the resource or refills at it, and released on departure,
+/// completion, a pursuit timeout or despawn
+pub fn reservation_release_system(
+    mut reservation_query: Query<(Entity, &mut ResourceReservation)>,
+    holder_query: Query<(&PathTarget, Option<&RefillState>)>,
+    time: Res<Time>,
+) {
+    let current_time = time.elapsed_secs();
+
+    for (resource, mut reservation) in reservation_query.iter_mut() {
+        if reservation.holders.is_empty() {
+            continue;
+        }
+
+        let still_held = |holder: &Entity| {
+            holder_query.get(*holder).is_ok_and(|(path_target, refill_state)| {
+                let refilling_here = refill_state.is_some_and(|refill| refill.is_refilling && refill.resource_entity == Some(resource));
+                let pursuing = path_target.has_target
+                    && path_target.target_entity == Some(resource)
+                    && !should_timeout_pursuit(path_target, current_time);
+                refilling_here || pursuing
+            })
+        };
+
+        if !reservation.holders.iter().all(still_held) {
+            reservation.holders.retain(still_held);
+        }
+    }
+}
+
+/// System keeping each agent's PhysicalContacts in step with Rapier collision events
+/// Sensor overlaps with resources arrive as CollisionEvent too, so this sees every resource an agent touches
+pub fn physical_contact_system(
+    mut collision_events: EventReader<CollisionEvent>,
+    mut contacts_query: Query<&mut PhysicalContacts>,
+) {
+    for collision_event in collision_events.read() {
+        let (first, second, touching) = match collision_event {
+            CollisionEvent::Started(first, second, _flags) => (*first, *second, true),
+            CollisionEvent::Stopped(first, second, _flags) => (*first, *second, false),
+        };
+
+        for (own, other) in [(first, second), (second, first)] {
+            let Ok(mut contacts) = contacts_query.get_mut(own) else {
+                continue;
+            };
+            if touching {
+                contacts.touching.insert(other);
+            } else {
+                contacts.touching.remove(&other);
+            }
+        }
+    }
+}
+
 /// System implementing steering behaviors for autonomous NPC movement
 /// Based on Craig Reynolds' Boids algorithm and steering behaviors
-/// Now respects RefillState to stop movement during resource interactions
+/// Now respects RefillState to stop movement during resource interactions, and stops Incapacitated agents the same way
+/// Wander variation is drawn from SimulationRng, so seeded runs move identically
+/// Agents escaping through StuckEscape coast on their escape impulse, steered only away from the walls
+/// Velocity changes by at most SteeringBehavior::max_acceleration per second, on top of the speed cap
+/// The speed cap is the agent's BodySize::max_speed, or npc_speed for agents without one
+/// Wandering agents with a ForageMemory lean toward the cell they have found the most resources in
+/// Agents with PhysicalContacts also arrive the moment their body touches the target entity, even outside
+/// arrival_threshold; the distance check still covers targets without colliders
 pub fn steering_behavior_system(
-    mut npc_query: Query<(Entity, &Transform, &mut Velocity, &mut SteeringBehavior, &PathTarget, &Desire, &RefillState), With<Npc>>,
+    mut npc_query: Query<(Entity, &Transform, &mut Velocity, &mut SteeringBehavior, &PathTarget, &Desire, &RefillState, Option<&RelaxState>, Option<&PhysicalContacts>, Option<&StuckEscape>, Option<&BodySize>, Option<&ForageMemory>, Has<Incapacitated>), With<Npc>>,
     game_constants: Res<GameConstants>,
     mut reached_events: EventWriter<PathTargetReachedEvent>,
+    mut simulation_rng: ResMut<SimulationRng>,
     time: Res<Time>,
 ) {
     let current_time = time.elapsed_secs();
 
-    for (entity, transform, mut velocity, mut steering, path_target, desire, refill_state) in npc_query.iter_mut() {
+    for (entity, transform, mut velocity, mut steering, path_target, desire, refill_state, relax_state, contacts, stuck_escape, body, forage_memory, incapacitated) in npc_query.iter_mut() {
         let current_position = transform.translation.truncate();
         let current_velocity = velocity.linvel;
+        let max_speed = body.map_or(game_constants.npc_speed, |body| body.max_speed);
 
-        // Stop movement if NPC is refilling
-        if refill_state.is_refilling {
+        // Stop movement if NPC is refilling or has collapsed from a failed need
+        if refill_state.is_refilling || incapacitated {
             velocity.linvel = Vec2::ZERO;
             steering.steering_force = Vec2::ZERO;
             continue;
         }
 
-        if has_reached_target(current_position, path_target) {
+        let touching_target = contacts.is_some_and(|contacts| is_touching_target(path_target, contacts));
+        if touching_target || has_reached_target(current_position, path_target) {
             reached_events.write(PathTargetReachedEvent {
                 npc_entity: entity,
                 target_position: path_target.target_position,
@@ -184,17 +324,25 @@ pub fn steering_behavior_system(
         }
 
         let mut steering_force = Vec2::ZERO;
+        let escaping = stuck_escape.is_some_and(|escape| escape.is_escaping(current_time));
 
-        if path_target.has_target && !should_timeout_pursuit(&path_target, current_time) {
+        if escaping {
+            // Keep the escape impulse, only wall avoidance steers until the escape ends
+        } else if path_target.has_target && !should_timeout_pursuit(path_target, current_time) {
             // Calculate seek force towards target using helper
             let seek_force = calculate_seek_force(
                 current_position,
                 path_target.target_position,
                 current_velocity,
-                game_constants.npc_speed,
+                max_speed,
                 steering.max_steering_force,
             );
             steering_force += seek_force * steering.seek_weight;
+        } else if relax_state.is_some_and(|relax_state| relax_state.is_relaxing) {
+            // Nothing left to satisfy: brake to a stop within max_acceleration instead of wandering
+            steering.steering_force = Vec2::ZERO;
+            velocity.linvel = limit_velocity_change(current_velocity, Vec2::ZERO, steering.max_acceleration, time.delta_secs());
+            continue;
         } else {
             // Store values before mutable borrow to avoid borrow checker issues
             let max_steering_force = steering.max_steering_force;
@@ -203,19 +351,36 @@ pub fn steering_behavior_system(
             // Calculate wander force for exploration using helper
             let wander_force = calculate_wander_force(
                 &mut steering,
+                current_position,
+                game_constants.room_half_extents,
+                game_constants.wander_boundary_margin,
                 current_velocity,
-                game_constants.npc_speed,
+                max_speed,
                 max_steering_force,
                 50.0, // wander_radius
                 100.0, // wander_distance
                 time.delta_secs(),
+                &mut simulation_rng.rng,
             );
             steering_force += wander_force * wander_weight;
+
+            // Learned exploitation: drift back toward patches that paid off before
+            if let Some(memory) = forage_memory {
+                steering_force += calculate_forage_bias(memory, current_position) * max_steering_force * wander_weight;
+            }
         }
 
-        // Apply steering force to velocity
+        // Push away from the walls, weighted by avoidance_weight (raised while escaping)
+        let boundary_bias = calculate_boundary_avoidance_bias(
+            current_position,
+            game_constants.room_half_extents,
+            game_constants.wander_boundary_margin,
+        );
+        steering_force += boundary_bias * steering.max_steering_force * steering.avoidance_weight;
+
+        // Apply steering force to velocity, never changing it faster than max_acceleration allows
         steering.steering_force = steering_force;
-        velocity.linvel += steering_force * time.delta_secs();
-        velocity.linvel = velocity.linvel.clamp_length_max(game_constants.npc_speed);
+        let desired_velocity = (current_velocity + steering_force * time.delta_secs()).clamp_length_max(max_speed);
+        velocity.linvel = limit_velocity_change(current_velocity, desired_velocity, steering.max_acceleration, time.delta_secs());
     }
 }
@@ -0,0 +1,208 @@
+use bevy::ecs::event::{EventCursor, EventReader, EventWriter};
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::TimestepMode;
+use std::collections::VecDeque;
+use std::time::Instant;
+
+use crate::components::components_constants::GameConstants;
+use crate::components::components_npc::Npc;
+use crate::components::components_learning::{CognitiveMap, PathLearning};
+use crate::components::components_performance::{AgentLod, AgentLodConfig, EventBacklog, EventBacklogConfig, FrameBudgetGovernor, FrameTimeMonitor, LearningMemoryConfig, ProfilerConfig, SystemTimings};
+use crate::systems::events::events_performance::PerformanceAlertEvent;
+use crate::utils::helpers::performance_helpers::{build_profiler_report, calculate_lod_stride};
+use crate::utils::helpers::physics_helpers::physics_timestep_mode;
+
+/// System that smooths frame times and raises alerts when the budget is exceeded
+/// Uses hysteresis (recovery below a fraction of the budget) so alerts don't flicker around the limit
+pub fn frame_time_monitor_system(
+    mut monitor: ResMut<FrameTimeMonitor>,
+    mut alert_events: EventWriter<PerformanceAlertEvent>,
+    time: Res<Time>,
+) {
+    let frame_time_ms = time.delta_secs() * 1000.0;
+    let smoothing = monitor.smoothing.clamp(0.0, 1.0);
+    monitor.smoothed_ms = monitor.smoothed_ms + (frame_time_ms - monitor.smoothed_ms) * smoothing;
+
+    let budget_ms = monitor.budget_ms;
+    if !monitor.alert_active && monitor.smoothed_ms > budget_ms {
+        monitor.alert_active = true;
+        alert_events.write(PerformanceAlertEvent::HighFrameTime { frame_time_ms: monitor.smoothed_ms, budget_ms });
+        warn!("Frame time {:.1}ms exceeds budget {:.1}ms, degrading optional systems", monitor.smoothed_ms, budget_ms);
+    } else if monitor.alert_active && monitor.smoothed_ms < budget_ms * monitor.recovery_ratio {
+        monitor.alert_active = false;
+        alert_events.write(PerformanceAlertEvent::FrameTimeRecovered { frame_time_ms: monitor.smoothed_ms, budget_ms });
+        info!("Frame time recovered to {:.1}ms, restoring optional systems", monitor.smoothed_ms);
+    }
+}
+
+/// Event-driven system switching optional work between full and degraded modes
+/// **Single Responsibility:** Only reacts to performance alerts, the systems read the governor themselves
+pub fn frame_budget_governor_system(
+    mut alert_events: EventReader<PerformanceAlertEvent>,
+    mut governor: ResMut<FrameBudgetGovernor>,
+) {
+    for alert in alert_events.read() {
+        match alert {
+            PerformanceAlertEvent::HighFrameTime { .. } => governor.degrade(),
+            PerformanceAlertEvent::FrameTimeRecovered { .. } => governor.restore(),
+            PerformanceAlertEvent::EventBacklog { .. } | PerformanceAlertEvent::HighMemoryUsage { .. } => {}
+        }
+    }
+}
+
+/// Run condition letting perception skip frames while the governor is degraded
+pub fn perception_budget_allows(
+    governor: Option<Res<FrameBudgetGovernor>>,
+    mut frame_counter: Local<u32>,
+) -> bool {
+    let stride = governor.map_or(1, |governor| governor.perception_frame_stride.max(1));
+    *frame_counter = frame_counter.wrapping_add(1);
+    frame_counter.is_multiple_of(stride)
+}
+
+/// System deciding each agent's level of detail from the FrameBudgetGovernor alone
+/// Where an agent stands or who is watching never matters (Equality of Potential), so headless and windowed runs
+/// learn alike; when the stride changes each phase restarts from the entity index, spreading agents over the stride
+pub fn agent_lod_update_system(
+    mut agents_query: Query<(Entity, &mut AgentLod), With<Npc>>,
+    config: Res<AgentLodConfig>,
+    governor: Option<Res<FrameBudgetGovernor>>,
+) {
+    let degraded = governor.is_some_and(|governor| governor.degraded);
+    let stride = calculate_lod_stride(degraded, &config);
+
+    for (entity, mut lod) in agents_query.iter_mut() {
+        if stride != lod.stride {
+            lod.stride = stride;
+            lod.phase = entity.index() % stride;
+        } else {
+            lod.phase = (lod.phase + 1) % stride;
+        }
+    }
+}
+
+/// Diagnostic system catching events of type E that are dropped before their consumer reads them
+/// Piped from the consumer's own run condition, e.g. `cognition_enabled.pipe(event_backlog_system::<NeedChangeEvent>)`,
+/// and scheduled alongside the consumer: each update the consumer is skipped, the newly written events queue up,
+/// and once they are older than EventBacklogConfig::retained_updates they count as dropped and an
+/// EventBacklog alert is raised
+pub fn event_backlog_system<E: Event>(
+    In(consumer_runs): In<bool>,
+    events: Res<Events<E>>,
+    config: Res<EventBacklogConfig>,
+    mut backlog: ResMut<EventBacklog>,
+    mut alert_events: EventWriter<PerformanceAlertEvent>,
+    mut cursor: Local<EventCursor<E>>,
+    mut unread_per_update: Local<VecDeque<usize>>,
+) {
+    let written = cursor.read(&events).count();
+    if !config.enabled || consumer_runs {
+        unread_per_update.clear();
+        return;
+    }
+
+    unread_per_update.push_back(written);
+    let mut dropped = 0;
+    while unread_per_update.len() > config.retained_updates.max(1) {
+        dropped += unread_per_update.pop_front().unwrap_or(0);
+    }
+    if dropped == 0 {
+        return;
+    }
+
+    let event_type = std::any::type_name::<E>().rsplit("::").next().unwrap_or("unknown");
+    *backlog.dropped.entry(event_type.to_string()).or_insert(0) += dropped;
+    warn!("{} {} events were dropped before their consumer read them", dropped, event_type);
+    alert_events.write(PerformanceAlertEvent::EventBacklog { event_type, dropped });
+}
+
+/// System raising a HighMemoryUsage alert when the learned data of all agents together outgrows its budget
+/// Counts cognitive map cells, learned route values and path experiences; like HighFrameTime the alert is raised
+/// once per crossing, and learning_memory_pruning_system answers it by shrinking every agent's share
+pub fn learning_memory_monitor_system(
+    npc_query: Query<(Option<&CognitiveMap>, Option<&PathLearning>), With<Npc>>,
+    config: Res<LearningMemoryConfig>,
+    mut alert_events: EventWriter<PerformanceAlertEvent>,
+    mut alert_active: Local<bool>,
+) {
+    if !config.enabled {
+        return;
+    }
+
+    let learned_entries: usize = npc_query
+        .iter()
+        .map(|(map, learning)| {
+            map.map_or(0, |map| map.activations.len())
+                + learning.map_or(0, |learning| learning.path_values.len() + learning.path_memory.len())
+        })
+        .sum();
+
+    let over_budget = learned_entries > config.memory_alert_entries;
+    if over_budget && !*alert_active {
+        warn!("Learned data of all agents reached {} entries, over the budget of {}", learned_entries, config.memory_alert_entries);
+        alert_events.write(PerformanceAlertEvent::HighMemoryUsage { learned_entries, budget: config.memory_alert_entries });
+    }
+    *alert_active = over_budget;
+}
+
+/// Startup system applying GameConstants::simulation_tick_hz to the FixedUpdate clock
+/// Rapier steps once per tick with GameConstants::physics_substeps substeps, so physics and simulation agree on dt
+/// Runs after validate_game_constants_system so the rate is always positive
+pub fn configure_simulation_tick_rate_system(
+    game_constants: Res<GameConstants>,
+    mut fixed_time: ResMut<Time<Fixed>>,
+    mut timestep_mode: ResMut<TimestepMode>,
+) {
+    fixed_time.set_timestep_hz(game_constants.simulation_tick_hz as f64);
+    *timestep_mode = physics_timestep_mode(&game_constants);
+}
+
+/// System opening a timing span for a tracked system, chain it right before the system
+/// e.g. `(timing_span_start("vision_system"), vision_system, timing_span_end("vision_system")).chain()`
+pub fn timing_span_start(name: &'static str) -> impl FnMut(ResMut<SystemTimings>) {
+    move |mut timings: ResMut<SystemTimings>| {
+        timings.open_spans.insert(name, Instant::now());
+    }
+}
+
+/// System closing the timing span opened by timing_span_start and recording its duration
+pub fn timing_span_end(name: &'static str) -> impl FnMut(ResMut<SystemTimings>, Res<ProfilerConfig>) {
+    move |mut timings: ResMut<SystemTimings>, config: Res<ProfilerConfig>| {
+        if let Some(started) = timings.open_spans.remove(name) {
+            timings.record(name, started.elapsed().as_secs_f32() * 1000.0, config.max_samples);
+        }
+    }
+}
+
+/// System recording every frame time for the profiler report
+pub fn frame_time_sample_system(
+    mut timings: ResMut<SystemTimings>,
+    config: Res<ProfilerConfig>,
+    time: Res<Time>,
+) {
+    timings.record_frame(time.delta_secs() * 1000.0, config.max_samples);
+}
+
+/// System writing the ProfilerReport as JSON to ProfilerConfig::report_path
+/// Runs when the app is exiting and whenever the P key is pressed, so a long run can be checked midway
+pub fn profiler_report_system(
+    mut exit_events: EventReader<AppExit>,
+    keyboard: Option<Res<ButtonInput<KeyCode>>>,
+    timings: Res<SystemTimings>,
+    config: Res<ProfilerConfig>,
+) {
+    let exiting = exit_events.read().count() > 0;
+    let requested = keyboard.is_some_and(|keyboard| keyboard.just_pressed(KeyCode::KeyP));
+    if !exiting && !requested {
+        return;
+    }
+
+    let report = build_profiler_report(&timings, &config);
+    let written = serde_json::to_string_pretty(&report)
+        .map_err(std::io::Error::other)
+        .and_then(|json| std::fs::write(&config.report_path, json));
+    match written {
+        Ok(()) => info!("Profiler report for {} tracked systems written to {}", report.systems.len(), config.report_path),
+        Err(error) => warn!("Failed to write profiler report to {}: {error}", config.report_path),
+    }
+}
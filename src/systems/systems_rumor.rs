@@ -2,20 +2,22 @@ use bevy::ecs::event::{EventReader, EventWriter};
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::CollisionEvent;
 
-use crate::components::components_constants::{GameConstants, RumorTimer};
-use crate::components::components_knowledge::KnowledgeBase;
+use crate::components::components_constants::{GameConstants, RumorTimer, SimulationRng};
+use crate::components::components_knowledge::{KnowledgeBase, RumorRegistry};
 use crate::components::components_npc::{Npc, Personality};
-use crate::systems::events::events_rumor::{RumorInjectionEvent, RumorSpreadAttemptEvent, RumorSpreadEvent};
+use crate::systems::events::events_rumor::{RumorInjectionEvent, RumorMutationEvent, RumorSpreadAttemptEvent, RumorSpreadEvent};
 use crate::utils::helpers::{
-    calculate_rumor_decay, calculate_rumor_transmission_probability,
-    should_inject_rumor, should_rumor_spread,
+    apply_rumor_interference, calculate_rumor_decay, calculate_rumor_transmission_probability,
+    should_inject_rumor, should_rumor_mutate, should_rumor_spread,
 };
 
 /// System that periodically injects new rumors into the simulation
 /// Based on Social Psychology - certain individuals are more prone to starting rumors
+/// New rumors are registered with the registry's default spread parameters
 pub fn rumor_injection_system(
     mut query: Query<(Entity, &Personality, &mut KnowledgeBase), With<Npc>>,
     mut rumor_timer: ResMut<RumorTimer>,
+    mut registry: ResMut<RumorRegistry>,
     time: Res<Time>,
     mut injection_events: EventWriter<RumorInjectionEvent>,
 ) {
@@ -27,6 +29,8 @@ pub fn rumor_injection_system(
             if should_inject_rumor(personality, 0.3) {
                 let rumor_content = format!("Rumor_{}", rand::random::<u32>() % 1000);
                 knowledge.known_rumors.insert(rumor_content.clone(), 1.0);
+                let parameters = registry.default_parameters;
+                registry.register(rumor_content.clone(), parameters);
 
                 injection_events.write(RumorInjectionEvent {
                     entity,
@@ -45,10 +49,12 @@ pub fn rumor_injection_system(
 /// System that detects social interactions and attempts rumor transmission
 /// Based on Social Influence Theory - rumors spread through social contact
 /// **Single Responsibility:** Only handles interaction detection and attempt creation
+/// Every rumor the sender knows and the receiver does not is attempted separately, with its own parameters
 pub fn rumor_interaction_detection_system(
     mut collision_events: EventReader<CollisionEvent>,
     query: Query<(&Personality, &KnowledgeBase), With<Npc>>,
     mut attempt_events: EventWriter<RumorSpreadAttemptEvent>,
+    registry: Res<RumorRegistry>,
     game_constants: Res<GameConstants>,
 ) {
     for collision_event in collision_events.read() {
@@ -61,6 +67,7 @@ pub fn rumor_interaction_detection_system(
                     *entity1, *entity2,
                     personality1, personality2,
                     knowledge1, knowledge2,
+                    &registry,
                     &game_constants,
                     &mut attempt_events,
                 );
@@ -70,6 +77,7 @@ pub fn rumor_interaction_detection_system(
                     *entity2, *entity1,
                     personality2, personality1,
                     knowledge2, knowledge1,
+                    &registry,
                     &game_constants,
                     &mut attempt_events,
                 );
@@ -80,6 +88,7 @@ pub fn rumor_interaction_detection_system(
 
 /// Helper function to process rumor transmission attempts between two NPCs
 /// **Single Responsibility:** Only calculates transmission probability and fires attempt events
+/// Rumors are attempted in ID order, so seeded runs draw transmission rolls in the same order
 fn process_rumor_transmission_attempts(
     sender: Entity,
     receiver: Entity,
@@ -87,16 +96,20 @@ fn process_rumor_transmission_attempts(
     receiver_personality: &Personality,
     sender_knowledge: &KnowledgeBase,
     receiver_knowledge: &KnowledgeBase,
+    registry: &RumorRegistry,
     game_constants: &GameConstants,
     attempt_events: &mut EventWriter<RumorSpreadAttemptEvent>,
 ) {
-    for (rumor_content, &belief_strength) in sender_knowledge.known_rumors.iter() {
+    let mut rumors: Vec<&String> = sender_knowledge.known_rumors.keys().collect();
+    rumors.sort();
+
+    for rumor_content in rumors {
         if !receiver_knowledge.known_rumors.contains_key(rumor_content) {
             let transmission_prob = calculate_rumor_transmission_probability(
                 sender_personality,
                 receiver_personality,
                 game_constants.social_distance,
-                0.5, // base transmission rate
+                registry.parameters(rumor_content).transmission_rate,
             );
 
             attempt_events.write(RumorSpreadAttemptEvent {
@@ -116,34 +129,64 @@ fn process_rumor_transmission_attempts(
 /// Event-driven system that processes rumor transmission attempts
 /// **Single Responsibility:** Only handles the actual rumor spread based on attempts
 /// Much more performant than polling - only runs when attempts are made
+/// With the rumor's modification_probability the receiver hears an altered version, recorded as a new
+/// variant; adopting a version weakens the receiver's belief in competing versions of the same rumor
+/// Spread and mutation rolls come from SimulationRng, so seeded runs produce the same variants
 pub fn rumor_transmission_system(
     mut attempt_events: EventReader<RumorSpreadAttemptEvent>,
     mut query: Query<&mut KnowledgeBase, With<Npc>>,
     mut spread_events: EventWriter<RumorSpreadEvent>,
+    mut mutation_events: EventWriter<RumorMutationEvent>,
+    mut registry: ResMut<RumorRegistry>,
+    mut simulation_rng: ResMut<SimulationRng>,
 ) {
     for attempt in attempt_events.read() {
-        if should_rumor_spread(attempt.transmission_probability) {
-            if let Ok([sender_knowledge, mut receiver_knowledge]) =
-                query.get_many_mut([attempt.sender, attempt.receiver])
-            {
-                if let Some(&belief_strength) = sender_knowledge.known_rumors.get(&attempt.rumor_content) {
-                    let new_belief = belief_strength * 0.8; // Slight decay in transmission
-                    receiver_knowledge.known_rumors.insert(attempt.rumor_content.clone(), new_belief);
-
-                    spread_events.write(RumorSpreadEvent {
-                        sender: attempt.sender,
-                        receiver: attempt.receiver,
-                        spreader_entity: attempt.sender, // Compatibility alias
-                        receiver_entity: attempt.receiver, // Compatibility alias
-                        rumor_content: attempt.rumor_content.clone(),
-                        belief_strength: new_belief,
-                        spreader_openness: 0.5, // Default value - could be retrieved from personality
-                        receiver_openness: 0.5, // Default value - could be retrieved from personality
-                        spread_probability: attempt.transmission_probability,
-                    });
-                }
-            }
+        if !should_rumor_spread(attempt.transmission_probability, &mut simulation_rng.rng) {
+            continue;
         }
+        let Ok([sender_knowledge, mut receiver_knowledge]) = query.get_many_mut([attempt.sender, attempt.receiver]) else {
+            continue;
+        };
+        let Some(&belief_strength) = sender_knowledge.known_rumors.get(&attempt.rumor_content) else {
+            continue;
+        };
+
+        let parameters = registry.parameters(&attempt.rumor_content);
+        let new_belief = belief_strength * parameters.transmission_decay; // Slight decay in transmission
+
+        let received_rumor = if should_rumor_mutate(parameters.modification_probability, &mut simulation_rng.rng) {
+            let variant = registry.register_variant(&attempt.rumor_content);
+            mutation_events.write(RumorMutationEvent {
+                sender: attempt.sender,
+                receiver: attempt.receiver,
+                original_rumor: attempt.rumor_content.clone(),
+                variant_rumor: variant.clone(),
+            });
+            variant
+        } else {
+            attempt.rumor_content.clone()
+        };
+
+        let origin = registry.origin(&received_rumor);
+        apply_rumor_interference(
+            &mut receiver_knowledge.known_rumors,
+            &received_rumor,
+            |other| registry.origin(other) == origin,
+            parameters.interference_strength,
+        );
+        receiver_knowledge.known_rumors.insert(received_rumor.clone(), new_belief);
+
+        spread_events.write(RumorSpreadEvent {
+            sender: attempt.sender,
+            receiver: attempt.receiver,
+            spreader_entity: attempt.sender, // Compatibility alias
+            receiver_entity: attempt.receiver, // Compatibility alias
+            rumor_content: received_rumor,
+            belief_strength: new_belief,
+            spreader_openness: 0.5, // Default value - could be retrieved from personality
+            receiver_openness: 0.5, // Default value - could be retrieved from personality
+            spread_probability: attempt.transmission_probability,
+        });
     }
 }
 
@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+
+// Fixed ordering of one simulation tick
+// Bevy runs systems that do not conflict in whatever order its executor picks; when they share events that
+// order can change which agent acts first, and with it the outcome. Every FixedUpdate AI system belongs to
+// one of these stages and the stages always run in declaration order, so a seeded run replays identically
+
+/// Stages of one FixedUpdate simulation tick, run strictly in this order
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SimulationSet {
+    /// Despawns NPCs and purges references to them before anyone looks them up
+    Lifecycle,
+    /// Perception, need decay and other per-agent state updates - produces NeedChangeEvent
+    StateUpdate,
+    /// The DecisionPipelinePlugin pipeline - consumes need changes, produces DesireChangeEvent
+    Decision,
+    /// Discovery, world model and decision bookkeeping that reads this tick's perceptions and decisions
+    Cognition,
+    /// Target selection, steering and physical movement
+    Movement,
+    /// Refills, failures, social exchanges and resource use triggered by where agents ended up
+    Interaction,
+    /// Resource regeneration, beliefs, learning and population aggregates
+    WorldUpdate,
+}
+
+/// Plugin chaining the SimulationSet stages in FixedUpdate
+/// Added by DecisionPipelinePlugin, so every app running the decision pipeline gets the same ordering
+pub struct SimulationSchedulePlugin;
+
+impl Plugin for SimulationSchedulePlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(FixedUpdate, (
+            SimulationSet::Lifecycle,
+            SimulationSet::StateUpdate,
+            SimulationSet::Decision,
+            SimulationSet::Cognition,
+            SimulationSet::Movement,
+            SimulationSet::Interaction,
+            SimulationSet::WorldUpdate,
+        ).chain());
+    }
+}
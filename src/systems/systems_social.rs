@@ -0,0 +1,599 @@
+use bevy::ecs::event::{EventReader, EventWriter};
+use bevy::prelude::*;
+
+use crate::components::components_environment::{ResourceType, Restaurant, Well};
+use crate::components::components_needs::{BasicNeeds, CurrentDesire, Desire};
+use crate::components::components_npc::{ApparentState, Mood, Npc, Panic, PerceivedEntities, Personality};
+use crate::components::components_pathfinding::{PathTarget, ResourceMemory};
+use crate::components::components_constants::{GameConstants, LogCategory, LogGate, OpinionDynamicsConfig, PanicConfig, SimLogConfig, SocialConfig};
+use crate::components::components_social::{ActiveInteraction, Attitude, GatheringId, InteractionType, SocialEnergy, SocialProximity, SocialInfluence, SocialInteractionState, SocialMetrics, SocialRelationships};
+use crate::components::components_telemetry::SocialHierarchy;
+use crate::sim_log;
+use crate::systems::events::events_needs::{NeedChangeEvent, NeedType, SocialInteractionEvent};
+use crate::systems::events::events_pathfinding::PathTargetSetEvent;
+use crate::systems::events::events_social::{ConflictResolved, GatheringDissolved, GatheringFormed, InteractionCompleted, RelationshipChangedEvent};
+use crate::utils::helpers::pathfinding_helpers::{calculate_arrival_threshold, find_nearest_resource_position, remember_resource_location, share_resource_locations};
+use crate::utils::helpers::telemetry_helpers::build_social_hierarchy;
+use crate::utils::helpers::social_helpers::{
+    apply_bounded_confidence, apply_mood_to_satisfaction, calculate_interaction_satisfaction, calculate_local_network_density, calculate_social_fatigue, calculate_social_influence, choose_help_to_offer,
+    choose_interaction_type, detect_conflict, find_gatherings, find_pairs_within_distance, resolve_conflict, update_relationship_from_interaction,
+};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// Event-driven system letting each completed interaction move the two agents' attitudes
+/// Based on the Deffuant-Weisbuch bounded-confidence model - close opinions converge, distant ones are ignored
+/// or, with a repulsion_rate above zero, drift further apart
+/// **Single Responsibility:** Only updates Attitude, relationships are handled by social_interaction_system
+pub fn opinion_dynamics_system(
+    mut completed_events: EventReader<InteractionCompleted>,
+    mut attitudes_query: Query<&mut Attitude, With<Npc>>,
+    config: Res<OpinionDynamicsConfig>,
+) {
+    for event in completed_events.read() {
+        let Ok([mut attitude_1, mut attitude_2]) = attitudes_query.get_many_mut([event.entity_1, event.entity_2]) else {
+            continue;
+        };
+
+        let (value_1, value_2) = apply_bounded_confidence(attitude_1.value, attitude_2.value, &config);
+        attitude_1.value = value_1;
+        attitude_2.value = value_2;
+    }
+}
+
+/// Event-driven system turning social contacts into relationship changes
+/// Based on Social Exchange Theory (Homans, 1958) - each side evaluates the exchange independently
+/// Contacts between distrustful agents, or agents contesting a scarce resource, escalate to conflict
+/// Both agents' moods colour how satisfying a peaceful exchange is (see apply_mood_to_satisfaction)
+/// **Single Responsibility:** Only updates relationships, needs are handled by social_need_fulfillment_system
+pub fn social_interaction_system(
+    mut social_events: EventReader<SocialInteractionEvent>,
+    agents_query: Query<(&Personality, &BasicNeeds, Option<&CurrentDesire>, Option<&PathTarget>, Option<&Mood>), With<Npc>>,
+    resource_query: Query<AnyOf<(&Well, &Restaurant)>>,
+    mut relationships_query: Query<&mut SocialRelationships>,
+    mut relationship_events: EventWriter<RelationshipChangedEvent>,
+    mut conflict_events: EventWriter<ConflictResolved>,
+    log_config: Res<SimLogConfig>,
+    log_gate: Option<Res<LogGate>>,
+    time: Res<Time>,
+) {
+    for event in social_events.read() {
+        let Ok([(personality_1, needs_1, desire_1, target_1, mood_1), (personality_2, needs_2, desire_2, target_2, mood_2)]) =
+            agents_query.get_many([event.entity_1, event.entity_2])
+        else {
+            continue;
+        };
+
+        let shared_goal = matches!((desire_1, desire_2), (Some(a), Some(b)) if a.desire == b.desire);
+
+        // A resource both agents are heading for, and how much of it is left
+        let contested_capacity = match (target_1.and_then(|t| t.target_entity), target_2.and_then(|t| t.target_entity)) {
+            (Some(a), Some(b)) if a == b && shared_goal => resource_query.get(a).ok().map(|(well, restaurant)| {
+                well.map(|w| w.water_capacity)
+                    .or(restaurant.map(|r| r.food_capacity))
+                    .unwrap_or(1.0)
+            }),
+            _ => None,
+        };
+
+        let relationship_toward = |actor: Entity, other: Entity| {
+            relationships_query.get(actor).ok().and_then(|r| r.relationships.get(&other).copied())
+        };
+        let conflict = detect_conflict(
+            relationship_toward(event.entity_1, event.entity_2).as_ref(),
+            relationship_toward(event.entity_2, event.entity_1).as_ref(),
+            contested_capacity,
+        );
+
+        if let Some(cause) = conflict {
+            let (resolution, satisfaction_1, satisfaction_2) = resolve_conflict(personality_1, personality_2);
+
+            for (actor, partner, satisfaction) in [
+                (event.entity_1, event.entity_2, satisfaction_1),
+                (event.entity_2, event.entity_1, satisfaction_2),
+            ] {
+                let Ok(mut relationships) = relationships_query.get_mut(actor) else {
+                    continue;
+                };
+                let relationship = relationships.relationships.entry(partner).or_default();
+                update_relationship_from_interaction(relationship, InteractionType::Conflict, satisfaction, time.elapsed_secs());
+
+                relationship_events.write(RelationshipChangedEvent {
+                    entity: actor,
+                    other: partner,
+                    interaction_type: InteractionType::Conflict,
+                    affinity: relationship.affinity,
+                    trust: relationship.trust,
+                });
+            }
+
+            conflict_events.write(ConflictResolved {
+                entity_1: event.entity_1,
+                entity_2: event.entity_2,
+                cause,
+                resolution,
+                satisfaction_1,
+                satisfaction_2,
+            });
+            sim_log!(log_gate, log_config, LogCategory::Social, "NPCs {:?} and {:?} clashed over {:?}, resolved by {:?}", event.entity_1, event.entity_2, cause, resolution);
+            continue;
+        }
+
+        // Each direction is evaluated from the actor's perspective
+        let (mood_1, mood_2) = (mood_1.copied().unwrap_or_default(), mood_2.copied().unwrap_or_default());
+        for (actor, partner, actor_personality, partner_personality, partner_needs, actor_mood, partner_mood) in [
+            (event.entity_1, event.entity_2, personality_1, personality_2, needs_2, &mood_1, &mood_2),
+            (event.entity_2, event.entity_1, personality_2, personality_1, needs_1, &mood_2, &mood_1),
+        ] {
+            let Ok(mut relationships) = relationships_query.get_mut(actor) else {
+                continue;
+            };
+
+            let already_acquainted = relationships.relationships.contains_key(&partner);
+            let interaction_type = choose_interaction_type(actor_personality, partner_needs, shared_goal, already_acquainted);
+            let satisfaction = apply_mood_to_satisfaction(calculate_interaction_satisfaction(actor_personality, partner_personality), actor_mood, partner_mood);
+
+            let relationship = relationships.relationships.entry(partner).or_default();
+            update_relationship_from_interaction(relationship, interaction_type, satisfaction, time.elapsed_secs());
+
+            relationship_events.write(RelationshipChangedEvent {
+                entity: actor,
+                other: partner,
+                interaction_type,
+                affinity: relationship.affinity,
+                trust: relationship.trust,
+            });
+        }
+    }
+}
+
+/// System completing social interactions that have run their full duration
+/// Each pair is completed once, from the side of its lowest entity, and both agents become available again
+/// The completion time is remembered per partner so handle_social_interactions can enforce the cooldown
+/// The event carries the exchange's type and each side's satisfaction, as judged when the interaction started
+/// An agent whose partner no longer exists simply drops the interaction
+pub fn social_interaction_lifecycle_system(
+    mut states_query: Query<(Entity, &mut SocialInteractionState), With<Npc>>,
+    mut completed_events: EventWriter<InteractionCompleted>,
+    time: Res<Time>,
+) {
+    let current_time = time.elapsed_secs();
+
+    let mut due: Vec<(Entity, Entity, f32, ActiveInteraction)> = states_query
+        .iter()
+        .filter_map(|(entity, state)| state.active.map(|active| (entity, active)))
+        .filter(|(_, active)| current_time - active.started_at >= active.duration)
+        .map(|(entity, active)| (entity, active.partner, current_time - active.started_at, active))
+        .collect();
+    // Query order is not guaranteed to be stable, completions are reported in entity order instead
+    due.sort_unstable_by_key(|(entity, partner, _, _)| (*entity, *partner));
+
+    for (entity_1, entity_2, duration, active_1) in due {
+        // A live partner with the lower entity completes the pair from its side
+        if entity_2 < entity_1 && states_query.contains(entity_2) {
+            continue;
+        }
+
+        match states_query.get_many_mut([entity_1, entity_2]) {
+            Ok([(_, mut state_1), (_, mut state_2)]) => {
+                let satisfaction_2 = state_2
+                    .active
+                    .filter(|active| active.partner == entity_1)
+                    .map_or(active_1.satisfaction, |active| active.satisfaction);
+                for (state, partner) in [(&mut state_1, entity_2), (&mut state_2, entity_1)] {
+                    state.active = None;
+                    state.completed_interactions += 1;
+                    state.last_completed_with.insert(partner, current_time);
+                }
+                completed_events.write(InteractionCompleted {
+                    entity_1,
+                    entity_2,
+                    duration,
+                    interaction_type: active_1.interaction_type,
+                    satisfaction_1: active_1.satisfaction,
+                    satisfaction_2,
+                });
+            }
+            Err(_) => {
+                if let Ok((_, mut state)) = states_query.get_mut(entity_1) {
+                    state.active = None;
+                }
+            }
+        }
+    }
+}
+
+/// System draining SocialEnergy as interactions complete and restoring it while agents are on their own
+/// The more interactions an agent completed within SocialConfig::fatigue_window, the more each new one costs
+/// and the slower it recovers (see calculate_social_fatigue); handle_social_interactions turns away agents
+/// below min_social_energy, so the most sociable agents cannot monopolize everyone else's time
+pub fn social_energy_system(
+    mut completed_events: EventReader<InteractionCompleted>,
+    mut agents_query: Query<(&mut SocialInteractionState, &mut SocialEnergy), With<Npc>>,
+    social_config: Res<SocialConfig>,
+    time: Res<Time>,
+) {
+    if !social_config.social_fatigue {
+        completed_events.clear();
+        return;
+    }
+
+    let current_time = time.elapsed_secs();
+    let fatigue = |state: &SocialInteractionState| calculate_social_fatigue(state.interaction_history.len(), social_config.fatigue_per_interaction);
+
+    for (mut state, mut energy) in agents_query.iter_mut() {
+        while state.interaction_history.front().is_some_and(|completed_at| current_time - completed_at >= social_config.fatigue_window) {
+            state.interaction_history.pop_front();
+        }
+        if state.is_available() {
+            let recovery = social_config.social_energy_recovery * time.delta_secs() / fatigue(&state);
+            energy.level = (energy.level + recovery).min(1.0);
+        }
+    }
+
+    for event in completed_events.read() {
+        for entity in [event.entity_1, event.entity_2] {
+            if let Ok((mut state, mut energy)) = agents_query.get_mut(entity) {
+                energy.level = (energy.level - social_config.social_energy_cost * fatigue(&state)).max(0.0);
+                state.interaction_history.push_back(current_time);
+            }
+        }
+    }
+}
+
+/// System recomputing every agent's SocialMetrics every SocialConfig::metrics_interval seconds
+/// Density uses the contacts' own relationships, so a link counts if either contact knows the other
+/// ML-HOOK: degree, density and trust are shown in the inspector and available as network features
+pub fn social_metrics_system(
+    relationships_query: Query<&SocialRelationships>,
+    mut metrics_query: Query<(&SocialRelationships, &mut SocialMetrics), With<Npc>>,
+    social_config: Res<SocialConfig>,
+    mut time_since_update: Local<f32>,
+    time: Res<Time>,
+) {
+    *time_since_update += time.delta_secs();
+    if *time_since_update < social_config.metrics_interval {
+        return;
+    }
+    *time_since_update = 0.0;
+
+    let knows = |agent: Entity, other: Entity| {
+        relationships_query.get(agent).is_ok_and(|r| r.relationships.contains_key(&other))
+    };
+
+    for (relationships, mut metrics) in metrics_query.iter_mut() {
+        let contacts: Vec<Entity> = relationships.relationships.keys().copied().collect();
+        let degree = contacts.len();
+
+        metrics.degree = degree;
+        metrics.local_density = calculate_local_network_density(&contacts, knows);
+        metrics.average_trust = if degree > 0 {
+            relationships.relationships.values().map(|r| r.trust).sum::<f32>() / degree as f32
+        } else {
+            0.0
+        };
+    }
+}
+
+/// System detecting emergent leaders from the trust and affinity others direct at each agent
+/// Every SocialConfig::leadership_interval seconds it rescores each agent's SocialInfluence and refreshes
+/// the SocialHierarchy summary; relationships are only read, never changed
+/// ML-HOOK: hierarchy emergence is exposed in the inspector and in telemetry snapshots
+pub fn leadership_detection_system(
+    networks_query: Query<(Entity, &SocialRelationships), With<Npc>>,
+    mut influence_query: Query<&mut SocialInfluence>,
+    mut hierarchy: ResMut<SocialHierarchy>,
+    social_config: Res<SocialConfig>,
+    mut time_since_update: Local<f32>,
+    time: Res<Time>,
+) {
+    *time_since_update += time.delta_secs();
+    if *time_since_update < social_config.leadership_interval {
+        return;
+    }
+    *time_since_update = 0.0;
+
+    let scores = calculate_social_influence(networks_query.iter(), social_config.leadership_ratio);
+    for (agent, score) in &scores {
+        if let Ok(mut influence) = influence_query.get_mut(*agent) {
+            influence.set_if_neq(*score);
+        }
+    }
+
+    *hierarchy = build_social_hierarchy(scores.values());
+}
+
+/// System forming transient gatherings among agents that can see each other
+/// Built from each agent's PerceivedEntities rather than global positions, so only agents that are mutually aware
+/// end up sharing a GatheringId (see find_gatherings); a gathering keeps the lowest id its members already
+/// shared while agents join and leave, and its id is released once fewer than gathering_min_members remain
+/// ML-HOOK: gatherings are the unit later crowd effects such as emotional contagion and conformity act on
+pub fn group_formation_system(
+    mut commands: Commands,
+    agents_query: Query<(Entity, &PerceivedEntities, Option<&GatheringId>), With<Npc>>,
+    social_config: Res<SocialConfig>,
+    mut formed_events: EventWriter<GatheringFormed>,
+    mut dissolved_events: EventWriter<GatheringDissolved>,
+    mut next_id: Local<u32>,
+) {
+    let sight: HashMap<Entity, HashSet<Entity>> = agents_query
+        .iter()
+        .map(|(entity, perceived, _)| (entity, perceived.in_sight.iter().map(|(other, _)| *other).collect()))
+        .collect();
+    let previous: HashMap<Entity, GatheringId> = agents_query.iter().filter_map(|(entity, _, id)| id.map(|id| (entity, *id))).collect();
+    *next_id = previous.values().map(|id| id.0 + 1).fold(*next_id, u32::max);
+
+    let mut assigned: HashMap<Entity, GatheringId> = HashMap::new();
+    let mut active_ids = HashSet::new();
+    for members in find_gatherings(&sight, social_config.gathering_min_members) {
+        let inherited = members
+            .iter()
+            .filter_map(|member| previous.get(member))
+            .filter(|id| !active_ids.contains(*id))
+            .min_by_key(|id| id.0)
+            .copied();
+        let id = inherited.unwrap_or_else(|| {
+            let id = GatheringId(*next_id);
+            *next_id += 1;
+            formed_events.write(GatheringFormed { id: id.0, members: members.clone() });
+            id
+        });
+
+        active_ids.insert(id);
+        assigned.extend(members.into_iter().map(|member| (member, id)));
+    }
+
+    for (entity, id) in &assigned {
+        if previous.get(entity) != Some(id) {
+            commands.entity(*entity).insert(*id);
+        }
+    }
+    for entity in previous.keys().filter(|entity| !assigned.contains_key(*entity)) {
+        commands.entity(*entity).remove::<GatheringId>();
+    }
+
+    let dissolved: BTreeSet<u32> = previous.values().filter(|id| !active_ids.contains(*id)).map(|id| id.0).collect();
+    for id in dissolved {
+        dissolved_events.write(GatheringDissolved { id });
+    }
+}
+
+/// System detecting agents close enough to converse, independent of physical contact
+/// Only runs with SocialConfig::proximity_interactions: pairs coming within interaction_distance are queued in
+/// SocialProximity for handle_social_interactions, found through a uniform grid rather than by testing all pairs
+pub fn social_proximity_system(
+    agents_query: Query<(Entity, &Transform), With<Npc>>,
+    social_config: Res<SocialConfig>,
+    mut proximity: ResMut<SocialProximity>,
+) {
+    if !social_config.proximity_interactions {
+        return;
+    }
+
+    let positions: Vec<(Entity, Vec2)> = agents_query.iter().map(|(entity, transform)| (entity, transform.translation.truncate())).collect();
+    let in_range: HashSet<(Entity, Entity)> = find_pairs_within_distance(&positions, social_config.interaction_distance).into_iter().collect();
+
+    let mut entered: Vec<(Entity, Entity)> = in_range.difference(&proximity.in_range).copied().collect();
+    entered.sort_unstable();
+    proximity.entered.extend(entered);
+    proximity.in_range = in_range;
+}
+
+/// System letting agreeable agents help neighbours in critical need of something they have plenty of
+/// Based on the Empathy-Altruism Hypothesis (Batson, 1991) and Reciprocal Altruism (Trivers, 1971)
+/// Only runs with SocialConfig::helping: a helper within interaction_distance tells the needy agent where the
+/// nearest resource it knows of is, and escorts it there by setting its PathTarget if the needy agent is looking
+/// for that resource but has no target yet. Help that changed something counts as a Helping interaction for both
+/// Each needy agent is helped by at most one neighbour per tick, the first able one in canonical pair order
+pub fn helping_system(
+    mut agents_query: Query<(Entity, &Transform, &Personality, &BasicNeeds, &mut ResourceMemory, &mut PathTarget, Option<&Desire>), With<Npc>>,
+    mut relationships_query: Query<&mut SocialRelationships>,
+    mut target_events: EventWriter<PathTargetSetEvent>,
+    mut relationship_events: EventWriter<RelationshipChangedEvent>,
+    social_config: Res<SocialConfig>,
+    game_constants: Res<GameConstants>,
+    time: Res<Time>,
+) {
+    if !social_config.helping {
+        return;
+    }
+    let current_time = time.elapsed_secs();
+
+    let positions: Vec<(Entity, Vec2)> = agents_query.iter().map(|(entity, transform, ..)| (entity, transform.translation.truncate())).collect();
+    let pairs = find_pairs_within_distance(&positions, social_config.interaction_distance);
+
+    // Decide every act of help from this tick's state before any memory or target changes
+    let mut helped = HashSet::new();
+    let mut offers = Vec::new();
+    for (helper, needy) in pairs.iter().flat_map(|&(first, second)| [(first, second), (second, first)]) {
+        if helped.contains(&needy) {
+            continue;
+        }
+        let Ok([(_, _, helper_personality, helper_needs, helper_memory, ..), (_, needy_transform, _, needy_needs, ..)]) =
+            agents_query.get_many([helper, needy])
+        else {
+            continue;
+        };
+        let Some(resource_type) = choose_help_to_offer(helper_personality, helper_needs, needy_needs, &social_config) else {
+            continue;
+        };
+
+        let known = match resource_type {
+            ResourceType::Water => &helper_memory.known_wells,
+            ResourceType::Food => &helper_memory.known_restaurants,
+            ResourceType::Rest => &helper_memory.known_hotels,
+            _ => &helper_memory.known_safe_zones,
+        };
+        if let Some(position) = find_nearest_resource_position(needy_transform.translation.truncate(), known) {
+            helped.insert(needy);
+            offers.push((helper, needy, resource_type, position));
+        }
+    }
+
+    for (helper, needy, resource_type, position) in offers {
+        let Ok((_, transform, _, _, mut memory, mut path_target, desire)) = agents_query.get_mut(needy) else {
+            continue;
+        };
+
+        let learned = remember_resource_location(&mut memory, resource_type, position, current_time);
+
+        let wanted = match desire {
+            Some(Desire::FindWater) => Some(ResourceType::Water),
+            Some(Desire::FindFood) => Some(ResourceType::Food),
+            Some(Desire::Rest) => Some(ResourceType::Rest),
+            Some(Desire::FindSafety) => Some(ResourceType::Safety),
+            _ => None,
+        };
+        let escorted = wanted == Some(resource_type) && !path_target.has_target;
+        if escorted {
+            let npc_position = transform.translation.truncate();
+            path_target.target_position = position;
+            path_target.target_entity = None;
+            path_target.origin_position = npc_position;
+            path_target.arrival_threshold = calculate_arrival_threshold(game_constants.resource_radius, game_constants.npc_radius);
+            path_target.has_target = true;
+            path_target.target_set_time = current_time;
+
+            target_events.write(PathTargetSetEvent {
+                npc_entity: needy,
+                target_position: position,
+                target_entity: None,
+                target_type: resource_type,
+                distance_to_target: npc_position.distance(position),
+            });
+        }
+
+        if !learned && !escorted {
+            continue;
+        }
+        for (actor, partner) in [(helper, needy), (needy, helper)] {
+            let Ok(mut relationships) = relationships_query.get_mut(actor) else {
+                continue;
+            };
+            let relationship = relationships.relationships.entry(partner).or_default();
+            update_relationship_from_interaction(relationship, InteractionType::Helping, 1.0, current_time);
+
+            relationship_events.write(RelationshipChangedEvent {
+                entity: actor,
+                other: partner,
+                interaction_type: InteractionType::Helping,
+                affinity: relationship.affinity,
+                trust: relationship.trust,
+            });
+        }
+    }
+}
+
+/// Event-driven system letting agents who completed an InformationSharing interaction tell each other where things are
+/// Based on Social Learning Theory (Bandura, 1977) - much of what an agent knows about its world is learned from others
+/// Only runs with SocialConfig::knowledge_exchange. Each agent takes on up to max_shared_locations scaled by its trust
+/// in the partner of the places it did not know, and ignores a partner it trusts less than min_sharing_trust
+/// Both agents share what they knew before the exchange, so nothing learned from the partner is told back to it
+pub fn knowledge_exchange_system(
+    mut completed_events: EventReader<InteractionCompleted>,
+    mut memory_query: Query<&mut ResourceMemory, With<Npc>>,
+    relationships_query: Query<&SocialRelationships>,
+    social_config: Res<SocialConfig>,
+    time: Res<Time>,
+) {
+    if !social_config.knowledge_exchange {
+        completed_events.clear();
+        return;
+    }
+
+    for event in completed_events.read() {
+        if event.interaction_type != InteractionType::InformationSharing {
+            continue;
+        }
+        let Ok([mut memory_1, mut memory_2]) = memory_query.get_many_mut([event.entity_1, event.entity_2]) else {
+            continue;
+        };
+        let (told_by_1, told_by_2) = (memory_1.clone(), memory_2.clone());
+
+        for (receiver, giver, memory, told) in [
+            (event.entity_1, event.entity_2, &mut memory_1, &told_by_2),
+            (event.entity_2, event.entity_1, &mut memory_2, &told_by_1),
+        ] {
+            let trust = relationships_query
+                .get(receiver)
+                .ok()
+                .and_then(|relationships| relationships.relationships.get(&giver))
+                .map_or(0.0, |relationship| relationship.trust);
+            if trust < social_config.min_sharing_trust {
+                continue;
+            }
+
+            let limit = (social_config.max_shared_locations as f32 * trust).ceil() as usize;
+            share_resource_locations(told, memory, limit, time.elapsed_secs());
+        }
+    }
+}
+
+/// System spreading panic from agents in critical danger to the agents who see or hear them
+/// Based on Emotional Contagion (Hatfield et al., 1993) applied to the safety dimension
+/// Only runs with PanicConfig::enabled. An agent whose safety falls below panic_safety_threshold panics at full
+/// intensity; an agent that has it in sight or within hearing_distance catches damping times the alarm it perceives
+/// and loses contagion_strength times that caught alarm from safety, once. Alarm weaker than min_intensity is
+/// shrugged off, so each hop halves (by default) and the cascade dies out a few agents from its source
+/// A caught panic never restarts itself at full intensity, even when the hit pushed safety below the threshold
+/// Alarms are read as they stood at the start of the tick, so a panic travels one hop per tick
+/// The safety drop is a regular NeedChangeEvent, letting the safety fast path trigger FindSafety at once
+pub fn panic_contagion_system(
+    mut agents_query: Query<(Entity, &Transform, &mut BasicNeeds, &mut Panic, &mut ApparentState, Option<&PerceivedEntities>), With<Npc>>,
+    mut need_change_events: EventWriter<NeedChangeEvent>,
+    config: Res<PanicConfig>,
+    time: Res<Time>,
+) {
+    if !config.enabled {
+        return;
+    }
+
+    // Wear off old panics and start new ones before anyone looks at them
+    for (_, _, needs, mut panic, _, _) in agents_query.iter_mut() {
+        panic.remaining = (panic.remaining - time.delta_secs()).max(0.0);
+        if panic.remaining <= 0.0 {
+            panic.intensity = 0.0;
+            panic.caught = false;
+        }
+        if needs.safety < config.panic_safety_threshold && !panic.caught {
+            panic.intensity = 1.0;
+            panic.remaining = config.alarm_duration;
+        }
+    }
+
+    let alarms: Vec<(Entity, Vec2, f32)> = agents_query
+        .iter()
+        .filter(|(_, _, _, panic, _, _)| panic.intensity > 0.0)
+        .map(|(entity, transform, _, panic, _, _)| (entity, transform.translation.truncate(), panic.intensity))
+        .collect();
+
+    for (entity, transform, mut needs, mut panic, mut apparent_state, perceived) in agents_query.iter_mut() {
+        let position = transform.translation.truncate();
+        let perceived_alarm = alarms
+            .iter()
+            .filter(|(source, source_position, _)| {
+                *source != entity
+                    && (position.distance(*source_position) <= config.hearing_distance
+                        || perceived.is_some_and(|perceived| perceived.in_sight.iter().any(|(seen, _)| seen == source)))
+            })
+            .map(|(_, _, intensity)| *intensity)
+            .fold(0.0, f32::max);
+
+        let caught = perceived_alarm * config.damping;
+        if caught >= config.min_intensity && caught > panic.intensity {
+            let old_safety = needs.safety;
+            needs.safety = (needs.safety - caught * config.contagion_strength).max(0.0);
+            panic.intensity = caught;
+            panic.remaining = config.alarm_duration;
+            panic.caught = true;
+            need_change_events.write(NeedChangeEvent {
+                entity,
+                need_type: NeedType::Safety,
+                old_value: old_safety,
+                new_value: needs.safety,
+                change_amount: needs.safety - old_safety,
+            });
+        }
+
+        apparent_state.alarm = panic.intensity;
+    }
+}
@@ -0,0 +1,214 @@
+use bevy::prelude::*;
+
+use crate::components::components_environment::ZoneOccupancy;
+use crate::components::components_needs::{BasicNeeds, CurrentDesire, Desire};
+use crate::components::components_npc::{Npc, Personality};
+use crate::components::components_social::SocialInteractionState;
+use crate::components::components_performance::FrameTimeMonitor;
+use crate::components::components_telemetry::{
+    DesireCensusConfig, DiagnosticOverlay, DiagnosticOverlayText, NeedHistory, NeedHistoryConfig, NeedSample, SocialHierarchy, SocietyWellbeing, TelemetryConfig, TelemetryRecorder, WellbeingConfig,
+};
+use crate::systems::events::events_needs::DesireCensus;
+use crate::utils::helpers::telemetry_helpers::{
+    append_jsonl_lines, build_desire_census, build_society_wellbeing, build_telemetry_snapshot, format_diagnostic_overlay, rank_desire_counts, record_need_sample,
+};
+
+/// System recording an aggregate snapshot of the population every TelemetryConfig::interval_secs
+/// Snapshots are buffered and appended to the JSONL output once flush_batch_size have accumulated;
+/// lines that fail to write stay buffered and are retried with the next batch, up to max_pending_lines
+/// The SocialHierarchy and ZoneOccupancy from their latest updates are carried along when present,
+/// and so is the latest DesireCensus, which replaces the per-snapshot desire_histogram once one has been taken
+/// ML-HOOK: population-level time series for offline analysis of emergent behaviour
+pub fn telemetry_snapshot_system(
+    config: Res<TelemetryConfig>,
+    mut recorder: ResMut<TelemetryRecorder>,
+    agents_query: Query<(&BasicNeeds, Option<&Desire>, Option<&Personality>, Option<&SocialInteractionState>), With<Npc>>,
+    hierarchy: Option<Res<SocialHierarchy>>,
+    zone_occupancy: Option<Res<ZoneOccupancy>>,
+    desire_census: Option<Res<DesireCensus>>,
+    time: Res<Time>,
+) {
+    if !config.enabled {
+        return;
+    }
+
+    let current_time = time.elapsed_secs();
+    let due = recorder
+        .last_snapshot_time
+        .is_none_or(|last| current_time - last >= config.interval_secs);
+    if !due {
+        return;
+    }
+    recorder.last_snapshot_time = Some(current_time);
+
+    let mut snapshot = build_telemetry_snapshot(current_time, agents_query.iter());
+    if let Some(hierarchy) = hierarchy {
+        snapshot.hierarchy = *hierarchy;
+    }
+    if let Some(zone_occupancy) = zone_occupancy {
+        snapshot.zone_occupancy = zone_occupancy.counts.clone();
+    }
+    if let Some(desire_census) = desire_census.filter(|census| census.population > 0) {
+        snapshot.desire_histogram = desire_census
+            .counts
+            .iter()
+            .map(|(desire, count)| (format!("{desire:?}"), *count))
+            .collect();
+    }
+    match serde_json::to_string(&snapshot) {
+        Ok(line) => {
+            recorder.pending_lines.push(line);
+            recorder.snapshots_recorded += 1;
+        }
+        Err(error) => warn!("Failed to serialize telemetry snapshot: {}", error),
+    }
+
+    if recorder.pending_lines.len() >= config.flush_batch_size.max(1) {
+        flush_pending_telemetry(&config, &mut recorder);
+    }
+
+    let overflow = recorder.pending_lines.len().saturating_sub(config.max_pending_lines);
+    if overflow > 0 {
+        recorder.pending_lines.drain(..overflow);
+        recorder.snapshots_dropped += overflow;
+    }
+}
+
+/// System writing out the buffered telemetry snapshots when the app exits
+/// Runs in Last like the profiler report, so snapshots short of a full batch are not lost on shutdown
+pub fn telemetry_flush_on_exit_system(
+    mut exit_events: EventReader<AppExit>,
+    config: Res<TelemetryConfig>,
+    mut recorder: ResMut<TelemetryRecorder>,
+) {
+    if exit_events.read().count() == 0 || recorder.pending_lines.is_empty() {
+        return;
+    }
+    flush_pending_telemetry(&config, &mut recorder);
+}
+
+fn flush_pending_telemetry(config: &TelemetryConfig, recorder: &mut TelemetryRecorder) {
+    match append_jsonl_lines(&config.output_path, &recorder.pending_lines) {
+        Ok(()) => recorder.pending_lines.clear(),
+        Err(error) => warn!("Failed to write telemetry to {}: {}", config.output_path, error),
+    }
+}
+
+/// System recomputing SocietyWellbeing every WellbeingConfig::interval_secs
+/// The resource is shown in the inspector; telemetry snapshots carry the same aggregate computed at snapshot time
+pub fn society_wellbeing_system(
+    needs_query: Query<&BasicNeeds, With<Npc>>,
+    config: Res<WellbeingConfig>,
+    mut wellbeing: ResMut<SocietyWellbeing>,
+    mut time_since_update: Local<f32>,
+    time: Res<Time>,
+) {
+    *time_since_update += time.delta_secs();
+    if *time_since_update < config.interval_secs {
+        return;
+    }
+    *time_since_update = 0.0;
+
+    *wellbeing = build_society_wellbeing(needs_query.iter());
+}
+
+/// System counting how many agents hold each CurrentDesire every DesireCensusConfig::interval_secs
+/// Fires a DesireCensus event and keeps the latest census as a resource for telemetry and dashboards;
+/// counting once per interval is far cheaper than dashboards inspecting every agent every frame
+pub fn society_desire_census_system(
+    desires_query: Query<&CurrentDesire, With<Npc>>,
+    config: Res<DesireCensusConfig>,
+    mut latest_census: ResMut<DesireCensus>,
+    mut census_events: EventWriter<DesireCensus>,
+    mut time_since_census: Local<f32>,
+    time: Res<Time>,
+) {
+    *time_since_census += time.delta_secs();
+    if *time_since_census < config.interval_secs {
+        return;
+    }
+    *time_since_census = 0.0;
+
+    let census = build_desire_census(time.elapsed_secs(), desires_query.iter().map(|current| &current.desire));
+    census_events.write(census.clone());
+    *latest_census = census;
+}
+
+/// System appending every agent's current needs to its NeedHistory every NeedHistoryConfig::interval_secs
+/// Runs separately from the per-frame decay, so keeping a history costs one push per agent per interval
+pub fn need_history_sampling_system(
+    mut history_query: Query<(&BasicNeeds, &mut NeedHistory), With<Npc>>,
+    config: Res<NeedHistoryConfig>,
+    mut time_since_sample: Local<f32>,
+    time: Res<Time>,
+) {
+    *time_since_sample += time.delta_secs();
+    if *time_since_sample < config.interval_secs {
+        return;
+    }
+    *time_since_sample = 0.0;
+
+    let now = time.elapsed_secs();
+    for (needs, mut history) in history_query.iter_mut() {
+        record_need_sample(&mut history, NeedSample { time: now, needs: *needs }, config.capacity);
+    }
+}
+
+/// Startup system spawning the hidden text panel of the diagnostic overlay in the top-left corner
+pub fn spawn_diagnostic_overlay_system(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Diagnostic Overlay"),
+        Text::new(""),
+        TextFont { font_size: 14.0, ..default() },
+        Node { position_type: PositionType::Absolute, top: Val::Px(8.0), left: Val::Px(8.0), ..default() },
+        Visibility::Hidden,
+        DiagnosticOverlayText,
+    ));
+}
+
+/// System toggling the diagnostic overlay with the F3 key
+pub fn diagnostic_overlay_toggle_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut overlay: ResMut<DiagnosticOverlay>,
+) {
+    if keyboard.just_pressed(KeyCode::F3) {
+        overlay.enabled = !overlay.enabled;
+    }
+}
+
+/// System copying the latest global figures into DiagnosticOverlay
+/// Subscribes to DesireCensus events and SocietyWellbeing changes instead of counting agents itself, and only
+/// touches the overlay when something new arrived, so the panel costs nothing between updates while hidden
+pub fn diagnostic_overlay_update_system(
+    mut overlay: ResMut<DiagnosticOverlay>,
+    mut census_events: EventReader<DesireCensus>,
+    wellbeing: Option<Res<SocietyWellbeing>>,
+    frame_monitor: Option<Res<FrameTimeMonitor>>,
+) {
+    if let Some(census) = census_events.read().last() {
+        overlay.population = census.population;
+        overlay.desire_counts = rank_desire_counts(census);
+    }
+    if let Some(wellbeing) = wellbeing.filter(|wellbeing| wellbeing.is_changed()) {
+        overlay.wellbeing = *wellbeing;
+    }
+    if overlay.enabled && let Some(frame_monitor) = frame_monitor {
+        overlay.fps = 1000.0 / frame_monitor.smoothed_ms.max(f32::EPSILON);
+    }
+}
+
+/// System writing DiagnosticOverlay into its text panel and showing or hiding it
+/// Only needs to run when the overlay resource changed
+pub fn diagnostic_overlay_render_system(
+    overlay: Res<DiagnosticOverlay>,
+    mut text_query: Query<(&mut Text, &mut Visibility), With<DiagnosticOverlayText>>,
+) {
+    for (mut text, mut visibility) in text_query.iter_mut() {
+        if overlay.enabled {
+            text.0 = format_diagnostic_overlay(&overlay);
+            *visibility = Visibility::Visible;
+        } else {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use bevy::ecs::event::{EventReader, EventWriter};
+use bevy::prelude::*;
+use std::time::SystemTime;
+
+use crate::components::components_constants::{ConstantViolation, ConstantsHotReloadConfig, GameConstants, ScenarioFile, ScenarioLoadError, SimulationRng};
+use crate::components::components_environment::ZoneLayout;
+use crate::components::components_needs::{BasicNeeds, DesireThresholds};
+use crate::systems::events::events_validation::{ConstantsFileChanged, GenericComponentEvent};
+use crate::utils::helpers::constants_helpers::{apply_constant_overrides, load_scenario};
+use crate::utils::helpers::needs_helpers::{sanitize_dual_threshold, sanitize_need_value};
+
+/// Startup system applying the scenario file named by ScenarioFile, before the constants are validated
+/// A scenario that cannot be read, parsed or validated is reported and the defaults are kept in full, never half
+/// applied; having no scenario file at all is the normal case and stays silent
+/// Zones the scenario defines replace the ZoneLayout, keeping its grid cell size
+pub fn load_scenario_system(
+    scenario_file: Res<ScenarioFile>,
+    mut game_constants: ResMut<GameConstants>,
+    mut simulation_rng: ResMut<SimulationRng>,
+    zone_layout: Option<ResMut<ZoneLayout>>,
+) {
+    match load_scenario(&scenario_file.path) {
+        Ok(scenario) => {
+            *game_constants = scenario.game_constants;
+            if let Some(seed) = scenario.seed {
+                *simulation_rng = SimulationRng::from_seed(seed);
+            }
+            if let (Some(zones), Some(mut zone_layout)) = (scenario.zones, zone_layout) {
+                zone_layout.zones = zones;
+            }
+            info!("Loaded scenario {}", scenario_file.path);
+        }
+        Err(ScenarioLoadError::Io(error)) if error.kind() == std::io::ErrorKind::NotFound => {}
+        Err(error) => warn!("Ignoring scenario {}, running with default constants: {error}", scenario_file.path),
+    }
+}
+
+/// Startup system that validates GameConstants before anything is spawned from them
+/// Invalid values are corrected in place so scenario overrides can never put the simulation in a nonsensical state
+pub fn validate_game_constants_system(
+    mut game_constants: ResMut<GameConstants>,
+    mut validation_events: EventWriter<GenericComponentEvent>,
+) {
+    report_constant_violations(game_constants.validate(), &mut validation_events);
+}
+
+/// Logs and reports every GameConstants correction as a ValidationFailed event
+fn report_constant_violations(violations: Vec<ConstantViolation>, validation_events: &mut EventWriter<GenericComponentEvent>) {
+    for violation in violations {
+        warn!(
+            "GameConstants.{} = {} is out of range, corrected to {}",
+            violation.field, violation.original_value, violation.corrected_value
+        );
+
+        validation_events.write(GenericComponentEvent::ValidationFailed {
+            entity: None,
+            component: "GameConstants",
+            field: violation.field,
+            original_value: violation.original_value,
+            corrected_value: violation.corrected_value,
+        });
+    }
+}
+
+/// System polling the constants file named by ConstantsHotReloadConfig for changes
+/// Fires ConstantsFileChanged the first time the file is seen and whenever its modification time changes;
+/// a missing file is not an error, there is simply nothing to tune
+pub fn constants_file_watch_system(
+    config: Res<ConstantsHotReloadConfig>,
+    mut changed_events: EventWriter<ConstantsFileChanged>,
+    mut last_modified: Local<Option<SystemTime>>,
+    mut time_since_poll: Local<f32>,
+    time: Res<Time>,
+) {
+    *time_since_poll += time.delta_secs();
+    if *time_since_poll < config.poll_interval_secs {
+        return;
+    }
+    *time_since_poll = 0.0;
+
+    let Ok(modified) = std::fs::metadata(&config.path).and_then(|metadata| metadata.modified()) else {
+        return;
+    };
+    if *last_modified == Some(modified) {
+        return;
+    }
+    *last_modified = Some(modified);
+
+    match std::fs::read_to_string(&config.path) {
+        Ok(contents) => {
+            changed_events.write(ConstantsFileChanged { path: config.path.clone(), contents });
+        }
+        Err(error) => warn!("Failed to read constants file {}: {error}", config.path),
+    }
+}
+
+/// System applying a changed constants file to GameConstants at runtime
+/// The overrides are applied to a copy and validated like at startup before the resource is replaced,
+/// so a malformed file or nonsensical value never reaches the simulation. Agent state, desires included,
+/// is left untouched and simply sees the new rates from the next fixed tick on; spawn-time constants
+/// (num_npcs, room_half_extents) only affect what is spawned afterwards
+pub fn constants_hot_reload_system(
+    mut changed_events: EventReader<ConstantsFileChanged>,
+    mut game_constants: ResMut<GameConstants>,
+    mut validation_events: EventWriter<GenericComponentEvent>,
+) {
+    // Only the newest version of the file matters
+    let Some(event) = changed_events.read().last() else {
+        return;
+    };
+
+    let overrides = match serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(&event.contents) {
+        Ok(overrides) => overrides,
+        Err(error) => {
+            warn!("Ignoring constants file {}: {error}", event.path);
+            return;
+        }
+    };
+
+    let mut reloaded = game_constants.clone();
+    let ignored = apply_constant_overrides(&mut reloaded, &overrides);
+    if !ignored.is_empty() {
+        warn!("Constants file {} has fields that cannot be tuned live: {}", event.path, ignored.join(", "));
+    }
+    report_constant_violations(reloaded.validate(), &mut validation_events);
+
+    *game_constants = reloaded;
+    info!("Reloaded GameConstants from {} ({} fields)", event.path, overrides.len() - ignored.len());
+}
+
+/// System that validates the dual thresholds of newly spawned agents
+/// Enforces low_threshold <= high_threshold so the hysteresis band is well-formed
+/// Only runs on Added components, so each agent is checked once
+pub fn validate_desire_thresholds_system(
+    mut query: Query<(Entity, &mut DesireThresholds), Added<DesireThresholds>>,
+    mut validation_events: EventWriter<GenericComponentEvent>,
+) {
+    for (entity, mut thresholds) in query.iter_mut() {
+        let thresholds = thresholds.bypass_change_detection();
+        let dual_thresholds = [
+            ("hunger_threshold", &mut thresholds.hunger_threshold),
+            ("thirst_threshold", &mut thresholds.thirst_threshold),
+            ("rest_threshold", &mut thresholds.rest_threshold),
+            ("safety_threshold", &mut thresholds.safety_threshold),
+            ("social_threshold", &mut thresholds.social_threshold),
+        ];
+
+        for (field, dual_threshold) in dual_thresholds {
+            let original_low = dual_threshold.low_threshold;
+            if sanitize_dual_threshold(dual_threshold) {
+                warn!(
+                    "NPC {:?} had an invalid {} (low {:.2}), corrected to low {:.2} / high {:.2}",
+                    entity, field, original_low, dual_threshold.low_threshold, dual_threshold.high_threshold
+                );
+
+                validation_events.write(GenericComponentEvent::ValidationFailed {
+                    entity: Some(entity),
+                    component: "DesireThresholds",
+                    field,
+                    original_value: original_low,
+                    corrected_value: dual_threshold.low_threshold,
+                });
+            }
+        }
+    }
+}
+
+/// System that validates need values whenever they are written
+/// Every need must stay within 0.0-1.0; anything else (including NaN) is clamped and reported,
+/// so a corrupted value shows up in the integrity monitoring instead of skewing decisions silently
+pub fn validate_basic_needs_system(
+    mut query: Query<(Entity, &mut BasicNeeds), Changed<BasicNeeds>>,
+    mut validation_events: EventWriter<GenericComponentEvent>,
+) {
+    for (entity, mut needs) in query.iter_mut() {
+        let needs = needs.bypass_change_detection();
+        let values = [
+            ("hunger", &mut needs.hunger),
+            ("thirst", &mut needs.thirst),
+            ("rest", &mut needs.rest),
+            ("safety", &mut needs.safety),
+            ("social", &mut needs.social),
+        ];
+
+        for (field, value) in values {
+            let original_value = *value;
+            if sanitize_need_value(value) {
+                warn!("NPC {:?} had an out-of-range {} need ({}), corrected to {:.2}", entity, field, original_value, *value);
+
+                validation_events.write(GenericComponentEvent::ValidationFailed {
+                    entity: Some(entity),
+                    component: "BasicNeeds",
+                    field,
+                    original_value,
+                    corrected_value: *value,
+                });
+            }
+        }
+    }
+}
@@ -1,8 +1,21 @@
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 
+use crate::components::components_camera::SelectedNpc;
+use crate::components::components_environment::{Weather, WeatherEffects};
 use crate::components::components_knowledge::KnowledgeBase;
-use crate::components::components_npc::{ApparentState, Npc, PerceivedEntities, Posture, RefillState, VisionRange};
+use crate::components::components_needs::{BasicNeeds, Desire};
+use crate::components::components_npc::{
+    AgentTint, ApparentState, ModeledAgent, Npc, PerceivedEntities, Perception, Personality, Posture, Proprioception, RefillState,
+    VisionConeOverlay, VisionRange, WorldModel,
+};
+use crate::components::components_social::SocialInfluence;
+use crate::components::components_telemetry::{NeedHistory, NeedHistoryConfig};
+use crate::utils::helpers::color_helpers::{agent_tint_color, desire_color};
+use crate::utils::helpers::telemetry_helpers::need_history_plot_points;
+use crate::utils::helpers::movement_helpers::update_proprioception;
+use crate::utils::helpers::vision_helpers::{calculate_vision_cone_points, is_facing};
+use crate::utils::helpers::weather_helpers::calculate_light_vision_multiplier;
 
 /// System for updating NPC sprites based on rumor knowledge
 /// System based on Visual Information Theory - visual cues affect social perception
@@ -23,6 +36,30 @@ pub fn color_system(
     }
 }
 
+/// System tinting NPC sprites by the state AgentTint selects
+/// Multiplies the sprite image, so the rumor sprite swap from color_system stays visible underneath
+pub fn agent_tint_system(
+    mut query: Query<(&mut Sprite, &Desire, &BasicNeeds, &Personality, Option<&SocialInfluence>), With<Npc>>,
+    tint: Res<AgentTint>,
+) {
+    for (mut sprite, desire, needs, personality, influence) in query.iter_mut() {
+        sprite.color = agent_tint_color(&tint, *desire, needs, personality, influence);
+    }
+}
+
+/// System switching what the sprite tint shows: C cycles the visualization mode, T the personality trait
+pub fn agent_tint_toggle_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut tint: ResMut<AgentTint>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyC) {
+        tint.mode = tint.mode.next();
+    }
+    if keyboard.just_pressed(KeyCode::KeyT) {
+        tint.personality_trait = tint.personality_trait.next();
+    }
+}
+
 /// PERCEPTION SYSTEM: Updates agents' apparent state based on their internal state
 /// This system translates internal components into externally observable information
 /// Based on Theory of Mind - what others can observe about an agent's behavior
@@ -67,16 +104,28 @@ pub fn update_apparent_state_system(
 /// Based on Human Visual Perception research and Cognitive Psychology
 /// CRITICAL: Follows "Mantle of Ignorance" - agents only see apparent state, never internal state
 /// NOTE: Simplified version without line-of-sight for initial implementation
+/// Low Weather light shrinks the effective range, bounded by WeatherEffects::min_vision_fraction
+/// An agent's Perception scales its range before the light does
 pub fn vision_system(
     mut observer_query: Query<(
         Entity,
         &Transform,
         &mut PerceivedEntities,
         &VisionRange,
+        Option<&Perception>,
     ), With<Npc>>,
     world_query: Query<(Entity, &Transform, &ApparentState), With<Npc>>,
+    weather: Option<Res<Weather>>,
+    weather_effects: Option<Res<WeatherEffects>>,
 ) {
-    for (observer_entity, observer_transform, mut perception, vision_range) in observer_query.iter_mut() {
+    let light_multiplier = match (weather, weather_effects) {
+        (Some(weather), Some(effects)) => calculate_light_vision_multiplier(weather.light_level, &effects),
+        _ => 1.0,
+    };
+
+    for (observer_entity, observer_transform, mut perception, vision_range, acuity) in observer_query.iter_mut() {
+        let effective_range = vision_range.max_distance * acuity.map_or(1.0, Perception::range_multiplier) * light_multiplier;
+
         // Clear previous perception data
         perception.in_sight.clear();
 
@@ -94,17 +143,12 @@ pub fn vision_system(
             let distance = observer_pos.distance(other_pos);
 
             // Check if within vision range
-            if distance > vision_range.max_distance {
+            if distance > effective_range {
                 continue;
             }
 
             // Check field of view
-            let to_target = (other_pos - observer_pos).normalize();
-            let observer_forward = observer_transform.rotation * Vec3::Y; // Assuming Y-up forward
-            let observer_forward_2d = observer_forward.truncate().normalize();
-
-            let angle_to_target = to_target.dot(observer_forward_2d).acos();
-            if angle_to_target > vision_range.field_of_view / 2.0 {
+            if !is_facing(observer_transform, vision_range, other_pos) {
                 continue;
             }
 
@@ -130,3 +174,121 @@ pub fn vision_system(
         // Number of perceived entities and their states can be used as observation space
     }
 }
+
+/// PERCEPTION SYSTEM: Folds this tick's perception into each agent's WorldModel
+/// Agents in PerceivedEntities are recorded at their current position; agents unseen for longer than
+/// agent_memory_secs are forgotten
+/// CRITICAL: Positions are only looked up for entities the agent actually perceives
+pub fn world_model_update_system(
+    mut model_query: Query<(&mut WorldModel, Option<&PerceivedEntities>), With<Npc>>,
+    agents_query: Query<&Transform, With<Npc>>,
+    time: Res<Time>,
+) {
+    let current_time = time.elapsed_secs();
+
+    for (mut model, perception) in model_query.iter_mut() {
+        for (seen, _) in perception.map_or(&[][..], |perception| &perception.in_sight[..]) {
+            if let Ok(transform) = agents_query.get(*seen) {
+                model.agents.insert(*seen, ModeledAgent {
+                    position: transform.translation.truncate(),
+                    last_seen: current_time,
+                });
+            }
+        }
+
+        let memory_secs = model.agent_memory_secs;
+        model.agents.retain(|_, agent| current_time - agent.last_seen <= memory_secs);
+    }
+}
+
+/// PERCEPTION SYSTEM: Updates each agent's Proprioception from how far its Transform moved since the last tick
+/// Self-motion is sensed from position changes alone, independent of the physics Velocity, so agents can
+/// path-integrate their displacement when no landmark is visible
+pub fn proprioception_system(
+    mut query: Query<(&Transform, &mut Proprioception), With<Npc>>,
+    time: Res<Time>,
+) {
+    for (transform, mut proprioception) in query.iter_mut() {
+        update_proprioception(&mut proprioception, transform.translation.truncate(), time.delta_secs());
+    }
+}
+
+/// System toggling the vision cone overlay with the V key
+pub fn vision_cone_toggle_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut overlay: ResMut<VisionConeOverlay>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyV) {
+        overlay.enabled = !overlay.enabled;
+    }
+}
+
+/// Run condition keeping the vision cone overlay free while it is disabled
+pub fn vision_cone_overlay_enabled(overlay: Res<VisionConeOverlay>) -> bool {
+    overlay.enabled
+}
+
+/// System drawing each agent's vision cone as a gizmo outline
+/// Uses the same facing, field of view and light-scaled range as vision_system, so the cone shows
+/// exactly the area an agent perceives; agents currently seeing someone are drawn in seeing_color
+pub fn vision_cone_gizmo_system(
+    mut gizmos: Gizmos,
+    overlay: Res<VisionConeOverlay>,
+    selected: Res<SelectedNpc>,
+    observer_query: Query<(Entity, &Transform, &VisionRange, &PerceivedEntities, Option<&Perception>), With<Npc>>,
+    weather: Option<Res<Weather>>,
+    weather_effects: Option<Res<WeatherEffects>>,
+) {
+    let light_multiplier = match (weather, weather_effects) {
+        (Some(weather), Some(effects)) => calculate_light_vision_multiplier(weather.light_level, &effects),
+        _ => 1.0,
+    };
+
+    for (entity, transform, vision_range, perception, acuity) in observer_query.iter() {
+        if overlay.selected_only && selected.0 != Some(entity) {
+            continue;
+        }
+
+        let forward = (transform.rotation * Vec3::Y).truncate();
+        let points = calculate_vision_cone_points(
+            transform.translation.truncate(),
+            forward,
+            vision_range.field_of_view,
+            vision_range.max_distance * acuity.map_or(1.0, Perception::range_multiplier) * light_multiplier,
+            overlay.arc_segments,
+        );
+        let color = if perception.in_sight.is_empty() { overlay.idle_color } else { overlay.seeing_color };
+        gizmos.linestrip_2d(points, color);
+    }
+}
+
+/// System plotting the selected NPC's NeedHistory above it, one line per need
+/// Each need is drawn in the color of the desire it drives, over a faint frame marking 0.0 and 1.0
+pub fn need_history_plot_system(
+    mut gizmos: Gizmos,
+    selected: Res<SelectedNpc>,
+    history_query: Query<(&Transform, &NeedHistory), With<Npc>>,
+    config: Res<NeedHistoryConfig>,
+) {
+    let Some((transform, history)) = selected.0.and_then(|entity| history_query.get(entity).ok()) else {
+        return;
+    };
+    if history.samples.len() < 2 {
+        return;
+    }
+
+    let size = config.plot_size;
+    let origin = transform.translation.truncate() + Vec2::new(-size.x / 2.0, size.y / 2.0);
+    gizmos.rect_2d(Isometry2d::from_translation(origin + size / 2.0), size, Color::srgba(1.0, 1.0, 1.0, 0.2));
+
+    let lines: [(fn(&BasicNeeds) -> f32, Desire); 5] = [
+        (|needs| needs.hunger, Desire::FindFood),
+        (|needs| needs.thirst, Desire::FindWater),
+        (|needs| needs.rest, Desire::Rest),
+        (|needs| needs.safety, Desire::FindSafety),
+        (|needs| needs.social, Desire::Socialize),
+    ];
+    for (need, desire) in lines {
+        gizmos.linestrip_2d(need_history_plot_points(history, need, origin, size), desire_color(desire));
+    }
+}
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use bevy::render::camera::ScalingMode;
+
+/// Helper function choosing a projection scaling that keeps the whole room in view
+/// At zoom 1.0 the visible area is always at least the room plus `margin` on every side, whatever the window's
+/// size or aspect ratio; the spare space goes to whichever axis the window has more of
+pub fn calculate_world_fit_scaling(room_half_extents: Vec2, margin: f32) -> ScalingMode {
+    let visible = (room_half_extents + Vec2::splat(margin.max(0.0))) * 2.0;
+    ScalingMode::AutoMin { min_width: visible.x, min_height: visible.y }
+}
+
+/// Helper function moving the camera toward a followed agent
+/// Exponential smoothing (1 - e^(-k*dt)) makes the catch-up independent of the frame rate
+pub fn calculate_follow_position(camera_position: Vec2, target_position: Vec2, smoothing: f32, delta_time: f32) -> Vec2 {
+    let blend = 1.0 - (-smoothing.max(0.0) * delta_time.max(0.0)).exp();
+    camera_position.lerp(target_position, blend)
+}
+
+/// Helper function applying scroll-wheel input to the zoom level
+/// Scrolling up zooms in; each line changes the scale by the same fraction, clamped to the allowed range
+pub fn calculate_zoom(current_zoom: f32, scroll_lines: f32, zoom_speed: f32, min_zoom: f32, max_zoom: f32) -> f32 {
+    let zoomed = current_zoom * (1.0 - zoom_speed).powf(scroll_lines);
+    zoomed.clamp(min_zoom, max_zoom.max(min_zoom))
+}
+
+/// Helper function finding the NPC closest to a world position within a picking radius
+pub fn pick_nearest_agent(
+    world_position: Vec2,
+    agents: impl IntoIterator<Item = (Entity, Vec2)>,
+    pick_radius: f32,
+) -> Option<Entity> {
+    agents
+        .into_iter()
+        .map(|(entity, position)| (entity, position.distance_squared(world_position)))
+        .filter(|(_, distance_squared)| *distance_squared <= pick_radius * pick_radius)
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(entity, _)| entity)
+}
@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+
+use crate::components::components_needs::{BasicNeeds, Desire};
+use crate::components::components_npc::{AgentTint, Personality, VisualizationMode};
+use crate::components::components_social::SocialInfluence;
+use crate::utils::helpers::needs_helpers::calculate_overall_satisfaction;
+
+/// Helper function picking an NPC's sprite tint for the active visualization mode
+/// The single place that turns agent state into a display color, so every mode stays consistent
+pub fn agent_tint_color(tint: &AgentTint, desire: Desire, needs: &BasicNeeds, personality: &Personality, influence: Option<&SocialInfluence>) -> Color {
+    match tint.mode {
+        VisualizationMode::ByDesire => desire_color(desire),
+        VisualizationMode::ByNeed => need_color(calculate_overall_satisfaction(needs)),
+        VisualizationMode::ByPersonality => personality_color(personality.trait_value(tint.personality_trait)),
+        VisualizationMode::ByReputation => reputation_color(influence.map_or(0.0, |influence| influence.score)),
+    }
+}
+
+/// Helper function giving each desire a distinct, readable color
+/// Custom desires share one color, since the registry can grow without bound
+pub fn desire_color(desire: Desire) -> Color {
+    match desire {
+        Desire::Wander => Color::srgb(0.85, 0.85, 0.85),
+        Desire::FindFood => Color::srgb(1.0, 0.6, 0.2),
+        Desire::FindWater => Color::srgb(0.3, 0.6, 1.0),
+        Desire::FindSafety => Color::srgb(1.0, 0.25, 0.25),
+        Desire::Rest => Color::srgb(0.6, 0.4, 0.9),
+        Desire::Socialize => Color::srgb(1.0, 0.9, 0.3),
+        Desire::Custom(_) => Color::srgb(0.3, 0.9, 0.6),
+    }
+}
+
+/// Helper function blending from red (deprived, 0.0) to green (satisfied, 1.0)
+pub fn need_color(satisfaction: f32) -> Color {
+    lerp_srgb([1.0, 0.2, 0.2], [0.2, 1.0, 0.2], satisfaction)
+}
+
+/// Helper function blending from cool blue (low trait, 0.0) to warm orange (high trait, 1.0)
+pub fn personality_color(trait_value: f32) -> Color {
+    lerp_srgb([0.3, 0.5, 1.0], [1.0, 0.55, 0.2], trait_value)
+}
+
+/// Helper function blending from grey (no standing, 0.0) to gold (high standing, 1.0)
+pub fn reputation_color(score: f32) -> Color {
+    lerp_srgb([0.5, 0.5, 0.5], [1.0, 0.85, 0.2], score)
+}
+
+/// Linear blend between two sRGB colors, with `t` clamped to 0.0-1.0
+fn lerp_srgb(from: [f32; 3], to: [f32; 3], t: f32) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    Color::srgb(
+        from[0] + (to[0] - from[0]) * t,
+        from[1] + (to[1] - from[1]) * t,
+        from[2] + (to[2] - from[2]) * t,
+    )
+}
@@ -0,0 +1,100 @@
+use crate::components::components_constants::{GameConstants, ScenarioConfig, ScenarioLoadError};
+use crate::components::components_environment::Zone;
+use bevy::prelude::Vec2;
+use bevy::reflect::{PartialReflect, Struct};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::path::Path;
+
+/// A zone as written in a scenario file, corners as [x, y] pairs
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ZoneDefinition {
+    name: String,
+    min: [f32; 2],
+    max: [f32; 2],
+}
+
+/// Helper function reading a scenario file (see ScenarioFile)
+pub fn load_scenario(path: impl AsRef<Path>) -> Result<ScenarioConfig, ScenarioLoadError> {
+    parse_scenario(&std::fs::read_to_string(path)?)
+}
+
+/// Helper function building a scenario from the contents of a RON scenario file
+/// The top level is an anonymous struct or a map of field names, so a JSON object of overrides parses as well
+/// Unlike a hot reload, nothing is silently skipped or corrected: an unknown or structured field, a value of the
+/// wrong type (a negative count included) or a constant outside its sane range rejects the whole scenario
+pub fn parse_scenario(contents: &str) -> Result<ScenarioConfig, ScenarioLoadError> {
+    // RON struct fields arrive as identifiers, so the document is read as a RON value before it is turned
+    // into the same field map a hot reload applies
+    let document: ron::Value = ron::from_str(contents)?;
+    let mut overrides: Map<String, Value> = match Value::deserialize(document) {
+        Ok(Value::Object(fields)) => fields,
+        Ok(other) => return Err(ScenarioLoadError::Validation(vec![format!("a scenario must be a struct of fields, got {other}")])),
+        Err(error) => return Err(ScenarioLoadError::Validation(vec![format!("a scenario must be a struct of fields: {error}")])),
+    };
+    let mut problems = Vec::new();
+
+    let seed = overrides.remove("seed").and_then(|seed| {
+        let parsed = seed.as_u64();
+        if parsed.is_none() {
+            problems.push(format!("seed must be a non-negative integer, got {seed}"));
+        }
+        parsed
+    });
+
+    let zones = overrides.remove("zones").and_then(|zones| match serde_json::from_value::<Vec<ZoneDefinition>>(zones) {
+        Ok(definitions) => Some(
+            definitions
+                .into_iter()
+                .map(|zone| Zone::new(zone.name, Vec2::from_array(zone.min), Vec2::from_array(zone.max)))
+                .collect(),
+        ),
+        Err(error) => {
+            problems.push(format!("zones must be a list of (name, min: (x, y), max: (x, y)): {error}"));
+            None
+        }
+    });
+
+    let mut game_constants = GameConstants::default();
+    for name in apply_constant_overrides(&mut game_constants, &overrides) {
+        problems.push(format!("{name} cannot be set to {}", overrides[name.as_str()]));
+    }
+    for violation in game_constants.validate() {
+        problems.push(format!("{} = {} is out of range", violation.field, violation.original_value));
+    }
+
+    if problems.is_empty() {
+        Ok(ScenarioConfig { seed, game_constants, zones })
+    } else {
+        Err(ScenarioLoadError::Validation(problems))
+    }
+}
+
+/// Helper function overwriting GameConstants fields from a JSON object of field names to values
+/// Only scalar fields (numbers and booleans) can be tuned this way; unknown fields, structured fields
+/// such as room_half_extents, and values of the wrong type are left unchanged and returned by name
+pub fn apply_constant_overrides(constants: &mut GameConstants, overrides: &Map<String, Value>) -> Vec<String> {
+    overrides
+        .iter()
+        .filter(|(name, value)| !constants.field_mut(name).is_some_and(|field| set_scalar_field(field, value)))
+        .map(|(name, _)| name.clone())
+        .collect()
+}
+
+/// Writes a JSON value into a reflected scalar field, returning whether the types matched
+fn set_scalar_field(field: &mut dyn PartialReflect, value: &Value) -> bool {
+    if let Some(target) = field.try_downcast_mut::<f32>() {
+        return value.as_f64().map(|number| *target = number as f32).is_some();
+    }
+    if let Some(target) = field.try_downcast_mut::<usize>() {
+        return value.as_u64().and_then(|number| usize::try_from(number).ok()).map(|number| *target = number).is_some();
+    }
+    if let Some(target) = field.try_downcast_mut::<u8>() {
+        return value.as_u64().and_then(|number| u8::try_from(number).ok()).map(|number| *target = number).is_some();
+    }
+    if let Some(target) = field.try_downcast_mut::<bool>() {
+        return value.as_bool().map(|flag| *target = flag).is_some();
+    }
+    false
+}
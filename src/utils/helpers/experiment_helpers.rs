@@ -0,0 +1,47 @@
+use crate::components::components_needs::BasicNeeds;
+use crate::utils::helpers::needs_helpers::calculate_overall_satisfaction;
+use crate::utils::helpers::social_helpers::ordered_entity_pair;
+use bevy::prelude::*;
+use std::collections::BTreeSet;
+
+/// Helper function averaging every need of every agent into one satisfaction value (0.0-1.0)
+/// An empty population yields 0.0, so a run in which everyone died scores as badly as possible
+pub fn calculate_population_satisfaction<'a>(needs: impl IntoIterator<Item = &'a BasicNeeds>) -> f32 {
+    let mut total = 0.0;
+    let mut population = 0;
+
+    for needs in needs {
+        total += calculate_overall_satisfaction(needs);
+        population += 1;
+    }
+
+    if population > 0 { total / population as f32 } else { 0.0 }
+}
+
+/// Helper function finding every pair of agents whose bodies overlap
+/// Pairs are returned in canonical order inside an ordered set, so iterating them is reproducible
+/// Brute force over all pairs - headless experiments run small populations
+pub fn find_touching_pairs(positions: &[(Entity, Vec2)], contact_distance: f32) -> BTreeSet<(Entity, Entity)> {
+    let mut touching = BTreeSet::new();
+
+    for (index, (first, first_position)) in positions.iter().enumerate() {
+        for (second, second_position) in &positions[index + 1..] {
+            if first_position.distance(*second_position) <= contact_distance {
+                touching.insert(ordered_entity_pair(*first, *second));
+            }
+        }
+    }
+
+    touching
+}
+
+/// Helper function locating the first line where a recorded event stream departs from the expected one
+/// A stream that stops early or runs on past the expected one diverges at the shorter stream's length
+/// Returns None when both streams are identical
+pub fn first_event_stream_divergence(expected: &[String], actual: &[String]) -> Option<usize> {
+    expected
+        .iter()
+        .zip(actual)
+        .position(|(expected_line, actual_line)| expected_line != actual_line)
+        .or_else(|| (expected.len() != actual.len()).then(|| expected.len().min(actual.len())))
+}
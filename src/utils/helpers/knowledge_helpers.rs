@@ -0,0 +1,72 @@
+use crate::components::components_knowledge::KnowledgeBase;
+use crate::components::components_pathfinding::ResourceMemory;
+use bevy::prelude::Vec2;
+
+/// Returns true when signed evidence points the same way as the current belief
+/// Undecided beliefs (exactly 0.5) treat all evidence as confirming
+pub fn is_confirming_evidence(certainty: f32, evidence: f32) -> bool {
+    (certainty - 0.5) * evidence >= 0.0
+}
+
+/// Helper function to apply evidence to a certainty value with confirmation bias
+/// Based on Confirmation Bias research (Nickerson, 1998) - contradicting evidence is discounted
+/// by `confirmation_bias_strength` (0.0 = unbiased, 1.0 = contradicting evidence is ignored)
+pub fn apply_belief_evidence(certainty: f32, evidence: f32, confirmation_bias_strength: f32) -> f32 {
+    let weight = if is_confirming_evidence(certainty, evidence) {
+        1.0
+    } else {
+        1.0 - confirmation_bias_strength.clamp(0.0, 1.0)
+    };
+
+    // Move toward the bound the evidence points at, proportionally to the remaining distance
+    let effective = (evidence * weight).clamp(-1.0, 1.0);
+    let new_certainty = if effective >= 0.0 {
+        certainty + (1.0 - certainty) * effective
+    } else {
+        certainty + certainty * effective
+    };
+
+    new_certainty.clamp(0.0, 1.0)
+}
+
+/// Number of items passed on when sharing `fraction` of `available` items
+/// Rounds up so a dying agent always passes on something it alone knew while sharing is enabled
+fn shared_count(available: usize, fraction: f32) -> usize {
+    ((available as f32) * fraction.clamp(0.0, 1.0)).ceil() as usize
+}
+
+/// Appends up to `fraction` of the locations the heir does not know yet, in the order they were learned
+fn inherit_locations(heir: &mut Vec<Vec2>, deceased: &[Vec2], fraction: f32) {
+    let unknown: Vec<Vec2> = deceased.iter().copied().filter(|location| !heir.contains(location)).collect();
+    heir.extend(unknown.into_iter().take(shared_count(deceased.len(), fraction)));
+}
+
+/// Helper function passing part of a dying agent's resource map on to a survivor
+/// Based on Cultural Transmission theory (Boyd & Richerson, 1985) - knowledge outlives the individual
+/// only if it is handed on before death
+pub fn inherit_resource_memory(heir: &mut ResourceMemory, deceased: &ResourceMemory, fraction: f32) {
+    inherit_locations(&mut heir.known_wells, &deceased.known_wells, fraction);
+    inherit_locations(&mut heir.known_restaurants, &deceased.known_restaurants, fraction);
+    inherit_locations(&mut heir.known_hotels, &deceased.known_hotels, fraction);
+    inherit_locations(&mut heir.known_safe_zones, &deceased.known_safe_zones, fraction);
+    for (id, sites) in &deceased.known_custom {
+        inherit_locations(heir.known_custom.entry(*id).or_default(), sites, fraction);
+    }
+}
+
+/// Helper function passing part of a dying agent's rumors on to a survivor
+/// The most strongly believed rumors are passed on first, at the belief the deceased held them with
+pub fn inherit_rumors(heir: &mut KnowledgeBase, deceased: &KnowledgeBase, fraction: f32) {
+    let mut unknown: Vec<(&String, f32)> = deceased
+        .known_rumors
+        .iter()
+        .filter(|(rumor, _)| !heir.known_rumors.contains_key(*rumor))
+        .map(|(rumor, belief)| (rumor, *belief))
+        .collect();
+    unknown.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal).then_with(|| a.0.cmp(b.0)));
+
+    for (rumor, belief) in unknown.into_iter().take(shared_count(deceased.known_rumors.len(), fraction)) {
+        heir.known_rumors.insert(rumor.clone(), belief);
+        heir.knows_rumor = true;
+    }
+}
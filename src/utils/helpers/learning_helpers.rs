@@ -0,0 +1,369 @@
+use crate::components::components_learning::{AdaptiveBehavior, CognitiveMap, ForageMemory, Landmark, LandmarkId, LearnedModel, PathExperience, PathLearning, PathSegment};
+use crate::systems::events::events_needs::NeedType;
+use bevy::prelude::*;
+use rand::Rng;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::path::Path;
+
+/// Helper function folding one action outcome into the agent's exploration rate
+/// Based on the Exploration-Exploitation Tradeoff (Cohen et al., 2007) - a falling success rate
+/// pushes the agent toward exploration, a rising one toward exploiting what it already knows
+pub fn update_adaptive_behavior(behavior: &mut AdaptiveBehavior, success: bool) {
+    let outcome = if success { 1.0 } else { 0.0 };
+    let smoothing = behavior.outcome_smoothing.clamp(0.0, 1.0);
+
+    behavior.recent_success_rate += (outcome - behavior.recent_success_rate) * smoothing;
+    behavior.exploration_rate = behavior.min_exploration_rate
+        + (behavior.max_exploration_rate - behavior.min_exploration_rate) * (1.0 - behavior.recent_success_rate);
+}
+
+/// Helper function raising the activation of the occupied cell and fading every other cell
+/// Cells that fade back to zero are dropped so the map only stores remembered territory
+pub fn update_cognitive_map(map: &mut CognitiveMap, position: Vec2, delta_time: f32) {
+    let current_cell = map.cell_at(position);
+    let decay = map.activation_decay * delta_time;
+
+    map.activations.retain(|cell, activation| {
+        if *cell != current_cell {
+            *activation -= decay;
+        }
+        *activation > 0.0
+    });
+
+    let gain = map.activation_gain * delta_time;
+    let activation = map.activations.entry(current_cell).or_insert(0.0);
+    *activation = (*activation + gain).min(1.0);
+}
+
+/// Helper function adding a landmark at a position unless the agent already knows one there
+/// A new landmark is named after its owner, so it starts out known to that agent alone
+pub fn record_landmark(map: &mut CognitiveMap, owner: Entity, position: Vec2) {
+    if map.landmark_near(position).is_some() {
+        return;
+    }
+    let id = LandmarkId { origin: owner, index: map.landmarks_named };
+    map.landmarks_named += 1;
+    map.landmarks.push(Landmark { id, position });
+}
+
+/// Helper function reconciling two agents' landmarks that refer to the same place, returning how many were merged
+/// Each landmark pairs with the other agent's nearest one within the smaller landmark_merge_radius; both then
+/// know it under the lower of the two ids, at the midpoint of where each had placed it
+pub fn merge_shared_landmarks(first: &mut CognitiveMap, second: &mut CognitiveMap) -> usize {
+    let radius = first.landmark_merge_radius.min(second.landmark_merge_radius);
+    let mut paired = vec![false; second.landmarks.len()];
+    let mut merged = 0;
+
+    for own in first.landmarks.iter_mut() {
+        let nearest = second
+            .landmarks
+            .iter()
+            .enumerate()
+            .filter(|(index, other)| !paired[*index] && other.position.distance(own.position) <= radius)
+            .min_by(|(_, a), (_, b)| a.position.distance(own.position).total_cmp(&b.position.distance(own.position)))
+            .map(|(index, _)| index);
+        let Some(index) = nearest else {
+            continue;
+        };
+
+        paired[index] = true;
+        let other = &mut second.landmarks[index];
+        let shared = Landmark { id: own.id.min(other.id), position: (own.position + other.position) / 2.0 };
+        *own = shared;
+        *other = shared;
+        merged += 1;
+    }
+    merged
+}
+
+/// Helper function crediting the cell a resource was found in
+pub fn record_forage_reward(memory: &mut ForageMemory, resource_position: Vec2) {
+    let cell = memory.cell_at(resource_position);
+    *memory.rewards.entry(cell).or_insert(0.0) += memory.reward_per_discovery;
+}
+
+/// Helper function fading every remembered reward
+/// Cells that fade back to zero are dropped so the map only stores patches still worth returning to
+pub fn decay_forage_memory(memory: &mut ForageMemory, delta_time: f32) {
+    let decay = memory.reward_decay * delta_time;
+    memory.rewards.retain(|_, reward| {
+        *reward -= decay;
+        *reward > 0.0
+    });
+}
+
+/// Helper function computing the pull of an idle agent toward its most rewarding remembered cell
+/// The result points at the cell's center with length attraction, scaled down while the reward is below 1.0;
+/// ties between cells go to the lowest cell so the choice does not depend on map iteration order
+pub fn calculate_forage_bias(memory: &ForageMemory, position: Vec2) -> Vec2 {
+    let best = memory
+        .rewards
+        .iter()
+        .max_by(|(cell_a, reward_a), (cell_b, reward_b)| {
+            reward_a.total_cmp(reward_b).then_with(|| (cell_b.x, cell_b.y).cmp(&(cell_a.x, cell_a.y)))
+        });
+
+    match best {
+        Some((cell, reward)) => {
+            (memory.cell_center(*cell) - position).normalize_or_zero() * memory.attraction * reward.clamp(0.0, 1.0)
+        }
+        None => Vec2::ZERO,
+    }
+}
+
+/// Helper function choosing the least familiar cell around the agent that lies inside the room
+/// Ties are broken toward the nearer cell so agents map their surroundings outward
+/// Returns the world position of the chosen cell's center
+pub fn find_least_explored_cell(
+    map: &CognitiveMap,
+    position: Vec2,
+    radius_cells: i32,
+    room_half_extents: Vec2,
+) -> Option<Vec2> {
+    let current_cell = map.cell_at(position);
+    let radius = radius_cells.max(1);
+
+    (-radius..=radius)
+        .flat_map(|x| (-radius..=radius).map(move |y| current_cell + IVec2::new(x, y)))
+        .filter(|cell| *cell != current_cell)
+        .map(|cell| map.cell_center(cell))
+        .filter(|center| center.x.abs() < room_half_extents.x && center.y.abs() < room_half_extents.y)
+        .min_by(|a, b| {
+            let key_a = (map.activation(map.cell_at(*a)), a.distance_squared(position));
+            let key_b = (map.activation(map.cell_at(*b)), b.distance_squared(position));
+            key_a.partial_cmp(&key_b).unwrap_or(std::cmp::Ordering::Equal)
+        })
+}
+
+/// Helper function converting a per-second exploration rate into the chance of exploring this frame
+pub fn exploration_chance(exploration_rate: f32, delta_time: f32) -> f32 {
+    (exploration_rate * delta_time).clamp(0.0, 1.0)
+}
+
+/// Helper function aggregating a traversal outcome into a single reward
+/// Successful arrivals earn 1.0 scaled down to 0.5 as they approach the pursuit time limit,
+/// failures always cost -1.0 so unreliable routes end up below untried ones
+pub fn calculate_path_reward(success: bool, traversal_time: f32, max_pursuit_time: f32) -> f32 {
+    if success {
+        let slowness = (traversal_time / max_pursuit_time.max(f32::EPSILON)).clamp(0.0, 1.0);
+        1.0 - 0.5 * slowness
+    } else {
+        -1.0
+    }
+}
+
+/// Reward lost for an action that did not achieve its goal
+pub const ACTION_FAILURE_PENALTY: f32 = 0.25;
+
+/// Helper function aggregating an action's result into a single reward
+/// The reward is the total need satisfaction the action restored, less ACTION_FAILURE_PENALTY if it failed,
+/// so refilling a depleted need pays more than topping off a full one
+pub fn calculate_action_reward(success: bool, need_deltas: &[(NeedType, f32)]) -> f32 {
+    let restored: f32 = need_deltas.iter().map(|(_, delta)| delta).sum();
+    if success { restored } else { restored - ACTION_FAILURE_PENALTY }
+}
+
+/// Helper function appending a traversal experience to an agent's path memory
+/// The oldest experiences are forgotten once max_path_memory is reached
+pub fn record_path_experience(
+    learning: &mut PathLearning,
+    map: &CognitiveMap,
+    origin: Vec2,
+    destination: Vec2,
+    traversal_time: f32,
+    success: bool,
+    max_pursuit_time: f32,
+) {
+    let experience = PathExperience {
+        segment: PathSegment { from: map.cell_at(origin), to: map.cell_at(destination) },
+        traversal_time,
+        success,
+        reward: calculate_path_reward(success, traversal_time, max_pursuit_time),
+        learned: false,
+    };
+
+    learning.path_memory.push(experience);
+    let overflow = learning.path_memory.len().saturating_sub(learning.max_path_memory);
+    learning.path_memory.drain(..overflow);
+}
+
+/// Helper function folding every not-yet-learned experience into the learned path values
+/// Based on Temporal Difference Learning (Sutton, 1988): V(s) += alpha * (reward - V(s))
+pub fn learn_from_path_memory(learning: &mut PathLearning) {
+    let learning_rate = learning.learning_rate.clamp(0.0, 1.0);
+
+    for experience in learning.path_memory.iter_mut().filter(|experience| !experience.learned) {
+        let value = learning.path_values.entry(experience.segment).or_insert(0.0);
+        *value += learning_rate * (experience.reward - *value);
+        experience.learned = true;
+    }
+}
+
+/// Helper function choosing among known resource positions by learned path value
+/// Based on Epsilon-Greedy action selection (Sutton & Barto, 2018) - with probability `exploration_rate`
+/// a random candidate is tried, otherwise the candidate whose route has the highest learned value wins
+/// Equal values (e.g. routes never tried) fall back to the nearest candidate
+pub fn select_target_epsilon_greedy<R: Rng + ?Sized>(
+    position: Vec2,
+    candidates: &[Vec2],
+    map: &CognitiveMap,
+    learning: &PathLearning,
+    exploration_rate: f32,
+    rng: &mut R,
+) -> Option<Vec2> {
+    if candidates.len() > 1 && rng.random::<f32>() < exploration_rate {
+        return Some(candidates[rng.random_range(0..candidates.len())]);
+    }
+
+    let origin = map.cell_at(position);
+    candidates
+        .iter()
+        .max_by(|a, b| {
+            let value_a = learning.value(PathSegment { from: origin, to: map.cell_at(**a) });
+            let value_b = learning.value(PathSegment { from: origin, to: map.cell_at(**b) });
+            value_a
+                .partial_cmp(&value_b)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| {
+                    let dist_a = position.distance_squared(**a);
+                    let dist_b = position.distance_squared(**b);
+                    dist_b.partial_cmp(&dist_a).unwrap_or(std::cmp::Ordering::Equal)
+                })
+        })
+        .copied()
+}
+
+/// Helper function capturing an agent's learned path values and cognitive map in a LearnedModel
+/// Entries are sorted so the same knowledge always produces the same file
+pub fn build_learned_model(map: &CognitiveMap, learning: &PathLearning) -> LearnedModel {
+    let mut path_values: Vec<([i32; 2], [i32; 2], f32)> = learning
+        .path_values
+        .iter()
+        .map(|(segment, value)| (segment.from.to_array(), segment.to.to_array(), *value))
+        .collect();
+    path_values.sort_by_key(|a| (a.0, a.1));
+
+    let mut activations: Vec<([i32; 2], f32)> = map.activations.iter().map(|(cell, activation)| (cell.to_array(), *activation)).collect();
+    activations.sort_by_key(|a| a.0);
+
+    LearnedModel { cell_size: map.cell_size, path_values, activations }
+}
+
+/// Helper function replacing an agent's learned path values and cognitive map with a LearnedModel
+/// Cells recorded on a different grid are remapped through their world-space centers; routes that land on
+/// the same segment average their values and cells that merge keep their highest activation
+pub fn apply_learned_model(model: &LearnedModel, map: &mut CognitiveMap, learning: &mut PathLearning) {
+    let remap = |cell: [i32; 2]| map.cell_at((IVec2::from_array(cell).as_vec2() + Vec2::splat(0.5)) * model.cell_size);
+
+    let mut merged: HashMap<PathSegment, (f32, u32)> = HashMap::new();
+    for &(from, to, value) in &model.path_values {
+        let entry = merged.entry(PathSegment { from: remap(from), to: remap(to) }).or_insert((0.0, 0));
+        entry.0 += value;
+        entry.1 += 1;
+    }
+
+    let mut activations: HashMap<IVec2, f32> = HashMap::new();
+    for &(cell, activation) in &model.activations {
+        let entry = activations.entry(remap(cell)).or_insert(0.0);
+        *entry = entry.max(activation);
+    }
+
+    learning.path_values = merged.into_iter().map(|(segment, (sum, count))| (segment, sum / count as f32)).collect();
+    map.activations = activations;
+}
+
+/// Reads an agent's learned model out of the world, None unless it has both a CognitiveMap and PathLearning
+pub fn export_learned_model(world: &World, entity: Entity) -> Option<LearnedModel> {
+    let entity = world.get_entity(entity).ok()?;
+    Some(build_learned_model(entity.get::<CognitiveMap>()?, entity.get::<PathLearning>()?))
+}
+
+/// Loads a learned model into an agent, giving a fresh body default learning components first
+/// Returns false if the entity does not exist
+pub fn import_learned_model(world: &mut World, entity: Entity, model: &LearnedModel) -> bool {
+    let Ok(mut body) = world.get_entity_mut(entity) else {
+        return false;
+    };
+    if !body.contains::<CognitiveMap>() {
+        body.insert(CognitiveMap::default());
+    }
+    if !body.contains::<PathLearning>() {
+        body.insert(PathLearning::default());
+    }
+
+    let Ok((mut map, mut learning)) = world.query::<(&mut CognitiveMap, &mut PathLearning)>().get_mut(world, entity) else {
+        return false;
+    };
+    apply_learned_model(model, &mut map, &mut learning);
+    true
+}
+
+/// Writes a learned model to `path` as compact JSON
+pub fn save_learned_model(path: impl AsRef<Path>, model: &LearnedModel) -> std::io::Result<()> {
+    let json = serde_json::to_string(model).map_err(std::io::Error::other)?;
+    std::fs::write(path, json)
+}
+
+/// Reads a learned model written by save_learned_model
+pub fn load_learned_model(path: impl AsRef<Path>) -> std::io::Result<LearnedModel> {
+    let json = std::fs::read_to_string(path)?;
+    serde_json::from_str(&json).map_err(std::io::Error::other)
+}
+
+/// Helper function trimming a CognitiveMap to at most `max_cells` remembered cells, least familiar first
+/// Cells at or above `consolidated_activation` are never pruned, even if that leaves the map over its cap
+/// Returns how many cells were forgotten
+pub fn prune_cognitive_map(map: &mut CognitiveMap, max_cells: usize, consolidated_activation: f32) -> usize {
+    prune_weakest(&mut map.activations, max_cells, |activation| activation, consolidated_activation, |cell| (cell.x, cell.y))
+}
+
+/// Helper function trimming learned route values to `max_values` and experiences to `max_memory`
+/// Values closest to 0.0 (no expectation either way) go first and values of at least `consolidated_value` either
+/// way are kept; the oldest experiences already folded into path_values go first and unlearned ones are kept
+/// Returns how many values and experiences were forgotten
+pub fn prune_path_learning(learning: &mut PathLearning, max_values: usize, max_memory: usize, consolidated_value: f32) -> usize {
+    let pruned_values = prune_weakest(&mut learning.path_values, max_values, f32::abs, consolidated_value, |segment| {
+        (segment.from.x, segment.from.y, segment.to.x, segment.to.y)
+    });
+
+    let memory_before = learning.path_memory.len();
+    let mut excess = memory_before.saturating_sub(max_memory);
+    learning.path_memory.retain(|experience| {
+        if excess > 0 && experience.learned {
+            excess -= 1;
+            false
+        } else {
+            true
+        }
+    });
+
+    pruned_values + memory_before - learning.path_memory.len()
+}
+
+/// Removes the weakest unconsolidated entries until at most `cap` remain, returning how many were removed
+/// Ties in strength are broken by `order`, so what is pruned never depends on hash order
+fn prune_weakest<K: Copy + Eq + Hash, O: Ord>(
+    values: &mut HashMap<K, f32>,
+    cap: usize,
+    strength: impl Fn(f32) -> f32,
+    consolidated: f32,
+    order: impl Fn(&K) -> O,
+) -> usize {
+    if values.len() <= cap {
+        return 0;
+    }
+
+    let mut prunable: Vec<(K, f32)> = values
+        .iter()
+        .map(|(key, value)| (*key, strength(*value)))
+        .filter(|(_, weight)| *weight < consolidated)
+        .collect();
+    prunable.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| order(&a.0).cmp(&order(&b.0))));
+
+    let pruned = (values.len() - cap).min(prunable.len());
+    for (key, _) in prunable.into_iter().take(pruned) {
+        values.remove(&key);
+    }
+    pruned
+}
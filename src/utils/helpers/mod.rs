@@ -1,11 +1,24 @@
 /// Helper modules for domain-specific utility functions
 /// Following data-oriented design principles with pure functions
 
+pub mod camera_helpers;
+pub mod color_helpers;
+pub mod constants_helpers;
+pub mod experiment_helpers;
+pub mod knowledge_helpers;
+pub mod learning_helpers;
 pub mod movement_helpers;
 pub mod needs_helpers;
 pub mod pathfinding_helpers;
+pub mod performance_helpers;
+pub mod physics_helpers;
 pub mod resource_helpers;
 pub mod rumor_helpers;
+pub mod social_helpers;
+pub mod spawn_helpers;
+pub mod telemetry_helpers;
+pub mod vision_helpers;
+pub mod weather_helpers;
 
 // Re-export commonly used functions for convenience
 pub use movement_helpers::*;
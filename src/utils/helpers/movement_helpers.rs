@@ -1,4 +1,5 @@
 use crate::components::components_needs::Desire;
+use crate::components::components_npc::Proprioception;
 use bevy::prelude::*;
 
 /// Helper function implementing boundary physics based on elastic collision theory
@@ -23,6 +24,19 @@ pub fn reflect_velocity_off_boundary(velocity: Vec2, boundary_normal: Vec2) -> V
     velocity - 2.0 * velocity.dot(boundary_normal) * boundary_normal
 }
 
+/// Helper function stopping movement into a boundary while keeping the slide along it
+/// Each axis the boundary normal points along loses the part of the velocity heading out of the room
+pub fn stop_velocity_at_boundary(velocity: Vec2, boundary_normal: Vec2) -> Vec2 {
+    let stop = |speed: f32, normal: f32| if speed * normal < 0.0 { 0.0 } else { speed };
+    Vec2::new(stop(velocity.x, boundary_normal.x), stop(velocity.y, boundary_normal.y))
+}
+
+/// Helper function moving a position that left the room through one edge to the opposite edge
+/// Based on toroidal (periodic) boundary conditions - the room has no edge, so no position is special
+pub fn wrap_position(position: Vec2, half_extents: Vec2) -> Vec2 {
+    (position + half_extents).rem_euclid(half_extents * 2.0) - half_extents
+}
+
 /// Helper function for boundary detection with predictive collision system
 /// Based on Collision Detection Theory - predicts future collisions for smooth movement
 pub fn detect_boundary_collision(
@@ -73,6 +87,45 @@ pub fn safe_normalize(vector: Vec2, fallback: Vec2) -> Vec2 {
     }
 }
 
+/// Helper function turning a rotation toward a velocity's heading by at most turn_rate * delta_secs radians
+/// Sprites face along local +Y, the same forward the vision helpers use; slow movement keeps the current facing
+pub fn calculate_facing_rotation(current: Quat, velocity: Vec2, turn_rate: f32, delta_secs: f32, min_speed: f32) -> Quat {
+    if velocity.length() < min_speed {
+        return current;
+    }
+
+    let target = Quat::from_rotation_z(Vec2::Y.angle_to(velocity));
+    let remaining = current.angle_between(target);
+    if remaining <= f32::EPSILON {
+        return target;
+    }
+
+    current.slerp(target, (turn_rate * delta_secs / remaining).min(1.0))
+}
+
+/// Helper function updating an agent's self-motion estimate from its newly sensed position
+/// Speed and heading come from the position delta; the displacement is integrated from those two,
+/// the way dead reckoning would. The first call only records the starting position
+pub fn update_proprioception(proprioception: &mut Proprioception, position: Vec2, delta_secs: f32) {
+    let Some(last_position) = proprioception.last_position.replace(position) else {
+        return;
+    };
+    if delta_secs <= 0.0 {
+        return;
+    }
+
+    let delta = position - last_position;
+    let distance = delta.length();
+    proprioception.speed_estimate = distance / delta_secs;
+    if distance > f32::EPSILON {
+        proprioception.current_heading = delta.to_angle();
+    }
+
+    proprioception.integrated_displacement +=
+        Vec2::from_angle(proprioception.current_heading) * proprioception.speed_estimate * delta_secs;
+    proprioception.distance_travelled += distance;
+}
+
 /// Helper function implementing desire-driven movement behavior
 /// Based on Behavioral Psychology - desires influence movement patterns and speed
 pub fn calculate_desire_movement_modifier(desire: &Desire) -> (f32, f32) {
@@ -83,6 +136,7 @@ pub fn calculate_desire_movement_modifier(desire: &Desire) -> (f32, f32) {
         Desire::FindSafety => (1.8, 0.5),    // Very fast, very focused when threatened
         Desire::Socialize => (0.8, 1.5),     // Slower, more wandering when seeking social interaction
         Desire::Wander => (1.0, 1.0),        // Normal speed and wandering
+        Desire::Custom(_) => (1.1, 0.9),     // Purposeful but rarely urgent
     }
 }
 
@@ -1,15 +1,45 @@
-use crate::components::components_constants::GameConstants;
-use crate::components::components_needs::{BasicNeeds, Desire, DesireThresholds};
+use crate::components::components_constants::{GameConstants, NeedCouplingConfig, RestQualityConfig, SatiationConfig};
+use crate::components::components_environment::ResourceType;
+use crate::components::components_needs::{
+    BasicNeeds, CustomDesireDefinition, CustomDesireRegistry, CustomNeeds, DecisionStability, Desire, DesireThresholds, DualProcess, DualThreshold,
+    HomeostaticSetpoints, NeedDepletion, NeedPriorityWeights, NeedSetpoint, Willpower,
+};
+use crate::components::components_npc::Mood;
+use crate::systems::events::events_needs::NeedType;
+use bevy::math::Vec2;
 
 /// Helper function to decay needs over time based on physiological models
 /// Based on Homeostatic Drive Theory - all needs naturally decrease over time without intervention
 /// FIXED: All decay functions now use consistent "higher = better satisfied" semantics
 pub fn decay_needs(needs: &mut BasicNeeds, game_constants: &GameConstants, delta_time: f32) -> (f32, f32, f32, f32, f32) {
-    let hunger_change = -game_constants.hunger_decay * delta_time; // Hunger satisfaction DECREASES over time
-    let thirst_change = -game_constants.thirst_decay * delta_time; // Thirst satisfaction DECREASES over time
-    let rest_change = -game_constants.fatigue_regen * delta_time; // Rest level DECREASES over time (fatigue increases)
-    let safety_change = -game_constants.safety_decay * delta_time; // Safety DECREASES over time
-    let social_change = -game_constants.loneliness_decay * delta_time; // Social satisfaction DECREASES over time
+    decay_needs_with_strain(needs, game_constants, delta_time, 1.0)
+}
+
+/// Helper function decaying needs with thirst and rest accelerated by environmental strain
+/// A strain of 1.0 is the normal rate (see calculate_temperature_decay_multiplier)
+pub fn decay_needs_with_strain(
+    needs: &mut BasicNeeds,
+    game_constants: &GameConstants,
+    delta_time: f32,
+    strain_multiplier: f32,
+) -> (f32, f32, f32, f32, f32) {
+    decay_needs_with_multipliers(needs, game_constants, delta_time, [1.0, strain_multiplier, strain_multiplier, 1.0, 1.0])
+}
+
+/// Helper function decaying each need at its base rate scaled by its own multiplier
+/// Multipliers follow NeedType order: hunger, thirst, rest, safety, social
+pub fn decay_needs_with_multipliers(
+    needs: &mut BasicNeeds,
+    game_constants: &GameConstants,
+    delta_time: f32,
+    multipliers: [f32; 5],
+) -> (f32, f32, f32, f32, f32) {
+    let [hunger_rate, thirst_rate, rest_rate, safety_rate, social_rate] = multipliers;
+    let hunger_change = -game_constants.hunger_decay * hunger_rate * delta_time; // Hunger satisfaction DECREASES over time
+    let thirst_change = -game_constants.thirst_decay * thirst_rate * delta_time; // Thirst satisfaction DECREASES over time
+    let rest_change = -game_constants.fatigue_regen * rest_rate * delta_time; // Rest level DECREASES over time (fatigue increases)
+    let safety_change = -game_constants.safety_decay * safety_rate * delta_time; // Safety DECREASES over time
+    let social_change = -game_constants.loneliness_decay * social_rate * delta_time; // Social satisfaction DECREASES over time
 
     needs.hunger = (needs.hunger + hunger_change).clamp(0.0, 1.0);
     needs.thirst = (needs.thirst + thirst_change).clamp(0.0, 1.0);
@@ -20,6 +50,69 @@ pub fn decay_needs(needs: &mut BasicNeeds, game_constants: &GameConstants, delta
     (hunger_change, thirst_change, rest_change, safety_change, social_change)
 }
 
+/// How much faster a need held above its comfort band decays back toward the setpoint
+const SETPOINT_SURPLUS_DECAY_MULTIPLIER: f32 = 2.0;
+
+/// Helper function reading one need relative to an agent's setpoint
+/// Anything from target - comfort_band up reads as fully satisfied (1.0); below that the shortfall counts
+/// one-for-one, so a threshold means the same distance from comfort for every agent
+/// The default setpoint (target 1.0, no band) returns the value unchanged
+pub fn calculate_relative_need(value: f32, setpoint: &NeedSetpoint) -> f32 {
+    (1.0 - (setpoint.target - setpoint.comfort_band - value).max(0.0)).clamp(0.0, 1.0)
+}
+
+/// Helper function reading all basic needs relative to an agent's setpoints, for threshold and utility checks
+pub fn calculate_relative_needs(needs: &BasicNeeds, setpoints: &HomeostaticSetpoints) -> BasicNeeds {
+    BasicNeeds {
+        hunger: calculate_relative_need(needs.hunger, &setpoints.hunger),
+        thirst: calculate_relative_need(needs.thirst, &setpoints.thirst),
+        rest: calculate_relative_need(needs.rest, &setpoints.rest),
+        safety: calculate_relative_need(needs.safety, &setpoints.safety),
+        social: calculate_relative_need(needs.social, &setpoints.social),
+    }
+}
+
+/// Helper function giving each need's decay multiplier under its setpoint
+/// A need above target + comfort_band decays SETPOINT_SURPLUS_DECAY_MULTIPLIER times faster, so the agent drifts
+/// back to the level it defends; within or below the band it decays at the regular rate
+pub fn calculate_setpoint_decay_multipliers(needs: &BasicNeeds, setpoints: &HomeostaticSetpoints) -> [f32; 5] {
+    let multiplier = |value: f32, setpoint: &NeedSetpoint| {
+        if value > setpoint.target + setpoint.comfort_band { SETPOINT_SURPLUS_DECAY_MULTIPLIER } else { 1.0 }
+    };
+    [
+        multiplier(needs.hunger, &setpoints.hunger),
+        multiplier(needs.thirst, &setpoints.thirst),
+        multiplier(needs.rest, &setpoints.rest),
+        multiplier(needs.safety, &setpoints.safety),
+        multiplier(needs.social, &setpoints.social),
+    ]
+}
+
+/// Helper function scaling the decay multiplier of the need a resource satisfies
+/// Used while an agent refills so decay does not fight the refill; other needs are untouched
+pub fn apply_refill_decay_immunity(multipliers: &mut [f32; 5], refilled: ResourceType, refill_decay_multiplier: f32) {
+    let index = match refilled {
+        ResourceType::Food => 0,
+        ResourceType::Water => 1,
+        ResourceType::Rest => 2,
+        ResourceType::Safety => 3,
+        ResourceType::Loneliness => 4,
+        // Custom needs do not decay through BasicNeeds
+        ResourceType::Custom(_) => return,
+    };
+    multipliers[index] *= refill_decay_multiplier;
+}
+
+/// Helper function giving each need's decay multiplier under cross-need coupling
+/// Each multiplier is 1.0 plus the coupled deficits of the other needs, never below zero
+pub fn calculate_coupled_decay_multipliers(needs: &BasicNeeds, coupling: &NeedCouplingConfig) -> [f32; 5] {
+    let deficits = [1.0 - needs.hunger, 1.0 - needs.thirst, 1.0 - needs.rest, 1.0 - needs.safety, 1.0 - needs.social];
+    coupling.matrix.map(|row| {
+        let coupled: f32 = row.iter().zip(deficits).map(|(strength, deficit)| strength * deficit).sum();
+        (1.0 + coupled).max(0.0)
+    })
+}
+
 /// Helper function to increase social satisfaction based on Social Exchange Theory
 /// System based on Social Exchange Theory - positive interactions increase social satisfaction
 pub fn increase_social_satisfaction(needs: &mut BasicNeeds, amount: f32) -> f32 {
@@ -28,36 +121,84 @@ pub fn increase_social_satisfaction(needs: &mut BasicNeeds, amount: f32) -> f32
     needs.social - old_social // Return actual change for ML tracking
 }
 
+/// Helper function re-rating an agent's decision stability from its recorded desire changes
+/// Changes older than the window are forgotten, so the bias lifts again once the agent has settled
+pub fn update_decision_stability(
+    stability: &mut DecisionStability,
+    current_time: f32,
+    window_secs: f32,
+    max_changes_per_minute: f32,
+    commitment_bias: f32,
+) {
+    let window_secs = window_secs.max(f32::EPSILON);
+    stability.recent_changes.retain(|&time| current_time - time <= window_secs);
+
+    stability.changes_per_minute = stability.recent_changes.len() as f32 * 60.0 / window_secs;
+    stability.commitment_bias = if stability.changes_per_minute > max_changes_per_minute { commitment_bias } else { 0.0 };
+}
+
+/// Helper function keeping the current desire unless the best alternative beats it by more than the commitment bias
+pub fn apply_commitment_bias(current: (Desire, f32), best: (Desire, f32), commitment_bias: f32) -> (Desire, f32) {
+    let (current_desire, current_utility) = current;
+    if commitment_bias > 0.0 && best.0 != current_desire && current_utility + commitment_bias >= best.1 {
+        (current_desire, current_utility)
+    } else {
+        best
+    }
+}
+
+/// Helper function giving mutable access to a single need by its NeedType
+pub fn need_value_mut(needs: &mut BasicNeeds, need_type: NeedType) -> &mut f32 {
+    match need_type {
+        NeedType::Hunger => &mut needs.hunger,
+        NeedType::Thirst => &mut needs.thirst,
+        NeedType::Rest => &mut needs.rest,
+        NeedType::Safety => &mut needs.safety,
+        NeedType::Social => &mut needs.social,
+    }
+}
+
+/// Helper function naming the desire that seeks to satisfy a need
+pub fn desire_for_need(need_type: NeedType) -> Desire {
+    match need_type {
+        NeedType::Hunger => Desire::FindFood,
+        NeedType::Thirst => Desire::FindWater,
+        NeedType::Rest => Desire::Rest,
+        NeedType::Safety => Desire::FindSafety,
+        NeedType::Social => Desire::Socialize,
+    }
+}
+
 /// Helper function implementing Maslow's Hierarchy of Needs for desire evaluation
 /// System based on Maslow's Hierarchy of Needs and Threshold Psychology
 /// All values are normalized between 0.0-1.0
-pub fn evaluate_most_urgent_desire(needs: &BasicNeeds, thresholds: &DesireThresholds) -> (Desire, f32) {
+pub fn evaluate_most_urgent_desire(needs: &BasicNeeds, thresholds: &DesireThresholds, weights: &NeedPriorityWeights) -> (Desire, f32) {
     let mut desire_utilities = Vec::new();
 
     // Calculate weighted utility for each potential desire using the new formula
     // ML-HOOK: Each utility calculation provides quantifiable state for observation space
 
-    let safety_utility = calculate_desire_utility(Desire::FindSafety, needs, thresholds);
+    let safety_utility = calculate_desire_utility(Desire::FindSafety, needs, thresholds, weights);
     if safety_utility > 0.0 {
         desire_utilities.push((Desire::FindSafety, safety_utility));
     }
 
-    let water_utility = calculate_desire_utility(Desire::FindWater, needs, thresholds);
+    let water_utility = calculate_desire_utility(Desire::FindWater, needs, thresholds, weights);
     if water_utility > 0.0 {
         desire_utilities.push((Desire::FindWater, water_utility));
     }
 
-    let food_utility = calculate_desire_utility(Desire::FindFood, needs, thresholds);
+    let food_utility = calculate_desire_utility(Desire::FindFood, needs, thresholds, weights);
     if food_utility > 0.0 {
         desire_utilities.push((Desire::FindFood, food_utility));
     }
 
-    let rest_utility = calculate_desire_utility(Desire::Rest, needs, thresholds);
+    let rest_utility = calculate_desire_utility(Desire::Rest, needs, thresholds, weights);
     if rest_utility > 0.0 {
         desire_utilities.push((Desire::Rest, rest_utility));
     }
 
-    let social_utility = calculate_desire_utility(Desire::Socialize, needs, thresholds);
+    let social_utility = calculate_desire_utility(Desire::Socialize, needs, thresholds, weights);
     if social_utility > 0.0 {
         desire_utilities.push((Desire::Socialize, social_utility));
     }
@@ -69,6 +210,131 @@ pub fn evaluate_most_urgent_desire(needs: &BasicNeeds, thresholds: &DesireThresh
         .unwrap_or((Desire::Wander, 0.5)) // Default to Wander if no urgent desires
 }
 
+/// Seconds between re-evaluations for a fully reactive System 1 agent
+pub const SYSTEM1_DECISION_INTERVAL: f32 = 0.5;
+/// Seconds between re-evaluations for a fully deliberate System 2 agent
+pub const SYSTEM2_DECISION_INTERVAL: f32 = 3.0;
+/// Furthest a System 2 agent projects its needs into the future (seconds)
+const MAX_DECISION_LOOKAHEAD: f32 = 10.0;
+
+/// Helper function to derive stress from unmet needs
+/// Based on Allostatic Load Theory - stress rises with the most neglected need
+pub fn calculate_need_stress(needs: &BasicNeeds) -> f32 {
+    let lowest = needs.hunger.min(needs.thirst).min(needs.rest).min(needs.safety).min(needs.social);
+    (1.0 - lowest).clamp(0.0, 1.0)
+}
+
+/// Helper function returning how much an agent engages System 2 (0.0 = pure System 1, 1.0 = pure System 2)
+/// Based on Dual-Process Theory (Kahneman, 2011) - deliberation needs willpower and low stress
+/// Agents without a Willpower component deliberate as if fully rested
+pub fn calculate_system2_engagement(dual_process: &DualProcess, willpower: Option<&Willpower>) -> f32 {
+    let willpower = willpower.map_or(1.0, |willpower| (willpower.current / willpower.max.max(0.001)).clamp(0.0, 1.0));
+    (willpower * (1.0 - dual_process.stress)).clamp(0.0, 1.0)
+}
+
+/// Helper function mapping System 2 engagement to a re-evaluation interval in seconds
+/// Stressed agents react quickly and often, calm agents think less often but further ahead
+pub fn calculate_decision_interval(system2_engagement: f32) -> f32 {
+    SYSTEM1_DECISION_INTERVAL + (SYSTEM2_DECISION_INTERVAL - SYSTEM1_DECISION_INTERVAL) * system2_engagement.clamp(0.0, 1.0)
+}
+
+/// Helper function choosing a desire at the depth allowed by the agent's dual-process state
+/// System 1 (engagement < 0.5): satisficing - takes the first pressing desire in Maslow's order
+/// System 2: projects needs forward by a lookahead scaled with engagement and deliberation depth
+/// (see Personality::deliberation_depth) and compares every desire
+pub fn evaluate_desire_dual_process(
+    needs: &BasicNeeds,
+    thresholds: &DesireThresholds,
+    weights: &NeedPriorityWeights,
+    game_constants: &GameConstants,
+    system2_engagement: f32,
+    deliberation_depth: f32,
+) -> (Desire, f32) {
+    if system2_engagement < 0.5 {
+        const MASLOW_ORDER: [Desire; 5] = [
+            Desire::FindSafety,
+            Desire::FindWater,
+            Desire::FindFood,
+            Desire::Rest,
+            Desire::Socialize,
+        ];
+
+        return MASLOW_ORDER
+            .iter()
+            .map(|desire| (*desire, calculate_desire_utility(*desire, needs, thresholds, weights)))
+            .find(|(_, utility)| *utility > 0.0)
+            .unwrap_or((Desire::Wander, 0.5));
+    }
+
+    let mut projected_needs = *needs;
+    decay_needs(&mut projected_needs, game_constants, MAX_DECISION_LOOKAHEAD * system2_engagement * deliberation_depth);
+    evaluate_most_urgent_desire(&projected_needs, thresholds, weights)
+}
+
+/// Helper function choosing a desire from the needs projected horizon seconds ahead
+/// One-step bounded lookahead: every need decays at its own rate, so a need that is fine now but drains
+/// quickly can outrank a larger but slower deficit before it becomes urgent
+pub fn evaluate_desire_with_lookahead(
+    needs: &BasicNeeds,
+    thresholds: &DesireThresholds,
+    weights: &NeedPriorityWeights,
+    game_constants: &GameConstants,
+    horizon: f32,
+) -> (Desire, f32) {
+    let mut projected_needs = *needs;
+    decay_needs(&mut projected_needs, game_constants, horizon.max(0.0));
+    evaluate_most_urgent_desire(&projected_needs, thresholds, weights)
+}
+
+/// Helper function picking the most urgent physiological desire (thirst, hunger, rest)
+/// Used when an agent has no willpower left to resist its bodily drives
+pub fn select_physiological_desire(needs: &BasicNeeds, thresholds: &DesireThresholds, weights: &NeedPriorityWeights) -> (Desire, f32) {
+    [Desire::FindWater, Desire::FindFood, Desire::Rest]
+        .into_iter()
+        .map(|desire| (desire, calculate_desire_utility(desire, needs, thresholds, weights)))
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .unwrap_or((Desire::Wander, 0.5))
+}
+
+/// Helper function applying ego depletion to a decision
+/// Based on Ego Depletion Theory (Baumeister et al., 1998):
+/// - persisting with an active, non-dominant desire or choosing a non-dominant one costs willpower
+/// - once willpower is exhausted the most urgent physiological desire is forced
+pub fn resolve_desire_with_willpower(
+    current_desire: Desire,
+    chosen: (Desire, f32),
+    needs: &BasicNeeds,
+    thresholds: &DesireThresholds,
+    weights: &NeedPriorityWeights,
+    willpower: &mut Willpower,
+    depletion_rate: f32,
+) -> (Desire, f32) {
+    if willpower.current <= 0.0 {
+        return select_physiological_desire(needs, thresholds, weights);
+    }
+
+    let (dominant_desire, _) = evaluate_most_urgent_desire(needs, thresholds, weights);
+    let persisting = current_desire != dominant_desire
+        && current_desire != Desire::Wander
+        && should_activate_desire(current_desire, needs, thresholds);
+
+    let decision = if persisting {
+        (current_desire, calculate_desire_utility(current_desire, needs, thresholds, weights))
+    } else {
+        chosen
+    };
+
+    // Following the dominant desire is free, every override draws on willpower
+    if decision.0 != dominant_desire {
+        willpower.current = (willpower.current - depletion_rate).max(0.0);
+        if willpower.current <= 0.0 {
+            return select_physiological_desire(needs, thresholds, weights);
+        }
+    }
+
+    decision
+}
+
 /// Helper function to get quantifiable satisfaction levels for ML observation space
 /// ML-HOOK: Provides normalized satisfaction metrics for reward calculation
 /// All values are already normalized between 0.0-1.0
@@ -81,7 +347,89 @@ pub fn get_satisfaction_level(needs: &BasicNeeds, desire: &Desire) -> f32 {
         Desire::FindSafety => needs.safety,
         Desire::Socialize => needs.social,
         Desire::Wander => 0.5, // Neutral satisfaction for wandering
+        Desire::Custom(_) => 0.5, // Custom needs live in CustomNeeds, not BasicNeeds
+    }
+}
+
+/// Helper function scaling a refill by how satisfied the need already is
+/// Full boost below onset_fraction of the high threshold, falling linearly to min_fraction at the high threshold
+/// and staying there above it, so topping off a nearly satisfied need is mostly wasted
+pub fn calculate_satiation_multiplier(need_level: f32, high_threshold: f32, config: &SatiationConfig) -> f32 {
+    let onset = high_threshold * config.onset_fraction.clamp(0.0, 1.0);
+    let min_fraction = config.min_fraction.clamp(0.0, 1.0);
+    if need_level <= onset {
+        return 1.0;
     }
+    if need_level >= high_threshold {
+        return min_fraction;
+    }
+
+    let progress = (need_level - onset) / (high_threshold - onset).max(f32::EPSILON);
+    1.0 - progress * (1.0 - min_fraction)
+}
+
+/// Helper function finding how safe a resting spot is, from the shelters around it
+/// Each shelter is (centre, radius, safety 0.0-1.0); the safest one covering the position wins, open ground is 0.0
+pub fn calculate_rest_site_safety(position: Vec2, shelters: impl IntoIterator<Item = (Vec2, f32, f32)>) -> f32 {
+    shelters
+        .into_iter()
+        .filter(|(centre, radius, _)| position.distance(*centre) <= *radius)
+        .map(|(_, _, safety)| safety.clamp(0.0, 1.0))
+        .fold(0.0, f32::max)
+}
+
+/// Helper function scaling rest recovery by the safety of the resting spot
+/// open_ground_quality of the full recovery with no shelter, rising linearly to the full recovery at safety 1.0
+pub fn calculate_rest_quality(site_safety: f32, config: &RestQualityConfig) -> f32 {
+    let open_ground_quality = config.open_ground_quality.clamp(0.0, 1.0);
+    open_ground_quality + (1.0 - open_ground_quality) * site_safety.clamp(0.0, 1.0)
+}
+
+/// Helper function advancing how long each physiological need has been at 0.0
+/// Returns the need that has been depleted longest once it reaches `critical_duration_secs`, and only once
+/// per depletion: NeedDepletion::critical stays set until no physiological need is at 0.0 any more
+pub fn advance_need_depletion(depletion: &mut NeedDepletion, needs: &BasicNeeds, delta_secs: f32, critical_duration_secs: f32) -> Option<(NeedType, f32)> {
+    for (value, secs) in [
+        (needs.hunger, &mut depletion.hunger_secs),
+        (needs.thirst, &mut depletion.thirst_secs),
+        (needs.rest, &mut depletion.rest_secs),
+    ] {
+        *secs = if value <= 0.0 { *secs + delta_secs } else { 0.0 };
+    }
+
+    let depleted = [
+        (NeedType::Hunger, depletion.hunger_secs),
+        (NeedType::Thirst, depletion.thirst_secs),
+        (NeedType::Rest, depletion.rest_secs),
+    ];
+    if depleted.iter().all(|(_, secs)| *secs <= 0.0) {
+        depletion.critical = false;
+        return None;
+    }
+    if depletion.critical {
+        return None;
+    }
+
+    let failed = depleted
+        .into_iter()
+        .filter(|(_, secs)| *secs >= critical_duration_secs)
+        .max_by(|a, b| a.1.total_cmp(&b.1))?;
+    depletion.critical = true;
+    Some(failed)
+}
+
+/// Helper function deriving an agent's mood from its needs and stress
+/// Valence maps overall satisfaction 0.0-1.0 onto -1.0-1.0; arousal is the stress level
+pub fn calculate_mood(needs: &BasicNeeds, stress: f32) -> Mood {
+    Mood {
+        valence: (calculate_overall_satisfaction(needs) * 2.0 - 1.0).clamp(-1.0, 1.0),
+        arousal: stress.clamp(0.0, 1.0),
+    }
+}
+
+/// Helper function averaging an agent's five needs into one overall satisfaction value (0.0-1.0)
+pub fn calculate_overall_satisfaction(needs: &BasicNeeds) -> f32 {
+    (needs.hunger + needs.thirst + needs.rest + needs.safety + needs.social) / 5.0
 }
 
 /// Creates randomized initial needs based on realistic biological variation
@@ -89,9 +437,11 @@ pub fn get_satisfaction_level(needs: &BasicNeeds, desire: &Desire) -> f32 {
 /// References: Maslow (1943) - individual variation in need satisfaction patterns
 /// FIXED: Now uses consistent "higher = better satisfied" semantics for all needs
 pub fn create_random_basic_needs() -> BasicNeeds {
-    use rand::prelude::*;
-    let mut rng = rand::rng();
+    random_basic_needs_from_rng(&mut rand::rng())
+}
 
+/// Draws initial needs from the given generator, so seeded runs start from identical needs
+pub fn random_basic_needs_from_rng<R: rand::Rng + ?Sized>(rng: &mut R) -> BasicNeeds {
     BasicNeeds {
         // Hunger: Start moderately satisfied (0.6-0.9 range)
         // Higher values = more satisfied = less urgent
@@ -120,37 +470,59 @@ pub fn create_random_basic_needs() -> BasicNeeds {
 /// For "higher = better" needs, we use (1.0 - Current_Need_Value) to get urgency
 /// Utility = ((1.0 - Current_Need_Value) / (1.0 - High_Threshold)) * Priority_Weight
 /// Higher utility = more urgent movement behavior
-pub fn calculate_desire_utility(desire: Desire, basic_needs: &BasicNeeds, thresholds: &DesireThresholds) -> f32 {
+pub fn calculate_desire_utility(
+    desire: Desire,
+    basic_needs: &BasicNeeds,
+    thresholds: &DesireThresholds,
+    weights: &NeedPriorityWeights,
+) -> f32 {
     match desire {
         Desire::FindSafety => {
             let urgency = 1.0 - basic_needs.safety;
             let max_urgency = 1.0 - thresholds.safety_threshold.high_threshold.min(0.999);
-            (urgency / max_urgency.max(0.001)) * thresholds.priority_weights.safety
+            (urgency / max_urgency.max(0.001)) * weights.safety
         }
         Desire::FindWater => {
             let urgency = 1.0 - basic_needs.thirst;
             let max_urgency = 1.0 - thresholds.thirst_threshold.high_threshold.min(0.999);
-            (urgency / max_urgency.max(0.001)) * thresholds.priority_weights.thirst
+            (urgency / max_urgency.max(0.001)) * weights.thirst
         }
         Desire::FindFood => {
             let urgency = 1.0 - basic_needs.hunger;
             let max_urgency = 1.0 - thresholds.hunger_threshold.high_threshold.min(0.999);
-            (urgency / max_urgency.max(0.001)) * thresholds.priority_weights.hunger
+            (urgency / max_urgency.max(0.001)) * weights.hunger
         }
         Desire::Rest => {
             let urgency = 1.0 - basic_needs.rest;
             let max_urgency = 1.0 - thresholds.rest_threshold.high_threshold.min(0.999);
-            (urgency / max_urgency.max(0.001)) * thresholds.priority_weights.rest
+            (urgency / max_urgency.max(0.001)) * weights.rest
         }
         Desire::Socialize => {
             let urgency = 1.0 - basic_needs.social;
             let max_urgency = 1.0 - thresholds.social_threshold.high_threshold.min(0.999);
-            (urgency / max_urgency.max(0.001)) * thresholds.priority_weights.social
+            (urgency / max_urgency.max(0.001)) * weights.social
         }
         Desire::Wander => 0.5, // Low utility for wandering
+        Desire::Custom(_) => 0.0, // Rated from CustomNeeds by calculate_custom_desire_utility
     }
 }
 
+/// Helper function rating a registered custom desire with the same formula as the built-in ones
+pub fn calculate_custom_desire_utility(definition: &CustomDesireDefinition, need_level: f32) -> f32 {
+    let urgency = 1.0 - need_level;
+    let max_urgency = 1.0 - definition.threshold.high_threshold.min(0.999);
+    (urgency / max_urgency.max(0.001)) * definition.weight
+}
+
+/// Helper function finding the most urgent registered custom desire of an agent, if any has a positive utility
+pub fn evaluate_most_urgent_custom_desire(registry: &CustomDesireRegistry, custom_needs: &CustomNeeds) -> Option<(Desire, f32)> {
+    registry
+        .iter()
+        .map(|(id, definition)| (Desire::Custom(id), calculate_custom_desire_utility(definition, custom_needs.value(id))))
+        .filter(|(_, utility)| *utility > 0.0)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+}
+
 /// Helper function to check if a desire should be activated (need value < high_threshold)
 /// FIXED: Now activates when satisfaction is BELOW threshold (need is urgent)
 pub fn should_activate_desire(desire: Desire, basic_needs: &BasicNeeds, thresholds: &DesireThresholds) -> bool {
@@ -164,19 +536,28 @@ pub fn should_activate_desire(desire: Desire, basic_needs: &BasicNeeds, threshol
     }
 }
 
-/// Helper function to check if a desire should be deactivated (need value > low_threshold)
-/// FIXED: Now deactivates when satisfaction is ABOVE low threshold (need is satisfied enough)
+/// Helper function to check if a desire should be deactivated (need value >= high_threshold)
+/// Hysteresis: a desire switched on below high_threshold stays on until its own need is back at or above
+/// high_threshold, so wobbling around low_threshold (or another need recovering) never switches it off
 pub fn should_deactivate_desire(desire: Desire, basic_needs: &BasicNeeds, thresholds: &DesireThresholds) -> bool {
     match desire {
-        Desire::FindSafety => basic_needs.safety > thresholds.safety_threshold.low_threshold,
-        Desire::FindWater => basic_needs.thirst > thresholds.thirst_threshold.low_threshold,
-        Desire::FindFood => basic_needs.hunger > thresholds.hunger_threshold.low_threshold,
-        Desire::Rest => basic_needs.rest > thresholds.rest_threshold.low_threshold,
-        Desire::Socialize => basic_needs.social > thresholds.social_threshold.low_threshold,
+        Desire::FindSafety => basic_needs.safety >= thresholds.safety_threshold.high_threshold,
+        Desire::FindWater => basic_needs.thirst >= thresholds.thirst_threshold.high_threshold,
+        Desire::FindFood => basic_needs.hunger >= thresholds.hunger_threshold.high_threshold,
+        Desire::Rest => basic_needs.rest >= thresholds.rest_threshold.high_threshold,
+        Desire::Socialize => basic_needs.social >= thresholds.social_threshold.high_threshold,
         _ => false,
     }
 }
 
+/// Helper function checking whether every basic need sits at or above its high threshold
+/// Used to tell a fully satisfied agent, which can relax in place, from one that is merely wandering
+pub fn are_all_needs_satisfied(basic_needs: &BasicNeeds, thresholds: &DesireThresholds) -> bool {
+    [Desire::FindFood, Desire::FindWater, Desire::Rest, Desire::FindSafety, Desire::Socialize]
+        .into_iter()
+        .all(|desire| !should_activate_desire(desire, basic_needs, thresholds))
+}
+
 /// Helper function to decrease social satisfaction (no longer needed but kept for compatibility)
 /// System based on Social Exchange Theory - positive interactions increase social satisfaction
 /// All values are normalized between 0.0-1.0
@@ -186,3 +567,30 @@ pub fn decrease_loneliness(needs: &mut BasicNeeds, amount: f32) -> f32 {
     needs.social = (needs.social + amount).clamp(0.0, 1.0);
     needs.social - old_social // Return actual change for ML tracking
 }
+
+/// Helper function to bring a need value back into the normalized 0.0-1.0 range
+/// Non-finite values are treated as fully depleted
+/// Returns true when the value had to be corrected
+pub fn sanitize_need_value(value: &mut f32) -> bool {
+    let corrected = if value.is_finite() { value.clamp(0.0, 1.0) } else { 0.0 };
+    let changed = corrected.to_bits() != value.to_bits();
+    *value = corrected;
+    changed
+}
+
+/// Helper function to enforce the hysteresis invariant of a dual threshold
+/// Both thresholds are clamped into 0.0-1.0 and swapped when low_threshold > high_threshold
+/// Returns true when the threshold had to be corrected
+pub fn sanitize_dual_threshold(threshold: &mut DualThreshold) -> bool {
+    let original = (threshold.low_threshold, threshold.high_threshold);
+
+    let mut low = if threshold.low_threshold.is_finite() { threshold.low_threshold.clamp(0.0, 1.0) } else { 0.0 };
+    let mut high = if threshold.high_threshold.is_finite() { threshold.high_threshold.clamp(0.0, 1.0) } else { 1.0 };
+    if low > high {
+        std::mem::swap(&mut low, &mut high);
+    }
+
+    threshold.low_threshold = low;
+    threshold.high_threshold = high;
+    original != (low, high)
+}
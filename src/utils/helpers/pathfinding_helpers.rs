@@ -1,6 +1,7 @@
-use crate::components::components_npc::Npc;
-use crate::components::components_pathfinding::{PathTarget, SteeringBehavior};
+use crate::components::components_environment::ResourceType;
+use crate::components::components_pathfinding::{MemoryTrace, PathTarget, PhysicalContacts, ResourceMemory, SteeringBehavior};
 use bevy::prelude::*;
+use rand::Rng;
 
 /// Helper function implementing Craig Reynolds' Seek steering behavior
 /// Based on Boids algorithm and steering behaviors for autonomous agents
@@ -16,20 +17,31 @@ pub fn calculate_seek_force(
     steering_force.clamp_length_max(max_force)
 }
 
+/// Helper function moving a velocity toward a desired one by at most max_acceleration * delta_time
+/// Based on Newtonian mechanics - bounded force means bounded change in velocity per step
+pub fn limit_velocity_change(current_velocity: Vec2, desired_velocity: Vec2, max_acceleration: f32, delta_time: f32) -> Vec2 {
+    current_velocity + (desired_velocity - current_velocity).clamp_length_max(max_acceleration * delta_time)
+}
+
 /// Helper function implementing Wander steering behavior for autonomous movement
 /// Based on Craig Reynolds' autonomous agent behaviors for emergent movement patterns
+/// The random heading is blended with a boundary avoidance bias so agents drift back from the walls
 pub fn calculate_wander_force(
     steering_behavior: &mut SteeringBehavior,
+    current_position: Vec2,
+    room_half_extents: Vec2,
+    boundary_margin: f32,
     current_velocity: Vec2,
     max_speed: f32,
     max_force: f32,
     wander_radius: f32,
     wander_distance: f32,
     delta_time: f32,
+    rng: &mut impl Rng,
 ) -> Vec2 {
     // Update wander angle with random variation scaled by delta_time for frame-rate independence
     // This ensures consistent wandering behavior regardless of FPS
-    steering_behavior.wander_angle += (rand::random::<f32>() - 0.5) * steering_behavior.wander_angle_change * delta_time;
+    steering_behavior.wander_angle += (rng.random::<f32>() - 0.5) * steering_behavior.wander_angle_change * delta_time;
 
     // Calculate circle center in front of agent
     let circle_center = current_velocity.normalize_or_zero() * wander_distance;
@@ -41,12 +53,48 @@ pub fn calculate_wander_force(
     );
 
     let wander_target = circle_center + displacement;
-    let desired_velocity = wander_target.normalize_or_zero() * max_speed;
+    let boundary_bias = calculate_boundary_avoidance_bias(current_position, room_half_extents, boundary_margin);
+    let desired_velocity = (wander_target.normalize_or_zero() + boundary_bias).normalize_or_zero() * max_speed;
     let steering_force = desired_velocity - current_velocity;
 
     steering_force.clamp_length_max(max_force)
 }
 
+/// Helper function computing a soft push away from the room walls
+/// Each axis ramps linearly from 0.0 at `margin` away from a wall to 1.0 at the wall itself,
+/// pointing back toward the interior, so agents far from walls are unaffected
+pub fn calculate_boundary_avoidance_bias(position: Vec2, room_half_extents: Vec2, margin: f32) -> Vec2 {
+    let margin = margin.max(f32::EPSILON);
+    let axis_bias = |coordinate: f32, half_extent: f32| {
+        let from_min = coordinate + half_extent;
+        let from_max = half_extent - coordinate;
+        let push_positive = (1.0 - from_min / margin).clamp(0.0, 1.0);
+        let push_negative = (1.0 - from_max / margin).clamp(0.0, 1.0);
+        push_positive - push_negative
+    };
+
+    Vec2::new(
+        axis_bias(position.x, room_half_extents.x),
+        axis_bias(position.y, room_half_extents.y),
+    )
+}
+
+/// Helper function checking whether an agent has stopped making headway
+/// True once a whole progress window has passed with less than `min_progress` distance covered
+pub fn is_stuck(current_position: Vec2, anchor_position: Vec2, elapsed_since_anchor: f32, window: f32, min_progress: f32) -> bool {
+    elapsed_since_anchor >= window && current_position.distance(anchor_position) < min_progress
+}
+
+/// Helper function choosing the impulse that knocks a stuck agent loose
+/// Points sideways from the heading toward the target, on a random side and with a random
+/// forward or backward lean, so repeated escapes from the same spot try different directions
+pub fn calculate_escape_impulse(current_position: Vec2, target_position: Vec2, speed: f32, rng: &mut impl Rng) -> Vec2 {
+    let heading = (target_position - current_position).normalize_or(Vec2::Y);
+    let side = if rng.random::<bool>() { heading.perp() } else { -heading.perp() };
+    let lean = rng.random_range(-0.5..=0.5);
+    (side + heading * lean).normalize_or_zero() * speed
+}
+
 /// Helper function to find nearest resource of a specific type
 /// Based on Spatial Cognition Theory - agents use spatial memory for resource location
 pub fn find_nearest_resource_position(
@@ -63,6 +111,130 @@ pub fn find_nearest_resource_position(
         .copied()
 }
 
+/// Helper function estimating how long satisfying a need would take: travel to the nearest remembered
+/// resource of any kind plus the refill itself, capped at max_horizon
+/// An agent that remembers nothing would have to search, so it is given the full horizon
+pub fn estimate_lookahead_horizon(
+    agent_position: Vec2,
+    memory: &ResourceMemory,
+    speed: f32,
+    refill_secs: f32,
+    max_horizon: f32,
+) -> f32 {
+    let known = [&memory.known_wells, &memory.known_restaurants, &memory.known_hotels, &memory.known_safe_zones];
+    let nearest_distance = known
+        .into_iter()
+        .filter_map(|positions| find_nearest_resource_position(agent_position, positions))
+        .map(|position| agent_position.distance(position))
+        .min_by(f32::total_cmp);
+
+    match nearest_distance {
+        Some(distance) => (distance / speed.max(f32::EPSILON) + refill_secs).min(max_horizon),
+        None => max_horizon,
+    }
+}
+
+/// Helper function computing how much of a memory is retained after `age` seconds
+/// Based on Ebbinghaus' forgetting curve R = e^(-t/S) - a stronger trace fades more slowly
+pub fn memory_retention(age: f32, strength: f32, decay_rate: f32) -> f32 {
+    (-decay_rate * age.max(0.0) / strength.max(f32::EPSILON)).exp()
+}
+
+/// Helper function recording that an agent saw a resource at `position`, returns true if it was new to it
+/// A known location is reinforced instead, and more so the longer it went unconfirmed (the spacing effect),
+/// so standing next to a resource does not strengthen it much more than passing by once
+/// Learning a location beyond capacity_per_type forgets the least retained one of that type,
+/// the earliest learned when several have faded equally
+pub fn remember_resource_location(memory: &mut ResourceMemory, resource_type: ResourceType, position: Vec2, current_time: f32) -> bool {
+    let ResourceMemory { known_wells, known_restaurants, known_hotels, known_safe_zones, known_custom, memory_decay_rate, capacity_per_type, traces, .. } = memory;
+    let known = match resource_type {
+        ResourceType::Water => known_wells,
+        ResourceType::Food => known_restaurants,
+        ResourceType::Rest => known_hotels,
+        ResourceType::Safety => known_safe_zones,
+        ResourceType::Custom(id) => known_custom.entry(id).or_default(),
+        // Social gathering spaces are never remembered as places
+        ResourceType::Loneliness => return false,
+    };
+    let is_trace_of = |trace: &MemoryTrace, location: Vec2| trace.resource_type == resource_type && trace.position == location;
+
+    if known.contains(&position) {
+        match traces.iter_mut().find(|trace| is_trace_of(trace, position)) {
+            Some(trace) => {
+                trace.strength += ((current_time - trace.last_confirmed) * *memory_decay_rate).clamp(0.0, 1.0);
+                trace.last_confirmed = current_time;
+            }
+            // Locations inherited or handed over without a trace start one when first confirmed
+            None => traces.push(MemoryTrace { position, resource_type, last_confirmed: current_time, strength: 1.0 }),
+        }
+        return false;
+    }
+
+    known.push(position);
+    traces.push(MemoryTrace { position, resource_type, last_confirmed: current_time, strength: 1.0 });
+
+    if known.len() > *capacity_per_type {
+        // Locations without a trace count as learned at the start of the run and never confirmed
+        let retention = |location: Vec2| {
+            traces.iter().find(|trace| is_trace_of(trace, location)).map_or_else(
+                || memory_retention(current_time, 1.0, *memory_decay_rate),
+                |trace| memory_retention(current_time - trace.last_confirmed, trace.strength, *memory_decay_rate),
+            )
+        };
+        let forgotten = (0..known.len())
+            .min_by(|a, b| retention(known[*a]).total_cmp(&retention(known[*b])))
+            .map(|index| known.remove(index));
+        if let Some(forgotten) = forgotten {
+            traces.retain(|trace| !is_trace_of(trace, forgotten));
+        }
+    }
+    true
+}
+
+/// Helper function passing up to `limit` locations the receiver does not know yet from one agent's memory to another's
+/// Locations are offered type by type (water, food, rest, safety, then custom sites by id) in the order the giver
+/// learned them, and are remembered as if seen at `current_time`, so the receiver's capacity still applies
+/// Hearing of a place the receiver already knows reinforces it without counting toward the limit
+/// Returns how many locations were new to the receiver
+pub fn share_resource_locations(giver: &ResourceMemory, receiver: &mut ResourceMemory, limit: usize, current_time: f32) -> usize {
+    let mut custom_ids: Vec<u8> = giver.known_custom.keys().copied().collect();
+    custom_ids.sort_unstable();
+
+    let offered = [
+        (ResourceType::Water, &giver.known_wells),
+        (ResourceType::Food, &giver.known_restaurants),
+        (ResourceType::Rest, &giver.known_hotels),
+        (ResourceType::Safety, &giver.known_safe_zones),
+    ]
+    .into_iter()
+    .chain(custom_ids.into_iter().map(|id| (ResourceType::Custom(id), &giver.known_custom[&id])))
+    .flat_map(|(resource_type, known)| known.iter().map(move |&position| (resource_type, position)));
+
+    let mut shared = 0;
+    for (resource_type, position) in offered {
+        if shared >= limit {
+            break;
+        }
+        if remember_resource_location(receiver, resource_type, position, current_time) {
+            shared += 1;
+        }
+    }
+    shared
+}
+
+/// Helper function converting a world position into integer grid coordinates
+/// Shared by every grid-based spatial structure so they agree on cell boundaries
+pub fn world_to_cell(position: Vec2, cell_size: f32) -> IVec2 {
+    (position / cell_size.max(f32::EPSILON)).floor().as_ivec2()
+}
+
+/// Helper function giving the distance at which an agent counts as having reached a target
+/// Centre-to-centre distance at which the two bodies touch, so large resources are reached from farther
+/// out and small ones need a closer approach
+pub fn calculate_arrival_threshold(target_radius: f32, agent_radius: f32) -> f32 {
+    target_radius.max(0.0) + agent_radius.max(0.0)
+}
+
 /// Helper function to check if target has been reached
 /// Based on Goal-Oriented Action Planning - determines successful target arrival
 pub fn has_reached_target(current_position: Vec2, target: &PathTarget) -> bool {
@@ -73,6 +245,12 @@ pub fn has_reached_target(current_position: Vec2, target: &PathTarget) -> bool {
     current_position.distance(target.target_position) <= target.arrival_threshold
 }
 
+/// Helper function to check if the agent's body is touching the entity it is navigating to
+/// Contact is authoritative: it counts as arrival even when the distance check has not caught up yet
+pub fn is_touching_target(target: &PathTarget, contacts: &PhysicalContacts) -> bool {
+    target.has_target && target.target_entity.is_some_and(|entity| contacts.touching.contains(&entity))
+}
+
 /// Helper function to check if pursuit should timeout
 /// Based on Behavioral Economics - prevents infinite pursuit of unreachable goals
 pub fn should_timeout_pursuit(target: &PathTarget, current_time: f32) -> bool {
@@ -82,23 +260,3 @@ pub fn should_timeout_pursuit(target: &PathTarget, current_time: f32) -> bool {
 
     (current_time - target.target_set_time) > target.max_pursuit_time
 }
-
-/// Helper function to find the nearest NPC position for social interaction
-/// Based on Social Psychology - agents seek proximity to others for social needs
-pub fn find_nearest_npc_position(
-    self_entity: Entity,
-    current_position: Vec2,
-    other_npcs_query: &Query<(Entity, &Transform), (With<Npc>, Without<PathTarget>)>,
-) -> Option<Vec2> {
-    other_npcs_query
-        .iter()
-        .filter(|(entity, _)| *entity != self_entity) // Don't target self
-        .min_by(|(_, transform_a), (_, transform_b)| {
-            let pos_a = transform_a.translation.truncate();
-            let pos_b = transform_b.translation.truncate();
-            let dist_a = current_position.distance(pos_a);
-            let dist_b = current_position.distance(pos_b);
-            dist_a.partial_cmp(&dist_b).unwrap_or(std::cmp::Ordering::Equal)
-        })
-        .map(|(_, transform)| transform.translation.truncate())
-}
\ No newline at end of file
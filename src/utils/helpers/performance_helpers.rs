@@ -0,0 +1,60 @@
+use crate::components::components_performance::{AgentLodConfig, HistogramBucket, ProfilerConfig, ProfilerReport, SystemTimings, TimingSummary};
+
+/// Helper function giving the nearest-rank percentile of ascending samples
+/// `fraction` is the percentile as 0.0-1.0; empty series give 0.0
+pub fn nearest_rank_percentile(sorted_samples: &[f32], fraction: f32) -> f32 {
+    if sorted_samples.is_empty() {
+        return 0.0;
+    }
+    let rank = (fraction.clamp(0.0, 1.0) * sorted_samples.len() as f32).ceil() as usize;
+    sorted_samples[rank.clamp(1, sorted_samples.len()) - 1]
+}
+
+/// Helper function summarising a series of timing samples
+pub fn summarize_timings(name: &str, samples: &[f32]) -> TimingSummary {
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(f32::total_cmp);
+
+    TimingSummary {
+        name: name.to_string(),
+        samples: sorted.len(),
+        mean_ms: if sorted.is_empty() { 0.0 } else { sorted.iter().sum::<f32>() / sorted.len() as f32 },
+        p95_ms: nearest_rank_percentile(&sorted, 0.95),
+        max_ms: sorted.last().copied().unwrap_or(0.0),
+    }
+}
+
+/// Helper function counting frames into fixed-width buckets
+/// The last bucket is open-ended so every frame is counted exactly once
+pub fn calculate_frame_time_histogram(frame_times_ms: &[f32], bucket_ms: f32, buckets: usize) -> Vec<HistogramBucket> {
+    let bucket_ms = bucket_ms.max(f32::EPSILON);
+    let buckets = buckets.max(1);
+
+    let mut histogram: Vec<HistogramBucket> = (0..buckets)
+        .map(|index| HistogramBucket {
+            from_ms: index as f32 * bucket_ms,
+            to_ms: if index + 1 == buckets { f32::INFINITY } else { (index + 1) as f32 * bucket_ms },
+            frames: 0,
+        })
+        .collect();
+    for &frame_time in frame_times_ms {
+        let index = ((frame_time.max(0.0) / bucket_ms) as usize).min(buckets - 1);
+        histogram[index].frames += 1;
+    }
+    histogram
+}
+
+/// Helper function building the profiler report from the collected timings
+pub fn build_profiler_report(timings: &SystemTimings, config: &ProfilerConfig) -> ProfilerReport {
+    ProfilerReport {
+        systems: timings.samples.iter().map(|(name, samples)| summarize_timings(name, samples)).collect(),
+        frame_time: summarize_timings("frame", &timings.frame_times_ms),
+        frame_time_histogram: calculate_frame_time_histogram(&timings.frame_times_ms, config.histogram_bucket_ms, config.histogram_buckets),
+    }
+}
+
+/// Helper function choosing how many ticks apart expensive systems update an agent
+/// Every agent updates every tick unless the governor is degraded, so the stride is the same for the whole population
+pub fn calculate_lod_stride(degraded: bool, config: &AgentLodConfig) -> u32 {
+    if config.enabled && degraded { config.degraded_stride.max(1) } else { 1 }
+}
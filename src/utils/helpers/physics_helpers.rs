@@ -0,0 +1,85 @@
+use crate::components::components_constants::GameConstants;
+use crate::components::components_npc::BodySize;
+use bevy_rapier2d::prelude::{Ccd, Collider, CollisionGroups, Group, TimestepMode};
+
+// Collision layer scheme for every physical body in the simulation
+// Based on Rapier's membership/filter bitmasks - two colliders only interact when each one's
+// memberships intersect the other's filters
+
+/// Collision layer of all NPC bodies
+pub const NPC_GROUP: Group = Group::GROUP_1;
+/// Collision layer of environmental resources (wells, restaurants, hotels, safe zones)
+pub const RESOURCE_GROUP: Group = Group::GROUP_2;
+/// Collision layer of static walls and obstacles
+pub const WALL_GROUP: Group = Group::GROUP_3;
+
+/// Collision groups for NPCs - they touch each other, resources and walls
+pub fn npc_collision_groups() -> CollisionGroups {
+    CollisionGroups::new(NPC_GROUP, NPC_GROUP | RESOURCE_GROUP | WALL_GROUP)
+}
+
+/// Collision groups for resources - only NPCs can reach them, resources never touch each other
+pub fn resource_collision_groups() -> CollisionGroups {
+    CollisionGroups::new(RESOURCE_GROUP, NPC_GROUP)
+}
+
+/// Collision groups for walls - they only block NPCs
+pub fn wall_collision_groups() -> CollisionGroups {
+    CollisionGroups::new(WALL_GROUP, NPC_GROUP)
+}
+
+/// Helper function to check whether a contact happened between two NPC bodies
+/// Colliders without collision groups are never considered NPCs
+pub fn is_npc_npc_contact(groups_a: Option<&CollisionGroups>, groups_b: Option<&CollisionGroups>) -> bool {
+    matches!(
+        (groups_a, groups_b),
+        (Some(a), Some(b)) if a.memberships.contains(NPC_GROUP) && b.memberships.contains(NPC_GROUP)
+    )
+}
+
+/// Helper function giving the radius a collider reaches out from its centre
+/// Balls report their radius and cuboids their larger half extent; other shapes return None
+pub fn collider_radius(collider: &Collider) -> Option<f32> {
+    collider
+        .as_ball()
+        .map(|ball| ball.radius())
+        .or_else(|| collider.as_cuboid().map(|cuboid| cuboid.half_extents().max_element()))
+}
+
+/// Helper function deriving an agent's body from its size relative to the base NPC
+/// Radius scales linearly; top speed with the square root of size, following Froude scaling of
+/// walking speed with leg length (Alexander, 1976); damping inversely, since a bigger body has more
+/// momentum relative to the drag on its outline
+pub fn calculate_body_size(base_radius: f32, base_speed: f32, base_linear_damping: f32, scale: f32) -> BodySize {
+    let scale = scale.max(f32::EPSILON);
+    BodySize {
+        scale,
+        radius: base_radius * scale,
+        max_speed: base_speed * scale.sqrt(),
+        linear_damping: base_linear_damping / scale,
+    }
+}
+
+/// Helper function deriving the body of an NPC spawned with the current GameConstants
+pub fn npc_body_size(game_constants: &GameConstants) -> BodySize {
+    calculate_body_size(
+        game_constants.npc_radius,
+        game_constants.npc_speed,
+        game_constants.npc_linear_damping,
+        game_constants.npc_size_scale,
+    )
+}
+
+/// Helper function giving the continuous collision detection setting for NPC bodies
+pub fn npc_ccd(game_constants: &GameConstants) -> Ccd {
+    Ccd { enabled: game_constants.npc_ccd_enabled }
+}
+
+/// Helper function deriving Rapier's stepping from the fixed simulation tick
+/// Physics advances exactly one tick per FixedUpdate, split into physics_substeps equal steps
+pub fn physics_timestep_mode(game_constants: &GameConstants) -> TimestepMode {
+    TimestepMode::Fixed {
+        dt: 1.0 / game_constants.simulation_tick_hz,
+        substeps: game_constants.physics_substeps.max(1),
+    }
+}
@@ -1,3 +1,4 @@
+use crate::components::components_constants::ResourceContentionPolicy;
 use crate::components::components_environment::{Resource, ResourceType};
 use crate::components::components_needs::BasicNeeds;
 
@@ -15,6 +16,7 @@ pub fn calculate_satisfaction_gain(
         ResourceType::Rest => 0.3,    // Moderate satisfaction for recovery
         ResourceType::Safety => 0.35, // Moderate satisfaction for security
         ResourceType::Loneliness => 0.2,  // Lower but sustained satisfaction
+        ResourceType::Custom(_) => 0.0,   // Custom needs are restored by custom_needs_system
     };
 
     // Diminishing returns: satisfaction decreases as need is already fulfilled
@@ -38,6 +40,7 @@ pub fn calculate_consumption_rate(
         ResourceType::Rest => 0.0,    // Rest doesn't deplete (hotels are safe zones)
         ResourceType::Safety => 0.0,  // Safety doesn't deplete
         ResourceType::Loneliness => 0.05, // Light consumption of loneliness resources
+        ResourceType::Custom(_) => 0.0,   // Custom sites are never used up
     };
 
     // Higher urgency leads to higher consumption (desperate behavior)
@@ -46,6 +49,39 @@ pub fn calculate_consumption_rate(
     (base_consumption * urgency_multiplier).clamp(0.0, 0.2)
 }
 
+/// Helper function splitting a limited stock between claimants under a contention policy
+/// `demands` and `dominance` are given in arrival order; returns each claimant's allocation in the same order
+/// No claimant receives more than it asked for and the allocations never exceed the stock
+pub fn allocate_contested_stock(policy: ResourceContentionPolicy, stock: f32, demands: &[f32], dominance: &[f32]) -> Vec<f32> {
+    let mut allocations = vec![0.0; demands.len()];
+    let mut remaining = stock.max(0.0);
+
+    let mut order: Vec<usize> = (0..demands.len()).collect();
+    match policy {
+        ResourceContentionPolicy::FirstComeFirstServed => {}
+        // Stable sort, so equally dominant claimants keep their arrival order
+        ResourceContentionPolicy::DominanceBased => {
+            order.sort_by(|&a, &b| dominance.get(b).copied().unwrap_or(0.0).total_cmp(&dominance.get(a).copied().unwrap_or(0.0)));
+        }
+        // Smallest demands first, so the share they leave unused is spread over the rest
+        ResourceContentionPolicy::ShareEqually => {
+            order.sort_by(|&a, &b| demands[a].total_cmp(&demands[b]));
+            for (served, &index) in order.iter().enumerate() {
+                let share = remaining / (order.len() - served) as f32;
+                allocations[index] = demands[index].max(0.0).min(share);
+                remaining -= allocations[index];
+            }
+            return allocations;
+        }
+    }
+
+    for index in order {
+        allocations[index] = demands[index].max(0.0).min(remaining);
+        remaining -= allocations[index];
+    }
+    allocations
+}
+
 /// Helper function to calculate resource regeneration
 /// Based on Natural Resource Economics - different regeneration patterns
 pub fn calculate_regeneration_amount(
@@ -62,6 +98,7 @@ pub fn calculate_regeneration_amount(
         ResourceType::Rest => 0.0,    // Hotels don't regenerate capacity
         ResourceType::Safety => 0.0,  // Safety zones don't regenerate
         ResourceType::Loneliness => 0.03, // Social spaces recover quickly
+        ResourceType::Custom(_) => 0.0,   // Custom sites are never used up
     };
 
     let max_regeneration = 1.0 - resource.availability;
@@ -96,6 +133,8 @@ pub fn get_need_level_for_resource(
         ResourceType::Rest => needs.rest,
         ResourceType::Safety => needs.safety,
         ResourceType::Loneliness => needs.social,
+        // Custom needs live in CustomNeeds, so BasicNeeds never asks for more
+        ResourceType::Custom(_) => 1.0,
     }
 }
 
@@ -132,5 +171,6 @@ pub fn apply_satisfaction_to_needs(
             needs.social = (needs.social + satisfaction_amount).clamp(0.0, 1.0);
             needs.social - old_value
         }
+        ResourceType::Custom(_) => 0.0,
     }
 }
@@ -1,4 +1,6 @@
 use crate::components::components_npc::Personality;
+use rand::Rng;
+use std::collections::HashMap;
 
 /// Helper function implementing Social Influence Theory for rumor transmission
 /// Based on Katz & Lazarsfeld's Two-Step Flow Theory and Social Network Analysis
@@ -13,9 +15,8 @@ pub fn calculate_rumor_transmission_probability(
     let distance_factor = 1.0 / (1.0 + social_distance * 0.01);
 
     // Openness affects both transmission and reception
-    // High openness = more likely to share and believe new information
-    let sender_openness_factor = 0.5 + sender_personality.openness * 0.5;
-    let receiver_openness_factor = 0.5 + receiver_personality.openness * 0.5;
+    let sender_openness_factor = sender_personality.rumor_receptivity();
+    let receiver_openness_factor = receiver_personality.rumor_receptivity();
 
     // Combined transmission probability
     base_transmission_rate * distance_factor * sender_openness_factor * receiver_openness_factor
@@ -24,11 +25,33 @@ pub fn calculate_rumor_transmission_probability(
 /// Helper function to determine rumor spread success based on stochastic social dynamics
 /// Based on Threshold Models of Collective Behavior (Granovetter, 1978)
 /// Uses random sampling to simulate social uncertainty and individual differences
-pub fn should_rumor_spread(transmission_probability: f32) -> bool {
-    let random_value: f32 = rand::random();
+pub fn should_rumor_spread(transmission_probability: f32, rng: &mut impl Rng) -> bool {
+    let random_value: f32 = rng.random();
     random_value < transmission_probability.clamp(0.0, 1.0)
 }
 
+/// Helper function to determine whether a retelling alters the rumor
+/// Based on Serial Reproduction research (Bartlett, 1932) - stories change as they pass from person to person
+pub fn should_rumor_mutate(modification_probability: f32, rng: &mut impl Rng) -> bool {
+    let random_value: f32 = rng.random();
+    random_value < modification_probability.clamp(0.0, 1.0)
+}
+
+/// Helper function weakening belief in competing versions of a rumor an agent has just adopted
+/// Based on Retroactive Interference (McGeoch, 1932) - a newly learned account impairs similar older ones
+pub fn apply_rumor_interference(
+    known_rumors: &mut HashMap<String, f32>,
+    adopted_rumor: &str,
+    is_competing: impl Fn(&str) -> bool,
+    interference_strength: f32,
+) {
+    for (rumor, belief) in known_rumors.iter_mut() {
+        if rumor != adopted_rumor && is_competing(rumor) {
+            *belief *= 1.0 - interference_strength.clamp(0.0, 1.0);
+        }
+    }
+}
+
 /// Helper function to calculate rumor decay over time
 /// Based on Information Diffusion Theory - rumors lose credibility over time
 pub fn calculate_rumor_decay(current_belief: f32, decay_rate: f32, delta_time: f32) -> f32 {
@@ -0,0 +1,378 @@
+use crate::components::components_constants::{OpinionDynamicsConfig, SocialConfig};
+use crate::components::components_environment::ResourceType;
+use crate::components::components_needs::BasicNeeds;
+use crate::components::components_npc::{Mood, Personality, VisionRange};
+use crate::components::components_social::{ConflictCause, ConflictResolution, InteractionType, Relationship, SocialEnergy, SocialInfluence, SocialRelationships};
+use crate::utils::helpers::pathfinding_helpers::world_to_cell;
+use crate::utils::helpers::resource_helpers::get_need_level_for_resource;
+use crate::utils::helpers::vision_helpers::is_facing;
+use bevy::prelude::{Entity, IVec2, Transform, Vec2};
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// How strongly an interaction type moves affinity and trust
+/// Weights scale the satisfaction outcome, biases apply regardless of how the interaction went
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InteractionEffect {
+    /// Affinity change per unit of signed outcome
+    pub affinity_weight: f32,
+    /// Trust change per unit of signed outcome
+    pub trust_weight: f32,
+    /// Flat affinity change applied to every interaction of this type
+    pub affinity_bias: f32,
+    /// Flat trust change applied to every interaction of this type
+    pub trust_bias: f32,
+}
+
+/// Per-type effect table for social interactions
+/// Based on Social Exchange Theory (Homans, 1958) and Reciprocal Altruism (Trivers, 1971):
+/// - helping and emotional support are costly signals that build affinity and trust fastest
+/// - cooperation is evidence of reliability, so it builds trust specifically; so does information that proves useful
+/// - conflict always damages the relationship, a good resolution only softens the blow
+pub fn interaction_effect(interaction_type: InteractionType) -> InteractionEffect {
+    match interaction_type {
+        InteractionType::Greeting => InteractionEffect { affinity_weight: 0.02, trust_weight: 0.01, affinity_bias: 0.0, trust_bias: 0.0 },
+        InteractionType::Conversation => InteractionEffect { affinity_weight: 0.05, trust_weight: 0.02, affinity_bias: 0.0, trust_bias: 0.0 },
+        InteractionType::InformationSharing => InteractionEffect { affinity_weight: 0.03, trust_weight: 0.05, affinity_bias: 0.0, trust_bias: 0.0 },
+        InteractionType::Helping => InteractionEffect { affinity_weight: 0.10, trust_weight: 0.08, affinity_bias: 0.01, trust_bias: 0.01 },
+        InteractionType::EmotionalSupport => InteractionEffect { affinity_weight: 0.12, trust_weight: 0.06, affinity_bias: 0.01, trust_bias: 0.0 },
+        InteractionType::Cooperation => InteractionEffect { affinity_weight: 0.04, trust_weight: 0.10, affinity_bias: 0.0, trust_bias: 0.01 },
+        InteractionType::Conflict => InteractionEffect { affinity_weight: 0.05, trust_weight: 0.05, affinity_bias: -0.08, trust_bias: -0.06 },
+    }
+}
+
+/// Helper function updating a relationship after an interaction
+/// `satisfaction` is how the interaction felt (0.0 = awful, 0.5 = neutral, 1.0 = great)
+/// Returns the signed outcome so callers can report whether the interaction was positive
+pub fn update_relationship_from_interaction(
+    relationship: &mut Relationship,
+    interaction_type: InteractionType,
+    satisfaction: f32,
+    current_time: f32,
+) -> f32 {
+    let effect = interaction_effect(interaction_type);
+    let outcome = (satisfaction.clamp(0.0, 1.0) - 0.5) * 2.0;
+
+    relationship.affinity = (relationship.affinity + outcome * effect.affinity_weight + effect.affinity_bias).clamp(-1.0, 1.0);
+    relationship.trust = (relationship.trust + outcome * effect.trust_weight + effect.trust_bias).clamp(0.0, 1.0);
+
+    let net_change = outcome * (effect.affinity_weight + effect.trust_weight) + effect.affinity_bias + effect.trust_bias;
+    if net_change >= 0.0 {
+        relationship.positive_interactions += 1;
+    } else {
+        relationship.negative_interactions += 1;
+    }
+    relationship.last_interaction = current_time;
+
+    let interaction_count = (relationship.positive_interactions + relationship.negative_interactions).max(1) as f32;
+    relationship.mean_outcome += (outcome - relationship.mean_outcome) / interaction_count;
+
+    outcome
+}
+
+/// Helper function scoring how much an agent wants to interact with a partner again
+/// Based on Operant Conditioning (Skinner, 1938) - rewarding exchanges are sought out again
+/// Strangers score a neutral 0.0, so a bad history ranks below someone new and a good one above
+pub fn calculate_partner_preference(relationship: Option<&Relationship>) -> f32 {
+    relationship.map_or(0.0, |relationship| relationship.mean_outcome)
+}
+
+/// Helper function choosing what kind of interaction an agent offers its partner
+/// Based on Empathy-Altruism Hypothesis (Batson, 1991) - agreeable agents respond to visible need
+/// Acquaintances of a partner who is running low on something talk about where to find it
+pub fn choose_interaction_type(
+    actor_personality: &Personality,
+    partner_needs: &BasicNeeds,
+    shared_goal: bool,
+    already_acquainted: bool,
+) -> InteractionType {
+    let partner_physical_need = partner_needs.hunger.min(partner_needs.thirst).min(partner_needs.rest);
+
+    if actor_personality.agreeableness > 0.6 && partner_physical_need < 0.3 {
+        InteractionType::Helping
+    } else if actor_personality.agreeableness > 0.5 && partner_needs.social < 0.3 {
+        InteractionType::EmotionalSupport
+    } else if shared_goal {
+        InteractionType::Cooperation
+    } else if already_acquainted && partner_physical_need < 0.5 {
+        InteractionType::InformationSharing
+    } else if already_acquainted {
+        InteractionType::Conversation
+    } else {
+        InteractionType::Greeting
+    }
+}
+
+/// Helper function choosing which resource a helper could point a needy neighbour to
+/// Only an agreeable helper helps, and only with a need it has comfortably met itself; among the neighbour's
+/// critical needs the lowest one comes first. Returns None when there is nothing the helper can do
+pub fn choose_help_to_offer(helper_personality: &Personality, helper_needs: &BasicNeeds, needy_needs: &BasicNeeds, config: &SocialConfig) -> Option<ResourceType> {
+    if helper_personality.agreeableness < config.helping_min_agreeableness {
+        return None;
+    }
+
+    [ResourceType::Safety, ResourceType::Water, ResourceType::Food, ResourceType::Rest]
+        .into_iter()
+        .filter(|&resource_type| get_need_level_for_resource(needy_needs, resource_type) < config.helping_critical_need)
+        .filter(|&resource_type| get_need_level_for_resource(helper_needs, resource_type) >= config.helping_satisfied_need)
+        .min_by(|a, b| get_need_level_for_resource(needy_needs, *a).total_cmp(&get_need_level_for_resource(needy_needs, *b)))
+}
+
+/// Helper function estimating how satisfying an interaction is for an agent
+/// Based on Similarity-Attraction research - agreeable, extraverted pairs enjoy contact more
+pub fn calculate_interaction_satisfaction(own: &Personality, partner: &Personality) -> f32 {
+    let warmth = (own.extraversion + partner.agreeableness) / 2.0;
+    let friction = own.neuroticism * (1.0 - partner.agreeableness);
+    (0.5 + warmth * 0.5 - friction * 0.5).clamp(0.0, 1.0)
+}
+
+/// Helper function colouring an interaction's satisfaction with both participants' moods
+/// Based on Emotional Contagion (Hatfield et al., 1993) - the actor's own valence shapes the experience most,
+/// and the partner's rubs off, so two unhappy agents have a worse exchange and a cheerful one lifts a neutral one;
+/// arousal intensifies whichever way the valence points
+pub fn apply_mood_to_satisfaction(satisfaction: f32, own: &Mood, partner: &Mood) -> f32 {
+    let valence = 0.6 * own.valence.clamp(-1.0, 1.0) + 0.4 * partner.valence.clamp(-1.0, 1.0);
+    let intensity = 1.0 + 0.5 * (own.arousal + partner.arousal).clamp(0.0, 2.0) / 2.0;
+    (satisfaction + 0.25 * valence * intensity).clamp(0.0, 1.0)
+}
+
+/// Helper function scaling how hard an agent's recent social life weighs on its SocialEnergy
+/// Interactions drain energy this many times faster, and solitude restores it this many times slower
+pub fn calculate_social_fatigue(recent_interactions: usize, fatigue_per_interaction: f32) -> f32 {
+    1.0 + fatigue_per_interaction.max(0.0) * recent_interactions as f32
+}
+
+/// Trust below which acquainted agents who also dislike each other clash on contact
+pub const CONFLICT_TRUST_THRESHOLD: f32 = 0.15;
+/// Resource capacity below which agents competing for it may clash
+pub const SCARCE_RESOURCE_THRESHOLD: f32 = 0.2;
+
+/// Helper function deciding whether a contact escalates to conflict
+/// Based on Realistic Conflict Theory (Sherif, 1966) - competition for scarce resources breeds hostility
+pub fn detect_conflict(
+    relationship_1: Option<&Relationship>,
+    relationship_2: Option<&Relationship>,
+    contested_resource_capacity: Option<f32>,
+) -> Option<ConflictCause> {
+    if contested_resource_capacity.is_some_and(|capacity| capacity < SCARCE_RESOURCE_THRESHOLD) {
+        return Some(ConflictCause::ScarceResource);
+    }
+
+    let distrustful = |relationship: Option<&Relationship>| {
+        relationship.is_some_and(|r| r.trust < CONFLICT_TRUST_THRESHOLD && r.affinity < 0.0)
+    };
+    (distrustful(relationship_1) && distrustful(relationship_2)).then_some(ConflictCause::MutualDistrust)
+}
+
+/// Helper function resolving a conflict and returning each side's satisfaction
+/// Agreeable, emotionally stable pairs compromise; otherwise the more assertive agent prevails
+pub fn resolve_conflict(personality_1: &Personality, personality_2: &Personality) -> (ConflictResolution, f32, f32) {
+    let cooperativeness = (personality_1.agreeableness + personality_2.agreeableness) / 2.0;
+    let volatility = (personality_1.neuroticism + personality_2.neuroticism) / 2.0;
+
+    if cooperativeness - volatility * 0.5 > 0.35 {
+        return (ConflictResolution::Compromise, 0.4, 0.4);
+    }
+
+    let assertiveness_1 = personality_1.extraversion - personality_1.agreeableness;
+    let assertiveness_2 = personality_2.extraversion - personality_2.agreeableness;
+    if assertiveness_1 >= assertiveness_2 {
+        (ConflictResolution::Avoidance, 0.6, 0.1)
+    } else {
+        (ConflictResolution::Avoidance, 0.1, 0.6)
+    }
+}
+
+/// Helper function putting an interacting pair into a canonical order (lowest entity first)
+/// Physics reports contact pairs in whatever order the broad phase found them, so ordering them
+/// here keeps event fields identical across replays of the same seeded run
+pub fn ordered_entity_pair(first: Entity, second: Entity) -> (Entity, Entity) {
+    if first <= second { (first, second) } else { (second, first) }
+}
+
+/// Helper function dropping contact starts for pairs that already started one within `window` seconds
+/// Bodies jittering against each other re-report contact; only accepted starts are timestamped, so rejected
+/// ones cannot keep sliding the window forward
+pub fn debounce_contact_starts(contacts: &mut Vec<(Entity, Entity)>, last_contact: &mut HashMap<(Entity, Entity), f32>, current_time: f32, window: f32) {
+    last_contact.retain(|_, started_at| current_time - *started_at < window);
+    contacts.retain(|pair| match last_contact.entry(*pair) {
+        Entry::Occupied(_) => false,
+        Entry::Vacant(entry) => {
+            entry.insert(current_time);
+            true
+        }
+    });
+}
+
+/// Helper function ordering contacts so the most mutually preferred pairs claim their agents first
+/// `preference` scores one side's wish to interact with the other (see calculate_partner_preference);
+/// the sort is stable, so the incoming entity order breaks ties
+pub fn rank_contacts_by_preference(contacts: &mut [(Entity, Entity)], preference: impl Fn(Entity, Entity) -> f32) {
+    contacts.sort_by(|(a1, a2), (b1, b2)| {
+        let score_a = preference(*a1, *a2) + preference(*a2, *a1);
+        let score_b = preference(*b1, *b2) + preference(*b2, *b1);
+        score_b.total_cmp(&score_a)
+    });
+}
+
+/// Helper function checking whether social fatigue makes an agent turn a contact down
+/// Only applies with SocialConfig::social_fatigue; agents without SocialEnergy never tire
+pub fn is_socially_drained(energy: Option<&SocialEnergy>, config: &SocialConfig) -> bool {
+    config.social_fatigue && energy.is_some_and(|energy| energy.level < config.min_social_energy)
+}
+
+/// Helper function checking whether an agent sees its would-be partner, so a bump from behind is no greeting
+/// Agents without a Transform or VisionRange are not gated; bodies in contact leave no room for an occluding wall
+pub fn sees_partner(viewer: Option<&Transform>, vision: Option<&VisionRange>, partner: Option<&Transform>) -> bool {
+    match (viewer, vision, partner) {
+        (Some(viewer), Some(vision), Some(partner)) => is_facing(viewer, vision, partner.translation.truncate()),
+        _ => true,
+    }
+}
+
+/// Helper function returning the chance that a contact becomes an interaction
+/// The more extraverted agent decides (see Personality::interaction_initiation_chance); agents without a Personality always engage
+pub fn calculate_initiation_chance(personality_1: Option<&Personality>, personality_2: Option<&Personality>) -> f32 {
+    personality_1
+        .map_or(1.0, Personality::interaction_initiation_chance)
+        .max(personality_2.map_or(1.0, Personality::interaction_initiation_chance))
+}
+
+/// Helper function judging an interaction from one side: the type it offers and how satisfying it feels
+/// Agents without a Personality find every exchange neutral; both moods colour the satisfaction (see apply_mood_to_satisfaction)
+pub fn judge_interaction(
+    own: Option<&Personality>,
+    partner: Option<&Personality>,
+    partner_needs: Option<&BasicNeeds>,
+    shared_goal: bool,
+    acquainted: bool,
+    moods: (&Mood, &Mood),
+) -> (InteractionType, f32) {
+    let Some(own) = own else {
+        return (InteractionType::default(), 0.5);
+    };
+    let interaction_type = partner_needs.map_or(InteractionType::default(), |partner_needs| choose_interaction_type(own, partner_needs, shared_goal, acquainted));
+    let satisfaction = partner.map_or(0.5, |partner| calculate_interaction_satisfaction(own, partner));
+    (interaction_type, apply_mood_to_satisfaction(satisfaction, moods.0, moods.1))
+}
+
+/// Helper function applying one bounded-confidence exchange between two attitudes
+/// Based on the Deffuant-Weisbuch model: attitudes closer than confidence_bound each move convergence_rate of
+/// the gap toward the other; attitudes further apart push away by repulsion_rate of the gap, clamped to 0.0-1.0
+pub fn apply_bounded_confidence(own: f32, other: f32, config: &OpinionDynamicsConfig) -> (f32, f32) {
+    let gap = other - own;
+    let shift = if gap.abs() < config.confidence_bound {
+        gap * config.convergence_rate
+    } else {
+        -gap * config.repulsion_rate
+    };
+    ((own + shift).clamp(0.0, 1.0), (other - shift).clamp(0.0, 1.0))
+}
+
+/// Helper function computing the local network density around an agent
+/// Based on the Local Clustering Coefficient (Watts & Strogatz, 1998): links among the agent's contacts
+/// divided by the k(k-1)/2 links possible; a link exists if either contact knows the other
+/// Returns 0.0 when there are fewer than two contacts, since no link among them is possible
+pub fn calculate_local_network_density(contacts: &[Entity], knows: impl Fn(Entity, Entity) -> bool) -> f32 {
+    let count = contacts.len();
+    if count < 2 {
+        return 0.0;
+    }
+
+    let links = contacts
+        .iter()
+        .enumerate()
+        .flat_map(|(index, first)| contacts[index + 1..].iter().map(move |second| (*first, *second)))
+        .filter(|(first, second)| knows(*first, *second) || knows(*second, *first))
+        .count();
+
+    links as f32 / (count * (count - 1) / 2) as f32
+}
+
+/// Helper function scoring every agent's influence from the relationships others hold toward it
+/// Each incoming relationship contributes (trust + positive affinity) / 2, averaged over the other agents in the
+/// population, so an agent everyone trusts and likes scores 1.0; relationships toward agents outside the
+/// population are ignored. Agents at or above leadership_ratio times the mean score are potential leaders
+pub fn calculate_social_influence<'a>(
+    networks: impl IntoIterator<Item = (Entity, &'a SocialRelationships)>,
+    leadership_ratio: f32,
+) -> HashMap<Entity, SocialInfluence> {
+    let networks: Vec<(Entity, &SocialRelationships)> = networks.into_iter().collect();
+    let mut influence: HashMap<Entity, SocialInfluence> =
+        networks.iter().map(|(agent, _)| (*agent, SocialInfluence::default())).collect();
+    let others = networks.len().saturating_sub(1).max(1) as f32;
+
+    for (_, relationships) in &networks {
+        for (target, relationship) in &relationships.relationships {
+            if let Some(target_influence) = influence.get_mut(target) {
+                target_influence.in_degree += 1;
+                target_influence.score += (relationship.trust.clamp(0.0, 1.0) + relationship.affinity.clamp(0.0, 1.0)) / 2.0 / others;
+            }
+        }
+    }
+
+    let mean = influence.values().map(|agent| agent.score).sum::<f32>() / influence.len().max(1) as f32;
+    for agent in influence.values_mut() {
+        agent.is_potential_leader = agent.score > 0.0 && agent.score >= mean * leadership_ratio;
+    }
+
+    influence
+}
+
+/// Helper function grouping agents into gatherings from who perceives whom
+/// Two agents are linked only when each has the other in sight, and every connected cluster of at least
+/// `min_members` linked agents is one gathering; members and gatherings come out sorted by entity
+pub fn find_gatherings(sight: &HashMap<Entity, HashSet<Entity>>, min_members: usize) -> Vec<Vec<Entity>> {
+    let is_mutual = |a: Entity, b: Entity| sight.get(&b).is_some_and(|seen| seen.contains(&a));
+
+    let mut agents: Vec<Entity> = sight.keys().copied().collect();
+    agents.sort();
+
+    let mut visited = HashSet::new();
+    let mut gatherings = Vec::new();
+    for start in agents {
+        if !visited.insert(start) {
+            continue;
+        }
+
+        let mut members = vec![start];
+        let mut frontier = vec![start];
+        while let Some(agent) = frontier.pop() {
+            for &other in &sight[&agent] {
+                if is_mutual(agent, other) && visited.insert(other) {
+                    members.push(other);
+                    frontier.push(other);
+                }
+            }
+        }
+
+        if members.len() >= min_members.max(2) {
+            members.sort();
+            gatherings.push(members);
+        }
+    }
+    gatherings
+}
+
+/// Helper function finding every pair of agents within `distance` of each other
+/// Agents are bucketed into a uniform grid of `distance`-sized cells, so each is compared only with the agents in
+/// its own and the eight neighbouring cells; pairs are canonical inside an ordered set, so iteration is reproducible
+pub fn find_pairs_within_distance(positions: &[(Entity, Vec2)], distance: f32) -> BTreeSet<(Entity, Entity)> {
+    let mut grid: HashMap<IVec2, Vec<(Entity, Vec2)>> = HashMap::new();
+    for &(entity, position) in positions {
+        grid.entry(world_to_cell(position, distance)).or_default().push((entity, position));
+    }
+
+    let mut pairs = BTreeSet::new();
+    for &(entity, position) in positions {
+        let cell = world_to_cell(position, distance);
+        for offset in [-1, 0, 1].into_iter().flat_map(|x| [-1, 0, 1].map(|y| IVec2::new(x, y))) {
+            for &(other, other_position) in grid.get(&(cell + offset)).into_iter().flatten() {
+                if entity < other && position.distance(other_position) <= distance {
+                    pairs.insert((entity, other));
+                }
+            }
+        }
+    }
+    pairs
+}
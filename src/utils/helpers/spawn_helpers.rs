@@ -0,0 +1,53 @@
+use crate::components::components_constants::SpawnDistribution;
+use bevy::prelude::*;
+use rand::Rng;
+
+/// Helper function laying out the starting positions of `count` agents
+/// Positions stay `margin` away from the walls of a room spanning +-room_half_extents;
+/// a Clustered layout without centers falls back to Uniform
+pub fn calculate_spawn_positions(
+    distribution: &SpawnDistribution,
+    count: usize,
+    room_half_extents: Vec2,
+    margin: f32,
+    rng: &mut impl Rng,
+) -> Vec<Vec2> {
+    let bounds = (room_half_extents - Vec2::splat(margin.max(0.0))).max(Vec2::ZERO);
+
+    match distribution {
+        SpawnDistribution::Clustered { centers, spread } if !centers.is_empty() => (0..count)
+            .map(|index| {
+                // Uniform over the disc: the square root keeps the density from piling up at the center
+                let angle = rng.random_range(0.0..std::f32::consts::TAU);
+                let radius = spread.max(0.0) * rng.random::<f32>().sqrt();
+                let position = centers[index % centers.len()] + Vec2::from_angle(angle) * radius;
+                position.clamp(-bounds, bounds)
+            })
+            .collect(),
+        SpawnDistribution::Grid => {
+            // As many columns as keep cells roughly square for the room's aspect ratio
+            let aspect = if bounds.y > 0.0 { bounds.x / bounds.y } else { 1.0 };
+            let columns = ((count as f32 * aspect).sqrt().ceil() as usize).clamp(1, count.max(1));
+            let rows = count.div_ceil(columns).max(1);
+            let cell = bounds * 2.0 / Vec2::new(columns as f32, rows as f32);
+
+            (0..count)
+                .map(|index| {
+                    let column_row = Vec2::new((index % columns) as f32, (index / columns) as f32);
+                    -bounds + (column_row + Vec2::splat(0.5)) * cell
+                })
+                .collect()
+        }
+        SpawnDistribution::Uniform | SpawnDistribution::Clustered { .. } => (0..count)
+            .map(|_| Vec2::new(rng.random_range(-bounds.x..=bounds.x), rng.random_range(-bounds.y..=bounds.y)))
+            .collect(),
+    }
+}
+
+/// Helper function giving the area environmental resources are scattered over
+/// Spans the room spanning +-room_half_extents less `margin` on every side; a room too small for the margin
+/// collapses the area onto its centre rather than producing an empty range
+pub fn calculate_resource_spawn_area(room_half_extents: Vec2, margin: f32) -> Rect {
+    let bounds = (room_half_extents - Vec2::splat(margin.max(0.0))).max(Vec2::ZERO);
+    Rect::from_corners(-bounds, bounds)
+}
@@ -0,0 +1,176 @@
+use bevy::prelude::Vec2;
+
+use crate::components::components_needs::{BasicNeeds, Desire};
+use crate::components::components_npc::Personality;
+use crate::components::components_social::{SocialInfluence, SocialInteractionState};
+use crate::components::components_telemetry::{DiagnosticOverlay, NeedHistory, NeedSample, NeedStatistics, NeedsSummary, SocialHierarchy, SocietyWellbeing, TelemetrySnapshot};
+use crate::systems::events::events_needs::DesireCensus;
+use crate::utils::helpers::needs_helpers::calculate_overall_satisfaction;
+use std::collections::BTreeMap;
+use std::fs::OpenOptions;
+use std::io::Write;
+
+/// Helper function computing the mean and population variance of a set of values
+/// An empty set yields zeros rather than NaN so snapshots of an empty room stay valid JSON
+pub fn calculate_need_statistics(values: &[f32]) -> NeedStatistics {
+    if values.is_empty() {
+        return NeedStatistics::default();
+    }
+
+    let count = values.len() as f32;
+    let mean = values.iter().sum::<f32>() / count;
+    let variance = values.iter().map(|value| (value - mean).powi(2)).sum::<f32>() / count;
+    NeedStatistics { mean, variance }
+}
+
+/// Helper function computing the Gini coefficient of a set of non-negative values
+/// Based on the Gini index (Gini, 1912): 0.0 when everyone holds the same amount, approaching 1.0
+/// as the total concentrates in one member; an empty or all-zero set yields 0.0
+pub fn calculate_gini_coefficient(values: &[f32]) -> f32 {
+    let total: f32 = values.iter().sum();
+    if values.is_empty() || total <= 0.0 {
+        return 0.0;
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f32::total_cmp);
+    let count = sorted.len() as f32;
+    let weighted_sum: f32 = sorted.iter().enumerate().map(|(index, value)| (index + 1) as f32 * value).sum();
+    (2.0 * weighted_sum / (count * total) - (count + 1.0) / count).max(0.0)
+}
+
+/// Helper function aggregating the population's needs into one wellbeing record
+pub fn build_society_wellbeing<'a>(needs: impl IntoIterator<Item = &'a BasicNeeds>) -> SocietyWellbeing {
+    let satisfactions: Vec<f32> = needs.into_iter().map(calculate_overall_satisfaction).collect();
+    if satisfactions.is_empty() {
+        return SocietyWellbeing::default();
+    }
+
+    SocietyWellbeing {
+        mean_satisfaction: satisfactions.iter().sum::<f32>() / satisfactions.len() as f32,
+        min_satisfaction: satisfactions.iter().copied().fold(f32::INFINITY, f32::min),
+        inequality: calculate_gini_coefficient(&satisfactions),
+        population: satisfactions.len(),
+    }
+}
+
+/// Helper function counting how many agents hold each desire
+pub fn build_desire_census<'a>(time: f32, desires: impl IntoIterator<Item = &'a Desire>) -> DesireCensus {
+    let mut census = DesireCensus { time, ..Default::default() };
+    for desire in desires {
+        *census.counts.entry(*desire).or_insert(0) += 1;
+        census.population += 1;
+    }
+    census
+}
+
+/// Helper function summarising the population's influence scores into one hierarchy record
+pub fn build_social_hierarchy<'a>(influences: impl IntoIterator<Item = &'a SocialInfluence>) -> SocialHierarchy {
+    let influences: Vec<&SocialInfluence> = influences.into_iter().collect();
+    let scores: Vec<f32> = influences.iter().map(|influence| influence.score).collect();
+
+    SocialHierarchy {
+        top_influence: scores.iter().copied().fold(0.0, f32::max),
+        mean_influence: calculate_need_statistics(&scores).mean,
+        influence_inequality: calculate_gini_coefficient(&scores),
+        potential_leaders: influences.iter().filter(|influence| influence.is_potential_leader).count(),
+    }
+}
+
+/// Helper function aggregating every agent's state into one telemetry record
+pub fn build_telemetry_snapshot<'a>(
+    time: f32,
+    agents: impl IntoIterator<
+        Item = (&'a BasicNeeds, Option<&'a Desire>, Option<&'a Personality>, Option<&'a SocialInteractionState>),
+    >,
+) -> TelemetrySnapshot {
+    let mut columns: [Vec<f32>; 5] = Default::default();
+    let mut all_needs = Vec::new();
+    let mut desire_histogram = BTreeMap::new();
+    let mut social_energy = 0.0;
+    let mut agents_interacting = 0;
+
+    for (needs, desire, personality, interaction_state) in agents {
+        all_needs.push(needs);
+        for (column, value) in columns.iter_mut().zip([needs.hunger, needs.thirst, needs.rest, needs.safety, needs.social]) {
+            column.push(value);
+        }
+        if let Some(desire) = desire {
+            *desire_histogram.entry(format!("{desire:?}")).or_insert(0) += 1;
+        }
+        social_energy += needs.social * personality.map_or(1.0, Personality::social_energy_multiplier);
+        if interaction_state.is_some_and(|state| state.active.is_some()) {
+            agents_interacting += 1;
+        }
+    }
+
+    let population = columns[0].len();
+    let [hunger, thirst, rest, safety, social] = columns.map(|column| calculate_need_statistics(&column));
+
+    TelemetrySnapshot {
+        time,
+        population,
+        needs: NeedsSummary { hunger, thirst, rest, safety, social },
+        desire_histogram,
+        mean_social_energy: if population > 0 { social_energy / population as f32 } else { 0.0 },
+        active_interactions: agents_interacting / 2,
+        wellbeing: build_society_wellbeing(all_needs),
+        hierarchy: SocialHierarchy::default(),
+        zone_occupancy: BTreeMap::new(),
+    }
+}
+
+/// Helper function appending already-serialized records to a JSONL file, creating it if needed
+pub fn append_jsonl_lines(path: &str, lines: &[String]) -> std::io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    for line in lines {
+        writeln!(file, "{line}")?;
+    }
+    Ok(())
+}
+
+/// Helper function appending a sample to a NeedHistory, dropping the oldest samples beyond `capacity`
+pub fn record_need_sample(history: &mut NeedHistory, sample: NeedSample, capacity: usize) {
+    history.samples.push_back(sample);
+    while history.samples.len() > capacity {
+        history.samples.pop_front();
+    }
+}
+
+/// Helper function laying one need of a NeedHistory out as a polyline for plotting
+/// Samples are spaced evenly across `size.x` from `origin`, oldest on the left, with need values 0.0-1.0
+/// mapped onto 0 to `size.y`
+pub fn need_history_plot_points(history: &NeedHistory, need: impl Fn(&BasicNeeds) -> f32, origin: Vec2, size: Vec2) -> Vec<Vec2> {
+    let spacing = size.x / history.samples.len().saturating_sub(1).max(1) as f32;
+    history
+        .samples
+        .iter()
+        .enumerate()
+        .map(|(index, sample)| origin + Vec2::new(index as f32 * spacing, need(&sample.needs).clamp(0.0, 1.0) * size.y))
+        .collect()
+}
+
+/// Helper function listing a census's desire counts, most common first
+/// Ties are broken by desire name so the panel does not reshuffle between identical censuses
+pub fn rank_desire_counts(census: &DesireCensus) -> Vec<(Desire, usize)> {
+    let mut counts: Vec<(Desire, usize)> = census.counts.iter().map(|(desire, count)| (*desire, *count)).collect();
+    counts.sort_by(|(desire_a, count_a), (desire_b, count_b)| {
+        count_b.cmp(count_a).then_with(|| format!("{desire_a:?}").cmp(&format!("{desire_b:?}")))
+    });
+    counts
+}
+
+/// Helper function laying out the diagnostic overlay panel, one figure per line
+pub fn format_diagnostic_overlay(overlay: &DiagnosticOverlay) -> String {
+    let wellbeing = &overlay.wellbeing;
+    let mut lines = vec![
+        format!("FPS: {:.0}", overlay.fps),
+        format!("Population: {}", overlay.population),
+        format!(
+            "Wellbeing: mean {:.2}, min {:.2}, inequality {:.2}",
+            wellbeing.mean_satisfaction, wellbeing.min_satisfaction, wellbeing.inequality
+        ),
+    ];
+    lines.extend(overlay.desire_counts.iter().map(|(desire, count)| format!("{desire:?}: {count}")));
+    lines.join("\n")
+}
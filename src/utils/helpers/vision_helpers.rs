@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+use crate::components::components_npc::VisionRange;
+
+/// Helper function outlining a vision cone as a closed polyline
+/// Starts at the observer, sweeps the arc from the left edge of the field of view to the right edge
+/// and returns to the observer; the arc is split into `arc_segments` straight lines
+pub fn calculate_vision_cone_points(origin: Vec2, forward: Vec2, field_of_view: f32, range: f32, arc_segments: u32) -> Vec<Vec2> {
+    let forward = forward.normalize_or(Vec2::Y);
+    let field_of_view = field_of_view.clamp(0.0, std::f32::consts::TAU);
+    let segments = arc_segments.max(1);
+
+    let mut points = Vec::with_capacity(segments as usize + 3);
+    points.push(origin);
+    for step in 0..=segments {
+        let angle = field_of_view / 2.0 - field_of_view * step as f32 / segments as f32;
+        points.push(origin + Vec2::from_angle(angle).rotate(forward) * range);
+    }
+    points.push(origin);
+    points
+}
+
+/// Helper function checking whether a point lies inside an observer's field of view
+/// `forward` is the observer's facing; a target at the observer's own position always counts as seen
+pub fn is_within_field_of_view(observer: Vec2, forward: Vec2, target: Vec2, field_of_view: f32) -> bool {
+    let Some(to_target) = (target - observer).try_normalize() else {
+        return true;
+    };
+    to_target.angle_to(forward.normalize_or(Vec2::Y)).abs() <= field_of_view / 2.0
+}
+
+/// Helper function checking whether an agent is facing a target, using its Transform rotation as heading
+/// Shares the Y-up forward convention and field of view of vision_system
+pub fn is_facing(transform: &Transform, vision_range: &VisionRange, target: Vec2) -> bool {
+    let forward = (transform.rotation * Vec3::Y).truncate();
+    is_within_field_of_view(transform.translation.truncate(), forward, target, vision_range.field_of_view)
+}
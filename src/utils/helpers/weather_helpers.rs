@@ -0,0 +1,25 @@
+use crate::components::components_environment::WeatherEffects;
+
+/// Helper function scaling vision with ambient light
+/// Based on Scotopic Vision research - acuity falls off in the dark but never vanishes entirely
+pub fn calculate_light_vision_multiplier(light_level: f32, effects: &WeatherEffects) -> f32 {
+    let min_fraction = effects.min_vision_fraction.clamp(0.0, 1.0);
+    min_fraction + (1.0 - min_fraction) * light_level.clamp(0.0, 1.0)
+}
+
+/// Helper function scaling thirst and rest decay with temperature
+/// Based on Thermoregulation research - heat and cold both raise water loss and fatigue
+/// Grows linearly with the distance outside the comfortable range, bounded by max_decay_multiplier
+pub fn calculate_temperature_decay_multiplier(temperature: f32, effects: &WeatherEffects) -> f32 {
+    let deviation = (effects.comfortable_min_temperature - temperature)
+        .max(temperature - effects.comfortable_max_temperature)
+        .max(0.0);
+    (1.0 + deviation * effects.decay_increase_per_degree.max(0.0)).min(effects.max_decay_multiplier.max(1.0))
+}
+
+/// Helper function giving the daylight level at a point in the day/night cycle
+/// Days start at noon (full light) and reach complete darkness half a day later
+pub fn calculate_daylight(elapsed_secs: f32, day_length_secs: f32) -> f32 {
+    let phase = elapsed_secs / day_length_secs.max(f32::EPSILON) * std::f32::consts::TAU;
+    0.5 + 0.5 * phase.cos()
+}
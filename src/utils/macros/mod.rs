@@ -0,0 +1,16 @@
+/// Logs a routine simulation message at the verbosity configured for its category
+/// Usage: `sim_log!(log_gate, log_config, LogCategory::Needs, "NPC {:?} started refilling", entity);`
+/// where `log_gate` is an `Option<Res<LogGate>>` system parameter
+/// Nothing is formatted when the LogGate is closed or the category is Off
+#[macro_export]
+macro_rules! sim_log {
+    ($gate:expr, $config:expr, $category:expr, $($arg:tt)+) => {
+        if $crate::components::components_constants::LogGate::is_open($gate.as_deref()) {
+            match $config.verbosity($category) {
+                $crate::components::components_constants::LogVerbosity::Off => {}
+                $crate::components::components_constants::LogVerbosity::Trace => bevy::log::trace!($($arg)+),
+                $crate::components::components_constants::LogVerbosity::Info => bevy::log::info!($($arg)+),
+            }
+        }
+    };
+}
@@ -1 +1,4322 @@
 mod comprehensive_tests {
+    use artificial_society::components::components_camera::{CameraController, SelectedNpc};
+    use artificial_society::components::components_constants::{AiFeatureFlags, BoundaryPolicy, DecisionJitterConfig, DecisionPipelineMode, GameConstants, LifecycleConfig, LogGate, LogVerbosity, DecisionLookaheadConfig, DecisionStabilityConfig, NeedCouplingConfig, NeedEventMode, NeedFailureConfig, NeedFailureConsequence, OpinionDynamicsConfig, PanicConfig, ResourceContentionPolicy, RestQualityConfig, SafetyChannelConfig, SatiationConfig, ScenarioFile, ScenarioLoadError, SimLogConfig, SimulationRng, SocialConfig, SpawnDistribution};
+    use artificial_society::components::components_environment::{CurrentZone, CustomResourceSite, Hotel, ResourceReservation, ResourceType, Restaurant, SafeZone, Wall, Weather, WeatherEffects, Well, Zone, ZoneLayout, ZoneOccupancy};
+    use artificial_society::components::components_experiment::ExperimentVariant;
+    use artificial_society::components::components_inspection::AgentSnapshot;
+    use artificial_society::components::components_knowledge::{Beliefs, KnowledgeBase, Proposition, RumorParameters, RumorRegistry};
+    use artificial_society::components::components_learning::{AdaptiveBehavior, CognitiveMap, ForageMemory, LandmarkId, PathLearning, PathSegment};
+    use artificial_society::components::components_needs::{
+        BasicNeeds, CurrentDesire, CustomDesireDefinition, CustomDesireRegistry, CustomDesireRegistryFull, CustomNeeds, DecisionStability, Desire, DesireThresholds, DualProcess,
+        DualThreshold, HomeostaticSetpoints, NeedDepletion, NeedPriorityWeights, NeedSetpoint, SafetyHabituation, Willpower,
+    };
+    use artificial_society::components::components_npc::{
+        AgentTint, ApparentState, Incapacitated, Mood, Npc, Panic, PerceivedEntities, Perception, Personality, PersonalityTrait, Proprioception, RefillState, RelaxState, VisionRange,
+        VisualizationMode, WorldModel,
+    };
+    use artificial_society::components::components_performance::{AgentLod, AgentLodConfig, EventBacklog, EventBacklogConfig, FrameBudgetGovernor, FrameTimeMonitor, LearningMemoryConfig, ProfilerConfig, SystemTimings};
+    use artificial_society::components::components_telemetry::{DesireCensusConfig, DiagnosticOverlay, DiagnosticOverlayText, NeedHistory, NeedHistoryConfig, SocialHierarchy, SocietyWellbeing, TelemetryConfig, TelemetryRecorder, WellbeingConfig};
+    use artificial_society::components::components_social::{ActiveInteraction, Attitude, ConflictCause, ContactDebounce, GatheringId, InteractionType, Relationship, SocialEnergy, SocialInfluence, SocialInteractionState, SocialProximity, SocialRelationships};
+    use artificial_society::components::components_pathfinding::{AvoidedTargets, PathTarget, PhysicalContacts, ResourceMemory, ResourceSpatialIndex, SteeringBehavior, StuckEscape};
+    use artificial_society::entity_builders::entity_builders_default::{create_npc_entity, spawn_default_npc, spawn_explorer_npc, spawn_social_npc, WALL_THICKNESS};
+    use artificial_society::systems::events::events_knowledge::{BeliefEvidenceEvent, BeliefUpdated};
+    use artificial_society::systems::events::events_environment::{ResourceContentionResolved, ResourceInteractionAttemptEvent, ResourceInteractionSuccessEvent};
+    use artificial_society::systems::events::events_lifecycle::NpcDespawnEvent;
+    use artificial_society::systems::events::events_movement::BoundaryCollisionEvent;
+    use artificial_society::systems::events::events_needs::{
+        ActionCompleted, ActionCompletionReason, ActionOutcome, ActionTaken, CurrentDesireSet, DecisionTrigger, DesireCensus, DesireChangeEvent, DesireChangeReason, DesireFulfillmentAttemptEvent, EvaluateDecision,
+        NeedChangeEvent, NeedCriticalEvent, NeedDecayEvent, NeedSatisfactionEvent, NeedType, NeedsChangedBatch, SocialInteractionEvent, ThresholdCrossedEvent,
+    };
+    use artificial_society::systems::events::events_pathfinding::{PathTargetReachedEvent, PathTargetSetEvent, ReservationDenied, ResourceDiscoveredEvent};
+    use artificial_society::systems::events::events_performance::PerformanceAlertEvent;
+    use artificial_society::systems::events::events_rumor::{RumorMutationEvent, RumorSpreadAttemptEvent, RumorSpreadEvent};
+    use artificial_society::systems::events::events_social::{ConflictResolved, GatheringDissolved, GatheringFormed, InteractionCompleted, InteractionStarted, RelationshipChangedEvent};
+    use artificial_society::systems::events::events_validation::{ConstantsFileChanged, GenericComponentEvent};
+    use artificial_society::systems::systems_camera::camera_controller_system;
+    use artificial_society::systems::systems_environment::{agent_zone_system, create_empty_room_system, refill_management_system, resource_interaction_system};
+    use artificial_society::systems::systems_experiment::ExperimentRunner;
+    use artificial_society::systems::systems_knowledge::{belief_evidence_system, belief_update_system};
+    use artificial_society::systems::systems_learning::{action_outcome_system, cognitive_map_update_system, curiosity_exploration_system, landmark_recording_system, landmark_sharing_system, learning_memory_pruning_system, forage_memory_system, path_experience_system, path_learning_system};
+    use artificial_society::systems::systems_lifecycle::npc_despawn_system;
+    use artificial_society::systems::systems_movement::{boundary_collision_system, face_velocity_system};
+    use artificial_society::systems::systems_needs::{
+        action_failure_handling_system, custom_needs_system, decay_basic_needs, decision_making_system, decision_phase_jitter_system, decision_stability_system, desire_fulfillment_system, desire_update_system, handle_social_interactions,
+        need_deficit_injection_system, need_failure_system, optimized_threshold_monitoring_system, periodic_decision_trigger_system, relax_state_system, safety_threat_detection_system, social_need_fulfillment_system, threshold_monitoring_system,
+        DecisionPipelinePlugin,
+    };
+    use artificial_society::systems::systems_pathfinding::{
+        desire_pathfinding_system, physical_contact_system, reservation_release_system, resource_discovery_system, resource_spatial_index_system, steering_behavior_system,
+    };
+    use artificial_society::systems::systems_features::cognition_enabled;
+    use artificial_society::systems::systems_performance::{agent_lod_update_system, event_backlog_system, frame_budget_governor_system, learning_memory_monitor_system};
+    use artificial_society::systems::systems_schedule::SimulationSet;
+    use artificial_society::systems::systems_rumor::{rumor_interaction_detection_system, rumor_transmission_system};
+    use artificial_society::systems::systems_social::{group_formation_system, helping_system, knowledge_exchange_system, leadership_detection_system, opinion_dynamics_system, panic_contagion_system, social_energy_system, social_interaction_lifecycle_system, social_interaction_system, social_proximity_system};
+    use artificial_society::systems::systems_telemetry::{diagnostic_overlay_render_system, diagnostic_overlay_update_system, need_history_sampling_system, society_desire_census_system, society_wellbeing_system, telemetry_flush_on_exit_system, telemetry_snapshot_system};
+    use artificial_society::systems::systems_validation::{constants_hot_reload_system, load_scenario_system, validate_basic_needs_system, validate_desire_thresholds_system};
+    use artificial_society::systems::systems_visual::{proprioception_system, vision_system, world_model_update_system};
+    use artificial_society::utils::helpers::camera_helpers::calculate_world_fit_scaling;
+    use artificial_society::utils::helpers::color_helpers::agent_tint_color;
+    use artificial_society::utils::helpers::constants_helpers::{load_scenario, parse_scenario};
+    use artificial_society::utils::helpers::experiment_helpers::first_event_stream_divergence;
+    use artificial_society::utils::helpers::learning_helpers::{
+        export_learned_model, import_learned_model, learn_from_path_memory, load_learned_model, record_path_experience, save_learned_model,
+    };
+    use artificial_society::utils::helpers::needs_helpers::{evaluate_most_urgent_desire, random_basic_needs_from_rng};
+    use artificial_society::utils::helpers::pathfinding_helpers::{calculate_boundary_avoidance_bias, memory_retention, remember_resource_location};
+    use artificial_society::utils::helpers::telemetry_helpers::{build_society_wellbeing, build_telemetry_snapshot, format_diagnostic_overlay};
+    use artificial_society::utils::helpers::vision_helpers::calculate_vision_cone_points;
+    use artificial_society::utils::helpers::social_helpers::{apply_mood_to_satisfaction, calculate_interaction_satisfaction, calculate_local_network_density, update_relationship_from_interaction};
+    use artificial_society::utils::helpers::performance_helpers::build_profiler_report;
+    use artificial_society::utils::helpers::physics_helpers::{npc_body_size, npc_ccd, npc_collision_groups, physics_timestep_mode, wall_collision_groups};
+    use artificial_society::utils::helpers::spawn_helpers::{calculate_resource_spawn_area, calculate_spawn_positions};
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::input::mouse::AccumulatedMouseScroll;
+    use bevy::log::tracing::{self, Level, Subscriber};
+    use bevy::log::tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+    use bevy::log::tracing_subscriber::Registry;
+    use bevy::prelude::*;
+    use bevy::render::camera::ScalingMode;
+    use bevy::time::{TimePlugin, TimeUpdateStrategy};
+    use bevy_rapier2d::prelude::{
+        Collider, CollisionEvent, GravityScale, NoUserData, RapierPhysicsPlugin, Restitution, RigidBody, TimestepMode, Velocity,
+    };
+    use bevy_rapier2d::rapier::geometry::CollisionEventFlags;
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::{Rng, SeedableRng};
+    use std::collections::HashSet;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    fn lonely_needs() -> BasicNeeds {
+        BasicNeeds { hunger: 1.0, thirst: 1.0, rest: 1.0, safety: 1.0, social: 0.2 }
+    }
+
+    // =========================================================================
+    // VALIDATION
+    // =========================================================================
+
+    #[test]
+    fn inverted_dual_threshold_is_corrected_on_spawn() {
+        let mut world = World::new();
+        world.init_resource::<Events<GenericComponentEvent>>();
+
+        let mut thresholds = DesireThresholds::default();
+        thresholds.hunger_threshold.high_threshold = 0.3;
+        thresholds.hunger_threshold.low_threshold = 0.7;
+        let entity = world.spawn(thresholds).id();
+
+        world.run_system_once(validate_desire_thresholds_system).unwrap();
+
+        let corrected = world.get::<DesireThresholds>(entity).unwrap();
+        assert_eq!(corrected.hunger_threshold.low_threshold, 0.3);
+        assert_eq!(corrected.hunger_threshold.high_threshold, 0.7);
+
+        let events: Vec<_> = world.resource_mut::<Events<GenericComponentEvent>>().drain().collect();
+        assert_eq!(events.len(), 1, "only the inverted threshold should be reported");
+    }
+
+    #[test]
+    fn out_of_range_need_values_are_flagged_and_clamped() {
+        let mut world = World::new();
+        world.init_resource::<Events<GenericComponentEvent>>();
+        let entity = world.spawn(BasicNeeds { hunger: 1.4, thirst: 0.5, rest: f32::NAN, safety: 0.5, social: 0.5 }).id();
+
+        world.run_system_once(validate_basic_needs_system).unwrap();
+
+        let corrected = *world.get::<BasicNeeds>(entity).unwrap();
+        assert_eq!(corrected.hunger, 1.0);
+        assert_eq!(corrected.rest, 0.0);
+        assert_eq!(corrected.thirst, 0.5);
+
+        let fields: Vec<_> = world
+            .resource_mut::<Events<GenericComponentEvent>>()
+            .drain()
+            .map(|event| match event {
+                GenericComponentEvent::ValidationFailed { entity: flagged, component, field, .. } => {
+                    assert_eq!(flagged, Some(entity));
+                    assert_eq!(component, "BasicNeeds");
+                    field
+                }
+            })
+            .collect();
+        assert_eq!(fields, ["hunger", "rest"]);
+    }
+
+    #[test]
+    fn invalid_game_constants_are_clamped() {
+        let mut constants = GameConstants { npc_speed: -50.0, thirst_decay: 2.0, ..Default::default() };
+
+        let violations = constants.validate();
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(constants.npc_speed, GameConstants::default().npc_speed);
+        assert_eq!(constants.thirst_decay, 1.0);
+        assert!(GameConstants::default().validate().is_empty(), "defaults must always be valid");
+    }
+
+    #[test]
+    fn edited_constants_file_changes_the_decay_rate_from_the_next_tick_on() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(GameConstants { hunger_decay: 0.01, ..default() });
+        world.init_resource::<NeedEventMode>();
+        world.init_resource::<NeedCouplingConfig>();
+        world.init_resource::<Events<NeedDecayEvent>>();
+        world.init_resource::<Events<NeedChangeEvent>>();
+        world.init_resource::<Events<NeedsChangedBatch>>();
+        world.init_resource::<Events<ConstantsFileChanged>>();
+        world.init_resource::<Events<GenericComponentEvent>>();
+        let npc = world.spawn((Npc, BasicNeeds { hunger: 1.0, thirst: 1.0, rest: 1.0, safety: 1.0, social: 1.0 })).id();
+        let hunger_lost_in_one_second = |world: &mut World| {
+            let before = world.get::<BasicNeeds>(npc).unwrap().hunger;
+            world.resource_mut::<Time>().advance_by(Duration::from_secs(1));
+            world.run_system_once(decay_basic_needs).unwrap();
+            before - world.get::<BasicNeeds>(npc).unwrap().hunger
+        };
+
+        assert!((hunger_lost_in_one_second(&mut world) - 0.01).abs() < 1e-6);
+
+        // The file names an out-of-range value and a field that cannot be tuned live alongside the real edit
+        world.send_event(ConstantsFileChanged {
+            path: "game_constants.json".to_string(),
+            contents: r#"{ "hunger_decay": 0.05, "thirst_decay": 3.0, "room_half_extents": [1, 1] }"#.to_string(),
+        });
+        world.run_system_once(constants_hot_reload_system).unwrap();
+
+        let constants = world.resource::<GameConstants>();
+        assert_eq!(constants.hunger_decay, 0.05);
+        assert_eq!(constants.thirst_decay, 1.0, "reloaded values are clamped like at startup");
+        assert_eq!(constants.room_half_extents, GameConstants::default().room_half_extents);
+        assert_eq!(world.resource::<Events<GenericComponentEvent>>().len(), 1);
+        assert!((hunger_lost_in_one_second(&mut world) - 0.05).abs() < 1e-6, "decay uses the reloaded rate");
+
+        // A malformed file leaves the constants as they were
+        world.send_event(ConstantsFileChanged { path: "game_constants.json".to_string(), contents: "{ not json".to_string() });
+        world.run_system_once(constants_hot_reload_system).unwrap();
+        assert_eq!(world.resource::<GameConstants>().hunger_decay, 0.05);
+    }
+
+    #[test]
+    fn scenario_loading_reports_why_a_file_was_rejected() {
+        let path = std::env::temp_dir().join(format!("scenario_test_{}.ron", std::process::id()));
+        let load = |contents: Option<&str>| {
+            match contents {
+                Some(contents) => std::fs::write(&path, contents).unwrap(),
+                None => std::fs::remove_file(&path).unwrap_or_default(),
+            }
+            load_scenario(&path)
+        };
+
+        let scenario = load(Some("(seed: 7, num_npcs: 20, hunger_decay: 0.02)")).unwrap();
+        assert_eq!(scenario.seed, Some(7));
+        assert_eq!(scenario.game_constants.num_npcs, 20);
+        assert_eq!(scenario.game_constants.hunger_decay, 0.02);
+        // A JSON object of overrides is also a valid RON map
+        assert_eq!(load(Some(r#"{ "num_npcs": 20 }"#)).unwrap().game_constants.num_npcs, 20);
+
+        assert!(matches!(load(None), Err(ScenarioLoadError::Io(_))), "missing file");
+        assert!(matches!(load(Some("(num_npcs: 20, ")), Err(ScenarioLoadError::Parse(_))), "syntactically invalid file");
+        match load(Some("(num_npcs: -3)")) {
+            Err(ScenarioLoadError::Validation(problems)) => assert!(problems[0].contains("num_npcs"), "got {problems:?}"),
+            other => panic!("a negative count must fail validation, got {other:?}"),
+        }
+        let _ = std::fs::remove_file(&path);
+    }
+
+    // =========================================================================
+    // NPC PRESETS
+    // =========================================================================
+
+    #[test]
+    fn each_npc_preset_spawns_a_complete_agent_with_its_archetype_values() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+        app.init_asset::<Image>();
+        app.insert_resource(GameConstants::default());
+
+        let [average, social, explorer] = app
+            .world_mut()
+            .run_system_once(|mut commands: Commands, asset_server: Res<AssetServer>, constants: Res<GameConstants>| {
+                let mut rng = StdRng::seed_from_u64(2395);
+                let registry = CustomDesireRegistry::default();
+                [
+                    spawn_default_npc(&mut commands, &asset_server, &constants, &registry, Vec2::ZERO, &mut rng),
+                    spawn_social_npc(&mut commands, &asset_server, &constants, &registry, Vec2::new(50.0, 0.0), &mut rng),
+                    spawn_explorer_npc(&mut commands, &asset_server, &constants, &registry, Vec2::new(-50.0, 0.0), &mut rng),
+                ]
+            })
+            .unwrap();
+        let world = app.world();
+
+        // Every preset goes through the validated chain, so all of them are full agents where they were placed
+        for npc in [average, social, explorer] {
+            assert!(world.get::<Npc>(npc).is_some());
+            assert!(world.get::<CustomNeeds>(npc).is_none(), "no custom desires are registered");
+            assert!(world.get::<ResourceMemory>(npc).is_some());
+            assert!(world.get::<Velocity>(npc).is_some());
+        }
+        assert_eq!(world.get::<Transform>(social).unwrap().translation, Vec3::new(50.0, 0.0, 0.0));
+
+        let average_personality = world.get::<Personality>(average).unwrap();
+        assert_eq!((average_personality.openness, average_personality.neuroticism), (0.5, 0.5));
+        assert_eq!(world.get::<Perception>(average).unwrap().acuity, 0.5);
+
+        let social_personality = world.get::<Personality>(social).unwrap();
+        assert_eq!((social_personality.extraversion, social_personality.agreeableness), (0.9, 0.8));
+        assert_eq!(world.get::<DesireThresholds>(social).unwrap().social_threshold.high_threshold, 0.8);
+
+        assert_eq!(world.get::<Personality>(explorer).unwrap().openness, 0.9);
+        assert_eq!(world.get::<Perception>(explorer).unwrap().acuity, 0.9);
+        assert_eq!(world.get::<DesireThresholds>(explorer).unwrap().social_threshold.high_threshold, 0.6, "unchanged by the preset");
+    }
+
+    #[test]
+    fn npcs_built_from_the_same_seed_draw_the_same_individual_traits() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+        app.init_asset::<Image>();
+        app.insert_resource(GameConstants::default());
+
+        let [first, second] = app
+            .world_mut()
+            .run_system_once(|mut commands: Commands, asset_server: Res<AssetServer>, constants: Res<GameConstants>| {
+                [7, 7].map(|seed| {
+                    let mut rng = SimulationRng::from_seed(seed);
+                    create_npc_entity(&mut commands, &asset_server, &constants, &CustomDesireRegistry::default(), Vec2::ZERO, &mut rng.rng)
+                })
+            })
+            .unwrap();
+        let world = app.world();
+
+        assert_eq!(world.get::<Personality>(first), world.get::<Personality>(second));
+        assert_eq!(world.get::<Perception>(first), world.get::<Perception>(second));
+        assert_eq!(world.get::<Attitude>(first), world.get::<Attitude>(second));
+        assert_eq!(world.get::<HomeostaticSetpoints>(first), world.get::<HomeostaticSetpoints>(second));
+    }
+
+    // =========================================================================
+    // COLLISION LAYERS
+    // =========================================================================
+
+    /// World with everything the social contact -> interaction -> need pipeline reads and writes
+    fn social_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(GameConstants::default());
+        world.init_resource::<Events<CollisionEvent>>();
+        world.init_resource::<Events<InteractionStarted>>();
+        world.init_resource::<Events<InteractionCompleted>>();
+        world.init_resource::<Events<SocialInteractionEvent>>();
+        world.init_resource::<Events<NeedChangeEvent>>();
+        world.insert_resource(SimulationRng::from_seed(42));
+        world.insert_resource(SocialConfig::default());
+        world.init_resource::<ContactDebounce>();
+        world.init_resource::<SimLogConfig>();
+        world
+    }
+
+    /// Lets any started interactions run their full duration and applies their outcome
+    fn complete_social_interactions(world: &mut World) {
+        let duration = world.resource::<GameConstants>().social_interaction_duration;
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(duration));
+        world.run_system_once(social_interaction_lifecycle_system).unwrap();
+        world.run_system_once(social_need_fulfillment_system).unwrap();
+    }
+
+    #[test]
+    fn interactions_need_both_agents_facing_each_other() {
+        let started_between = |facing_a: f32, facing_b: f32| {
+            let mut world = social_world();
+            let mut spawn_facing = |position: Vec2, facing: f32| {
+                world.spawn((
+                    Npc,
+                    lonely_needs(),
+                    npc_collision_groups(),
+                    SocialInteractionState::default(),
+                    VisionRange::default(),
+                    Transform::from_translation(position.extend(0.0)).with_rotation(Quat::from_rotation_z(facing)),
+                )).id()
+            };
+            // a sits just below b, touching; a rotation of zero faces +Y
+            let npc_a = spawn_facing(Vec2::ZERO, facing_a);
+            let npc_b = spawn_facing(Vec2::new(0.0, 10.0), facing_b);
+
+            world.send_event(CollisionEvent::Started(npc_a, npc_b, CollisionEventFlags::empty()));
+            world.run_system_once(handle_social_interactions).unwrap();
+            world.resource::<Events<InteractionStarted>>().len()
+        };
+
+        let face_to_face = started_between(0.0, std::f32::consts::PI);
+        let back_to_back = started_between(std::f32::consts::PI, 0.0);
+        let from_behind = started_between(0.0, 0.0);
+        assert_eq!(face_to_face, 1);
+        assert_eq!(back_to_back, 0);
+        assert_eq!(from_behind, 0, "b never sees a approaching from behind");
+    }
+
+    #[test]
+    fn contact_order_does_not_change_who_pairs_with_whom() {
+        // The same seeded crowd, with every contact reported in a different order on each run
+        let started_sequence = |order_seed: u64| {
+            let mut world = social_world();
+            let mut personality_rng = StdRng::seed_from_u64(5);
+            let agents: Vec<Entity> = (0..6)
+                .map(|_| {
+                    world.spawn((
+                        Npc,
+                        lonely_needs(),
+                        npc_collision_groups(),
+                        SocialInteractionState::default(),
+                        Personality::from_rng(&mut personality_rng),
+                    )).id()
+                })
+                .collect();
+
+            let mut order_rng = StdRng::seed_from_u64(order_seed);
+            let mut sequence = Vec::new();
+            for _ in 0..3 {
+                let mut contacts: Vec<(Entity, Entity)> = agents
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(index, first)| agents[index + 1..].iter().map(move |second| (*first, *second)))
+                    .map(|(first, second)| if order_rng.random::<bool>() { (first, second) } else { (second, first) })
+                    .collect();
+                contacts.shuffle(&mut order_rng);
+                for (first, second) in contacts {
+                    world.send_event(CollisionEvent::Started(first, second, CollisionEventFlags::empty()));
+                }
+
+                world.run_system_once(handle_social_interactions).unwrap();
+                sequence.extend(
+                    world.resource_mut::<Events<InteractionStarted>>().drain().map(|event| (event.entity_1, event.entity_2)),
+                );
+                complete_social_interactions(&mut world);
+            }
+            sequence
+        };
+
+        let first_run = started_sequence(1);
+        assert!(!first_run.is_empty(), "the crowd should start some interactions");
+        assert_eq!(started_sequence(2), first_run);
+        assert_eq!(started_sequence(3), first_run);
+    }
+
+    #[test]
+    fn agent_touching_two_neighbours_engages_the_historically_satisfying_one() {
+        let mut world = social_world();
+
+        // Spawned first, so plain entity order would pair the actor with the sour acquaintance
+        let sour = world.spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default())).id();
+        let friend = world.spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default())).id();
+
+        let mut history = SocialRelationships::default();
+        for (partner, satisfaction) in [(sour, 0.1), (friend, 0.9)] {
+            let relationship = history.relationships.entry(partner).or_default();
+            for _ in 0..3 {
+                update_relationship_from_interaction(relationship, InteractionType::Conversation, satisfaction, 0.0);
+            }
+        }
+        let actor = world.spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default(), history)).id();
+
+        world.send_event(CollisionEvent::Started(actor, sour, CollisionEventFlags::empty()));
+        world.send_event(CollisionEvent::Started(actor, friend, CollisionEventFlags::empty()));
+        world.run_system_once(handle_social_interactions).unwrap();
+
+        let active = world.get::<SocialInteractionState>(actor).unwrap().active.expect("the actor engages someone");
+        assert_eq!(active.partner, friend);
+        assert!(world.get::<SocialInteractionState>(sour).unwrap().is_available());
+    }
+
+    #[test]
+    fn only_npc_npc_contacts_trigger_social_interactions() {
+        let mut world = social_world();
+
+        let npc_a = world.spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default())).id();
+        let npc_b = world.spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default())).id();
+        // A body on the wall layer must never count as a social partner, even if it carries needs
+        let wall = world.spawn((Npc, lonely_needs(), wall_collision_groups(), SocialInteractionState::default())).id();
+
+        world.send_event(CollisionEvent::Started(npc_a, wall, CollisionEventFlags::empty()));
+        world.run_system_once(handle_social_interactions).unwrap();
+        let events: Vec<_> = world.resource_mut::<Events<InteractionStarted>>().drain().collect();
+        assert!(events.is_empty(), "NPC-wall contact must not be social");
+
+        world.send_event(CollisionEvent::Started(npc_a, npc_b, CollisionEventFlags::empty()));
+        world.run_system_once(handle_social_interactions).unwrap();
+        let events: Vec<_> = world.resource_mut::<Events<InteractionStarted>>().drain().collect();
+        assert_eq!(events.len(), 1);
+        assert!(world.get::<SocialInteractionState>(wall).unwrap().is_available());
+    }
+
+    #[test]
+    fn same_collision_produces_identically_ordered_social_event_fields() {
+        let run = |reverse_contact: bool| {
+            let mut world = social_world();
+
+            let npc_a = world.spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default())).id();
+            let npc_b = world.spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default())).id();
+            let (first, second) = if reverse_contact { (npc_b, npc_a) } else { (npc_a, npc_b) };
+
+            world.send_event(CollisionEvent::Started(first, second, CollisionEventFlags::empty()));
+            world.run_system_once(handle_social_interactions).unwrap();
+            complete_social_interactions(&mut world);
+
+            let events: Vec<_> = world.resource_mut::<Events<SocialInteractionEvent>>().drain().collect();
+            assert_eq!(events.len(), 1);
+            (events[0].entity_1, events[0].entity_2, events[0].social_boost)
+        };
+
+        let forward = run(false);
+        let reversed = run(true);
+        assert_eq!(forward, reversed);
+        assert!(forward.0 < forward.1, "pairs are reported lowest entity first");
+    }
+
+    #[test]
+    fn collision_starts_interaction_and_completion_boosts_social_need() {
+        let mut world = social_world();
+
+        let npc_a = world.spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default())).id();
+        let npc_b = world.spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default())).id();
+
+        world.send_event(CollisionEvent::Started(npc_a, npc_b, CollisionEventFlags::empty()));
+        world.run_system_once(handle_social_interactions).unwrap();
+
+        // Contact alone starts the interaction but does not satisfy the need
+        let active = world.get::<SocialInteractionState>(npc_a).unwrap().active.expect("interaction should be active");
+        assert_eq!(active.partner, npc_b);
+        assert_eq!(world.get::<SocialInteractionState>(npc_b).unwrap().active.map(|a| a.partner), Some(npc_a));
+        assert_eq!(world.get::<BasicNeeds>(npc_a).unwrap().social, 0.2);
+
+        // Halfway through, nothing has completed yet
+        let duration = world.resource::<GameConstants>().social_interaction_duration;
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(duration / 2.0));
+        world.run_system_once(social_interaction_lifecycle_system).unwrap();
+        assert!(world.resource::<Events<InteractionCompleted>>().is_empty());
+
+        complete_social_interactions(&mut world);
+
+        let completed: Vec<_> = world.resource_mut::<Events<InteractionCompleted>>().drain().collect();
+        assert_eq!(completed.len(), 1);
+        for npc in [npc_a, npc_b] {
+            assert!(world.get::<BasicNeeds>(npc).unwrap().social > 0.2);
+            let state = world.get::<SocialInteractionState>(npc).unwrap();
+            assert!(state.is_available());
+            assert_eq!(state.completed_interactions, 1);
+        }
+    }
+
+    #[test]
+    fn pair_cannot_interact_again_until_the_cooldown_elapses() {
+        let mut world = social_world();
+        // No debounce, so the probes just either side of the cooldown are judged by the cooldown alone
+        world.insert_resource(SocialConfig { interaction_cooldown: 5.0, contact_debounce_window: 0.0, ..default() });
+
+        let npc_a = world.spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default())).id();
+        let npc_b = world.spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default())).id();
+        let contact = |world: &mut World| {
+            world.send_event(CollisionEvent::Started(npc_a, npc_b, CollisionEventFlags::empty()));
+            world.run_system_once(handle_social_interactions).unwrap();
+            world.resource_mut::<Events<InteractionStarted>>().drain().count()
+        };
+
+        assert_eq!(contact(&mut world), 1);
+        complete_social_interactions(&mut world);
+        assert!(world.get::<SocialInteractionState>(npc_a).unwrap().is_available());
+
+        // Still adjacent right after completing - and still just before the cooldown ends
+        assert_eq!(contact(&mut world), 0);
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(4.9));
+        assert_eq!(contact(&mut world), 0);
+
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(0.2));
+        assert_eq!(contact(&mut world), 1, "the pair may interact again once the cooldown has elapsed");
+    }
+
+    #[test]
+    fn repeated_contact_starts_within_the_debounce_window_boost_the_social_need_once() {
+        let mut world = social_world();
+        // Durations that are exact in binary, so elapsed time lands precisely on each interaction's end
+        world.insert_resource(GameConstants { social_interaction_duration: 0.125, ..default() });
+        world.insert_resource(SocialConfig { interaction_cooldown: 0.0, contact_debounce_window: 0.5, ..default() });
+
+        let npc_a = world.spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default())).id();
+        let npc_b = world.spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default())).id();
+        let contact_and_complete = |world: &mut World, first: Entity, second: Entity| {
+            world.send_event(CollisionEvent::Started(first, second, CollisionEventFlags::empty()));
+            world.run_system_once(handle_social_interactions).unwrap();
+            complete_social_interactions(world);
+            // run_system_once reads from the start of the queue, so finished interactions must not be applied twice
+            world.resource_mut::<Events<InteractionCompleted>>().clear();
+            world.resource_mut::<Events<SocialInteractionEvent>>().drain().count()
+        };
+
+        // Rapier re-reports the same touching pair, in either order, well inside the window
+        assert_eq!(contact_and_complete(&mut world, npc_a, npc_b), 1);
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(0.125));
+        assert_eq!(contact_and_complete(&mut world, npc_b, npc_a), 0, "the duplicate start must not boost again");
+        let social_after_one_boost = world.get::<BasicNeeds>(npc_a).unwrap().social;
+        assert!(social_after_one_boost > 0.2);
+
+        // A genuine new contact after the window counts again
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(1));
+        assert_eq!(contact_and_complete(&mut world, npc_a, npc_b), 1);
+        assert!(world.get::<BasicNeeds>(npc_a).unwrap().social > social_after_one_boost);
+    }
+
+    #[test]
+    fn rejected_contact_starts_do_not_slide_the_debounce_window() {
+        let mut world = social_world();
+        world.insert_resource(GameConstants { social_interaction_duration: 0.125, ..default() });
+        world.insert_resource(SocialConfig { interaction_cooldown: 0.0, contact_debounce_window: 0.5, ..default() });
+
+        let npc_a = world.spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default())).id();
+        let npc_b = world.spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default())).id();
+        let contact = |world: &mut World| {
+            world.send_event(CollisionEvent::Started(npc_a, npc_b, CollisionEventFlags::empty()));
+            world.run_system_once(handle_social_interactions).unwrap();
+            world.resource_mut::<Events<CollisionEvent>>().clear();
+            world.resource_mut::<Events<InteractionStarted>>().drain().count()
+        };
+
+        assert_eq!(contact(&mut world), 1);
+        complete_social_interactions(&mut world);
+
+        // The colliders keep re-reporting contact every 0.125s for the rest of the window
+        for _ in 0..3 {
+            assert_eq!(contact(&mut world), 0, "a start inside the window is debounced");
+            world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(0.125));
+        }
+
+        // 0.625s after the accepted start, only 0.25s after the last rejected one
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(0.125));
+        assert_eq!(contact(&mut world), 1, "the window runs from the accepted start");
+    }
+
+    #[test]
+    fn despawning_a_partner_mid_interaction_leaves_the_survivor_consistent() {
+        let mut world = social_world();
+        world.init_resource::<Events<NpcDespawnEvent>>();
+        world.init_resource::<LifecycleConfig>();
+        world.init_resource::<Events<RelationshipChangedEvent>>();
+        world.init_resource::<Events<ConflictResolved>>();
+
+        let npc_a = world
+            .spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default(), SocialRelationships::default()))
+            .id();
+        let npc_b = world
+            .spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default(), SocialRelationships::default()))
+            .id();
+        world.get_mut::<SocialRelationships>(npc_a).unwrap().relationships.insert(npc_b, Relationship::default());
+
+        world.send_event(CollisionEvent::Started(npc_a, npc_b, CollisionEventFlags::empty()));
+        world.run_system_once(handle_social_interactions).unwrap();
+        assert!(!world.get::<SocialInteractionState>(npc_a).unwrap().is_available());
+        world.resource_mut::<Events<InteractionStarted>>().clear();
+
+        world.send_event(NpcDespawnEvent { entity: npc_b });
+        world.run_system_once(npc_despawn_system).unwrap();
+        assert!(world.get_entity(npc_b).is_err());
+
+        let state = world.get::<SocialInteractionState>(npc_a).unwrap();
+        assert!(state.is_available(), "the survivor must not stay locked to a despawned partner");
+        assert_eq!(state.completed_interactions, 0);
+        assert!(world.get::<SocialRelationships>(npc_a).unwrap().relationships.is_empty());
+
+        // Events still naming the despawned agent are skipped rather than panicking
+        world.send_event(CollisionEvent::Started(npc_b, npc_a, CollisionEventFlags::empty()));
+        world.send_event(InteractionCompleted {
+            entity_1: npc_a,
+            entity_2: npc_b,
+            duration: 1.0,
+            interaction_type: InteractionType::Greeting,
+            satisfaction_1: 0.5,
+            satisfaction_2: 0.5,
+        });
+        world.run_system_once(handle_social_interactions).unwrap();
+        complete_social_interactions(&mut world);
+        world.run_system_once(social_interaction_system).unwrap();
+
+        assert!(world.get::<SocialInteractionState>(npc_a).unwrap().is_available());
+        assert_eq!(world.get::<BasicNeeds>(npc_a).unwrap().social, 0.2);
+        assert!(world.resource::<Events<InteractionStarted>>().is_empty());
+        assert!(world.resource::<Events<SocialInteractionEvent>>().is_empty());
+    }
+
+    #[test]
+    fn high_satisfaction_support_restores_more_social_need_than_a_low_satisfaction_greeting() {
+        let social_after = |interaction_type: InteractionType, satisfaction: f32| {
+            let mut world = social_world();
+            let npc_a = world.spawn((Npc, lonely_needs())).id();
+            let npc_b = world.spawn((Npc, lonely_needs())).id();
+
+            world.send_event(InteractionCompleted {
+                entity_1: npc_a,
+                entity_2: npc_b,
+                duration: 2.0,
+                interaction_type,
+                satisfaction_1: satisfaction,
+                satisfaction_2: satisfaction,
+            });
+            world.run_system_once(social_need_fulfillment_system).unwrap();
+            world.get::<BasicNeeds>(npc_a).unwrap().social
+        };
+
+        let supported = social_after(InteractionType::EmotionalSupport, 0.9);
+        let greeted = social_after(InteractionType::Greeting, 0.2);
+        assert!(supported > greeted, "support restored {supported}, a greeting {greeted}");
+        assert!(greeted > 0.2, "even a perfunctory greeting helps a little");
+
+        // Out-of-range satisfaction is clamped rather than draining or overfilling the need
+        assert_eq!(social_after(InteractionType::Conversation, -1.0), 0.2);
+        assert_eq!(social_after(InteractionType::Conversation, 5.0), social_after(InteractionType::Conversation, 1.0));
+    }
+
+    #[test]
+    fn despawning_agent_passes_its_unique_well_location_to_a_nearby_agent() {
+        let mut world = World::new();
+        world.init_resource::<Events<NpcDespawnEvent>>();
+        world.insert_resource(LifecycleConfig { share_memory_on_death: true, ..default() });
+
+        let unique_well = Vec2::new(300.0, -120.0);
+        let mut dying_memory = ResourceMemory::default();
+        dying_memory.known_wells.push(unique_well);
+        let dying_knowledge = KnowledgeBase { knows_rumor: true, known_rumors: [("Rumor_well".to_string(), 0.9)].into() };
+
+        let dying = world.spawn((Npc, Transform::from_xyz(0.0, 0.0, 0.0), dying_memory, dying_knowledge)).id();
+        let nearby = world.spawn((Npc, Transform::from_xyz(40.0, 0.0, 0.0), ResourceMemory::default(), KnowledgeBase::default())).id();
+        let distant = world.spawn((Npc, Transform::from_xyz(900.0, 0.0, 0.0), ResourceMemory::default(), KnowledgeBase::default())).id();
+
+        world.send_event(NpcDespawnEvent { entity: dying });
+        world.run_system_once(npc_despawn_system).unwrap();
+
+        assert!(world.get_entity(dying).is_err());
+        assert_eq!(world.get::<ResourceMemory>(nearby).unwrap().known_wells, vec![unique_well]);
+        assert_eq!(world.get::<KnowledgeBase>(nearby).unwrap().known_rumors.get("Rumor_well"), Some(&0.9));
+        assert!(world.get::<ResourceMemory>(distant).unwrap().known_wells.is_empty(), "only agents in range inherit");
+
+        // With the mechanic disabled the knowledge dies with the agent
+        world.insert_resource(LifecycleConfig::default());
+        let mut second_memory = ResourceMemory::default();
+        second_memory.known_wells.push(Vec2::new(-300.0, 50.0));
+        let second = world.spawn((Npc, Transform::from_xyz(0.0, 0.0, 0.0), second_memory, KnowledgeBase::default())).id();
+        world.send_event(NpcDespawnEvent { entity: second });
+        world.run_system_once(npc_despawn_system).unwrap();
+        assert_eq!(world.get::<ResourceMemory>(nearby).unwrap().known_wells, vec![unique_well]);
+    }
+
+    #[test]
+    fn partner_despawned_without_an_event_is_dropped_by_the_lifecycle() {
+        let mut world = social_world();
+
+        let npc_a = world.spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default())).id();
+        let npc_b = world.spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default())).id();
+
+        world.send_event(CollisionEvent::Started(npc_a, npc_b, CollisionEventFlags::empty()));
+        world.run_system_once(handle_social_interactions).unwrap();
+        world.despawn(npc_b);
+        complete_social_interactions(&mut world);
+
+        let state = world.get::<SocialInteractionState>(npc_a).unwrap();
+        assert!(state.is_available());
+        assert_eq!(state.completed_interactions, 0);
+        assert!(world.resource::<Events<InteractionCompleted>>().is_empty());
+    }
+
+    #[test]
+    fn agents_within_the_social_range_interact_without_touching() {
+        let mut world = social_world();
+        world.insert_resource(SocialConfig { proximity_interactions: true, interaction_distance: 60.0, ..default() });
+        world.init_resource::<SocialProximity>();
+
+        // No colliders and no collision events - only distance matters
+        let mut spawn_at = |x: f32| world.spawn((Npc, lonely_needs(), SocialInteractionState::default(), Transform::from_xyz(x, 0.0, 0.0))).id();
+        let npc_a = spawn_at(0.0);
+        let npc_b = spawn_at(40.0);
+        let distant = spawn_at(500.0);
+
+        world.run_system_once(social_proximity_system).unwrap();
+        world.run_system_once(handle_social_interactions).unwrap();
+
+        let started: Vec<InteractionStarted> = world.resource_mut::<Events<InteractionStarted>>().drain().collect();
+        assert_eq!(started.len(), 1);
+        assert!(!world.get::<SocialInteractionState>(npc_a).unwrap().is_available());
+        assert!(!world.get::<SocialInteractionState>(npc_b).unwrap().is_available());
+        assert!(world.get::<SocialInteractionState>(distant).unwrap().is_available(), "agents out of range stay free");
+
+        // A pair that stays in range is not re-queued on the next tick
+        world.run_system_once(social_proximity_system).unwrap();
+        assert!(world.resource::<SocialProximity>().entered.is_empty());
+    }
+
+    #[test]
+    fn busy_agent_drains_social_energy_faster_and_recovers_slower_than_a_quiet_one() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Events<InteractionCompleted>>();
+        world.insert_resource(SocialConfig { social_fatigue: true, ..default() });
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(10));
+
+        // The busy agent finished five interactions in the last few seconds, the quiet one none
+        let busy_state = SocialInteractionState { interaction_history: [5.0, 6.0, 7.0, 8.0, 9.0].into(), ..default() };
+        let busy = world.spawn((Npc, busy_state, SocialEnergy::default())).id();
+        let quiet = world.spawn((Npc, SocialInteractionState::default(), SocialEnergy::default())).id();
+        let energy = |world: &World, npc: Entity| world.get::<SocialEnergy>(npc).unwrap().level;
+
+        // They finish an interaction with each other
+        world.send_event(InteractionCompleted {
+            entity_1: busy,
+            entity_2: quiet,
+            duration: 2.0,
+            interaction_type: InteractionType::Conversation,
+            satisfaction_1: 0.5,
+            satisfaction_2: 0.5,
+        });
+        world.run_system_once(social_energy_system).unwrap();
+        let (busy_after, quiet_after) = (energy(&world, busy), energy(&world, quiet));
+        assert!(1.0 - busy_after > 1.0 - quiet_after, "busy drained {}, quiet drained {}", 1.0 - busy_after, 1.0 - quiet_after);
+        assert_eq!(world.get::<SocialInteractionState>(quiet).unwrap().interaction_history, [10.0]);
+        world.resource_mut::<Events<InteractionCompleted>>().clear();
+
+        // Ten seconds of solitude restore the quiet agent more
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(10));
+        world.run_system_once(social_energy_system).unwrap();
+        let busy_recovered = energy(&world, busy) - busy_after;
+        let quiet_recovered = energy(&world, quiet) - quiet_after;
+        assert!(busy_recovered > 0.0 && busy_recovered < quiet_recovered, "busy recovered {busy_recovered}, quiet {quiet_recovered}");
+
+        // Once the fatigue window has passed, the history is forgotten
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(60));
+        world.run_system_once(social_energy_system).unwrap();
+        assert!(world.get::<SocialInteractionState>(busy).unwrap().interaction_history.is_empty());
+    }
+
+    #[test]
+    fn well_fed_agreeable_agent_shows_a_starving_neighbour_where_the_food_is() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(GameConstants::default());
+        world.insert_resource(SocialConfig { helping: true, ..default() });
+        world.init_resource::<Events<PathTargetSetEvent>>();
+        world.init_resource::<Events<RelationshipChangedEvent>>();
+
+        let restaurant = Vec2::new(300.0, 0.0);
+        let personality = |agreeableness: f32| Personality { openness: 0.5, extraversion: 0.5, agreeableness, conscientiousness: 0.5, neuroticism: 0.5 };
+        let mut helper_memory = ResourceMemory::default();
+        helper_memory.known_restaurants.push(restaurant);
+        let helper = world.spawn((
+            Npc,
+            Transform::default(),
+            personality(0.9),
+            BasicNeeds { hunger: 0.9, thirst: 0.9, rest: 0.9, safety: 0.9, social: 0.9 },
+            helper_memory,
+            PathTarget::default(),
+            SocialRelationships::default(),
+        )).id();
+        let starving = world.spawn((
+            Npc,
+            Transform::from_xyz(30.0, 0.0, 0.0),
+            personality(0.5),
+            BasicNeeds { hunger: 0.05, thirst: 0.9, rest: 0.9, safety: 0.9, social: 0.9 },
+            ResourceMemory::default(),
+            PathTarget::default(),
+            Desire::FindFood,
+            SocialRelationships::default(),
+        )).id();
+
+        world.run_system_once(helping_system).unwrap();
+
+        assert_eq!(world.get::<ResourceMemory>(starving).unwrap().known_restaurants, [restaurant]);
+        let target = world.get::<PathTarget>(starving).unwrap();
+        assert!(target.has_target);
+        assert_eq!(target.target_position, restaurant);
+        assert!(!world.get::<PathTarget>(helper).unwrap().has_target, "the helper stays where it is");
+
+        let affinity = |world: &World, actor: Entity, other: Entity| world.get::<SocialRelationships>(actor).unwrap().relationships[&other].affinity;
+        assert!(affinity(&world, helper, starving) > 0.0 && affinity(&world, starving, helper) > 0.0);
+
+        // Once the neighbour knows the place and is on its way there is nothing left to help with
+        let changes_before = world.resource::<Events<RelationshipChangedEvent>>().len();
+        world.run_system_once(helping_system).unwrap();
+        assert_eq!(world.resource::<Events<RelationshipChangedEvent>>().len(), changes_before);
+    }
+
+    #[test]
+    fn information_sharing_passes_a_partners_well_on_unless_the_partner_is_distrusted() {
+        let learned_wells = |trust: f32| {
+            let mut world = World::new();
+            world.init_resource::<Time>();
+            world.init_resource::<Events<InteractionCompleted>>();
+            world.insert_resource(SocialConfig { knowledge_exchange: true, ..default() });
+
+            let mut guide_memory = ResourceMemory::default();
+            guide_memory.known_wells.push(Vec2::new(-200.0, 50.0));
+            let guide = world.spawn((Npc, guide_memory, SocialRelationships::default())).id();
+            let listener = world.spawn((Npc, ResourceMemory::default(), SocialRelationships::default())).id();
+            let relationship = Relationship { trust, ..default() };
+            world.get_mut::<SocialRelationships>(listener).unwrap().relationships.insert(guide, relationship);
+
+            world.send_event(InteractionCompleted {
+                entity_1: guide,
+                entity_2: listener,
+                duration: 2.0,
+                interaction_type: InteractionType::InformationSharing,
+                satisfaction_1: 0.5,
+                satisfaction_2: 0.5,
+            });
+            world.run_system_once(knowledge_exchange_system).unwrap();
+            world.get::<ResourceMemory>(listener).unwrap().known_wells.clone()
+        };
+
+        assert_eq!(learned_wells(0.6), [Vec2::new(-200.0, 50.0)]);
+        assert!(learned_wells(0.0).is_empty(), "a distrusted partner's directions are ignored");
+    }
+
+    #[test]
+    fn one_panicking_agent_sets_off_a_bounded_cascade_of_flight_along_a_line_of_neighbours() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(GameConstants::default());
+        world.init_resource::<SimLogConfig>();
+        world.init_resource::<SafetyChannelConfig>();
+        world.insert_resource(PanicConfig { enabled: true, ..default() });
+        world.init_resource::<Events<NeedChangeEvent>>();
+        world.init_resource::<Events<NeedsChangedBatch>>();
+        world.init_resource::<Events<EvaluateDecision>>();
+        world.init_resource::<Events<CurrentDesireSet>>();
+        world.init_resource::<Events<DesireChangeEvent>>();
+
+        // Neighbours stand 50 apart, so each one only hears the agents next to it
+        let agents: Vec<Entity> = (0..7)
+            .map(|index| {
+                let safety = if index == 0 { 0.1 } else { 0.9 };
+                world.spawn((
+                    Npc,
+                    Transform::from_xyz(index as f32 * 50.0, 0.0, 0.0),
+                    BasicNeeds { hunger: 0.9, thirst: 0.9, rest: 0.9, safety, social: 0.9 },
+                    Panic::default(),
+                    ApparentState::default(),
+                    DesireThresholds::default(),
+                    Desire::Wander,
+                    CurrentDesire::default(),
+                )).id()
+            })
+            .collect();
+
+        for _ in 0..10 {
+            world.resource_mut::<Time>().advance_by(Duration::from_millis(100));
+            world.run_system_once(panic_contagion_system).unwrap();
+            world.run_system_once(safety_threat_detection_system).unwrap();
+            world.run_system_once(decision_making_system).unwrap();
+            world.resource_mut::<Events<NeedChangeEvent>>().clear();
+            world.resource_mut::<Events<EvaluateDecision>>().clear();
+        }
+
+        // The alarm halves with every hop and is shrugged off once it falls below min_intensity
+        let alarms: Vec<f32> = agents.iter().map(|&agent| world.get::<ApparentState>(agent).unwrap().alarm).collect();
+        assert_eq!(alarms, [1.0, 0.5, 0.25, 0.125, 0.0, 0.0, 0.0]);
+
+        let desires: Vec<Desire> = agents[1..].iter().map(|&agent| *world.get::<Desire>(agent).unwrap()).collect();
+        assert_eq!(desires, [Desire::FindSafety, Desire::FindSafety, Desire::Wander, Desire::Wander, Desire::Wander, Desire::Wander]);
+        assert_eq!(world.get::<BasicNeeds>(agents[4]).unwrap().safety, 0.9, "agents beyond the cascade are not unsettled at all");
+        assert!((world.get::<BasicNeeds>(agents[1]).unwrap().safety - 0.4).abs() < 1e-6, "the first hop costs the caught half alarm");
+    }
+
+    #[test]
+    fn a_caught_panic_does_not_restart_at_full_intensity_when_it_leaves_safety_critical() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(PanicConfig { enabled: true, ..default() });
+        world.init_resource::<Events<NeedChangeEvent>>();
+
+        let spawn = |world: &mut World, x: f32, safety: f32| {
+            world
+                .spawn((
+                    Npc,
+                    Transform::from_xyz(x, 0.0, 0.0),
+                    BasicNeeds { hunger: 0.9, thirst: 0.9, rest: 0.9, safety, social: 0.9 },
+                    Panic::default(),
+                    ApparentState::default(),
+                ))
+                .id()
+        };
+        spawn(&mut world, 0.0, 0.1);
+        let fragile = spawn(&mut world, 50.0, 0.3);
+
+        for _ in 0..5 {
+            world.resource_mut::<Time>().advance_by(Duration::from_millis(100));
+            world.run_system_once(panic_contagion_system).unwrap();
+        }
+
+        assert!(world.get::<BasicNeeds>(fragile).unwrap().safety < PanicConfig::default().panic_safety_threshold);
+        let panic = world.get::<Panic>(fragile).unwrap();
+        assert_eq!(panic.intensity, 0.5, "the damped alarm holds instead of re-panicking through the threshold");
+        assert!(panic.caught);
+    }
+
+    #[test]
+    fn need_held_at_zero_past_the_critical_duration_fires_the_event_and_applies_the_consequence() {
+        let starve = |consequence: NeedFailureConsequence| {
+            let mut world = World::new();
+            world.init_resource::<Time>();
+            world.init_resource::<SimLogConfig>();
+            world.init_resource::<LifecycleConfig>();
+            world.insert_resource(NeedFailureConfig { enabled: true, critical_duration_secs: 2.0, consequence });
+            world.init_resource::<Events<NeedCriticalEvent>>();
+            world.init_resource::<Events<NpcDespawnEvent>>();
+            let npc = world
+                .spawn((Npc, BasicNeeds { hunger: 0.0, thirst: 1.0, rest: 1.0, safety: 1.0, social: 1.0 }, NeedDepletion::default()))
+                .id();
+
+            // One second at zero is survivable
+            world.resource_mut::<Time>().advance_by(Duration::from_secs(1));
+            world.run_system_once(need_failure_system).unwrap();
+            assert!(world.resource::<Events<NeedCriticalEvent>>().is_empty());
+
+            for _ in 0..2 {
+                world.resource_mut::<Time>().advance_by(Duration::from_secs(1));
+                world.run_system_once(need_failure_system).unwrap();
+            }
+            let fired: Vec<NeedCriticalEvent> = world.resource_mut::<Events<NeedCriticalEvent>>().drain().collect();
+            assert_eq!(fired.len(), 1, "the failure fires once per depletion");
+            assert_eq!(fired[0].need_type, NeedType::Hunger);
+            assert!((fired[0].secs_at_zero - 2.0).abs() < 1e-6);
+
+            world.run_system_once(npc_despawn_system).unwrap();
+            (world, npc)
+        };
+
+        let (world, npc) = starve(NeedFailureConsequence::Incapacitate);
+        assert!(world.get::<Incapacitated>(npc).is_some(), "the starving agent collapses");
+
+        let (world, npc) = starve(NeedFailureConsequence::Despawn);
+        assert!(world.get_entity(npc).is_err(), "the starving agent dies");
+
+        // Feeding a collapsed agent brings it back
+        let (mut world, npc) = starve(NeedFailureConsequence::Incapacitate);
+        world.get_mut::<BasicNeeds>(npc).unwrap().hunger = 0.2;
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(1));
+        world.run_system_once(need_failure_system).unwrap();
+        assert!(world.get::<Incapacitated>(npc).is_none());
+    }
+
+    fn personality_with(extraversion: f32, openness: f32) -> Personality {
+        Personality { openness, extraversion, agreeableness: 0.5, conscientiousness: 0.5, neuroticism: 0.5 }
+    }
+
+    #[test]
+    fn extraverts_initiate_interactions_more_readily_than_introverts() {
+        const CONTACTS: usize = 200;
+
+        let count_started = |extraversion: f32| {
+            let mut world = social_world();
+            let npc_a = world
+                .spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default(), personality_with(extraversion, 0.5)))
+                .id();
+            let npc_b = world
+                .spawn((Npc, lonely_needs(), npc_collision_groups(), SocialInteractionState::default(), personality_with(extraversion, 0.5)))
+                .id();
+
+            let mut started = 0;
+            for _ in 0..CONTACTS {
+                world.send_event(CollisionEvent::Started(npc_a, npc_b, CollisionEventFlags::empty()));
+                world.run_system_once(handle_social_interactions).unwrap();
+                started += world.resource_mut::<Events<InteractionStarted>>().drain().count();
+                // Free both agents and let the debounce window pass, so every contact is a fresh chance to engage
+                for npc in [npc_a, npc_b] {
+                    world.get_mut::<SocialInteractionState>(npc).unwrap().active = None;
+                }
+                world.resource_mut::<Time>().advance_by(Duration::from_secs(1));
+            }
+            started
+        };
+
+        let extravert_starts = count_started(0.9);
+        let introvert_starts = count_started(0.1);
+        assert!(
+            extravert_starts > introvert_starts * 2,
+            "extraverts started {extravert_starts} interactions, introverts {introvert_starts}"
+        );
+    }
+
+    /// Fires a fast NPC body at a thin wall through real Rapier stepping and returns where it ends up
+    fn final_x_after_charging_a_thin_wall(npc_ccd_enabled: bool) -> f32 {
+        let constants = GameConstants { npc_ccd_enabled, ..default() };
+        let tick = Duration::from_secs_f32(1.0 / constants.simulation_tick_hz);
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, TransformPlugin, RapierPhysicsPlugin::<NoUserData>::default()))
+            .insert_resource(physics_timestep_mode(&constants))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(tick));
+
+        // Two units thick, while the agent covers a hundred units every tick
+        app.world_mut().spawn((RigidBody::Fixed, Collider::cuboid(1.0, 200.0), wall_collision_groups(), Transform::default()));
+        let npc = app.world_mut().spawn((
+            RigidBody::Dynamic,
+            GravityScale(0.0),
+            Collider::ball(5.0),
+            Restitution::coefficient(0.0),
+            npc_collision_groups(),
+            npc_ccd(&constants),
+            Velocity::linear(Vec2::new(100.0 * constants.simulation_tick_hz, 0.0)),
+            Transform::from_xyz(-60.0, 0.0, 0.0),
+        )).id();
+
+        for _ in 0..5 {
+            app.update();
+        }
+        app.world().get::<Transform>(npc).unwrap().translation.x
+    }
+
+    #[test]
+    fn fast_agent_with_ccd_is_stopped_by_a_thin_wall_it_would_otherwise_tunnel_through() {
+        assert!(final_x_after_charging_a_thin_wall(false) > 1.0, "without CCD the body skips straight past the wall");
+        assert!(final_x_after_charging_a_thin_wall(true) < 0.0, "with CCD the sweep catches the wall");
+
+        let mut constants = GameConstants { physics_substeps: 0, ..default() };
+        let violations = constants.validate();
+        assert!(violations.iter().any(|violation| violation.field == "physics_substeps"));
+        assert!(matches!(physics_timestep_mode(&constants), TimestepMode::Fixed { substeps: 1, .. }));
+    }
+
+    // =========================================================================
+    // RUMORS
+    // =========================================================================
+
+    fn rumor_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(GameConstants::default());
+        world.insert_resource(SimulationRng::from_seed(5));
+        world.init_resource::<RumorRegistry>();
+        world.init_resource::<Events<CollisionEvent>>();
+        world.init_resource::<Events<RumorSpreadAttemptEvent>>();
+        world.init_resource::<Events<RumorSpreadEvent>>();
+        world.init_resource::<Events<RumorMutationEvent>>();
+        world
+    }
+
+    /// Spread parameters that always transmit (the probability clamps to 1.0) and never alter the rumor
+    fn certain_rumor() -> RumorParameters {
+        RumorParameters { transmission_rate: 100.0, modification_probability: 0.0, ..default() }
+    }
+
+    fn rumor_knowledge(rumors: &[(&str, f32)]) -> KnowledgeBase {
+        KnowledgeBase {
+            knows_rumor: !rumors.is_empty(),
+            known_rumors: rumors.iter().map(|(rumor, belief)| (rumor.to_string(), *belief)).collect(),
+        }
+    }
+
+    fn tell_rumors(world: &mut World, sender: Entity, receiver: Entity) {
+        world.send_event(CollisionEvent::Started(sender, receiver, CollisionEventFlags::empty()));
+        world.run_system_once(rumor_interaction_detection_system).unwrap();
+        world.run_system_once(rumor_transmission_system).unwrap();
+        // run_system_once reads from the start of the queue, so this contact must not be replayed by the next one
+        world.resource_mut::<Events<CollisionEvent>>().clear();
+        world.resource_mut::<Events<RumorSpreadAttemptEvent>>().clear();
+    }
+
+    #[test]
+    fn two_rumors_spread_independently() {
+        let mut world = rumor_world();
+        let mut registry = world.resource_mut::<RumorRegistry>();
+        registry.register("Rumor_well", certain_rumor());
+        registry.register("Rumor_fire", certain_rumor());
+
+        let sender = world.spawn((Npc, personality_with(0.5, 0.5), rumor_knowledge(&[("Rumor_well", 1.0), ("Rumor_fire", 1.0)]))).id();
+        let receiver = world.spawn((Npc, personality_with(0.5, 0.5), rumor_knowledge(&[("Rumor_well", 0.3)]))).id();
+        tell_rumors(&mut world, sender, receiver);
+
+        let spread: Vec<String> = world.resource_mut::<Events<RumorSpreadEvent>>().drain().map(|event| event.rumor_content).collect();
+        assert_eq!(spread, vec!["Rumor_fire".to_string()], "only the rumor the receiver lacked is passed on");
+        let known = &world.get::<KnowledgeBase>(receiver).unwrap().known_rumors;
+        assert_eq!(known.get("Rumor_fire"), Some(&0.8));
+        assert_eq!(known.get("Rumor_well"), Some(&0.3), "an unrelated rumor is not interfered with");
+    }
+
+    #[test]
+    fn mutated_rumor_is_recorded_on_the_receiver_as_a_new_variant() {
+        let mut world = rumor_world();
+        world.resource_mut::<RumorRegistry>().register(
+            "Rumor_well",
+            RumorParameters { modification_probability: 1.0, interference_strength: 0.5, ..certain_rumor() },
+        );
+
+        let sender = world.spawn((Npc, personality_with(0.5, 0.5), rumor_knowledge(&[("Rumor_well", 1.0)]))).id();
+        let receiver = world.spawn((Npc, personality_with(0.5, 0.5), rumor_knowledge(&[]))).id();
+        tell_rumors(&mut world, sender, receiver);
+
+        let mutations: Vec<RumorMutationEvent> = world.resource_mut::<Events<RumorMutationEvent>>().drain().collect();
+        assert_eq!(mutations.len(), 1);
+        let variant = mutations[0].variant_rumor.clone();
+        assert_eq!(mutations[0].original_rumor, "Rumor_well");
+        assert_ne!(variant, "Rumor_well");
+        assert_eq!(world.resource::<RumorRegistry>().origin(&variant), "Rumor_well");
+
+        let known = &world.get::<KnowledgeBase>(receiver).unwrap().known_rumors;
+        assert!(known.contains_key(&variant));
+        assert!(!known.contains_key("Rumor_well"), "the receiver only heard the altered version");
+
+        // Hearing a retold version back weakens the sender's belief in the original it competes with
+        tell_rumors(&mut world, receiver, sender);
+        let sender_rumors = &world.get::<KnowledgeBase>(sender).unwrap().known_rumors;
+        assert_eq!(sender_rumors.get("Rumor_well"), Some(&0.5));
+        assert_eq!(sender_rumors.len(), 2);
+    }
+
+    #[test]
+    fn open_agents_adopt_rumors_more_often_than_closed_ones() {
+        const TRIALS: usize = 1000;
+        const RUMOR: &str = "Rumor_test";
+
+        let mut world = rumor_world();
+        world.resource_mut::<RumorRegistry>().default_parameters.modification_probability = 0.0;
+
+        let knowledge = |knows: bool| KnowledgeBase {
+            knows_rumor: knows,
+            known_rumors: if knows { [(RUMOR.to_string(), 1.0)].into() } else { Default::default() },
+        };
+        let sender = world.spawn((Npc, personality_with(0.5, 0.5), knowledge(true))).id();
+        let open_receiver = world.spawn((Npc, personality_with(0.5, 1.0), knowledge(false))).id();
+        let closed_receiver = world.spawn((Npc, personality_with(0.5, 0.0), knowledge(false))).id();
+
+        let (mut open_adoptions, mut closed_adoptions) = (0, 0);
+        for _ in 0..TRIALS {
+            world.send_event(CollisionEvent::Started(sender, open_receiver, CollisionEventFlags::empty()));
+            world.send_event(CollisionEvent::Started(sender, closed_receiver, CollisionEventFlags::empty()));
+            world.run_system_once(rumor_interaction_detection_system).unwrap();
+            world.run_system_once(rumor_transmission_system).unwrap();
+            world.resource_mut::<Events<CollisionEvent>>().clear();
+            world.resource_mut::<Events<RumorSpreadAttemptEvent>>().clear();
+
+            // Count and forget so every trial starts from the same knowledge
+            for (receiver, adoptions) in [(open_receiver, &mut open_adoptions), (closed_receiver, &mut closed_adoptions)] {
+                let mut receiver_knowledge = world.get_mut::<KnowledgeBase>(receiver).unwrap();
+                if receiver_knowledge.known_rumors.remove(RUMOR).is_some() {
+                    *adoptions += 1;
+                }
+            }
+        }
+
+        assert!(
+            open_adoptions > closed_adoptions,
+            "open receiver adopted {open_adoptions} times, closed receiver {closed_adoptions}"
+        );
+    }
+
+    // =========================================================================
+    // BELIEFS
+    // =========================================================================
+
+    #[test]
+    fn confirming_evidence_moves_beliefs_more_than_contradicting_evidence() {
+        let mut world = World::new();
+        world.insert_resource(GameConstants::default());
+        world.init_resource::<Events<BeliefEvidenceEvent>>();
+        world.init_resource::<Events<BeliefUpdated>>();
+
+        // Both agents lean toward believing water exists
+        let leaning = || {
+            let mut beliefs = Beliefs::default();
+            beliefs.certainties.insert(Proposition::WaterAvailable, 0.6);
+            beliefs
+        };
+        let confirmed = world.spawn((Npc, leaning())).id();
+        let contradicted = world.spawn((Npc, leaning())).id();
+
+        world.send_event(BeliefEvidenceEvent { entity: confirmed, proposition: Proposition::WaterAvailable, strength: 0.3 });
+        world.send_event(BeliefEvidenceEvent { entity: contradicted, proposition: Proposition::WaterAvailable, strength: -0.3 });
+        world.run_system_once(belief_update_system).unwrap();
+
+        let raised = world.get::<Beliefs>(confirmed).unwrap().certainty(Proposition::WaterAvailable) - 0.6;
+        let lowered = 0.6 - world.get::<Beliefs>(contradicted).unwrap().certainty(Proposition::WaterAvailable);
+        assert!(raised > 0.0 && lowered > 0.0);
+        assert!(raised > lowered, "confirmation bias should discount contradicting evidence ({raised} vs {lowered})");
+
+        let updates: Vec<_> = world.resource_mut::<Events<BeliefUpdated>>().drain().collect();
+        assert_eq!(updates.len(), 2);
+        assert_eq!(updates.iter().filter(|update| update.confirming).count(), 1);
+    }
+
+    #[test]
+    fn searching_for_water_in_vain_lowers_the_belief_that_water_exists() {
+        let mut world = World::new();
+        world.insert_resource(GameConstants::default());
+        world.init_resource::<Events<RumorSpreadEvent>>();
+        world.init_resource::<Events<ResourceInteractionSuccessEvent>>();
+        world.init_resource::<Events<ActionCompleted>>();
+        world.init_resource::<Events<BeliefEvidenceEvent>>();
+        world.init_resource::<Events<BeliefUpdated>>();
+
+        let believer = || {
+            let mut beliefs = Beliefs::default();
+            beliefs.certainties.insert(Proposition::WaterAvailable, 0.6);
+            beliefs
+        };
+        let disappointed = world.spawn((Npc, believer())).id();
+        let distracted = world.spawn((Npc, believer())).id();
+        for (entity, completion_reason) in [(disappointed, ActionCompletionReason::Timeout), (distracted, ActionCompletionReason::Interrupted)] {
+            world.send_event(ActionCompleted { entity, completed_desire: Desire::FindWater, completion_reason, duration: 10.0, success: false });
+        }
+        world.run_system_once(belief_evidence_system).unwrap();
+        world.run_system_once(belief_update_system).unwrap();
+
+        let certainty = |entity| world.get::<Beliefs>(entity).unwrap().certainty(Proposition::WaterAvailable);
+        assert!(certainty(disappointed) < 0.6, "a fruitless search is contradicting evidence");
+        assert_eq!(certainty(distracted), 0.6, "an interrupted search is no evidence at all");
+    }
+
+    // =========================================================================
+    // DUAL PROCESS
+    // =========================================================================
+
+    #[test]
+    fn stressed_agents_reevaluate_more_often_than_calm_agents() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Events<EvaluateDecision>>();
+
+        let stressed = world.spawn((CurrentDesire::default(), DualProcess { stress: 0.9, ..Default::default() })).id();
+        let calm = world.spawn((CurrentDesire::default(), DualProcess { stress: 0.0, ..Default::default() })).id();
+
+        // Simulate ten seconds at 10 frames per second
+        let mut evaluations = Vec::new();
+        for _ in 0..100 {
+            world.resource_mut::<Time>().advance_by(Duration::from_millis(100));
+            world.run_system_once(periodic_decision_trigger_system).unwrap();
+            evaluations.extend(world.resource_mut::<Events<EvaluateDecision>>().drain().map(|event| event.entity));
+        }
+
+        let stressed_count = evaluations.iter().filter(|entity| **entity == stressed).count();
+        let calm_count = evaluations.iter().filter(|entity| **entity == calm).count();
+        assert!(calm_count > 0);
+        assert!(stressed_count > calm_count, "stressed: {stressed_count}, calm: {calm_count}");
+    }
+
+    #[test]
+    fn depleted_willpower_speeds_up_reevaluation_without_any_need_changing() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Events<EvaluateDecision>>();
+
+        let depleted = world.spawn((CurrentDesire::default(), DualProcess::default(), Willpower { current: 0.1, max: 1.0 })).id();
+        let rested = world.spawn((CurrentDesire::default(), DualProcess::default(), Willpower::default())).id();
+
+        // No BasicNeeds at all: engagement is read straight from Willpower every frame
+        let mut evaluations = Vec::new();
+        for _ in 0..100 {
+            world.resource_mut::<Time>().advance_by(Duration::from_millis(100));
+            world.run_system_once(periodic_decision_trigger_system).unwrap();
+            evaluations.extend(world.resource_mut::<Events<EvaluateDecision>>().drain().map(|event| event.entity));
+        }
+
+        let depleted_count = evaluations.iter().filter(|entity| **entity == depleted).count();
+        let rested_count = evaluations.iter().filter(|entity| **entity == rested).count();
+        assert!(rested_count > 0);
+        assert!(depleted_count > rested_count, "depleted: {depleted_count}, rested: {rested_count}");
+    }
+
+    /// Runs a crowd of calm agents spawned on the same frame for twenty seconds at 10 frames per second
+    /// Returns the number of periodic evaluations fired on each frame
+    fn per_frame_periodic_evaluations(jitter: bool) -> Vec<usize> {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Events<EvaluateDecision>>();
+        world.insert_resource(SimulationRng::from_seed(7));
+        world.insert_resource(DecisionJitterConfig { enabled: jitter, ..Default::default() });
+
+        for _ in 0..100 {
+            world.spawn((CurrentDesire::default(), DualProcess::default()));
+        }
+        world.run_system_once(decision_phase_jitter_system).unwrap();
+
+        (0..200).map(|_| {
+            world.resource_mut::<Time>().advance_by(Duration::from_millis(100));
+            world.run_system_once(periodic_decision_trigger_system).unwrap();
+            world.resource_mut::<Events<EvaluateDecision>>().drain().count()
+        }).collect()
+    }
+
+    #[test]
+    fn phase_jitter_spreads_evaluations_across_frames_at_the_same_average_rate() {
+        let clustered = per_frame_periodic_evaluations(false);
+        let spread = per_frame_periodic_evaluations(true);
+
+        let clustered_peak = clustered.iter().copied().max().unwrap();
+        let spread_peak = spread.iter().copied().max().unwrap();
+        assert_eq!(clustered_peak, 100, "without jitter the whole crowd evaluates on one frame");
+        assert!(spread_peak * 4 <= clustered_peak, "peak with jitter: {spread_peak}, without: {clustered_peak}");
+
+        // The offset only moves the first evaluation, so totals differ by at most one evaluation per agent
+        let clustered_total: usize = clustered.iter().sum();
+        let spread_total: usize = spread.iter().sum();
+        assert!(clustered_total.abs_diff(spread_total) <= 100, "with jitter: {spread_total}, without: {clustered_total}");
+    }
+
+    // =========================================================================
+    // WILLPOWER
+    // =========================================================================
+
+    #[test]
+    fn overriding_hunger_drains_willpower_until_food_is_forced() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(GameConstants::default());
+        world.init_resource::<SimLogConfig>();
+        world.init_resource::<Events<EvaluateDecision>>();
+        world.init_resource::<Events<CurrentDesireSet>>();
+        world.init_resource::<Events<DesireChangeEvent>>();
+
+        // Starving but still choosing to socialize
+        let needs = BasicNeeds { hunger: 0.1, thirst: 1.0, rest: 1.0, safety: 1.0, social: 0.3 };
+        let agent = world.spawn((
+            needs,
+            DesireThresholds::default(),
+            CurrentDesire { desire: Desire::Socialize, ..Default::default() },
+            Willpower::default(),
+        )).id();
+
+        let mut previous_willpower = world.get::<Willpower>(agent).unwrap().current;
+        let mut overrides = 0;
+        for _ in 0..10 {
+            world.send_event(EvaluateDecision { entity: agent, trigger_reason: DecisionTrigger::Periodic });
+            world.run_system_once(decision_making_system).unwrap();
+            world.resource_mut::<Events<EvaluateDecision>>().clear();
+
+            if world.get::<CurrentDesire>(agent).unwrap().desire == Desire::FindFood {
+                break;
+            }
+            let willpower = world.get::<Willpower>(agent).unwrap().current;
+            assert!(willpower < previous_willpower, "every override must cost willpower");
+            previous_willpower = willpower;
+            overrides += 1;
+        }
+
+        assert!(overrides >= 3, "a rested agent should resist hunger for a while, got {overrides}");
+        assert_eq!(world.get::<CurrentDesire>(agent).unwrap().desire, Desire::FindFood);
+        assert_eq!(world.get::<Willpower>(agent).unwrap().current, 0.0);
+    }
+
+    #[test]
+    fn flip_flopping_agent_gains_a_commitment_bias_that_settles_it() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(GameConstants::default());
+        world.init_resource::<SimLogConfig>();
+        world.init_resource::<DecisionStabilityConfig>();
+        world.init_resource::<Events<EvaluateDecision>>();
+        world.init_resource::<Events<CurrentDesireSet>>();
+        world.init_resource::<Events<DesireChangeEvent>>();
+
+        // Hunger fixed, thirst wobbling either side of the point where water and food tie
+        let agent = world.spawn((
+            BasicNeeds { hunger: 0.4, thirst: 0.54, rest: 1.0, safety: 1.0, social: 1.0 },
+            DesireThresholds::default(),
+            CurrentDesire::default(),
+            DecisionStability::default(),
+        )).id();
+
+        let mut changes_per_tick = Vec::new();
+        for tick in 0..30 {
+            world.get_mut::<BasicNeeds>(agent).unwrap().thirst = if tick % 2 == 0 { 0.54 } else { 0.565 };
+            world.resource_mut::<Time>().advance_by(Duration::from_secs(1));
+            world.send_event(EvaluateDecision { entity: agent, trigger_reason: DecisionTrigger::Periodic });
+            world.run_system_once(decision_making_system).unwrap();
+            world.resource_mut::<Events<EvaluateDecision>>().clear();
+
+            changes_per_tick.push(world.resource::<Events<DesireChangeEvent>>().len());
+            world.run_system_once(decision_stability_system).unwrap();
+            world.resource_mut::<Events<DesireChangeEvent>>().clear();
+        }
+
+        let early_changes: usize = changes_per_tick[..10].iter().sum();
+        let late_changes: usize = changes_per_tick[10..].iter().sum();
+        assert!(early_changes >= 7, "a near tie makes the agent flip every tick at first, got {early_changes}");
+        assert_eq!(late_changes, 0, "once dithering the commitment bias holds the current desire");
+
+        let stability = world.get::<DecisionStability>(agent).unwrap();
+        assert!(stability.changes_per_minute > DecisionStabilityConfig::default().max_changes_per_minute);
+        assert_eq!(stability.commitment_bias, DecisionStabilityConfig::default().commitment_bias);
+    }
+
+    // =========================================================================
+    // RESOURCE SPATIAL INDEX
+    // =========================================================================
+
+    /// Spawns `agents` NPCs and `resources` resources scattered over a 2000x2000 area
+    fn populate_discovery_world(world: &mut World, agents: usize, resources: usize, seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let random_transform = |rng: &mut StdRng| {
+            Transform::from_xyz(rng.random_range(-1000.0..1000.0), rng.random_range(-1000.0..1000.0), 0.0)
+        };
+
+        for i in 0..resources {
+            let transform = random_transform(&mut rng);
+            match i % 4 {
+                0 => world.spawn((Well::default(), transform)),
+                1 => world.spawn((Restaurant::default(), transform)),
+                2 => world.spawn((Hotel::default(), transform)),
+                _ => world.spawn((SafeZone::default(), transform)),
+            };
+        }
+        for _ in 0..agents {
+            let transform = random_transform(&mut rng);
+            world.spawn((Npc, ResourceMemory::default(), transform));
+        }
+    }
+
+    /// Reference implementation: every NPC against every resource
+    fn brute_force_discoveries(world: &mut World) -> Vec<(Entity, Entity)> {
+        let resources: Vec<(Entity, Vec2)> = world
+            .query_filtered::<(Entity, &Transform), Or<(With<Well>, With<Restaurant>, With<Hotel>, With<SafeZone>)>>()
+            .iter(world)
+            .map(|(entity, transform)| (entity, transform.translation.truncate()))
+            .collect();
+
+        let mut pairs = Vec::new();
+        for (npc, transform, memory) in world.query_filtered::<(Entity, &Transform, &ResourceMemory), With<Npc>>().iter(world) {
+            for (resource, position) in &resources {
+                if transform.translation.truncate().distance(*position) <= memory.discovery_radius {
+                    pairs.push((npc, *resource));
+                }
+            }
+        }
+        pairs.sort();
+        pairs
+    }
+
+    fn indexed_discoveries(world: &mut World) -> Vec<(Entity, Entity)> {
+        world.insert_resource(GameConstants::default());
+        world.init_resource::<Time>();
+        world.init_resource::<ResourceSpatialIndex>();
+        world.init_resource::<Events<ResourceDiscoveredEvent>>();
+        world.run_system_once(resource_spatial_index_system).unwrap();
+        world.run_system_once(resource_discovery_system).unwrap();
+
+        let mut pairs: Vec<_> = world
+            .resource_mut::<Events<ResourceDiscoveredEvent>>()
+            .drain()
+            .map(|event| (event.npc_entity, event.resource_entity))
+            .collect();
+        pairs.sort();
+        pairs
+    }
+
+    #[test]
+    fn high_acuity_agent_discovers_a_resource_a_low_acuity_agent_misses() {
+        let mut world = World::new();
+        let well = world.spawn((Well::default(), Transform::from_xyz(120.0, 0.0, 0.0))).id();
+        let keen = world.spawn((Npc, ResourceMemory::default(), Perception { acuity: 0.9 }, Transform::default())).id();
+        world.spawn((Npc, ResourceMemory::default(), Perception { acuity: 0.1 }, Transform::default()));
+
+        // 120 units is beyond the 100 unit base radius: acuity 0.9 stretches it to 140, acuity 0.1 shrinks it to 60
+        assert_eq!(indexed_discoveries(&mut world), vec![(keen, well)]);
+        assert!(Perception { acuity: 0.9 }.range_multiplier() > Perception::default().range_multiplier());
+        assert_eq!(Perception::default().range_multiplier(), 1.0, "average acuity keeps the configured ranges");
+    }
+
+    #[test]
+    fn wells_beyond_capacity_evict_the_oldest_unreinforced_one_unless_it_was_revisited() {
+        let (first, second, third) = (Vec2::new(0.0, 0.0), Vec2::new(100.0, 0.0), Vec2::new(200.0, 0.0));
+        let remembered_after = |revisit_first: bool| {
+            let mut memory = ResourceMemory { capacity_per_type: 2, ..default() };
+            assert!(remember_resource_location(&mut memory, ResourceType::Water, first, 0.0));
+            assert!(remember_resource_location(&mut memory, ResourceType::Water, second, 10.0));
+            if revisit_first {
+                assert!(!remember_resource_location(&mut memory, ResourceType::Water, first, 50.0), "a revisit is not a discovery");
+            }
+            assert!(remember_resource_location(&mut memory, ResourceType::Water, third, 60.0));
+            assert_eq!(memory.traces.len(), 2, "forgotten locations leave no trace behind");
+            memory.known_wells
+        };
+
+        assert_eq!(remembered_after(false), vec![second, third], "the oldest unconfirmed well is forgotten");
+        assert_eq!(remembered_after(true), vec![first, third], "revisiting the first well protects it");
+
+        // Retention falls with age and more slowly for reinforced memories
+        assert!(memory_retention(100.0, 1.0, 0.01) < memory_retention(10.0, 1.0, 0.01));
+        assert!(memory_retention(100.0, 2.0, 0.01) > memory_retention(100.0, 1.0, 0.01));
+    }
+
+    /// Arrival threshold an agent gets when heading for a well with a collider of the given radius
+    fn arrival_threshold_for_well_radius(radius: f32) -> f32 {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(GameConstants::default());
+        world.insert_resource(SimulationRng::from_seed(1));
+        world.init_resource::<ResourceSpatialIndex>();
+        world.init_resource::<Events<PathTargetSetEvent>>();
+        world.init_resource::<Events<ReservationDenied>>();
+
+        let well_position = Vec2::new(200.0, 0.0);
+        world.spawn((Well::default(), Collider::ball(radius), Transform::from_translation(well_position.extend(0.0))));
+        let npc = world.spawn((
+            Npc,
+            Transform::default(),
+            Desire::FindWater,
+            ResourceMemory { known_wells: vec![well_position], ..Default::default() },
+            PathTarget::default(),
+        )).id();
+
+        world.run_system_once(resource_spatial_index_system).unwrap();
+        world.run_system_once(desire_pathfinding_system).unwrap();
+        world.get::<PathTarget>(npc).unwrap().arrival_threshold
+    }
+
+    #[test]
+    fn lookahead_addresses_a_fast_draining_need_before_it_is_urgent() {
+        let decide = |lookahead_enabled: bool| {
+            let mut world = World::new();
+            world.init_resource::<Time>();
+            world.insert_resource(GameConstants {
+                npc_speed: 100.0,
+                hunger_decay: 0.005,
+                thirst_decay: 0.02,
+                fatigue_regen: 0.0,
+                safety_decay: 0.0,
+                loneliness_decay: 0.0,
+                ..default()
+            });
+            world.insert_resource(DecisionLookaheadConfig { enabled: lookahead_enabled, ..default() });
+            world.init_resource::<SimLogConfig>();
+            world.init_resource::<Events<EvaluateDecision>>();
+            world.init_resource::<Events<CurrentDesireSet>>();
+            world.init_resource::<Events<DesireChangeEvent>>();
+
+            // Hungry now; thirst is still above its threshold but drains four times as fast,
+            // and the only food is a ten-second walk away
+            let agent = world.spawn((
+                BasicNeeds { hunger: 0.6, thirst: 0.8, rest: 1.0, safety: 1.0, social: 1.0 },
+                DesireThresholds::default(),
+                NeedPriorityWeights { safety: 1.0, thirst: 1.0, hunger: 1.0, rest: 1.0, social: 1.0 },
+                CurrentDesire::default(),
+                DualProcess { stress: 0.0, time_since_evaluation: 0.0 },
+                Willpower { current: 0.5, max: 1.0 },
+                Transform::default(),
+                ResourceMemory { known_restaurants: vec![Vec2::new(1000.0, 0.0)], ..default() },
+            )).id();
+
+            world.send_event(EvaluateDecision { entity: agent, trigger_reason: DecisionTrigger::Periodic });
+            world.run_system_once(decision_making_system).unwrap();
+            world.get::<CurrentDesire>(agent).unwrap().desire
+        };
+
+        assert_eq!(decide(false), Desire::FindFood, "a reactive agent goes for the larger deficit");
+        assert_eq!(decide(true), Desire::FindWater, "thirst would be critical by the time the trip is over");
+    }
+
+    #[test]
+    fn larger_resources_are_reached_from_farther_away() {
+        let npc_radius = GameConstants::default().npc_radius;
+        let small = arrival_threshold_for_well_radius(5.0);
+        let large = arrival_threshold_for_well_radius(60.0);
+
+        assert_eq!(small, 5.0 + npc_radius);
+        assert_eq!(large, 60.0 + npc_radius);
+        assert!(large > small);
+    }
+
+    #[test]
+    fn indexed_discovery_matches_brute_force() {
+        let mut world = World::new();
+        populate_discovery_world(&mut world, 300, 120, 7);
+
+        let expected = brute_force_discoveries(&mut world);
+        assert!(!expected.is_empty(), "the scenario should produce discoveries");
+        assert_eq!(indexed_discoveries(&mut world), expected);
+    }
+
+    /// Steady-state lookups against an index that is already built, as on every frame without resource changes
+    fn indexed_lookups(world: &mut World) -> Vec<(Entity, Entity)> {
+        let mut npc_query = world.query_filtered::<(Entity, &Transform, &ResourceMemory), With<Npc>>();
+        let index = world.resource::<ResourceSpatialIndex>();
+        let mut pairs: Vec<_> = npc_query
+            .iter(world)
+            .flat_map(|(npc, transform, memory)| index.query_radius(transform.translation.truncate(), memory.discovery_radius).map(move |resource| (npc, resource.entity)))
+            .collect();
+        pairs.sort();
+        pairs
+    }
+
+    #[test]
+    fn indexed_discovery_is_not_slower_than_brute_force_for_1000_agents_200_resources() {
+        let mut world = World::new();
+        populate_discovery_world(&mut world, 1000, 200, 42);
+        let expected = brute_force_discoveries(&mut world);
+        assert_eq!(indexed_discoveries(&mut world), expected);
+
+        // Best of several runs, so a single scheduler hiccup cannot decide the outcome
+        let mut best_of = |lookup: fn(&mut World) -> Vec<(Entity, Entity)>| {
+            (0..20)
+                .map(|_| {
+                    let start = std::time::Instant::now();
+                    assert_eq!(lookup(&mut world), expected);
+                    start.elapsed()
+                })
+                .min()
+                .unwrap()
+        };
+        let brute_force = best_of(brute_force_discoveries);
+        let indexed = best_of(indexed_lookups);
+
+        assert!(indexed <= brute_force, "spatial index took {indexed:?}, brute force {brute_force:?}");
+    }
+
+    // =========================================================================
+    // WANDER BOUNDS
+    // =========================================================================
+
+    #[test]
+    fn room_walls_and_spawn_areas_follow_the_configured_world_size_not_the_window() {
+        let room_half_extents = Vec2::new(1000.0, 400.0);
+        let mut world = World::new();
+        world.insert_resource(GameConstants { room_half_extents, ..default() });
+        // A default 1280x720 window, smaller than the room and of a different aspect ratio
+        world.spawn(Window::default());
+
+        world.run_system_once(create_empty_room_system).unwrap();
+        assert_eq!(world.resource::<GameConstants>().room_half_extents, room_half_extents, "the window must not resize the room");
+        let mut walls = world.query_filtered::<&Transform, With<Wall>>();
+        let rightmost = walls.iter(&world).map(|transform| transform.translation.x).fold(f32::MIN, f32::max);
+        assert_eq!(rightmost, room_half_extents.x + WALL_THICKNESS / 2.0);
+
+        let npc_radius = GameConstants::default().npc_radius;
+        let positions = calculate_spawn_positions(&SpawnDistribution::Uniform, 200, room_half_extents, npc_radius, &mut StdRng::seed_from_u64(4));
+        assert!(positions.iter().all(|position| position.abs().cmple(room_half_extents - Vec2::splat(npc_radius)).all()));
+        assert!(positions.iter().any(|position| position.x.abs() > 640.0), "agents must use the room beyond the window's half width");
+
+        let resource_area = calculate_resource_spawn_area(room_half_extents, 50.0);
+        assert_eq!(resource_area, Rect::new(-950.0, -350.0, 950.0, 350.0));
+
+        // At zoom 1.0 the camera shows the whole room and its walls, whatever the window
+        let fit = calculate_world_fit_scaling(room_half_extents, WALL_THICKNESS);
+        assert!(matches!(fit, ScalingMode::AutoMin { min_width, min_height } if min_width == 2040.0 && min_height == 840.0), "{fit:?}");
+    }
+
+    #[test]
+    fn agent_near_left_wall_is_biased_rightward() {
+        let constants = GameConstants::default();
+        let half = constants.room_half_extents;
+        let margin = constants.wander_boundary_margin;
+
+        let near_left = Vec2::new(-half.x + margin * 0.25, 0.0);
+        let bias = calculate_boundary_avoidance_bias(near_left, half, margin);
+        assert!(bias.x > 0.5, "expected a strong rightward bias, got {bias:?}");
+        assert_eq!(bias.y, 0.0, "no vertical wall is nearby");
+
+        let center_bias = calculate_boundary_avoidance_bias(Vec2::ZERO, half, margin);
+        assert_eq!(center_bias, Vec2::ZERO, "agents in the interior wander freely");
+    }
+
+    #[test]
+    fn boundary_policy_reflects_wraps_or_clamps_an_agent_at_the_right_wall() {
+        // An agent at the right edge heading right and slightly up, past the wall when wrapping
+        let at_right_edge = |policy: BoundaryPolicy, x: f32| {
+            let mut world = World::new();
+            world.insert_resource(GameConstants { boundary_policy: policy, ..default() });
+            world.init_resource::<Events<BoundaryCollisionEvent>>();
+            let npc = world.spawn((Npc, Transform::from_xyz(x, 0.0, 0.0), Velocity::linear(Vec2::new(100.0, 20.0)))).id();
+            world.run_system_once(boundary_collision_system).unwrap();
+            let collisions = world.resource::<Events<BoundaryCollisionEvent>>().len();
+            (world.get::<Transform>(npc).unwrap().translation.truncate(), world.get::<Velocity>(npc).unwrap().linvel, collisions)
+        };
+        let half = GameConstants::default().room_half_extents;
+
+        let (_, reflected, collisions) = at_right_edge(BoundaryPolicy::Reflect, half.x - 10.0);
+        assert_eq!(reflected, Vec2::new(-100.0, 20.0), "reflection flips the velocity into the room");
+        assert_eq!(collisions, 1);
+
+        let (wrapped, velocity, collisions) = at_right_edge(BoundaryPolicy::Wrap, half.x + 5.0);
+        assert!((wrapped - Vec2::new(-half.x + 5.0, 0.0)).length() < 1e-3, "re-enters at the left edge, got {wrapped:?}");
+        assert_eq!(velocity, Vec2::new(100.0, 20.0), "wrapping keeps the heading");
+        assert_eq!(collisions, 0);
+
+        let (clamped, stopped, collisions) = at_right_edge(BoundaryPolicy::Clamp, half.x - 10.0);
+        assert_eq!(stopped, Vec2::new(0.0, 20.0), "clamping stops movement into the wall but keeps the slide along it");
+        assert!(clamped.x < half.x);
+        assert_eq!(collisions, 1);
+    }
+
+    #[test]
+    fn opposite_seek_force_changes_velocity_by_at_most_max_acceleration() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Events<PathTargetReachedEvent>>();
+        world.insert_resource(SimulationRng::from_seed(1));
+        world.insert_resource(GameConstants::default());
+
+        let steering = SteeringBehavior { max_acceleration: 100.0, ..default() };
+        let max_acceleration = steering.max_acceleration;
+        let initial_velocity = Vec2::new(200.0, 0.0);
+        let npc = world.spawn((
+            Npc,
+            Transform::default(),
+            Velocity::linear(initial_velocity),
+            steering,
+            // The target lies straight behind the agent, so seek pulls fully against its motion
+            PathTarget { target_position: Vec2::new(-500.0, 0.0), has_target: true, ..default() },
+            Desire::FindWater,
+            RefillState::default(),
+        )).id();
+
+        let delta = 0.1;
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(delta));
+        world.run_system_once(steering_behavior_system).unwrap();
+
+        let velocity = world.get::<Velocity>(npc).unwrap().linvel;
+        let change = (velocity - initial_velocity).length();
+        assert!(change <= max_acceleration * delta + 1e-3, "velocity changed by {change}");
+        assert!(velocity.x < initial_velocity.x, "the agent starts braking");
+        assert!(velocity.x > 0.0, "no instant reversal");
+    }
+
+    #[test]
+    fn fully_satisfied_agent_relaxes_to_a_stop_and_moves_again_once_a_need_drops() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Events<PathTargetReachedEvent>>();
+        world.insert_resource(SimulationRng::from_seed(3));
+        world.insert_resource(GameConstants::default());
+
+        let npc = world.spawn((
+            Npc,
+            Transform::default(),
+            Velocity::linear(Vec2::new(50.0, 0.0)),
+            SteeringBehavior::default(),
+            PathTarget::default(),
+            Desire::Wander,
+            RefillState::default(),
+            RelaxState::default(),
+            BasicNeeds { hunger: 0.95, thirst: 0.95, rest: 0.95, safety: 0.95, social: 0.95 },
+            DesireThresholds::default(),
+        )).id();
+
+        let tick = |world: &mut World| {
+            world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(0.1));
+            world.run_system_once(relax_state_system).unwrap();
+            world.run_system_once(steering_behavior_system).unwrap();
+            world.get::<Velocity>(npc).unwrap().linvel.length()
+        };
+
+        let mut speed = f32::MAX;
+        for _ in 0..10 {
+            speed = tick(&mut world);
+        }
+        assert!(world.get::<RelaxState>(npc).unwrap().is_relaxing);
+        assert!(speed < 1e-3, "a fully satisfied agent brakes to a stop instead of wandering, still at {speed}");
+
+        // Hunger falls below its high threshold, so the agent has somewhere to be again
+        world.get_mut::<BasicNeeds>(npc).unwrap().hunger = 0.6;
+        for _ in 0..5 {
+            speed = tick(&mut world);
+        }
+        assert!(!world.get::<RelaxState>(npc).unwrap().is_relaxing);
+        assert!(speed > 10.0, "the agent resumes moving once a need drops, got {speed}");
+    }
+
+    #[test]
+    fn touching_the_target_resource_counts_as_arrival_outside_the_distance_threshold() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Events<PathTargetReachedEvent>>();
+        world.init_resource::<Events<CollisionEvent>>();
+        world.insert_resource(SimulationRng::from_seed(5));
+        world.insert_resource(GameConstants::default());
+
+        let well = world.spawn((Well::default(), Transform::from_xyz(100.0, 0.0, 0.0))).id();
+        let npc = world.spawn((
+            Npc,
+            Transform::from_xyz(55.0, 0.0, 0.0),
+            Velocity::linear(Vec2::new(20.0, 0.0)),
+            SteeringBehavior::default(),
+            PathTarget { target_position: Vec2::new(100.0, 0.0), target_entity: Some(well), arrival_threshold: 30.0, has_target: true, ..default() },
+            Desire::FindWater,
+            RefillState::default(),
+            PhysicalContacts::default(),
+        )).id();
+
+        // 45 units out with a 30 unit threshold: the distance check alone says the agent has not arrived
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(0.1));
+        world.run_system_once(physical_contact_system).unwrap();
+        world.run_system_once(steering_behavior_system).unwrap();
+        assert!(world.resource::<Events<PathTargetReachedEvent>>().is_empty());
+
+        world.send_event(CollisionEvent::Started(well, npc, CollisionEventFlags::SENSOR));
+        world.run_system_once(physical_contact_system).unwrap();
+        world.run_system_once(steering_behavior_system).unwrap();
+        let reached: Vec<(Entity, Option<Entity>)> = world
+            .resource_mut::<Events<PathTargetReachedEvent>>()
+            .drain()
+            .map(|event| (event.npc_entity, event.target_entity))
+            .collect();
+        assert_eq!(reached, vec![(npc, Some(well))], "contact with the target is authoritative");
+
+        world.send_event(CollisionEvent::Stopped(well, npc, CollisionEventFlags::SENSOR));
+        world.run_system_once(physical_contact_system).unwrap();
+        assert!(world.get::<PhysicalContacts>(npc).unwrap().touching.is_empty());
+    }
+
+    #[test]
+    fn doubling_size_scale_doubles_the_body_and_raises_speed_allometrically() {
+        let base = GameConstants::default();
+        let doubled = GameConstants { npc_size_scale: 2.0, ..GameConstants::default() };
+
+        let small = npc_body_size(&base);
+        let large = npc_body_size(&doubled);
+
+        assert_eq!(small.radius, base.npc_radius);
+        assert!((large.radius - 2.0 * small.radius).abs() < 1e-4, "collider radius, and the sprite drawn at twice it, double");
+        assert!((large.max_speed - small.max_speed * 2.0_f32.sqrt()).abs() < 1e-3, "speed grows with the square root of size");
+        assert!(large.max_speed < 2.0 * small.max_speed, "bigger agents are faster but not proportionally so");
+        assert!((large.linear_damping - small.linear_damping / 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn clustered_spawns_stay_near_their_centers_and_grid_spawns_are_evenly_spaced() {
+        let half_extents = Vec2::new(640.0, 360.0);
+        let margin = 15.0;
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let centers = vec![Vec2::new(-300.0, 100.0), Vec2::new(250.0, -150.0)];
+        let clustered = SpawnDistribution::Clustered { centers: centers.clone(), spread: 60.0 };
+        let positions = calculate_spawn_positions(&clustered, 40, half_extents, margin, &mut rng);
+        assert_eq!(positions.len(), 40);
+        for (index, position) in positions.iter().enumerate() {
+            let center = centers[index % centers.len()];
+            assert!(position.distance(center) <= 60.0 + 1e-3, "agent {index} strayed {} from its center", position.distance(center));
+        }
+
+        let grid = calculate_spawn_positions(&SpawnDistribution::Grid, 12, half_extents, margin, &mut rng);
+        assert_eq!(grid.len(), 12);
+        let mut xs: Vec<f32> = grid.iter().map(|p| p.x).collect();
+        let mut ys: Vec<f32> = grid.iter().map(|p| p.y).collect();
+        for axis in [&mut xs, &mut ys] {
+            axis.sort_by(f32::total_cmp);
+            axis.dedup_by(|a, b| (*a - *b).abs() < 1e-3);
+            let gaps: Vec<f32> = axis.windows(2).map(|pair| pair[1] - pair[0]).collect();
+            assert!(gaps.iter().all(|gap| (gap - gaps[0]).abs() < 1e-3), "uneven spacing {gaps:?}");
+        }
+        assert!(xs.len() > 1 && ys.len() > 1, "a wide room gets a two-dimensional lattice");
+        assert!(grid.iter().all(|p| p.x.abs() <= half_extents.x - margin && p.y.abs() <= half_extents.y - margin));
+    }
+
+    // =========================================================================
+    // WORLD MODEL
+    // =========================================================================
+
+    #[test]
+    fn agent_cannot_target_a_resource_or_agent_it_has_never_perceived() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(GameConstants::default());
+        world.insert_resource(SimulationRng::from_seed(1));
+        world.init_resource::<ResourceSpatialIndex>();
+        world.init_resource::<Events<PathTargetSetEvent>>();
+        world.init_resource::<Events<ReservationDenied>>();
+        world.init_resource::<Events<ResourceDiscoveredEvent>>();
+
+        let well_position = Vec2::new(60.0, 0.0);
+        world.spawn((Well::default(), Transform::from_translation(well_position.extend(0.0))));
+        let stranger_position = Vec2::new(0.0, 80.0);
+        let stranger = world.spawn((Npc, Transform::from_translation(stranger_position.extend(0.0)))).id();
+
+        let thirsty = world.spawn((
+            Npc,
+            Transform::default(),
+            Desire::FindWater,
+            ResourceMemory::default(),
+            PathTarget::default(),
+            PerceivedEntities::default(),
+            WorldModel::default(),
+        )).id();
+        let lonely = world.spawn((
+            Npc,
+            Transform::default(),
+            Desire::Socialize,
+            ResourceMemory::default(),
+            PathTarget::default(),
+            PerceivedEntities::default(),
+            WorldModel::default(),
+        )).id();
+
+        // The well is indexed and the stranger stands in the open, but neither has been perceived
+        world.run_system_once(resource_spatial_index_system).unwrap();
+        world.run_system_once(desire_pathfinding_system).unwrap();
+        assert!(!world.get::<PathTarget>(thirsty).unwrap().has_target);
+        assert!(!world.get::<PathTarget>(lonely).unwrap().has_target);
+
+        // Discovery fills the resource memory and vision the world model, and only then do the targets follow
+        world.run_system_once(resource_discovery_system).unwrap();
+        world.get_mut::<PerceivedEntities>(lonely).unwrap().in_sight.push((stranger, ApparentState::default()));
+        world.run_system_once(world_model_update_system).unwrap();
+
+        assert!(world.get::<ResourceMemory>(thirsty).unwrap().known_wells.contains(&well_position));
+        assert!(world.get::<WorldModel>(lonely).unwrap().agents.contains_key(&stranger));
+
+        world.run_system_once(desire_pathfinding_system).unwrap();
+        assert_eq!(world.get::<PathTarget>(thirsty).unwrap().target_position, well_position);
+        assert_eq!(world.get::<PathTarget>(lonely).unwrap().target_position, stranger_position);
+    }
+
+    // =========================================================================
+    // FACING
+    // =========================================================================
+
+    #[test]
+    fn agent_moving_right_turns_toward_x_and_holds_facing_when_stopped() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(GameConstants::default());
+
+        let npc = world.spawn((Npc, Transform::default(), Velocity::linear(Vec2::new(200.0, 0.0)))).id();
+        let angle_to_right = |world: &World| (world.get::<Transform>(npc).unwrap().rotation * Vec3::Y).truncate().angle_to(Vec2::X).abs();
+
+        let mut previous = angle_to_right(&world);
+        world.resource_mut::<Time>().advance_by(Duration::from_millis(50));
+        world.run_system_once(face_velocity_system).unwrap();
+        let after_one_step = angle_to_right(&world);
+        assert!(after_one_step < previous, "the agent starts turning right");
+        assert!(after_one_step > 0.01, "the turn is interpolated, not a snap");
+
+        for _ in 0..20 {
+            previous = angle_to_right(&world);
+            world.resource_mut::<Time>().advance_by(Duration::from_millis(50));
+            world.run_system_once(face_velocity_system).unwrap();
+            assert!(angle_to_right(&world) <= previous + 1e-5);
+        }
+        assert!(angle_to_right(&world) < 1e-3, "the agent ends up facing +X");
+
+        // A stopped agent keeps facing where it was going
+        let facing = world.get::<Transform>(npc).unwrap().rotation;
+        world.get_mut::<Velocity>(npc).unwrap().linvel = Vec2::ZERO;
+        world.resource_mut::<Time>().advance_by(Duration::from_millis(50));
+        world.run_system_once(face_velocity_system).unwrap();
+        assert_eq!(world.get::<Transform>(npc).unwrap().rotation, facing);
+    }
+
+    // =========================================================================
+    // PROPRIOCEPTION
+    // =========================================================================
+
+    #[test]
+    fn straight_line_motion_is_path_integrated_into_the_right_displacement() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+
+        let start = Vec2::new(-100.0, 40.0);
+        let step = Vec2::new(3.0, 4.0); // 5 units per 0.1 s tick, heading 53 degrees
+        let npc = world.spawn((Npc, Transform::from_translation(start.extend(0.0)), Proprioception::default())).id();
+
+        world.run_system_once(proprioception_system).unwrap();
+        for _ in 0..20 {
+            world.get_mut::<Transform>(npc).unwrap().translation += step.extend(0.0);
+            world.resource_mut::<Time>().advance_by(Duration::from_millis(100));
+            world.run_system_once(proprioception_system).unwrap();
+        }
+
+        let sense = *world.get::<Proprioception>(npc).unwrap();
+        assert!((sense.speed_estimate - 50.0).abs() < 1e-2, "speed: {}", sense.speed_estimate);
+        assert!((sense.current_heading - step.to_angle()).abs() < 1e-4);
+        assert!(sense.integrated_displacement.distance(step * 20.0) < 1e-2, "displacement: {}", sense.integrated_displacement);
+        assert!((sense.distance_travelled - 100.0).abs() < 1e-2);
+
+        // Standing still keeps the heading and adds nothing to the estimate
+        world.resource_mut::<Time>().advance_by(Duration::from_millis(100));
+        world.run_system_once(proprioception_system).unwrap();
+        let resting = world.get::<Proprioception>(npc).unwrap();
+        assert_eq!(resting.speed_estimate, 0.0);
+        assert_eq!(resting.current_heading, sense.current_heading);
+        assert_eq!(resting.integrated_displacement, sense.integrated_displacement);
+    }
+
+    // =========================================================================
+    // FRAME BUDGET GOVERNOR
+    // =========================================================================
+
+    /// Runs the periodic trigger for `seconds` at 10 FPS and returns how many evaluations fired
+    fn count_periodic_evaluations(world: &mut World, seconds: u32) -> usize {
+        let mut evaluations = 0;
+        for _ in 0..seconds * 10 {
+            world.resource_mut::<Time>().advance_by(Duration::from_millis(100));
+            world.run_system_once(periodic_decision_trigger_system).unwrap();
+            evaluations += world.resource_mut::<Events<EvaluateDecision>>().drain().count();
+        }
+        evaluations
+    }
+
+    #[test]
+    fn high_frame_time_alert_stretches_periodic_evaluation() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<FrameBudgetGovernor>();
+        world.init_resource::<Events<EvaluateDecision>>();
+        world.init_resource::<Events<PerformanceAlertEvent>>();
+        world.spawn((CurrentDesire::default(), DualProcess::default()));
+
+        let normal = count_periodic_evaluations(&mut world, 20);
+
+        world.send_event(PerformanceAlertEvent::HighFrameTime { frame_time_ms: 40.0, budget_ms: 16.7 });
+        world.run_system_once(frame_budget_governor_system).unwrap();
+        assert!(world.resource::<FrameBudgetGovernor>().degraded);
+
+        let degraded = count_periodic_evaluations(&mut world, 20);
+        assert!(degraded < normal, "degraded: {degraded}, normal: {normal}");
+    }
+
+    #[test]
+    fn degraded_governor_slows_every_agents_cognitive_map_alike_and_decisions_keep_running() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<AgentLodConfig>();
+        world.init_resource::<FrameBudgetGovernor>();
+        world.init_resource::<Events<EvaluateDecision>>();
+        // The camera only sees 200x200 units around the origin, which must not matter
+        world.spawn((
+            Camera2d,
+            GlobalTransform::default(),
+            Projection::Orthographic(OrthographicProjection { area: Rect::new(-100.0, -100.0, 100.0, 100.0), ..OrthographicProjection::default_2d() }),
+        ));
+        let agent_at = |world: &mut World, x: f32| {
+            world.spawn((Npc, Transform::from_xyz(x, 0.0, 0.0), AgentLod::default(), CognitiveMap::default(), CurrentDesire::default(), DualProcess::default())).id()
+        };
+        let in_view = agent_at(&mut world, 0.0);
+        let out_of_view = agent_at(&mut world, 1000.0);
+
+        let familiarity = |world: &World, agent: Entity| world.get::<CognitiveMap>(agent).unwrap().activations.values().sum::<f32>();
+        let count_map_updates = |world: &mut World, ticks: usize| {
+            let mut map_updates = [0, 0];
+            for _ in 0..ticks {
+                world.resource_mut::<Time>().advance_by(Duration::from_millis(100));
+                let before = [familiarity(world, in_view), familiarity(world, out_of_view)];
+                world.run_system_once(agent_lod_update_system).unwrap();
+                world.run_system_once(cognitive_map_update_system).unwrap();
+                world.run_system_once(periodic_decision_trigger_system).unwrap();
+                for (updates, (agent, before)) in map_updates.iter_mut().zip([in_view, out_of_view].into_iter().zip(before)) {
+                    if familiarity(world, agent) > before {
+                        *updates += 1;
+                    }
+                }
+            }
+            map_updates
+        };
+
+        assert_eq!(count_map_updates(&mut world, 8), [8, 8], "every agent learns every tick within budget");
+        world.resource_mut::<FrameBudgetGovernor>().degrade();
+        assert_eq!(count_map_updates(&mut world, 8), [2, 2], "every agent learns every fourth tick over budget");
+        // Decisions are not subject to the level of detail
+        let decision_clock = |agent: Entity| world.get::<DualProcess>(agent).unwrap().time_since_evaluation;
+        assert_eq!(decision_clock(in_view), decision_clock(out_of_view));
+        assert!(decision_clock(out_of_view) > 1.5);
+    }
+
+    #[test]
+    fn skipped_consumer_raises_a_backlog_alert_for_its_event_type() {
+        // Runs ten event updates with one NeedChangeEvent each and returns the backlog alerts raised
+        let backlog_alerts = |cognition: bool| {
+            let mut world = World::new();
+            world.init_resource::<Events<NeedChangeEvent>>();
+            world.init_resource::<Events<PerformanceAlertEvent>>();
+            world.init_resource::<EventBacklogConfig>();
+            world.init_resource::<EventBacklog>();
+            world.insert_resource(AiFeatureFlags { cognition, ..default() });
+            let monitor = world.register_system(cognition_enabled.pipe(event_backlog_system::<NeedChangeEvent>));
+            let npc = world.spawn(Npc).id();
+
+            let mut alerts = Vec::new();
+            for _ in 0..10 {
+                world.resource_mut::<Events<NeedChangeEvent>>().update();
+                world.send_event(NeedChangeEvent { entity: npc, need_type: NeedType::Thirst, old_value: 0.5, new_value: 0.49, change_amount: -0.01 });
+                world.run_system(monitor).unwrap();
+                alerts.extend(world.resource_mut::<Events<PerformanceAlertEvent>>().drain());
+            }
+            (alerts, world.resource::<EventBacklog>().dropped.get("NeedChangeEvent").copied())
+        };
+
+        let (alerts, dropped) = backlog_alerts(false);
+        assert!(!alerts.is_empty(), "a disabled consumer must be reported");
+        assert!(alerts.iter().all(|alert| matches!(alert, PerformanceAlertEvent::EventBacklog { event_type: "NeedChangeEvent", .. })));
+        assert_eq!(dropped, Some(8), "all but the two events still buffered were lost");
+
+        let (alerts, dropped) = backlog_alerts(true);
+        assert!(alerts.is_empty());
+        assert_eq!(dropped, None);
+    }
+
+    #[test]
+    fn learned_data_over_its_cap_loses_the_weakest_entries_and_keeps_consolidated_ones() {
+        let mut world = World::new();
+        world.insert_resource(LearningMemoryConfig { max_map_cells: 4, max_path_values: 2, memory_alert_entries: usize::MAX, ..default() });
+        world.init_resource::<Events<PerformanceAlertEvent>>();
+
+        // Familiarity rises from 0.05 in cell 0 to 0.95 in cell 9; cells 8 and 9 are consolidated
+        let activations = (0..10).map(|x| (IVec2::new(x, 0), 0.05 + x as f32 * 0.1)).collect();
+        let segment = |to: i32| PathSegment { from: IVec2::ZERO, to: IVec2::new(to, 0) };
+        let path_values = [(segment(1), 0.9), (segment(2), -0.7), (segment(3), 0.1), (segment(4), -0.05)].into();
+        let npc = world.spawn((Npc, CognitiveMap { activations, ..default() }, PathLearning { path_values, ..default() })).id();
+        let remembered_cells = |world: &World| {
+            let mut cells: Vec<i32> = world.get::<CognitiveMap>(npc).unwrap().activations.keys().map(|cell| cell.x).collect();
+            cells.sort();
+            cells
+        };
+        let learned_routes = |world: &World| {
+            let mut routes: Vec<i32> = world.get::<PathLearning>(npc).unwrap().path_values.keys().map(|segment| segment.to.x).collect();
+            routes.sort();
+            routes
+        };
+
+        world.run_system_once(learning_memory_pruning_system).unwrap();
+        assert_eq!(remembered_cells(&world), [6, 7, 8, 9], "the least familiar cells are forgotten first");
+        assert_eq!(learned_routes(&world), [1, 2], "routes with a clear verdict, good or bad, are kept");
+
+        // A memory alert halves every cap, but consolidated knowledge survives it
+        world.resource_mut::<LearningMemoryConfig>().memory_alert_entries = 5;
+        world.run_system_once(learning_memory_monitor_system).unwrap();
+        world.run_system_once(learning_memory_pruning_system).unwrap();
+        assert_eq!(remembered_cells(&world), [8, 9]);
+        assert_eq!(learned_routes(&world), [1, 2]);
+    }
+
+    #[test]
+    fn profiler_report_summarises_synthetic_timings_with_nearest_rank_percentiles() {
+        let config = ProfilerConfig { histogram_bucket_ms: 10.0, histogram_buckets: 3, max_samples: 100, ..Default::default() };
+        let mut timings = SystemTimings::default();
+        // 1..=100 ms in shuffled order, plus an older sample pushed out by max_samples
+        timings.record("vision_system", 1000.0, config.max_samples);
+        let mut samples: Vec<f32> = (1..=100).map(|ms| ms as f32).collect();
+        samples.shuffle(&mut StdRng::seed_from_u64(3));
+        for sample in samples {
+            timings.record("vision_system", sample, config.max_samples);
+        }
+        timings.record("decay_basic_needs", 2.0, config.max_samples);
+        for frame_time in [4.0, 9.9, 10.0, 25.0, 80.0] {
+            timings.record_frame(frame_time, config.max_samples);
+        }
+
+        let report = build_profiler_report(&timings, &config);
+
+        assert_eq!(report.systems.iter().map(|s| s.name.as_str()).collect::<Vec<_>>(), vec!["decay_basic_needs", "vision_system"]);
+        let vision = &report.systems[1];
+        assert_eq!(vision.samples, 100);
+        assert!((vision.mean_ms - 50.5).abs() < 1e-4);
+        assert_eq!(vision.p95_ms, 95.0);
+        assert_eq!(vision.max_ms, 100.0);
+        assert_eq!(report.systems[0].p95_ms, 2.0, "a single sample is its own percentile");
+
+        assert_eq!(report.frame_time.max_ms, 80.0);
+        assert_eq!(report.frame_time_histogram.iter().map(|b| b.frames).collect::<Vec<_>>(), vec![2, 1, 2]);
+        assert_eq!(report.frame_time_histogram[2].to_ms, f32::INFINITY, "the last bucket counts every longer frame");
+    }
+
+    // =========================================================================
+    // RELATIONSHIPS
+    // =========================================================================
+
+    #[test]
+    fn local_network_density_counts_links_among_contacts_in_either_direction() {
+        let [b, c, d, e] = [1, 2, 3, 4].map(Entity::from_raw);
+        // b knows c, d knows c (one-directional links still connect them), nobody links b and d
+        let known: HashSet<(Entity, Entity)> = [(b, c), (d, c)].into();
+        let knows = |agent: Entity, other: Entity| known.contains(&(agent, other));
+
+        assert!((calculate_local_network_density(&[b, c, d], knows) - 2.0 / 3.0).abs() < 1e-6);
+        assert_eq!(calculate_local_network_density(&[b, c], knows), 1.0);
+        assert_eq!(calculate_local_network_density(&[b, e], knows), 0.0);
+        assert_eq!(calculate_local_network_density(&[b], knows), 0.0, "a single contact has no possible links");
+    }
+
+    #[test]
+    fn attitudes_within_the_confidence_bound_converge_while_distant_ones_stay_put() {
+        let mut world = World::new();
+        world.insert_resource(OpinionDynamicsConfig { confidence_bound: 0.3, convergence_rate: 0.3, repulsion_rate: 0.0 });
+        world.init_resource::<Events<InteractionCompleted>>();
+
+        let close_a = world.spawn((Npc, Attitude { value: 0.4 })).id();
+        let close_b = world.spawn((Npc, Attitude { value: 0.6 })).id();
+        let far_a = world.spawn((Npc, Attitude { value: 0.1 })).id();
+        let far_b = world.spawn((Npc, Attitude { value: 0.9 })).id();
+
+        for _ in 0..10 {
+            for (entity_1, entity_2) in [(close_a, close_b), (far_a, far_b)] {
+                world.send_event(InteractionCompleted {
+                    entity_1,
+                    entity_2,
+                    duration: 1.0,
+                    interaction_type: InteractionType::Greeting,
+                    satisfaction_1: 0.5,
+                    satisfaction_2: 0.5,
+                });
+            }
+            world.run_system_once(opinion_dynamics_system).unwrap();
+            world.resource_mut::<Events<InteractionCompleted>>().clear();
+        }
+
+        let attitude = |world: &World, entity: Entity| world.get::<Attitude>(entity).unwrap().value;
+        assert!((attitude(&world, close_a) - attitude(&world, close_b)).abs() < 1e-3, "close opinions reach consensus");
+        assert!((attitude(&world, close_a) - 0.5).abs() < 1e-4, "and meet in the middle");
+        assert_eq!(attitude(&world, far_a), 0.1, "opinions beyond the bound do not influence each other");
+        assert_eq!(attitude(&world, far_b), 0.9);
+    }
+
+    #[test]
+    fn three_mutually_visible_agents_form_one_gathering_that_dissolves_when_they_split() {
+        let mut world = World::new();
+        world.insert_resource(SocialConfig::default());
+        world.init_resource::<Events<GatheringFormed>>();
+        world.init_resource::<Events<GatheringDissolved>>();
+
+        let all_round_vision = || VisionRange { max_distance: 100.0, field_of_view: std::f32::consts::TAU, ..default() };
+        let mut spawn_at = |x: f32, y: f32| {
+            world
+                .spawn((Npc, Transform::from_xyz(x, y, 0.0), PerceivedEntities::default(), all_round_vision(), ApparentState::default()))
+                .id()
+        };
+        let trio = [spawn_at(0.0, 0.0), spawn_at(50.0, 0.0), spawn_at(25.0, 40.0)];
+        let loner = spawn_at(300.0, 0.0);
+
+        let perceive_and_gather = |world: &mut World| {
+            world.run_system_once(vision_system).unwrap();
+            world.run_system_once(group_formation_system).unwrap();
+        };
+        perceive_and_gather(&mut world);
+
+        let formed: Vec<GatheringFormed> = world.resource_mut::<Events<GatheringFormed>>().drain().collect();
+        assert_eq!(formed.len(), 1);
+        let mut members = trio.to_vec();
+        members.sort();
+        assert_eq!(formed[0].members, members);
+        let id = GatheringId(formed[0].id);
+        assert!(trio.iter().all(|agent| world.get::<GatheringId>(*agent) == Some(&id)));
+        assert!(world.get::<GatheringId>(loner).is_none(), "an agent nobody can see is not part of the crowd");
+
+        // The third agent walks off, leaving a pair too small to count as a gathering
+        world.get_mut::<Transform>(trio[2]).unwrap().translation = Vec3::new(25.0, 400.0, 0.0);
+        perceive_and_gather(&mut world);
+
+        let dissolved: Vec<GatheringDissolved> = world.resource_mut::<Events<GatheringDissolved>>().drain().collect();
+        assert_eq!(dissolved, vec![GatheringDissolved { id: id.0 }]);
+        assert!(trio.iter().all(|agent| world.get::<GatheringId>(*agent).is_none()));
+    }
+
+    #[test]
+    fn interaction_satisfaction_follows_both_participants_moods() {
+        let personality = Personality { openness: 0.5, extraversion: 0.5, agreeableness: 0.5, conscientiousness: 0.5, neuroticism: 0.5 };
+        let base = calculate_interaction_satisfaction(&personality, &personality);
+        let happy = Mood { valence: 0.8, arousal: 0.2 };
+        let neutral = Mood::default();
+        let unhappy = Mood { valence: -0.8, arousal: 0.2 };
+
+        let high_high = apply_mood_to_satisfaction(base, &happy, &happy);
+        let high_low = apply_mood_to_satisfaction(base, &happy, &unhappy);
+        let low_low = apply_mood_to_satisfaction(base, &unhappy, &unhappy);
+        assert!(high_high > high_low && high_low > low_low, "{high_high} > {high_low} > {low_low}");
+        assert!(low_low < base, "two unhappy agents have a worse exchange than neutral ones");
+        assert!(apply_mood_to_satisfaction(base, &neutral, &happy) > base, "a cheerful partner lifts a neutral agent");
+
+        let elated = Mood { valence: 1.0, arousal: 1.0 };
+        assert_eq!(apply_mood_to_satisfaction(1.0, &elated, &elated), 1.0, "satisfaction stays clamped");
+    }
+
+    #[test]
+    fn helping_builds_more_trust_than_greeting() {
+        let mut helped = Relationship::default();
+        let mut greeted = Relationship::default();
+
+        update_relationship_from_interaction(&mut helped, InteractionType::Helping, 0.8, 1.0);
+        update_relationship_from_interaction(&mut greeted, InteractionType::Greeting, 0.8, 1.0);
+
+        assert!(helped.trust > greeted.trust, "helping: {}, greeting: {}", helped.trust, greeted.trust);
+        assert!(helped.affinity > greeted.affinity);
+        assert_eq!(helped.positive_interactions, 1);
+        assert_eq!(greeted.positive_interactions, 1);
+    }
+
+    #[test]
+    fn unpleasant_interactions_count_as_negative() {
+        let mut relationship = Relationship::default();
+
+        update_relationship_from_interaction(&mut relationship, InteractionType::Conversation, 0.1, 1.0);
+
+        assert_eq!(relationship.negative_interactions, 1);
+        assert_eq!(relationship.positive_interactions, 0);
+        assert!(relationship.affinity < 0.0);
+    }
+
+    #[test]
+    fn contesting_a_depleted_well_ends_in_conflict() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<SimLogConfig>();
+        world.init_resource::<Events<SocialInteractionEvent>>();
+        world.init_resource::<Events<RelationshipChangedEvent>>();
+        world.init_resource::<Events<ConflictResolved>>();
+
+        let well = world.spawn(Well { water_capacity: 0.05, consumption_rate: 0.1 }).id();
+        let thirsty_agent = |world: &mut World, agreeableness: f32| {
+            world.spawn((
+                Npc,
+                Personality { openness: 0.5, extraversion: 0.5, agreeableness, conscientiousness: 0.5, neuroticism: 0.5 },
+                BasicNeeds { hunger: 1.0, thirst: 0.2, rest: 1.0, safety: 1.0, social: 1.0 },
+                CurrentDesire { desire: Desire::FindWater, ..Default::default() },
+                PathTarget { target_entity: Some(well), has_target: true, ..Default::default() },
+                SocialRelationships::default(),
+            )).id()
+        };
+        let agent_1 = thirsty_agent(&mut world, 0.2);
+        let agent_2 = thirsty_agent(&mut world, 0.1);
+
+        // Both start out on friendly terms
+        let friendly = Relationship { affinity: 0.3, trust: 0.5, ..Default::default() };
+        for (actor, partner) in [(agent_1, agent_2), (agent_2, agent_1)] {
+            world.get_mut::<SocialRelationships>(actor).unwrap().relationships.insert(partner, friendly);
+        }
+
+        world.send_event(SocialInteractionEvent { entity_1: agent_1, entity_2: agent_2, social_boost: 0.1 });
+        world.run_system_once(social_interaction_system).unwrap();
+
+        let conflicts: Vec<_> = world.resource_mut::<Events<ConflictResolved>>().drain().collect();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].cause, ConflictCause::ScarceResource);
+        assert_ne!(conflicts[0].satisfaction_1, conflicts[0].satisfaction_2, "uncooperative agents resolve asymmetrically");
+
+        for (actor, partner) in [(agent_1, agent_2), (agent_2, agent_1)] {
+            let relationship = world.get::<SocialRelationships>(actor).unwrap().relationships[&partner];
+            assert!(relationship.affinity < friendly.affinity);
+            assert!(relationship.trust < friendly.trust);
+            assert_eq!(relationship.negative_interactions, 1);
+        }
+    }
+
+    #[test]
+    fn hub_of_a_star_network_is_the_most_influential_agent() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(SocialConfig { leadership_interval: 1.0, ..default() });
+        world.init_resource::<SocialHierarchy>();
+
+        let hub = world.spawn((Npc, SocialRelationships::default(), SocialInfluence::default())).id();
+        let spokes: Vec<Entity> = (0..5)
+            .map(|_| world.spawn((Npc, SocialRelationships::default(), SocialInfluence::default())).id())
+            .collect();
+
+        // Every spoke trusts the hub; the hub knows every spoke, but only lukewarmly
+        for &spoke in &spokes {
+            world.get_mut::<SocialRelationships>(spoke).unwrap().relationships
+                .insert(hub, Relationship { affinity: 0.7, trust: 0.8, ..default() });
+            world.get_mut::<SocialRelationships>(hub).unwrap().relationships
+                .insert(spoke, Relationship { affinity: 0.2, trust: 0.3, ..default() });
+        }
+
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(2));
+        world.run_system_once(leadership_detection_system).unwrap();
+
+        let hub_influence = *world.get::<SocialInfluence>(hub).unwrap();
+        assert_eq!(hub_influence.in_degree, 5);
+        assert!(hub_influence.is_potential_leader);
+        for &spoke in &spokes {
+            let spoke_influence = world.get::<SocialInfluence>(spoke).unwrap();
+            assert!(hub_influence.score > spoke_influence.score);
+            assert!(!spoke_influence.is_potential_leader);
+        }
+
+        let hierarchy = *world.resource::<SocialHierarchy>();
+        assert_eq!(hierarchy.top_influence, hub_influence.score);
+        assert_eq!(hierarchy.potential_leaders, 1);
+        assert!(hierarchy.influence_inequality > 0.0);
+    }
+
+    // =========================================================================
+    // NEED PRIORITY WEIGHTS
+    // =========================================================================
+
+    #[test]
+    fn boosted_social_weight_prefers_socializing_over_rest() {
+        let needs = BasicNeeds { hunger: 1.0, thirst: 1.0, rest: 0.3, safety: 1.0, social: 0.3 };
+        let thresholds = DesireThresholds::default();
+
+        let (default_choice, _) = evaluate_most_urgent_desire(&needs, &thresholds, &NeedPriorityWeights::default());
+        assert_eq!(default_choice, Desire::Rest, "survival-first defaults favour rest");
+
+        let social_butterfly = NeedPriorityWeights { social: 1.0, ..Default::default() };
+        let (boosted_choice, _) = evaluate_most_urgent_desire(&needs, &thresholds, &social_butterfly);
+        assert_eq!(boosted_choice, Desire::Socialize);
+    }
+
+    #[test]
+    fn agents_with_different_hunger_setpoints_look_for_food_at_different_hunger_levels() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(GameConstants::default());
+        world.init_resource::<SimLogConfig>();
+        world.init_resource::<Events<EvaluateDecision>>();
+        world.init_resource::<Events<CurrentDesireSet>>();
+        world.init_resource::<Events<DesireChangeEvent>>();
+
+        // The hardy agent is comfortable from 0.7 hunger up, where a typical agent already feels its hunger
+        let hardy_setpoints = HomeostaticSetpoints { hunger: NeedSetpoint { target: 0.8, comfort_band: 0.1 }, ..default() };
+        let spawn = |world: &mut World, setpoints: HomeostaticSetpoints| {
+            world.spawn((
+                BasicNeeds { hunger: 0.6, thirst: 1.0, rest: 1.0, safety: 1.0, social: 1.0 },
+                setpoints,
+                DesireThresholds::default(),
+                Desire::Wander,
+                CurrentDesire::default(),
+            )).id()
+        };
+        let typical = spawn(&mut world, HomeostaticSetpoints::default());
+        let hardy = spawn(&mut world, hardy_setpoints);
+
+        let decide = |world: &mut World, hunger: f32| {
+            for entity in [typical, hardy] {
+                world.get_mut::<BasicNeeds>(entity).unwrap().hunger = hunger;
+                world.send_event(EvaluateDecision { entity, trigger_reason: DecisionTrigger::Periodic });
+            }
+            world.run_system_once(decision_making_system).unwrap();
+            world.resource_mut::<Events<EvaluateDecision>>().clear();
+            (*world.get::<Desire>(typical).unwrap(), *world.get::<Desire>(hardy).unwrap())
+        };
+
+        assert_eq!(decide(&mut world, 0.75), (Desire::FindFood, Desire::Wander));
+        assert_eq!(decide(&mut world, 0.35), (Desire::FindFood, Desire::FindFood));
+    }
+
+    #[test]
+    fn topping_off_a_nearly_full_need_yields_far_less_than_refilling_a_depleted_one() {
+        let mut world = World::new();
+        world.init_resource::<SimLogConfig>();
+        world.insert_resource(SatiationConfig { enabled: true, ..Default::default() });
+        world.init_resource::<Events<DesireChangeEvent>>();
+        world.init_resource::<Events<DesireFulfillmentAttemptEvent>>();
+        world.init_resource::<Events<NeedSatisfactionEvent>>();
+        world.init_resource::<Events<NeedChangeEvent>>();
+
+        let hungry = world.spawn((BasicNeeds { hunger: 0.1, ..Default::default() }, DesireThresholds::default())).id();
+        let nearly_full = world.spawn((BasicNeeds { hunger: 0.68, ..Default::default() }, DesireThresholds::default())).id();
+        for entity in [hungry, nearly_full] {
+            world.send_event(DesireChangeEvent {
+                entity,
+                old_desire: Desire::Wander,
+                new_desire: Desire::FindFood,
+                urgency_score: 0.5,
+                trigger_reason: DesireChangeReason::ThresholdCrossed,
+            });
+        }
+        world.run_system_once(desire_fulfillment_system).unwrap();
+
+        let hungry_boost = world.get::<BasicNeeds>(hungry).unwrap().hunger - 0.1;
+        let topped_off_boost = world.get::<BasicNeeds>(nearly_full).unwrap().hunger - 0.68;
+        assert!(hungry_boost > 0.35, "a depleted need gets the full boost, got {hungry_boost}");
+        assert!(topped_off_boost > 0.0 && topped_off_boost < hungry_boost * 0.3, "a nearly full need gets a fraction, got {topped_off_boost}");
+    }
+
+    #[test]
+    fn resting_inside_a_safe_zone_restores_more_rest_than_resting_in_the_open() {
+        let mut world = World::new();
+        world.init_resource::<SimLogConfig>();
+        world.init_resource::<RestQualityConfig>();
+        world.init_resource::<Events<DesireChangeEvent>>();
+        world.init_resource::<Events<DesireFulfillmentAttemptEvent>>();
+        world.init_resource::<Events<NeedSatisfactionEvent>>();
+        world.init_resource::<Events<NeedChangeEvent>>();
+
+        world.spawn((Transform::default(), SafeZone { safety_level: 0.9, influence_radius: 50.0, ..Default::default() }));
+        let tired = || (BasicNeeds { rest: 0.2, ..Default::default() }, DesireThresholds::default());
+        let sheltered = world.spawn((tired(), Transform::from_xyz(20.0, 0.0, 0.0))).id();
+        let exposed = world.spawn((tired(), Transform::from_xyz(400.0, 0.0, 0.0))).id();
+        for entity in [sheltered, exposed] {
+            world.send_event(DesireChangeEvent {
+                entity,
+                old_desire: Desire::Wander,
+                new_desire: Desire::Rest,
+                urgency_score: 0.7,
+                trigger_reason: DesireChangeReason::ThresholdCrossed,
+            });
+        }
+        world.run_system_once(desire_fulfillment_system).unwrap();
+
+        let sheltered_recovery = world.get::<BasicNeeds>(sheltered).unwrap().rest - 0.2;
+        let exposed_recovery = world.get::<BasicNeeds>(exposed).unwrap().rest - 0.2;
+        assert!((exposed_recovery - 0.15).abs() < 1e-4, "resting in the open recovers half as much, got {exposed_recovery}");
+        assert!(sheltered_recovery > exposed_recovery * 1.8, "the safe zone nearly doubles recovery, got {sheltered_recovery} vs {exposed_recovery}");
+    }
+
+    #[test]
+    fn successful_refill_produces_one_action_outcome_with_matching_reward_and_deltas() {
+        let mut world = World::new();
+        world.init_resource::<SimLogConfig>();
+        world.init_resource::<Events<DesireChangeEvent>>();
+        world.init_resource::<Events<DesireFulfillmentAttemptEvent>>();
+        world.init_resource::<Events<NeedSatisfactionEvent>>();
+        world.init_resource::<Events<NeedChangeEvent>>();
+        world.init_resource::<Events<ActionCompleted>>();
+        world.init_resource::<Events<ActionOutcome>>();
+
+        let thirsty = world.spawn((BasicNeeds { thirst: 0.2, ..Default::default() }, DesireThresholds::default())).id();
+        let lost = world.spawn((BasicNeeds::default(), DesireThresholds::default())).id();
+        world.send_event(DesireChangeEvent {
+            entity: thirsty,
+            old_desire: Desire::Wander,
+            new_desire: Desire::FindWater,
+            urgency_score: 0.8,
+            trigger_reason: DesireChangeReason::ThresholdCrossed,
+        });
+        world.run_system_once(desire_fulfillment_system).unwrap();
+        // The refill is also reported as a completed action, and another agent gives up on its own
+        for (entity, desire, success) in [(thirsty, Desire::FindWater, true), (lost, Desire::FindFood, false)] {
+            world.send_event(ActionCompleted {
+                entity,
+                completed_desire: desire,
+                completion_reason: if success { ActionCompletionReason::Success } else { ActionCompletionReason::Timeout },
+                duration: 5.0,
+                success,
+            });
+        }
+        world.run_system_once(action_outcome_system).unwrap();
+
+        let outcomes: Vec<ActionOutcome> = world.resource_mut::<Events<ActionOutcome>>().drain().collect();
+        assert_eq!(outcomes.len(), 2, "one outcome per completed action");
+
+        let refill = outcomes.iter().find(|outcome| outcome.entity == thirsty).unwrap();
+        let gained = world.get::<BasicNeeds>(thirsty).unwrap().thirst - 0.2;
+        assert_eq!((refill.desire, refill.action, refill.success), (Desire::FindWater, ActionTaken::UseResource { resource_entity: None }, true));
+        assert_eq!(refill.need_deltas.len(), 1);
+        assert_eq!(refill.need_deltas[0].0, NeedType::Thirst);
+        assert!((refill.need_deltas[0].1 - gained).abs() < 1e-6);
+        assert!((refill.reward - gained).abs() < 1e-6, "the reward is the satisfaction restored");
+
+        let failure = outcomes.iter().find(|outcome| outcome.entity == lost).unwrap();
+        assert_eq!(failure.action, ActionTaken::Abandon(ActionCompletionReason::Timeout));
+        assert!(!failure.success && failure.need_deltas.is_empty() && failure.reward < 0.0);
+    }
+
+    // =========================================================================
+    // CUSTOM DESIRES
+    // =========================================================================
+
+    fn play_definition() -> CustomDesireDefinition {
+        CustomDesireDefinition {
+            name: "Play".to_string(),
+            threshold: DualThreshold { high_threshold: 0.7, low_threshold: 0.3 },
+            weight: 1.0,
+            decay_rate: 0.0,
+            satisfaction_rate: 0.5,
+        }
+    }
+
+    #[test]
+    fn custom_desire_registry_refuses_a_257th_desire() {
+        let mut registry = CustomDesireRegistry::default();
+        for expected in 0..=u8::MAX {
+            assert_eq!(registry.register(play_definition()), Ok(expected));
+        }
+        assert_eq!(registry.register(play_definition()), Err(CustomDesireRegistryFull));
+        assert_eq!(registry.desires.len(), 256);
+    }
+
+    #[test]
+    fn npcs_built_with_registered_custom_desires_carry_satisfied_custom_needs() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+        app.init_asset::<Image>();
+        app.insert_resource(GameConstants::default());
+        let mut registry = CustomDesireRegistry::default();
+        let play = registry.register(play_definition()).unwrap();
+        app.insert_resource(registry);
+
+        let npc = app
+            .world_mut()
+            .run_system_once(|mut commands: Commands, asset_server: Res<AssetServer>, constants: Res<GameConstants>, registry: Res<CustomDesireRegistry>| {
+                create_npc_entity(&mut commands, &asset_server, &constants, &registry, Vec2::ZERO, &mut StdRng::seed_from_u64(2391))
+            })
+            .unwrap();
+
+        assert_eq!(app.world().get::<CustomNeeds>(npc).unwrap().value(play), 1.0);
+    }
+
+    #[test]
+    fn registered_play_desire_sends_an_agent_to_a_playground_until_it_is_satisfied() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(GameConstants::default());
+        world.insert_resource(SimulationRng::from_seed(3));
+        world.init_resource::<SimLogConfig>();
+        world.init_resource::<ResourceSpatialIndex>();
+        world.init_resource::<Events<EvaluateDecision>>();
+        world.init_resource::<Events<CurrentDesireSet>>();
+        world.init_resource::<Events<DesireChangeEvent>>();
+        world.init_resource::<Events<ResourceDiscoveredEvent>>();
+        world.init_resource::<Events<PathTargetSetEvent>>();
+        world.init_resource::<Events<ReservationDenied>>();
+
+        let mut registry = CustomDesireRegistry::default();
+        let play = registry.register(play_definition()).unwrap();
+        world.insert_resource(registry);
+
+        let playground_position = Vec2::new(80.0, 0.0);
+        let playground = world.spawn((CustomResourceSite { id: play }, Transform::from_translation(playground_position.extend(0.0)))).id();
+        let npc = world.spawn((
+            Npc,
+            Transform::default(),
+            BasicNeeds { hunger: 1.0, thirst: 1.0, rest: 1.0, safety: 1.0, social: 1.0 },
+            DesireThresholds::default(),
+            Desire::Wander,
+            CurrentDesire::default(),
+            CustomNeeds { values: [(play, 0.2)].into() },
+            ResourceMemory::default(),
+            PathTarget::default(),
+            RefillState::default(),
+        )).id();
+
+        // The bored agent wants to play, and heads for the playground once it has discovered it
+        world.send_event(EvaluateDecision { entity: npc, trigger_reason: DecisionTrigger::Periodic });
+        world.run_system_once(decision_making_system).unwrap();
+        assert_eq!(*world.get::<Desire>(npc).unwrap(), Desire::Custom(play));
+
+        world.run_system_once(resource_spatial_index_system).unwrap();
+        world.run_system_once(resource_discovery_system).unwrap();
+        world.run_system_once(desire_pathfinding_system).unwrap();
+        assert_eq!(world.get::<ResourceMemory>(npc).unwrap().known_custom[&play], vec![playground_position]);
+        assert_eq!(world.get::<PathTarget>(npc).unwrap().target_position, playground_position);
+        let target_set: Vec<_> = world.resource_mut::<Events<PathTargetSetEvent>>().drain().collect();
+        assert_eq!(target_set[0].target_type, ResourceType::Custom(play));
+
+        // Arriving there, it plays until the need is met and the desire is released
+        world.get_mut::<Transform>(npc).unwrap().translation = playground_position.extend(0.0);
+        world.run_system_once(refill_management_system).unwrap();
+        assert_eq!(world.get::<RefillState>(npc).unwrap().resource_entity, Some(playground));
+
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(2));
+        world.run_system_once(custom_needs_system).unwrap();
+        assert_eq!(world.get::<CustomNeeds>(npc).unwrap().value(play), 1.0);
+
+        world.run_system_once(decision_making_system).unwrap();
+        assert_eq!(*world.get::<Desire>(npc).unwrap(), Desire::Wander);
+    }
+
+    // =========================================================================
+    // DECISION PIPELINE MODE
+    // =========================================================================
+
+    /// Runs one fixed simulation tick of the given pipeline after an NPC's hunger collapses
+    /// Returns the resulting (Desire, CurrentDesire) and how many desire changes were announced
+    fn run_pipeline(mode: DecisionPipelineMode) -> (Desire, Desire, usize) {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(GameConstants::default())
+            .init_resource::<SimLogConfig>()
+            .add_event::<NeedChangeEvent>()
+            .add_event::<NeedsChangedBatch>()
+            .add_event::<ThresholdCrossedEvent>()
+            .add_event::<EvaluateDecision>()
+            .add_event::<CurrentDesireSet>()
+            .add_event::<DesireChangeEvent>()
+            .add_plugins(DecisionPipelinePlugin { mode });
+
+        let npc = app.world_mut().spawn((
+            Npc,
+            BasicNeeds { hunger: 0.1, thirst: 1.0, rest: 1.0, safety: 1.0, social: 1.0 },
+            DesireThresholds::default(),
+            Desire::Wander,
+            CurrentDesire::default(),
+        )).id();
+        app.world_mut().send_event(NeedChangeEvent {
+            entity: npc,
+            need_type: NeedType::Hunger,
+            old_value: 0.8,
+            new_value: 0.1,
+            change_amount: -0.7,
+        });
+
+        app.world_mut().run_schedule(FixedUpdate);
+
+        let world = app.world_mut();
+        let changes = world.resource_mut::<Events<DesireChangeEvent>>().drain().count();
+        (*world.get::<Desire>(npc).unwrap(), world.get::<CurrentDesire>(npc).unwrap().desire, changes)
+    }
+
+    #[test]
+    fn direct_pipeline_updates_desire_without_utility_arbitration() {
+        let (desire, current_desire, changes) = run_pipeline(DecisionPipelineMode::Direct);
+
+        assert_eq!(desire, Desire::FindFood);
+        assert_eq!(current_desire, Desire::Wander, "decision_making_system must not run in Direct mode");
+        assert_eq!(changes, 1);
+    }
+
+    #[test]
+    fn utility_pipeline_updates_desire_through_decision_making_only() {
+        let (desire, current_desire, changes) = run_pipeline(DecisionPipelineMode::UtilityArbitrated);
+
+        assert_eq!(current_desire, Desire::FindFood);
+        assert_eq!(desire, Desire::FindFood, "Desire is kept in sync by decision_making_system");
+        assert_eq!(changes, 1, "desire_update_system must not also fire a change");
+    }
+
+    #[test]
+    fn sudden_safety_drop_interrupts_a_food_trip_within_the_same_tick() {
+        // Returns the desire after one tick and whether the food trip is still underway
+        let drop_safety = |old_value: f32, new_value: f32| {
+            let mut app = App::new();
+            app.init_resource::<Time>()
+                .insert_resource(GameConstants::default())
+                .init_resource::<SimLogConfig>()
+                .add_event::<NeedChangeEvent>()
+                .add_event::<NeedsChangedBatch>()
+                .add_event::<ThresholdCrossedEvent>()
+                .add_event::<EvaluateDecision>()
+                .add_event::<CurrentDesireSet>()
+                .add_event::<DesireChangeEvent>()
+                .add_plugins(DecisionPipelinePlugin { mode: DecisionPipelineMode::UtilityArbitrated });
+
+            let npc = app.world_mut().spawn((
+                Npc,
+                BasicNeeds { hunger: 0.5, thirst: 1.0, rest: 1.0, safety: new_value, social: 1.0 },
+                DesireThresholds::default(),
+                Desire::FindFood,
+                CurrentDesire { desire: Desire::FindFood, ..default() },
+                PathTarget { target_position: Vec2::new(300.0, 0.0), has_target: true, ..default() },
+            )).id();
+            app.world_mut().send_event(NeedChangeEvent {
+                entity: npc,
+                need_type: NeedType::Safety,
+                old_value,
+                new_value,
+                change_amount: new_value - old_value,
+            });
+
+            app.world_mut().run_schedule(FixedUpdate);
+
+            let world = app.world();
+            (*world.get::<Desire>(npc).unwrap(), world.get::<PathTarget>(npc).unwrap().has_target)
+        };
+
+        assert_eq!(drop_safety(0.9, 0.35), (Desire::FindSafety, false), "a sharp drop abandons the food trip at once");
+        assert_eq!(drop_safety(0.41, 0.35), (Desire::FindSafety, true), "gradual decay finishes the current trip first");
+    }
+
+    #[test]
+    fn sweeping_hunger_across_both_thresholds_toggles_the_desire_exactly_once_each_way() {
+        let mut world = World::new();
+        world.init_resource::<SimLogConfig>();
+        world.init_resource::<Events<NeedChangeEvent>>();
+        world.init_resource::<Events<NeedsChangedBatch>>();
+        world.init_resource::<Events<ThresholdCrossedEvent>>();
+        world.init_resource::<Events<DesireChangeEvent>>();
+
+        let npc = world.spawn((
+            Npc,
+            BasicNeeds { hunger: 1.0, thirst: 0.7, rest: 1.0, safety: 1.0, social: 1.0 },
+            DesireThresholds::default(),
+            Desire::Wander,
+        )).id();
+
+        let set_need = |world: &mut World, need_type: NeedType, new_value: f32| {
+            let mut needs = world.get_mut::<BasicNeeds>(npc).unwrap();
+            let value = match need_type {
+                NeedType::Hunger => &mut needs.hunger,
+                NeedType::Thirst => &mut needs.thirst,
+                _ => unreachable!(),
+            };
+            let old_value = *value;
+            *value = new_value;
+            world.send_event(NeedChangeEvent { entity: npc, need_type, old_value, new_value, change_amount: new_value - old_value });
+            world.run_system_once(threshold_monitoring_system).unwrap();
+            world.run_system_once(desire_update_system).unwrap();
+            // run_system_once reads from the start of the queue, so each step must only see its own change
+            world.resource_mut::<Events<NeedChangeEvent>>().clear();
+            world.resource_mut::<Events<ThresholdCrossedEvent>>().clear();
+            world.resource_mut::<Events<DesireChangeEvent>>().drain().map(|event| event.new_desire).collect::<Vec<_>>()
+        };
+
+        // Down through high (0.7) and low (0.3), wobbling around low, then back up past high
+        let mut changes = Vec::new();
+        for hunger in [0.9, 0.65, 0.5, 0.31, 0.29, 0.31, 0.29, 0.1, 0.29, 0.31, 0.29, 0.35, 0.5] {
+            changes.extend(set_need(&mut world, NeedType::Hunger, hunger));
+        }
+        // Another need recovering must not switch off a desire whose own need is still unmet
+        changes.extend(set_need(&mut world, NeedType::Thirst, 0.9));
+        assert_eq!(*world.get::<Desire>(npc).unwrap(), Desire::FindFood);
+        for hunger in [0.69, 0.75, 0.9] {
+            changes.extend(set_need(&mut world, NeedType::Hunger, hunger));
+        }
+
+        assert_eq!(changes, vec![Desire::FindFood, Desire::Wander]);
+    }
+
+    // =========================================================================
+    // CURIOSITY
+    // =========================================================================
+
+    #[test]
+    fn high_exploration_agent_heads_toward_low_activation_region() {
+        let mut world = World::new();
+        world.insert_resource(GameConstants::default());
+        world.init_resource::<SimLogConfig>();
+        world.insert_resource(SimulationRng::from_seed(7));
+        world.init_resource::<Time>();
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(1));
+
+        // Everything at or left of the agent is familiar, everything to its right is unexplored
+        let mut map = CognitiveMap::default();
+        for x in -3..=0 {
+            for y in -3..=3 {
+                map.activations.insert(IVec2::new(x, y), 1.0);
+            }
+        }
+
+        let spawn_agent = |world: &mut World, exploration_rate: f32, map: CognitiveMap| {
+            world.spawn((
+                Npc,
+                Transform::from_xyz(50.0, 50.0, 0.0),
+                Desire::Wander,
+                AdaptiveBehavior { exploration_rate, ..AdaptiveBehavior::default() },
+                map,
+                PathTarget::default(),
+            )).id()
+        };
+        let curious = spawn_agent(&mut world, 1.0, CognitiveMap { activations: map.activations.clone(), ..CognitiveMap::default() });
+        let incurious = spawn_agent(&mut world, 0.0, map);
+
+        world.run_system_once(curiosity_exploration_system).unwrap();
+
+        let target = world.get::<PathTarget>(curious).unwrap();
+        assert!(target.has_target, "an exploration rate of 1.0 per second must trigger within a one-second frame");
+        assert!(target.target_position.x > 100.0, "target {:?} should lie in the unexplored cells", target.target_position);
+        assert!(!world.get::<PathTarget>(incurious).unwrap().has_target);
+    }
+
+    #[test]
+    fn agent_that_found_food_in_one_quadrant_wanders_back_toward_it() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(GameConstants::default());
+        world.insert_resource(SimulationRng::from_seed(3));
+        world.init_resource::<Events<PathTargetReachedEvent>>();
+        world.init_resource::<Events<ResourceDiscoveredEvent>>();
+
+        // Both idle agents start in the middle, heading left, away from the upper-right quadrant
+        let spawn_wanderer = |world: &mut World| {
+            world.spawn((
+                Npc,
+                Transform::default(),
+                Velocity::linear(Vec2::new(-200.0, 0.0)),
+                SteeringBehavior::default(),
+                PathTarget::default(),
+                Desire::Wander,
+                RefillState::default(),
+                ForageMemory::default(),
+            )).id()
+        };
+        let forager = spawn_wanderer(&mut world);
+        let naive = spawn_wanderer(&mut world);
+
+        let restaurant = world.spawn_empty().id();
+        world.send_event(ResourceDiscoveredEvent {
+            npc_entity: forager,
+            resource_position: Vec2::new(450.0, 250.0),
+            resource_entity: restaurant,
+            resource_type: ResourceType::Food,
+            discovery_distance: 50.0,
+        });
+        world.run_system_once(forage_memory_system).unwrap();
+        assert!(world.get::<ForageMemory>(naive).unwrap().rewards.is_empty());
+
+        let delta = 0.05;
+        let mut ticks_in_quadrant = [0, 0];
+        for _ in 0..400 {
+            world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(delta));
+            world.run_system_once(steering_behavior_system).unwrap();
+            for (index, npc) in [forager, naive].into_iter().enumerate() {
+                let velocity = world.get::<Velocity>(npc).unwrap().linvel;
+                let mut transform = world.get_mut::<Transform>(npc).unwrap();
+                transform.translation += (velocity * delta).extend(0.0);
+                if transform.translation.x > 0.0 && transform.translation.y > 0.0 {
+                    ticks_in_quadrant[index] += 1;
+                }
+            }
+        }
+
+        let [forager_ticks, naive_ticks] = ticks_in_quadrant;
+        assert!(forager_ticks > 200, "the forager spent only {forager_ticks} of 400 ticks near its find");
+        assert!(forager_ticks > naive_ticks, "forager {forager_ticks} vs naive {naive_ticks}");
+    }
+
+    // =========================================================================
+    // PATH LEARNING
+    // =========================================================================
+
+    #[test]
+    fn agents_sharing_knowledge_merge_near_coincident_landmarks_into_one_reference() {
+        let mut world = World::new();
+        world.init_resource::<Events<ResourceDiscoveredEvent>>();
+        world.init_resource::<Events<InteractionCompleted>>();
+        let first = world.spawn((Npc, CognitiveMap::default())).id();
+        let second = world.spawn((Npc, CognitiveMap::default())).id();
+        let well = world.spawn(Well::default()).id();
+
+        // Both mapped the well a few units apart, and only the second knows a far corner
+        for (npc, position) in [(first, Vec2::new(100.0, 0.0)), (second, Vec2::new(104.0, 3.0)), (second, Vec2::new(-300.0, 0.0))] {
+            world.send_event(ResourceDiscoveredEvent {
+                npc_entity: npc,
+                resource_position: position,
+                resource_entity: well,
+                resource_type: ResourceType::Water,
+                discovery_distance: 50.0,
+            });
+        }
+        world.run_system_once(landmark_recording_system).unwrap();
+        let landmarks = |world: &World, npc: Entity| world.get::<CognitiveMap>(npc).unwrap().landmarks.clone();
+        assert_ne!(landmarks(&world, first)[0].id, landmarks(&world, second)[0].id, "independently mapped places start out unrelated");
+
+        // A greeting shares nothing, a conversation reconciles the landmarks both know
+        for interaction_type in [InteractionType::Greeting, InteractionType::Conversation] {
+            world.send_event(InteractionCompleted {
+                entity_1: first,
+                entity_2: second,
+                duration: 2.0,
+                interaction_type,
+                satisfaction_1: 0.5,
+                satisfaction_2: 0.5,
+            });
+            world.run_system_once(landmark_sharing_system).unwrap();
+            if interaction_type == InteractionType::Greeting {
+                assert_ne!(landmarks(&world, first)[0].id, landmarks(&world, second)[0].id);
+            }
+        }
+
+        let (first_landmarks, second_landmarks) = (landmarks(&world, first), landmarks(&world, second));
+        assert_eq!(first_landmarks.len(), 1, "landmarks only one agent knows are not handed over");
+        assert_eq!(first_landmarks[0], second_landmarks[0], "both now know the well under one reference");
+        assert_eq!(first_landmarks[0].id, LandmarkId { origin: first, index: 0 });
+        assert_eq!(first_landmarks[0].position, Vec2::new(102.0, 1.5));
+        assert_eq!(second_landmarks[1].position, Vec2::new(-300.0, 0.0));
+        assert_eq!(second_landmarks[1].id.origin, second);
+    }
+
+    #[test]
+    fn reaching_a_target_records_experience_and_raises_segment_value() {
+        let mut world = World::new();
+        world.init_resource::<Events<PathTargetReachedEvent>>();
+
+        let origin = Vec2::new(50.0, 50.0);
+        let destination = Vec2::new(350.0, 50.0);
+        let npc = world.spawn((
+            Npc,
+            PathTarget { origin_position: origin, target_position: destination, has_target: true, ..Default::default() },
+            CognitiveMap::default(),
+            PathLearning::default(),
+        )).id();
+        let arrival = || PathTargetReachedEvent {
+            npc_entity: npc,
+            target_position: destination,
+            target_entity: None,
+            time_to_reach: 2.0,
+        };
+
+        // The arrival is reported on two consecutive frames but is a single traversal
+        world.send_event(arrival());
+        world.run_system_once(path_experience_system).unwrap();
+        world.send_event(arrival());
+        world.run_system_once(path_experience_system).unwrap();
+
+        let segment = PathSegment { from: IVec2::new(0, 0), to: IVec2::new(3, 0) };
+        let learning = world.get::<PathLearning>(npc).unwrap();
+        assert_eq!(learning.path_memory.len(), 1);
+        assert!(learning.path_memory[0].success);
+        assert_eq!(learning.path_memory[0].segment, segment);
+        assert_eq!(learning.value(segment), 0.0, "values only change on the learning tick");
+
+        world.run_system_once(path_learning_system).unwrap();
+
+        let learning = world.get::<PathLearning>(npc).unwrap();
+        assert!(learning.value(segment) > 0.0);
+        assert!(learning.path_memory[0].learned);
+    }
+
+    #[test]
+    fn resource_with_failing_route_is_deprioritized_versus_equidistant_reachable_one() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Events<PathTargetSetEvent>>();
+        world.init_resource::<Events<ReservationDenied>>();
+        world.insert_resource(SimulationRng::from_seed(7));
+        world.insert_resource(GameConstants::default());
+        world.init_resource::<ResourceSpatialIndex>();
+
+        let position = Vec2::new(0.0, 50.0);
+        let reachable = Vec2::new(-300.0, 50.0);
+        let behind_obstacle = Vec2::new(300.0, 50.0);
+
+        // Three pursuits of the well behind the obstacle timed out
+        let map = CognitiveMap::default();
+        let mut learning = PathLearning::default();
+        for _ in 0..3 {
+            record_path_experience(&mut learning, &map, position, behind_obstacle, 10.0, false, 10.0);
+        }
+        learn_from_path_memory(&mut learning);
+
+        let npc = world.spawn((
+            Npc,
+            Transform::from_translation(position.extend(0.0)),
+            Desire::FindWater,
+            // Listed last, so plain nearest-first tie-breaking would pick the blocked well
+            ResourceMemory { known_wells: vec![reachable, behind_obstacle], ..Default::default() },
+            PathTarget::default(),
+            map,
+            learning,
+            AdaptiveBehavior { exploration_rate: 0.0, ..AdaptiveBehavior::default() },
+        )).id();
+
+        world.run_system_once(desire_pathfinding_system).unwrap();
+
+        let target = world.get::<PathTarget>(npc).unwrap();
+        assert!(target.has_target);
+        assert_eq!(target.target_position, reachable);
+    }
+
+    #[test]
+    fn failed_target_is_skipped_until_its_cooldown_passes() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Events<PathTargetSetEvent>>();
+        world.init_resource::<Events<ReservationDenied>>();
+        world.init_resource::<Events<ActionCompleted>>();
+        world.init_resource::<Events<EvaluateDecision>>();
+        world.init_resource::<SimLogConfig>();
+        world.insert_resource(SimulationRng::from_seed(7));
+        world.insert_resource(GameConstants::default());
+        world.init_resource::<ResourceSpatialIndex>();
+
+        let near = Vec2::new(100.0, 0.0);
+        let far = Vec2::new(-400.0, 0.0);
+        let avoided = AvoidedTargets::default();
+        let cooldown = avoided.cooldown;
+        let npc = world.spawn((
+            Npc,
+            Transform::default(),
+            Desire::FindWater,
+            CurrentDesire { desire: Desire::FindWater, attempt_start_time: 1.0, timeout_duration: 5.0, ..default() },
+            BasicNeeds { thirst: 0.1, ..lonely_needs() },
+            DesireThresholds::default(),
+            RefillState::default(),
+            ResourceMemory { known_wells: vec![near, far], ..Default::default() },
+            PathTarget { target_position: near, has_target: true, target_set_time: 1.0, ..default() },
+            avoided,
+        )).id();
+
+        // The pursuit of the near well times out
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(20));
+        world.run_system_once(action_failure_handling_system).unwrap();
+        assert!(!world.get::<PathTarget>(npc).unwrap().has_target);
+
+        world.run_system_once(desire_pathfinding_system).unwrap();
+        assert_eq!(world.get::<PathTarget>(npc).unwrap().target_position, far, "the failed well is skipped");
+
+        // Once the cooldown has passed the near well is the natural choice again
+        world.entity_mut(npc).insert(PathTarget::default());
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(cooldown + 1.0));
+        world.run_system_once(desire_pathfinding_system).unwrap();
+        assert_eq!(world.get::<PathTarget>(npc).unwrap().target_position, near);
+    }
+
+    #[test]
+    fn one_slot_well_admits_one_agent_and_turns_the_other_away() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Events<PathTargetSetEvent>>();
+        world.init_resource::<Events<ReservationDenied>>();
+        world.insert_resource(SimulationRng::from_seed(7));
+        world.insert_resource(GameConstants::default());
+        world.init_resource::<ResourceSpatialIndex>();
+
+        let popular = Vec2::new(200.0, 0.0);
+        let alternative = Vec2::new(-400.0, 0.0);
+        let well = world.spawn((Well::default(), ResourceReservation::with_capacity(1), Transform::from_translation(popular.extend(0.0)))).id();
+        world.spawn((Well::default(), ResourceReservation::with_capacity(1), Transform::from_translation(alternative.extend(0.0))));
+
+        let agents: Vec<Entity> = [Vec2::new(100.0, 0.0), Vec2::new(120.0, 20.0)]
+            .into_iter()
+            .map(|position| {
+                world.spawn((
+                    Npc,
+                    Transform::from_translation(position.extend(0.0)),
+                    Desire::FindWater,
+                    ResourceMemory { known_wells: vec![popular, alternative], ..Default::default() },
+                    PathTarget::default(),
+                )).id()
+            })
+            .collect();
+
+        world.run_system_once(resource_spatial_index_system).unwrap();
+        world.run_system_once(desire_pathfinding_system).unwrap();
+
+        let holders = world.get::<ResourceReservation>(well).unwrap().holders.clone();
+        assert_eq!(holders.len(), 1, "only one agent gets the single slot");
+
+        let denied: Vec<_> = world.resource_mut::<Events<ReservationDenied>>().drain().collect();
+        assert_eq!(denied.len(), 1);
+        assert_eq!(denied[0].resource_entity, well);
+        assert_ne!(denied[0].npc_entity, holders[0]);
+
+        // The turned-away agent re-evaluated and heads for the other well instead
+        let turned_away = world.get::<PathTarget>(denied[0].npc_entity).unwrap();
+        assert!(turned_away.has_target);
+        assert_eq!(turned_away.target_position, alternative);
+        assert!(agents.contains(&denied[0].npc_entity));
+
+        // Once the holder stops pursuing the well its slot is released
+        world.get_mut::<PathTarget>(holders[0]).unwrap().has_target = false;
+        world.run_system_once(reservation_release_system).unwrap();
+        assert!(world.get::<ResourceReservation>(well).unwrap().holders.is_empty());
+    }
+
+    /// Two parched agents claim a well holding less than both ask for in the same update
+    /// The second agent to arrive is the more influential one; returns (first, second) allocations and the well stock left
+    fn contest_well(policy: ResourceContentionPolicy) -> (f32, f32, f32) {
+        let mut world = World::new();
+        world.insert_resource(policy);
+        world.init_resource::<Events<ResourceInteractionAttemptEvent>>();
+        world.init_resource::<Events<ResourceInteractionSuccessEvent>>();
+        world.init_resource::<Events<ResourceContentionResolved>>();
+        world.init_resource::<Events<NeedChangeEvent>>();
+
+        let well = world.spawn(Well { water_capacity: 0.15, consumption_rate: 0.1 }).id();
+        let first = world.spawn(BasicNeeds { hunger: 1.0, thirst: 0.0, rest: 1.0, safety: 1.0, social: 1.0 }).id();
+        let second = world.spawn((
+            BasicNeeds { hunger: 1.0, thirst: 0.2, rest: 1.0, safety: 1.0, social: 1.0 },
+            SocialInfluence { score: 0.9, in_degree: 3, is_potential_leader: true },
+        )).id();
+        for npc_entity in [first, second] {
+            world.send_event(ResourceInteractionAttemptEvent { npc_entity, resource_entity: well, resource_type: ResourceType::Water, distance: 0.0 });
+        }
+
+        world.run_system_once(resource_interaction_system).unwrap();
+
+        let contests: Vec<ResourceContentionResolved> = world.resource_mut::<Events<ResourceContentionResolved>>().drain().collect();
+        assert_eq!(contests.len(), 1, "one contention per contested resource");
+        let contest = &contests[0];
+        assert_eq!(contest.policy, policy);
+        assert_eq!(contest.stock_before, 0.15);
+        assert_eq!(contest.allocations.iter().map(|a| a.npc_entity).collect::<Vec<_>>(), vec![first, second]);
+        assert!((contest.allocations[0].requested - 0.1).abs() < 1e-6);
+        assert!((contest.allocations[1].requested - 0.09).abs() < 1e-6);
+
+        // Whoever received more water ends up less thirsty relative to where it started
+        let gained = |entity, before: f32| world.get::<BasicNeeds>(entity).unwrap().thirst - before;
+        let (first_share, second_share) = (contest.allocations[0].allocated, contest.allocations[1].allocated);
+        assert_eq!(first_share > 0.0, gained(first, 0.0) > 0.0);
+        assert_eq!(second_share > 0.0, gained(second, 0.2) > 0.0);
+
+        (first_share, second_share, world.get::<Well>(well).unwrap().water_capacity)
+    }
+
+    #[test]
+    fn first_come_first_served_fills_the_first_arrival_and_leaves_the_rest() {
+        let (first, second, stock_left) = contest_well(ResourceContentionPolicy::FirstComeFirstServed);
+        assert!((first - 0.1).abs() < 1e-6, "the first arrival gets its whole request");
+        assert!((second - 0.05).abs() < 1e-6, "the second gets what is left");
+        assert!(stock_left < 1e-6);
+    }
+
+    #[test]
+    fn share_equally_splits_a_scarce_well_evenly() {
+        let (first, second, stock_left) = contest_well(ResourceContentionPolicy::ShareEqually);
+        assert!((first - 0.075).abs() < 1e-6);
+        assert!((second - 0.075).abs() < 1e-6);
+        assert!(stock_left < 1e-6);
+    }
+
+    #[test]
+    fn dominance_based_serves_the_more_influential_agent_first() {
+        let (first, second, stock_left) = contest_well(ResourceContentionPolicy::DominanceBased);
+        assert!((second - 0.09).abs() < 1e-6, "the influential late arrival gets its whole request");
+        assert!((first - 0.06).abs() < 1e-6);
+        assert!(stock_left < 1e-6);
+    }
+
+    #[test]
+    fn agent_wedged_in_a_corner_gets_an_escape_impulse() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Events<ActionCompleted>>();
+        world.init_resource::<Events<EvaluateDecision>>();
+        world.init_resource::<SimLogConfig>();
+        world.insert_resource(SimulationRng::from_seed(3));
+        world.insert_resource(GameConstants::default());
+
+        // Pressed into the top-right corner by a target beyond the walls, not moving at all
+        let corner = GameConstants::default().room_half_extents - Vec2::splat(5.0);
+        let npc = world.spawn((
+            Npc,
+            Transform::from_translation(corner.extend(0.0)),
+            Velocity::zero(),
+            SteeringBehavior::default(),
+            StuckEscape { anchor_position: corner, ..default() },
+            CurrentDesire { desire: Desire::FindWater, ..default() },
+            lonely_needs(),
+            DesireThresholds::default(),
+            RefillState::default(),
+            PathTarget { target_position: corner + Vec2::splat(200.0), has_target: true, ..default() },
+        )).id();
+
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(1));
+        world.run_system_once(action_failure_handling_system).unwrap();
+        assert_eq!(world.get::<Velocity>(npc).unwrap().linvel, Vec2::ZERO, "one second without headway is not yet stuck");
+
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(2));
+        world.run_system_once(action_failure_handling_system).unwrap();
+
+        let escape_velocity = world.get::<Velocity>(npc).unwrap().linvel;
+        assert!(escape_velocity.length() > 0.0);
+        let toward_target = Vec2::ONE.normalize();
+        assert!(escape_velocity.perp_dot(toward_target).abs() > 0.0, "the impulse has a sideways component");
+        let escape = world.get::<StuckEscape>(npc).unwrap();
+        assert!(escape.is_escaping(3.0));
+        assert_eq!(world.get::<SteeringBehavior>(npc).unwrap().avoidance_weight, escape.avoidance_boost);
+        assert!(world.resource::<Events<ActionCompleted>>().is_empty(), "being stuck is not a failure");
+    }
+
+    /// World running the failure handling and utility decision systems for a single agent
+    fn flight_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Events<ActionCompleted>>();
+        world.init_resource::<Events<EvaluateDecision>>();
+        world.init_resource::<Events<NeedChangeEvent>>();
+        world.init_resource::<Events<NeedsChangedBatch>>();
+        world.init_resource::<Events<ThresholdCrossedEvent>>();
+        world.init_resource::<Events<CurrentDesireSet>>();
+        world.init_resource::<Events<DesireChangeEvent>>();
+        world.init_resource::<SimLogConfig>();
+        world.insert_resource(SimulationRng::from_seed(2330));
+        world.insert_resource(GameConstants::default());
+        world
+    }
+
+    #[test]
+    fn agent_leaves_find_safety_once_its_safety_recovers() {
+        let mut world = flight_world();
+        let npc = world.spawn((
+            Npc,
+            Desire::FindSafety,
+            CurrentDesire { desire: Desire::FindSafety, ..default() },
+            BasicNeeds { hunger: 0.9, thirst: 0.9, rest: 0.9, safety: 0.9, social: 0.9 },
+            DesireThresholds::default(),
+        )).id();
+
+        world.send_event(NeedChangeEvent { entity: npc, need_type: NeedType::Safety, old_value: 0.3, new_value: 0.9, change_amount: 0.6 });
+        world.run_system_once(optimized_threshold_monitoring_system).unwrap();
+        world.run_system_once(decision_making_system).unwrap();
+
+        assert_ne!(world.get::<CurrentDesire>(npc).unwrap().desire, Desire::FindSafety);
+        assert_eq!(*world.get::<Desire>(npc).unwrap(), world.get::<CurrentDesire>(npc).unwrap().desire, "Desire follows the decision");
+    }
+
+    #[test]
+    fn agent_that_cannot_reach_safety_gives_up_fleeing_and_its_desire_follows() {
+        let mut world = flight_world();
+        let max_failures = GameConstants::default().max_failure_attempts;
+        let npc = world.spawn((
+            Npc,
+            Transform::default(),
+            Desire::FindSafety,
+            CurrentDesire { desire: Desire::FindSafety, failure_count: max_failures - 1, attempt_start_time: 1.0, timeout_duration: 5.0, ..default() },
+            BasicNeeds { hunger: 0.9, thirst: 0.6, rest: 0.9, safety: 0.3, social: 0.9 },
+            DesireThresholds::default(),
+            RefillState::default(),
+            PathTarget::default(),
+            SafetyHabituation::default(),
+        )).id();
+
+        // The last allowed attempt to find a safe zone times out
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(20));
+        world.run_system_once(action_failure_handling_system).unwrap();
+
+        assert_eq!(world.get::<BasicNeeds>(npc).unwrap().safety, 0.3, "giving up does not make the agent any safer");
+        assert!(world.get::<SafetyHabituation>(npc).unwrap().is_habituated(20.0), "the fear habituates instead");
+        assert_ne!(*world.get::<Desire>(npc).unwrap(), Desire::FindSafety);
+
+        // The forced re-evaluation lets the thirst it neglected outrank the damped threat
+        world.run_system_once(decision_making_system).unwrap();
+        assert_eq!(world.get::<CurrentDesire>(npc).unwrap().desire, Desire::FindWater);
+        assert_eq!(*world.get::<Desire>(npc).unwrap(), Desire::FindWater);
+
+        // Once the habituation wears off the unresolved threat wins again
+        world.resource_mut::<Events<EvaluateDecision>>().clear();
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(SafetyHabituation::default().cooldown));
+        world.send_event(EvaluateDecision { entity: npc, trigger_reason: DecisionTrigger::Periodic });
+        world.run_system_once(decision_making_system).unwrap();
+        assert_eq!(world.get::<CurrentDesire>(npc).unwrap().desire, Desire::FindSafety);
+    }
+
+    #[test]
+    fn exported_learned_model_reloads_into_a_fresh_body_with_the_same_route_rankings() {
+        let mut world = World::new();
+        let origin = Vec2::new(50.0, 50.0);
+        let map = CognitiveMap { activations: [(IVec2::new(0, 0), 0.8), (IVec2::new(3, 0), 0.4)].into(), ..default() };
+        let mut learning = PathLearning::default();
+        for (destination, success, time) in [
+            (Vec2::new(350.0, 50.0), true, 2.0),
+            (Vec2::new(50.0, 350.0), true, 8.0),
+            (Vec2::new(-250.0, 50.0), false, 10.0),
+        ] {
+            record_path_experience(&mut learning, &map, origin, destination, time, success, 10.0);
+        }
+        learn_from_path_memory(&mut learning);
+        let trained = world.spawn((Npc, map, learning)).id();
+
+        let ranking = |learning: &PathLearning| {
+            let mut segments: Vec<(PathSegment, f32)> = learning.path_values.iter().map(|(segment, value)| (*segment, *value)).collect();
+            segments.sort_by(|a, b| b.1.total_cmp(&a.1));
+            segments.into_iter().map(|(segment, _)| segment).collect::<Vec<_>>()
+        };
+        let expected = ranking(world.get::<PathLearning>(trained).unwrap());
+
+        let path = std::env::temp_dir().join(format!("learned_model_test_{}.json", std::process::id()));
+        save_learned_model(&path, &export_learned_model(&world, trained).unwrap()).unwrap();
+        let model = load_learned_model(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        // A fresh body without any learning components takes on the trained agent's knowledge
+        let fresh = world.spawn(Npc).id();
+        assert!(import_learned_model(&mut world, fresh, &model));
+        assert_eq!(ranking(world.get::<PathLearning>(fresh).unwrap()), expected);
+        assert_eq!(world.get::<CognitiveMap>(fresh).unwrap().activation(IVec2::new(0, 0)), 0.8);
+
+        // A body on a finer grid gets the same routes re-keyed to its own cells
+        let fine = world.spawn((Npc, CognitiveMap { cell_size: 50.0, ..default() })).id();
+        assert!(import_learned_model(&mut world, fine, &model));
+        let fine_learning = world.get::<PathLearning>(fine).unwrap();
+        let best = PathSegment { from: IVec2::new(1, 1), to: IVec2::new(7, 1) };
+        assert_eq!(ranking(fine_learning)[0], best);
+        assert_eq!(fine_learning.value(best), world.get::<PathLearning>(trained).unwrap().value(expected[0]));
+    }
+
+    // =========================================================================
+    // LOGGING
+    // =========================================================================
+
+    /// Tracing layer recording the level of every event it sees
+    struct LevelRecorder(Arc<Mutex<Vec<Level>>>);
+
+    impl<S: Subscriber> Layer<S> for LevelRecorder {
+        fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+            self.0.lock().unwrap().push(*event.metadata().level());
+        }
+    }
+
+    /// Runs refill_management_system once for a thirsty NPC standing at a well and returns the logged levels
+    fn capture_refill_log_levels(needs_verbosity: LogVerbosity, gate_open: bool) -> Vec<Level> {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(SimLogConfig { needs: needs_verbosity, ..Default::default() });
+        world.insert_resource(LogGate { open: gate_open });
+        world.spawn((Well::default(), Transform::from_xyz(10.0, 0.0, 0.0)));
+        world.spawn((Npc, Transform::default(), Desire::FindWater, RefillState::default()));
+
+        let levels = Arc::new(Mutex::new(Vec::new()));
+        let subscriber = Registry::default().with(LevelRecorder(levels.clone()));
+        tracing::subscriber::with_default(subscriber, || {
+            world.run_system_once(refill_management_system).unwrap();
+        });
+
+        levels.lock().unwrap().clone()
+    }
+
+    #[test]
+    fn disabled_needs_logging_emits_no_info_record_on_refill() {
+        let verbose = capture_refill_log_levels(LogVerbosity::Info, true);
+        assert!(verbose.contains(&Level::INFO), "sanity check: the refill is logged at info when asked to");
+
+        let default = capture_refill_log_levels(LogVerbosity::default(), true);
+        assert!(!default.contains(&Level::INFO), "routine refills are demoted to trace by default");
+        assert!(default.contains(&Level::TRACE));
+
+        let disabled = capture_refill_log_levels(LogVerbosity::Off, true);
+        assert!(!disabled.contains(&Level::INFO));
+        assert!(disabled.is_empty(), "nothing is logged for a disabled category: {disabled:?}");
+    }
+
+    #[test]
+    fn closed_log_gate_silences_a_refill_whatever_the_category_verbosity() {
+        assert!(!capture_refill_log_levels(LogVerbosity::Info, true).is_empty(), "sanity check: the open gate lets the refill through");
+
+        let gated = capture_refill_log_levels(LogVerbosity::Info, false);
+        assert!(gated.is_empty(), "nothing is logged while the gate is closed: {gated:?}");
+    }
+
+    // =========================================================================
+    // NEED EVENT BATCHING
+    // =========================================================================
+
+    /// Decays `agents` randomly initialised NPCs for `frames` five-second frames under the given mode
+    /// Returns how many need events were sent and every threshold crossing, sorted for comparison
+    fn run_need_decay(mode: NeedEventMode, agents: usize, frames: usize) -> (usize, Vec<(u32, String, u32)>) {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(GameConstants::default());
+        world.insert_resource(mode);
+        world.init_resource::<NeedCouplingConfig>();
+        world.init_resource::<Events<NeedDecayEvent>>();
+        world.init_resource::<Events<NeedChangeEvent>>();
+        world.init_resource::<Events<NeedsChangedBatch>>();
+        world.init_resource::<Events<ThresholdCrossedEvent>>();
+
+        let mut rng = StdRng::seed_from_u64(2342);
+        for _ in 0..agents {
+            world.spawn((
+                Npc,
+                BasicNeeds {
+                    hunger: rng.random_range(0.0..1.0),
+                    thirst: rng.random_range(0.0..1.0),
+                    rest: rng.random_range(0.0..1.0),
+                    safety: rng.random_range(0.0..1.0),
+                    social: rng.random_range(0.0..1.0),
+                },
+                DesireThresholds::default(),
+            ));
+        }
+
+        let mut need_events = 0;
+        let mut crossings = Vec::new();
+        for _ in 0..frames {
+            world.resource_mut::<Time>().advance_by(Duration::from_secs(5));
+            world.run_system_once(decay_basic_needs).unwrap();
+            need_events += world.resource::<Events<NeedChangeEvent>>().len() + world.resource::<Events<NeedsChangedBatch>>().len();
+
+            world.run_system_once(threshold_monitoring_system).unwrap();
+            crossings.extend(world.resource_mut::<Events<ThresholdCrossedEvent>>().drain().map(|crossing| {
+                (
+                    crossing.entity.index(),
+                    format!("{:?} {:?}", crossing.need_type, crossing.crossed_direction),
+                    crossing.threshold_value.to_bits(),
+                )
+            }));
+
+            world.resource_mut::<Events<NeedChangeEvent>>().clear();
+            world.resource_mut::<Events<NeedsChangedBatch>>().clear();
+        }
+
+        crossings.sort();
+        (need_events, crossings)
+    }
+
+    #[test]
+    fn batched_and_granular_need_events_produce_identical_threshold_crossings() {
+        let (granular_events, granular_crossings) = run_need_decay(NeedEventMode::Granular, 50, 10);
+        let (batched_events, batched_crossings) = run_need_decay(NeedEventMode::Batched, 50, 10);
+
+        assert!(!granular_crossings.is_empty(), "the scenario must actually cross thresholds");
+        assert_eq!(granular_crossings, batched_crossings);
+        assert_eq!(granular_events, 5 * batched_events, "every need decays each frame, so five events collapse into one");
+    }
+
+    #[test]
+    fn batching_cuts_need_event_volume_fivefold_at_2000_agents() {
+        const AGENTS: usize = 2000;
+        const FRAMES: usize = 10;
+        let (granular_events, granular_crossings) = run_need_decay(NeedEventMode::Granular, AGENTS, FRAMES);
+        let (batched_events, batched_crossings) = run_need_decay(NeedEventMode::Batched, AGENTS, FRAMES);
+
+        assert_eq!(batched_events, AGENTS * FRAMES, "one batch per agent per frame");
+        assert_eq!(granular_events, 5 * batched_events, "against one event per need per agent per frame");
+        assert_eq!(granular_crossings, batched_crossings);
+    }
+
+    // =========================================================================
+    // CAMERA
+    // =========================================================================
+
+    #[test]
+    fn follow_mode_tracks_a_moving_agent_with_bounded_lag() {
+        const AGENT_SPEED: f32 = 100.0;
+        const FRAME: f32 = 1.0 / 60.0;
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.init_resource::<AccumulatedMouseScroll>();
+        world.insert_resource(CameraController { follow_selected: true, ..default() });
+
+        let camera = world.spawn((Camera2d, Transform::default())).id();
+        let agent = world.spawn((Npc, Transform::from_xyz(200.0, -50.0, 0.0))).id();
+        world.insert_resource(SelectedNpc(Some(agent)));
+
+        for _ in 0..180 {
+            world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(FRAME));
+            world.get_mut::<Transform>(agent).unwrap().translation.x += AGENT_SPEED * FRAME;
+            world.run_system_once(camera_controller_system).unwrap();
+        }
+
+        let camera_position = world.get::<Transform>(camera).unwrap().translation.truncate();
+        let agent_position = world.get::<Transform>(agent).unwrap().translation.truncate();
+        let smoothing = world.resource::<CameraController>().follow_smoothing;
+
+        // Exponential following settles a speed / smoothing distance behind a steadily moving target
+        let expected_lag = AGENT_SPEED / smoothing;
+        let lag = agent_position.x - camera_position.x;
+        assert!((lag - expected_lag).abs() < expected_lag * 0.1, "lag {lag} should settle near {expected_lag}");
+        assert!((camera_position.y - agent_position.y).abs() < 0.01, "the stationary axis converges fully");
+    }
+
+    #[test]
+    fn agent_diff_pinpoints_the_single_differing_need() {
+        let memory = ResourceMemory { known_wells: vec![Vec2::ZERO], ..Default::default() };
+        let needs = BasicNeeds { hunger: 0.8, thirst: 0.6, rest: 0.7, safety: 0.9, social: 0.5 };
+        let first = AgentSnapshot::capture(&needs, &Desire::FindWater, None, Some(&memory), None);
+        let second = AgentSnapshot::capture(&BasicNeeds { thirst: 0.55, ..needs }, &Desire::FindWater, None, Some(&memory), None);
+
+        assert!(first.diff(&first.clone()).is_empty());
+
+        let diff = first.diff(&second);
+        assert_eq!(diff.differences.len(), 1, "{:?}", diff.differences);
+        assert_eq!(diff.differences[0].field, "needs.thirst");
+        assert_eq!(diff.differences[0].first, "0.6");
+        assert_eq!(diff.differences[0].second, "0.55");
+    }
+
+    #[test]
+    fn injected_hunger_deficit_drives_the_selected_agent_to_find_food() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(GameConstants::default())
+            .init_resource::<SimLogConfig>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_event::<NeedChangeEvent>()
+            .add_event::<NeedsChangedBatch>()
+            .add_event::<ThresholdCrossedEvent>()
+            .add_event::<EvaluateDecision>()
+            .add_event::<CurrentDesireSet>()
+            .add_event::<DesireChangeEvent>()
+            .add_plugins(DecisionPipelinePlugin { mode: DecisionPipelineMode::Direct });
+
+        let npc = app.world_mut().spawn((
+            Npc,
+            BasicNeeds { hunger: 0.9, thirst: 1.0, rest: 1.0, safety: 1.0, social: 1.0 },
+            DesireThresholds::default(),
+            Desire::Wander,
+            CurrentDesire::default(),
+        )).id();
+        app.insert_resource(SelectedNpc(Some(npc)));
+
+        // Without a key press nothing is injected
+        app.world_mut().run_system_once(need_deficit_injection_system).unwrap();
+        assert_eq!(app.world().get::<BasicNeeds>(npc).unwrap().hunger, 0.9);
+
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Digit1);
+        app.world_mut().run_system_once(need_deficit_injection_system).unwrap();
+        app.world_mut().run_schedule(FixedUpdate);
+
+        let world = app.world_mut();
+        assert!(world.get::<BasicNeeds>(npc).unwrap().hunger < 0.1);
+        let changes: Vec<Desire> = world.resource_mut::<Events<DesireChangeEvent>>().drain().map(|event| event.new_desire).collect();
+        assert_eq!(changes, vec![Desire::FindFood]);
+        assert_eq!(*world.get::<Desire>(npc).unwrap(), Desire::FindFood);
+    }
+
+    // =========================================================================
+    // TELEMETRY
+    // =========================================================================
+
+    #[test]
+    fn desire_census_counts_a_known_population_once_per_interval() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(DesireCensusConfig { interval_secs: 2.0 });
+        world.init_resource::<DesireCensus>();
+        world.init_resource::<Events<DesireCensus>>();
+
+        let desires = [Desire::FindWater, Desire::FindWater, Desire::FindWater, Desire::FindWater, Desire::FindFood, Desire::Rest];
+        for desire in desires.into_iter().chain([Desire::Wander; 4]) {
+            world.spawn((Npc, CurrentDesire { desire, ..default() }));
+        }
+
+        // Before the interval has passed nothing is counted
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(1.0));
+        world.run_system_once(society_desire_census_system).unwrap();
+        assert!(world.resource::<Events<DesireCensus>>().is_empty());
+
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(2.0));
+        world.run_system_once(society_desire_census_system).unwrap();
+        let fired: Vec<DesireCensus> = world.resource_mut::<Events<DesireCensus>>().drain().collect();
+        assert_eq!(fired.len(), 1);
+
+        let census = &fired[0];
+        assert_eq!(census.population, 10);
+        assert_eq!(census.count(Desire::FindWater), 4);
+        assert_eq!(census.count(Desire::FindFood), 1);
+        assert_eq!(census.count(Desire::Rest), 1);
+        assert_eq!(census.count(Desire::Wander), 4);
+        assert_eq!(census.count(Desire::Socialize), 0);
+        assert!((census.share(Desire::FindWater) - 0.4).abs() < 1e-6, "40% seeking water");
+        assert_eq!(world.resource::<DesireCensus>(), census, "the latest census stays readable between firings");
+    }
+
+    #[test]
+    fn diagnostic_overlay_shows_the_same_figures_as_the_aggregate_resources_after_an_update() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(DesireCensusConfig { interval_secs: 1.0 });
+        world.insert_resource(WellbeingConfig { interval_secs: 1.0 });
+        world.insert_resource(FrameTimeMonitor { smoothed_ms: 20.0, ..default() });
+        world.insert_resource(DiagnosticOverlay { enabled: true, ..default() });
+        world.init_resource::<DesireCensus>();
+        world.init_resource::<Events<DesireCensus>>();
+        world.init_resource::<SocietyWellbeing>();
+
+        for (desire, hunger) in [(Desire::FindFood, 0.1), (Desire::FindFood, 0.2), (Desire::FindWater, 0.9), (Desire::Wander, 1.0)] {
+            world.spawn((Npc, CurrentDesire { desire, ..default() }, BasicNeeds { hunger, ..default() }));
+        }
+        let panel = world.spawn((Text::new(""), Visibility::Hidden, DiagnosticOverlayText)).id();
+
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(1.0));
+        world.run_system_once(society_desire_census_system).unwrap();
+        world.run_system_once(society_wellbeing_system).unwrap();
+        world.run_system_once(diagnostic_overlay_update_system).unwrap();
+        world.run_system_once(diagnostic_overlay_render_system).unwrap();
+
+        let overlay = world.resource::<DiagnosticOverlay>().clone();
+        let census = world.resource::<DesireCensus>();
+        assert_eq!(overlay.population, census.population);
+        assert_eq!(overlay.desire_counts, [(Desire::FindFood, 2), (Desire::FindWater, 1), (Desire::Wander, 1)], "most common desire first");
+        assert!(overlay.desire_counts.iter().all(|(desire, count)| census.count(*desire) == *count));
+        assert_eq!(overlay.wellbeing, *world.resource::<SocietyWellbeing>());
+        assert!((overlay.fps - 50.0).abs() < 1e-3);
+
+        assert_eq!(world.get::<Text>(panel).unwrap().0, format_diagnostic_overlay(&overlay));
+        assert_eq!(world.get::<Visibility>(panel), Some(&Visibility::Visible));
+        assert!(world.get::<Text>(panel).unwrap().0.contains("Population: 4"));
+    }
+
+    #[test]
+    fn need_history_keeps_the_latest_samples_up_to_its_capacity() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(NeedHistoryConfig { interval_secs: 0.5, capacity: 3, ..default() });
+        let npc = world.spawn((Npc, BasicNeeds::default(), NeedHistory::default())).id();
+
+        // Hunger 0.1, 0.2, ... 0.5 at successive sampling intervals
+        for step in 1..=5 {
+            world.get_mut::<BasicNeeds>(npc).unwrap().hunger = step as f32 * 0.1;
+            world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(0.5));
+            world.run_system_once(need_history_sampling_system).unwrap();
+        }
+
+        // Only the three most recent survive, oldest first
+        let samples = &world.get::<NeedHistory>(npc).unwrap().samples;
+        assert_eq!(samples.len(), 3);
+        let hungers: Vec<f32> = samples.iter().map(|sample| sample.needs.hunger).collect();
+        let times: Vec<f32> = samples.iter().map(|sample| sample.time).collect();
+        assert!(hungers.iter().zip([0.3, 0.4, 0.5]).all(|(actual, expected)| (actual - expected).abs() < 1e-6), "got {hungers:?}");
+        assert!(times.iter().zip([1.5, 2.0, 2.5]).all(|(actual, expected)| (actual - expected).abs() < 1e-6), "got {times:?}");
+    }
+
+    #[test]
+    fn telemetry_snapshot_aggregates_a_known_population_and_serializes() {
+        let needs = [
+            BasicNeeds { hunger: 0.2, thirst: 1.0, rest: 0.5, safety: 1.0, social: 0.4 },
+            BasicNeeds { hunger: 0.6, thirst: 1.0, rest: 0.5, safety: 1.0, social: 0.8 },
+        ];
+        let desires = [Desire::FindFood, Desire::Wander];
+        let extravert = Personality { openness: 0.5, extraversion: 1.0, agreeableness: 0.5, conscientiousness: 0.5, neuroticism: 0.5 };
+        let interacting = SocialInteractionState {
+            active: Some(ActiveInteraction {
+                partner: Entity::PLACEHOLDER,
+                started_at: 0.0,
+                duration: 2.0,
+                interaction_type: InteractionType::Greeting,
+                satisfaction: 0.5,
+            }),
+            ..default()
+        };
+
+        let snapshot = build_telemetry_snapshot(
+            10.0,
+            [
+                (&needs[0], Some(&desires[0]), Some(&extravert), Some(&interacting)),
+                (&needs[1], Some(&desires[1]), None, Some(&interacting)),
+            ],
+        );
+
+        assert_eq!(snapshot.population, 2);
+        assert!((snapshot.needs.hunger.mean - 0.4).abs() < 1e-6);
+        assert!((snapshot.needs.hunger.variance - 0.04).abs() < 1e-6);
+        assert_eq!(snapshot.needs.thirst.variance, 0.0);
+        assert_eq!(snapshot.desire_histogram.get("FindFood"), Some(&1));
+        assert_eq!(snapshot.desire_histogram.get("Wander"), Some(&1));
+        // 0.4 * 1.5 for the extravert, 0.8 * 1.0 for the agent without a personality
+        assert!((snapshot.mean_social_energy - 0.7).abs() < 1e-6);
+        assert_eq!(snapshot.active_interactions, 1);
+
+        let json: serde_json::Value = serde_json::from_str(&serde_json::to_string(&snapshot).unwrap()).unwrap();
+        assert_eq!(json["population"], 2);
+        assert_eq!(json["desire_histogram"]["FindFood"], 1);
+        assert!(json["needs"]["hunger"]["mean"].is_number());
+    }
+
+    #[test]
+    fn telemetry_snapshots_follow_the_configured_interval_and_flush_in_batches() {
+        let output_path = std::env::temp_dir().join(format!("telemetry_test_{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&output_path);
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<TelemetryRecorder>();
+        world.insert_resource(TelemetryConfig {
+            enabled: true,
+            interval_secs: 1.0,
+            output_path: output_path.to_string_lossy().into_owned(),
+            flush_batch_size: 2,
+            max_pending_lines: 10,
+        });
+        world.spawn((Npc, lonely_needs(), Desire::Socialize));
+
+        // 2.5 simulated seconds in quarter-second frames: snapshots at 0, 1 and 2 seconds
+        for _ in 0..=10 {
+            world.run_system_once(telemetry_snapshot_system).unwrap();
+            world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(0.25));
+        }
+
+        let recorder = world.resource::<TelemetryRecorder>();
+        assert_eq!(recorder.snapshots_recorded, 3);
+        assert_eq!(recorder.pending_lines.len(), 1, "the third snapshot waits for the next batch");
+
+        let written = std::fs::read_to_string(&output_path).unwrap();
+        let lines: Vec<_> = written.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let second: serde_json::Value = serde_json::from_str(lines[1]).unwrap();
+        assert_eq!(second["time"], 1.0);
+        assert_eq!(second["desire_histogram"]["Socialize"], 1);
+
+        let _ = std::fs::remove_file(&output_path);
+    }
+
+    #[test]
+    fn telemetry_is_off_by_default() {
+        assert!(!TelemetryConfig::default().enabled);
+    }
+
+    #[test]
+    fn unwritable_telemetry_keeps_a_bounded_buffer() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<TelemetryRecorder>();
+        world.insert_resource(TelemetryConfig {
+            enabled: true,
+            interval_secs: 1.0,
+            output_path: std::env::temp_dir().join("no_such_dir").join("telemetry.jsonl").to_string_lossy().into_owned(),
+            flush_batch_size: 2,
+            max_pending_lines: 3,
+        });
+        world.spawn((Npc, lonely_needs(), Desire::Socialize));
+
+        for _ in 0..8 {
+            world.run_system_once(telemetry_snapshot_system).unwrap();
+            world.resource_mut::<Time>().advance_by(Duration::from_secs(1));
+        }
+
+        let recorder = world.resource::<TelemetryRecorder>();
+        assert_eq!(recorder.snapshots_recorded, 8);
+        assert_eq!(recorder.pending_lines.len(), 3);
+        assert_eq!(recorder.snapshots_dropped, 5);
+    }
+
+    #[test]
+    fn telemetry_flushes_a_partial_batch_on_app_exit() {
+        let output_path = std::env::temp_dir().join(format!("telemetry_exit_test_{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&output_path);
+
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<TelemetryRecorder>();
+        world.init_resource::<Events<AppExit>>();
+        world.insert_resource(TelemetryConfig {
+            enabled: true,
+            output_path: output_path.to_string_lossy().into_owned(),
+            ..default()
+        });
+        world.spawn((Npc, lonely_needs(), Desire::Socialize));
+
+        world.run_system_once(telemetry_snapshot_system).unwrap();
+        world.run_system_once(telemetry_flush_on_exit_system).unwrap();
+        assert!(!output_path.exists(), "nothing is written before the batch fills or the app exits");
+
+        world.send_event(AppExit::Success);
+        world.run_system_once(telemetry_flush_on_exit_system).unwrap();
+        assert!(world.resource::<TelemetryRecorder>().pending_lines.is_empty());
+        assert_eq!(std::fs::read_to_string(&output_path).unwrap().lines().count(), 1);
+
+        let _ = std::fs::remove_file(&output_path);
+    }
+
+    #[test]
+    fn society_wellbeing_aggregates_a_known_population() {
+        let uniform = |value: f32| BasicNeeds { hunger: value, thirst: value, rest: value, safety: value, social: value };
+        let equal = build_society_wellbeing(&[uniform(0.5), uniform(0.5)]);
+        assert!((equal.mean_satisfaction - 0.5).abs() < 1e-6);
+        assert_eq!(equal.inequality, 0.0);
+
+        // Overall satisfactions 0.2, 0.4 and 0.6 (the last agent's needs average to 0.6)
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(WellbeingConfig { interval_secs: 1.0 });
+        world.init_resource::<SocietyWellbeing>();
+        world.spawn((Npc, uniform(0.2)));
+        world.spawn((Npc, uniform(0.4)));
+        world.spawn((Npc, BasicNeeds { hunger: 1.0, thirst: 1.0, rest: 0.5, safety: 0.5, social: 0.0 }));
+
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(1));
+        world.run_system_once(society_wellbeing_system).unwrap();
+
+        let wellbeing = *world.resource::<SocietyWellbeing>();
+        assert_eq!(wellbeing.population, 3);
+        assert!((wellbeing.mean_satisfaction - 0.4).abs() < 1e-6);
+        assert!((wellbeing.min_satisfaction - 0.2).abs() < 1e-6);
+        // Gini of (0.2, 0.4, 0.6): mean absolute difference over all ordered pairs (1.6 / 9) over twice the mean
+        assert!((wellbeing.inequality - 2.0 / 9.0).abs() < 1e-5, "gini: {}", wellbeing.inequality);
+    }
+
+    // =========================================================================
+    // FIXED TIMESTEP
+    // =========================================================================
+
+    #[derive(Resource, Default)]
+    struct EvaluationCount(usize);
+
+    /// Renders `simulated_secs` of simulation at `fps` and counts the decision evaluations requested
+    fn count_evaluations_at_render_rate(fps: f64, simulated_secs: f64) -> usize {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(1.0 / fps)))
+            .insert_resource(Time::<Fixed>::from_hz(60.0))
+            .insert_resource(GameConstants::default())
+            .init_resource::<SimLogConfig>()
+            .init_resource::<EvaluationCount>()
+            .add_event::<NeedChangeEvent>()
+            .add_event::<NeedsChangedBatch>()
+            .add_event::<ThresholdCrossedEvent>()
+            .add_event::<EvaluateDecision>()
+            .add_event::<CurrentDesireSet>()
+            .add_event::<DesireChangeEvent>()
+            .add_plugins(DecisionPipelinePlugin::default())
+            .add_systems(FixedUpdate, |mut events: EventReader<EvaluateDecision>, mut count: ResMut<EvaluationCount>| {
+                count.0 += events.read().count();
+            });
+
+        for _ in 0..5 {
+            app.world_mut().spawn((
+                Npc,
+                BasicNeeds { hunger: 1.0, thirst: 1.0, rest: 1.0, safety: 1.0, social: 1.0 },
+                DesireThresholds::default(),
+                Desire::Wander,
+                CurrentDesire::default(),
+                DualProcess::default(),
+            ));
+        }
+
+        for _ in 0..(simulated_secs * fps).round() as usize {
+            app.update();
+        }
+        app.world().resource::<EvaluationCount>().0
+    }
+
+    #[test]
+    fn decision_evaluations_per_simulated_second_do_not_depend_on_render_rate() {
+        let at_30_fps = count_evaluations_at_render_rate(30.0, 10.5);
+        let at_144_fps = count_evaluations_at_render_rate(144.0, 10.5);
+
+        assert!(at_30_fps > 0, "the periodic trigger must fire within the run");
+        assert_eq!(at_30_fps, at_144_fps);
+    }
+
+    #[derive(Resource, Default)]
+    struct PipelineEventLog(Vec<String>);
+
+    /// Runs need decay and the decision pipeline on a seeded population and logs every event the pipeline emits, in order
+    fn decision_pipeline_event_log(seed: u64) -> Vec<String> {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(1.0 / 60.0)))
+            .insert_resource(Time::<Fixed>::from_hz(60.0))
+            .insert_resource(GameConstants { hunger_decay: 0.05, thirst_decay: 0.05, ..default() })
+            .init_resource::<SimLogConfig>()
+            .init_resource::<NeedCouplingConfig>()
+            .init_resource::<NeedEventMode>()
+            .init_resource::<PipelineEventLog>()
+            .add_event::<NeedDecayEvent>()
+            .add_event::<NeedChangeEvent>()
+            .add_event::<NeedsChangedBatch>()
+            .add_event::<ThresholdCrossedEvent>()
+            .add_event::<EvaluateDecision>()
+            .add_event::<CurrentDesireSet>()
+            .add_event::<DesireChangeEvent>()
+            .add_plugins(DecisionPipelinePlugin::default())
+            .add_systems(FixedUpdate, (
+                decay_basic_needs.in_set(SimulationSet::StateUpdate),
+                (|mut evaluations: EventReader<EvaluateDecision>,
+                  mut changes: EventReader<DesireChangeEvent>,
+                  mut log: ResMut<PipelineEventLog>| {
+                    for event in evaluations.read() {
+                        log.0.push(format!("evaluate {} {:?}", event.entity, event.trigger_reason));
+                    }
+                    for event in changes.read() {
+                        log.0.push(format!("change {} {:?} -> {:?}", event.entity, event.old_desire, event.new_desire));
+                    }
+                }).in_set(SimulationSet::WorldUpdate),
+            ));
+
+        let mut rng = StdRng::seed_from_u64(seed);
+        for _ in 0..12 {
+            app.world_mut().spawn((
+                Npc,
+                random_basic_needs_from_rng(&mut rng),
+                DesireThresholds::default(),
+                Desire::Wander,
+                CurrentDesire::default(),
+                DualProcess::default(),
+                PathTarget::default(),
+            ));
+        }
+
+        for _ in 0..240 {
+            app.update();
+        }
+        std::mem::take(&mut app.world_mut().resource_mut::<PipelineEventLog>().0)
+    }
+
+    #[test]
+    fn decision_pipeline_emits_events_in_the_same_order_on_every_run() {
+        let first = decision_pipeline_event_log(11);
+        assert!(first.iter().any(|line| line.starts_with("change")), "the run must produce desire changes to compare");
+
+        for _ in 0..3 {
+            assert_eq!(decision_pipeline_event_log(11), first);
+        }
+    }
+
+    // =========================================================================
+    // WEATHER
+    // =========================================================================
+
+    /// Whether an observer facing +Y sees an agent `distance` units ahead under the given light level
+    fn is_seen_at_light_level(light_level: f32, distance: f32) -> bool {
+        let mut world = World::new();
+        world.insert_resource(Weather { light_level, ..default() });
+        world.insert_resource(WeatherEffects::default());
+
+        let observer = world
+            .spawn((Npc, Transform::default(), PerceivedEntities::default(), VisionRange { max_distance: 200.0, ..default() }))
+            .id();
+        world.spawn((Npc, Transform::from_xyz(0.0, distance, 0.0), ApparentState::default()));
+
+        world.run_system_once(vision_system).unwrap();
+        !world.get::<PerceivedEntities>(observer).unwrap().in_sight.is_empty()
+    }
+
+    #[test]
+    fn low_light_shrinks_effective_vision_range() {
+        assert!(is_seen_at_light_level(1.0, 150.0));
+        assert!(!is_seen_at_light_level(0.2, 150.0), "a 200-unit range must shrink below 150 units at dusk");
+        // Darkness is bounded: min_vision_fraction of the range always remains
+        assert!(is_seen_at_light_level(0.0, 50.0));
+    }
+
+    #[test]
+    fn vision_cone_of_90_degrees_spans_the_two_diagonals() {
+        let origin = Vec2::new(10.0, -5.0);
+        let points = calculate_vision_cone_points(origin, Vec2::Y, std::f32::consts::FRAC_PI_2, 100.0, 4);
+
+        // Observer, five arc vertices, back to the observer
+        assert_eq!(points.len(), 7);
+        assert_eq!(points[0], origin);
+        assert_eq!(points[6], origin);
+        let diagonal = 100.0 * std::f32::consts::FRAC_1_SQRT_2;
+        assert!(points[1].distance(origin + Vec2::new(-diagonal, diagonal)) < 1e-3, "left edge: {:?}", points[1]);
+        assert!(points[3].distance(origin + Vec2::new(0.0, 100.0)) < 1e-3, "centre: {:?}", points[3]);
+        assert!(points[5].distance(origin + Vec2::new(diagonal, diagonal)) < 1e-3, "right edge: {:?}", points[5]);
+    }
+
+    #[test]
+    fn each_visualization_mode_maps_known_agent_state_to_its_color() {
+        let needs = BasicNeeds { hunger: 0.0, thirst: 0.0, rest: 0.0, safety: 0.0, social: 0.0 };
+        let personality = Personality { openness: 0.5, extraversion: 1.0, agreeableness: 0.5, conscientiousness: 0.5, neuroticism: 0.0 };
+        let leader = SocialInfluence { score: 1.0, in_degree: 4, is_potential_leader: true };
+        let color = |mode: VisualizationMode, personality_trait: PersonalityTrait, desire: Desire, needs: &BasicNeeds, influence: Option<&SocialInfluence>| {
+            agent_tint_color(&AgentTint { mode, personality_trait }, desire, needs, &personality, influence).to_srgba()
+        };
+        let assert_srgb = |actual: Srgba, expected: [f32; 3]| {
+            let channels = [actual.red, actual.green, actual.blue];
+            assert!(channels.iter().zip(expected).all(|(a, e)| (a - e).abs() < 1e-5), "expected {expected:?}, got {channels:?}");
+        };
+
+        // Desire: fixed palette, one color per desire
+        let by_desire = |desire| color(VisualizationMode::ByDesire, PersonalityTrait::Extraversion, desire, &needs, None);
+        assert_srgb(by_desire(Desire::FindWater), [0.3, 0.6, 1.0]);
+        assert_ne!(by_desire(Desire::FindFood), by_desire(Desire::FindWater));
+
+        // Needs: red when deprived, green when satisfied
+        let satisfied = BasicNeeds { hunger: 1.0, thirst: 1.0, rest: 1.0, safety: 1.0, social: 1.0 };
+        assert_srgb(color(VisualizationMode::ByNeed, PersonalityTrait::Extraversion, Desire::Wander, &needs, None), [1.0, 0.2, 0.2]);
+        assert_srgb(color(VisualizationMode::ByNeed, PersonalityTrait::Extraversion, Desire::Wander, &satisfied, None), [0.2, 1.0, 0.2]);
+
+        // Personality: the chosen trait runs from cool blue to warm orange
+        assert_srgb(color(VisualizationMode::ByPersonality, PersonalityTrait::Extraversion, Desire::Wander, &needs, None), [1.0, 0.55, 0.2]);
+        assert_srgb(color(VisualizationMode::ByPersonality, PersonalityTrait::Neuroticism, Desire::Wander, &needs, None), [0.3, 0.5, 1.0]);
+
+        // Reputation: grey without standing, gold for a leader
+        assert_srgb(color(VisualizationMode::ByReputation, PersonalityTrait::Extraversion, Desire::Wander, &needs, None), [0.5, 0.5, 0.5]);
+        assert_srgb(color(VisualizationMode::ByReputation, PersonalityTrait::Extraversion, Desire::Wander, &needs, Some(&leader)), [1.0, 0.85, 0.2]);
+    }
+
+    #[test]
+    fn high_temperature_speeds_thirst_decay() {
+        let thirst_after_decay = |temperature: f32| {
+            let mut world = World::new();
+            world.init_resource::<Time>();
+            world.insert_resource(GameConstants::default());
+            world.init_resource::<NeedEventMode>();
+            world.init_resource::<NeedCouplingConfig>();
+            world.init_resource::<Events<NeedDecayEvent>>();
+            world.init_resource::<Events<NeedChangeEvent>>();
+            world.init_resource::<Events<NeedsChangedBatch>>();
+            world.insert_resource(Weather { temperature, ..default() });
+            world.insert_resource(WeatherEffects::default());
+            let npc = world.spawn((Npc, BasicNeeds { hunger: 1.0, thirst: 1.0, rest: 1.0, safety: 1.0, social: 1.0 })).id();
+
+            world.resource_mut::<Time>().advance_by(Duration::from_secs(10));
+            world.run_system_once(decay_basic_needs).unwrap();
+            let needs = *world.get::<BasicNeeds>(npc).unwrap();
+            (needs.thirst, needs.hunger)
+        };
+
+        let (mild_thirst, mild_hunger) = thirst_after_decay(20.0);
+        let (hot_thirst, hot_hunger) = thirst_after_decay(40.0);
+        assert!(hot_thirst < mild_thirst, "hot: {hot_thirst}, mild: {mild_thirst}");
+        assert_eq!(hot_hunger, mild_hunger, "temperature only strains thirst and rest");
+    }
+
+    #[test]
+    fn exhausted_agent_loses_hunger_faster_under_rest_coupling() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(GameConstants::default());
+        world.init_resource::<NeedEventMode>();
+        world.init_resource::<Events<NeedDecayEvent>>();
+        world.init_resource::<Events<NeedChangeEvent>>();
+        world.init_resource::<Events<NeedsChangedBatch>>();
+
+        // Hunger row, rest column: a fully exhausted agent gets hungry three times as fast
+        let mut coupling = NeedCouplingConfig::default();
+        coupling.matrix[0][2] = 2.0;
+        world.insert_resource(coupling);
+
+        let rested = world.spawn((Npc, BasicNeeds { hunger: 1.0, thirst: 1.0, rest: 1.0, safety: 1.0, social: 1.0 })).id();
+        let exhausted = world.spawn((Npc, BasicNeeds { hunger: 1.0, thirst: 1.0, rest: 0.1, safety: 1.0, social: 1.0 })).id();
+
+        world.resource_mut::<Time>().advance_by(Duration::from_secs(10));
+        world.run_system_once(decay_basic_needs).unwrap();
+
+        let rested_needs = *world.get::<BasicNeeds>(rested).unwrap();
+        let exhausted_needs = *world.get::<BasicNeeds>(exhausted).unwrap();
+        let rested_loss = 1.0 - rested_needs.hunger;
+        let exhausted_loss = 1.0 - exhausted_needs.hunger;
+        assert!(exhausted_loss > rested_loss, "exhausted: {exhausted_loss}, rested: {rested_loss}");
+        assert!((exhausted_loss - rested_loss * 2.8).abs() < 1e-5);
+        assert_eq!(exhausted_needs.thirst, rested_needs.thirst, "only hunger is coupled to rest");
+    }
+
+    #[test]
+    fn refilled_need_rises_while_other_needs_keep_decaying() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(GameConstants::default());
+        world.init_resource::<NeedCouplingConfig>();
+        world.init_resource::<NeedEventMode>();
+        world.init_resource::<Events<NeedDecayEvent>>();
+        world.init_resource::<Events<NeedChangeEvent>>();
+        world.init_resource::<Events<NeedsChangedBatch>>();
+
+        let well = world.spawn(Well::default()).id();
+        let npc = world.spawn((
+            Npc,
+            BasicNeeds { hunger: 0.8, thirst: 0.2, rest: 0.8, safety: 0.8, social: 0.8 },
+            RefillState { is_refilling: true, refill_start_time: 0.0, refill_duration: 10.0, resource_entity: Some(well) },
+        )).id();
+
+        // A refill slower than thirst decays: 0.001 per tick against 0.0015 of decay
+        for _ in 0..10 {
+            let before = *world.get::<BasicNeeds>(npc).unwrap();
+            world.resource_mut::<Time>().advance_by(Duration::from_millis(100));
+            world.run_system_once(decay_basic_needs).unwrap();
+            world.get_mut::<BasicNeeds>(npc).unwrap().thirst += 0.001;
+
+            let after = *world.get::<BasicNeeds>(npc).unwrap();
+            assert!(after.thirst > before.thirst, "the refilled need strictly increases");
+            assert!(after.hunger < before.hunger, "hunger keeps decaying");
+            assert!(after.rest < before.rest, "rest keeps decaying");
+        }
+
+        // Once the refill completes thirst decays again
+        world.get_mut::<RefillState>(npc).unwrap().is_refilling = false;
+        let before = world.get::<BasicNeeds>(npc).unwrap().thirst;
+        world.resource_mut::<Time>().advance_by(Duration::from_millis(100));
+        world.run_system_once(decay_basic_needs).unwrap();
+        assert!(world.get::<BasicNeeds>(npc).unwrap().thirst < before);
+    }
+
+    #[test]
+    fn agents_are_tagged_with_their_zone_and_counted() {
+        let mut world = World::new();
+        world.insert_resource(ZoneLayout {
+            zones: vec![
+                Zone::new("well", Vec2::new(-100.0, -100.0), Vec2::new(0.0, 0.0)),
+                Zone::new("plaza", Vec2::new(100.0, 100.0), Vec2::new(0.0, 0.0)),
+                Zone::new("market", Vec2::new(200.0, 200.0), Vec2::new(300.0, 300.0)),
+            ],
+            ..default()
+        });
+        world.init_resource::<ZoneOccupancy>();
+
+        let mut spawn_at = |x: f32, y: f32| {
+            world.spawn((Npc, Transform::from_xyz(x, y, 0.0), CurrentZone::default())).id()
+        };
+        let at_well = spawn_at(-50.0, -50.0);
+        let also_at_well = spawn_at(-10.0, -90.0);
+        let in_plaza = spawn_at(50.0, 20.0);
+        let outside = spawn_at(500.0, 0.0);
+
+        world.run_system_once(agent_zone_system).unwrap();
+
+        let zone_of = |world: &World, entity| world.get::<CurrentZone>(entity).unwrap().zone.clone();
+        assert_eq!(zone_of(&world, at_well).as_deref(), Some("well"));
+        assert_eq!(zone_of(&world, also_at_well).as_deref(), Some("well"));
+        assert_eq!(zone_of(&world, in_plaza).as_deref(), Some("plaza"));
+        assert_eq!(zone_of(&world, outside), None);
+
+        let occupancy = world.resource::<ZoneOccupancy>();
+        assert_eq!(occupancy.counts["well"], 2);
+        assert_eq!(occupancy.counts["plaza"], 1);
+        assert_eq!(occupancy.counts["market"], 0, "empty zones are still reported");
+        assert_eq!(occupancy.unzoned, 1);
+
+        // Moving an agent moves its count with it
+        world.get_mut::<Transform>(at_well).unwrap().translation = Vec3::new(250.0, 250.0, 0.0);
+        world.run_system_once(agent_zone_system).unwrap();
+        assert_eq!(zone_of(&world, at_well).as_deref(), Some("market"));
+        let occupancy = world.resource::<ZoneOccupancy>();
+        assert_eq!(occupancy.counts["well"], 1);
+        assert_eq!(occupancy.counts["market"], 1);
+    }
+
+    #[test]
+    fn zones_defined_in_a_scenario_file_tag_agents() {
+        let path = std::env::temp_dir().join(format!("scenario_zones_test_{}.ron", std::process::id()));
+        std::fs::write(&path, r#"(zones: [(name: "well", min: (-100, -100), max: (0, 0)), (name: "plaza", min: (0, 0), max: (250, 250))])"#).unwrap();
+
+        let mut world = World::new();
+        world.insert_resource(ScenarioFile { path: path.to_string_lossy().into_owned() });
+        world.insert_resource(GameConstants::default());
+        world.init_resource::<SimulationRng>();
+        world.init_resource::<ZoneLayout>();
+        world.init_resource::<ZoneOccupancy>();
+        world.run_system_once(load_scenario_system).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        let names: Vec<&str> = world.resource::<ZoneLayout>().zones.iter().map(|zone| zone.name.as_str()).collect();
+        assert_eq!(names, ["well", "plaza"]);
+
+        // The plaza spans several grid cells, and an agent far from its corner is still found through the grid
+        let at_well = world.spawn((Npc, Transform::from_xyz(-50.0, -50.0, 0.0), CurrentZone::default())).id();
+        let deep_in_plaza = world.spawn((Npc, Transform::from_xyz(240.0, 240.0, 0.0), CurrentZone::default())).id();
+        world.run_system_once(agent_zone_system).unwrap();
+
+        assert_eq!(world.get::<CurrentZone>(at_well).unwrap().zone.as_deref(), Some("well"));
+        assert_eq!(world.get::<CurrentZone>(deep_in_plaza).unwrap().zone.as_deref(), Some("plaza"));
+        assert_eq!(world.resource::<ZoneOccupancy>().counts["plaza"], 1);
+
+        let malformed = parse_scenario(r#"(zones: [(name: "well", min: (0, 0))])"#);
+        assert!(matches!(malformed, Err(ScenarioLoadError::Validation(_))), "a zone without a max corner is rejected");
+    }
+
+    // =========================================================================
+    // EXPERIMENTS
+    // =========================================================================
+
+    #[test]
+    fn identical_variants_match_and_faster_decay_lowers_satisfaction() {
+        let runner = ExperimentRunner::new(42, 300);
+        let mut baseline = ExperimentVariant::new("baseline");
+        baseline.game_constants.num_npcs = 12;
+
+        let mut repeat = baseline.clone();
+        repeat.name = "repeat".to_string();
+        let replay = runner.compare(&baseline, &repeat);
+        assert!(replay.baseline.ticks > 0);
+        assert_eq!(replay.baseline, replay.candidate, "the same seed and parameters must replay exactly");
+
+        let mut harsh = baseline.clone();
+        harsh.name = "harsh".to_string();
+        harsh.game_constants.hunger_decay *= 5.0;
+        harsh.game_constants.thirst_decay *= 5.0;
+        let comparison = runner.compare(&baseline, &harsh);
+        assert_ne!(comparison.baseline, comparison.candidate);
+        assert!(comparison.mean_satisfaction_delta < 0.0, "delta: {}", comparison.mean_satisfaction_delta);
+        assert_eq!(comparison.candidate_name, "harsh");
+    }
+
+    #[test]
+    fn disabling_the_social_domain_stops_all_social_interactions() {
+        let runner = ExperimentRunner::new(7, 300);
+        // A crowded room guarantees contacts
+        let mut crowded = ExperimentVariant::new("crowded");
+        crowded.game_constants.num_npcs = 60;
+        crowded.game_constants.room_half_extents = Vec2::splat(150.0);
+
+        let mut asocial = crowded.clone();
+        asocial.name = "asocial".to_string();
+        asocial.feature_flags = AiFeatureFlags { social: false, ..default() };
+
+        let comparison = runner.compare(&crowded, &asocial);
+        assert!(comparison.baseline.interactions_started > 0);
+        assert_eq!(comparison.candidate.interactions_started, 0);
+        assert_eq!(comparison.candidate.interactions_completed, 0, "no completed interaction, so no SocialInteractionEvent");
+    }
+
+    /// Golden event stream of the tiny replay scenario below, committed alongside the tests
+    /// Regenerate it after an intended behaviour change with `UPDATE_GOLDEN=1 cargo test golden_event_stream`
+    const REPLAY_GOLDEN_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden/replay_tiny.events");
+
+    #[test]
+    fn seeded_headless_run_replays_the_golden_event_stream() {
+        let runner = ExperimentRunner::new(2024, 240);
+        let mut tiny = ExperimentVariant::new("replay_golden");
+        tiny.game_constants.num_npcs = 8;
+        tiny.game_constants.room_half_extents = Vec2::splat(120.0);
+        let recorded = runner.record_event_stream(&tiny);
+        assert!(!recorded.is_empty(), "the scenario must emit events to compare");
+
+        let golden_path = std::path::Path::new(REPLAY_GOLDEN_PATH);
+        if std::env::var_os("UPDATE_GOLDEN").is_some() {
+            std::fs::create_dir_all(golden_path.parent().unwrap()).unwrap();
+            std::fs::write(golden_path, recorded.join("\n") + "\n").unwrap();
+            eprintln!("wrote golden event stream to {}", golden_path.display());
+            return;
+        }
+
+        // A missing golden file is a failure, never a silent first recording
+        let golden: Vec<String> = std::fs::read_to_string(golden_path)
+            .unwrap_or_else(|error| panic!("cannot read {}: {error}; record it with UPDATE_GOLDEN=1", golden_path.display()))
+            .lines()
+            .map(str::to_string)
+            .collect();
+        if let Some(line) = first_event_stream_divergence(&golden, &recorded) {
+            panic!(
+                "event stream diverged from {} at line {}\n  golden:   {}\n  recorded: {}",
+                golden_path.display(),
+                line + 1,
+                golden.get(line).map_or("<end of stream>", String::as_str),
+                recorded.get(line).map_or("<end of stream>", String::as_str),
+            );
+        }
+    }
+}